@@ -0,0 +1,1350 @@
+//! This file defines abilities, the passive effects every Pokemon has exactly one of. There are 233
+//! of them as of Generation VII, from Stench to Neuroforce, and they're listed here in the order of
+//! their internal index numbers, with
+//! [Bulbapedia](https://bulbapedia.bulbagarden.net/wiki/Ability#List_of_Abilities) as a reference.
+//!
+//! The in-game names are used for displaying and parsing, with the spaces and punctuation removed
+//! as an alternative way of parsing them: both "Shields Down" and "ShieldsDown" parse as
+//! `Ability::ShieldsDown`, and the former is what gets displayed.
+//!
+//! This file also records which abilities each species can legally have, current to Gen VII, using
+//! the same reference.
+
+use crate::species::*;
+
+/// A Pokemon ability. Note that this doesn't record anything about what the ability actually does:
+/// that's the job of the code that uses abilities, like damage calculation.
+#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+pub enum Ability {
+    Stench,
+    Drizzle,
+    #[strum(serialize="Speed Boost", serialize="SpeedBoost")]
+    SpeedBoost,
+    #[strum(serialize="Battle Armor", serialize="BattleArmor")]
+    BattleArmor,
+    Sturdy,
+    Damp,
+    Limber,
+    #[strum(serialize="Sand Veil", serialize="SandVeil")]
+    SandVeil,
+    Static,
+    #[strum(serialize="Volt Absorb", serialize="VoltAbsorb")]
+    VoltAbsorb,
+    #[strum(serialize="Water Absorb", serialize="WaterAbsorb")]
+    WaterAbsorb,
+    Oblivious,
+    #[strum(serialize="Cloud Nine", serialize="CloudNine")]
+    CloudNine,
+    #[strum(serialize="Compound Eyes", serialize="CompoundEyes")]
+    CompoundEyes,
+    Insomnia,
+    #[strum(serialize="Color Change", serialize="ColorChange")]
+    ColorChange,
+    Immunity,
+    #[strum(serialize="Flash Fire", serialize="FlashFire")]
+    FlashFire,
+    #[strum(serialize="Shield Dust", serialize="ShieldDust")]
+    ShieldDust,
+    #[strum(serialize="Own Tempo", serialize="OwnTempo")]
+    OwnTempo,
+    #[strum(serialize="Suction Cups", serialize="SuctionCups")]
+    SuctionCups,
+    Intimidate,
+    #[strum(serialize="Shadow Tag", serialize="ShadowTag")]
+    ShadowTag,
+    #[strum(serialize="Rough Skin", serialize="RoughSkin")]
+    RoughSkin,
+    #[strum(serialize="Wonder Guard", serialize="WonderGuard")]
+    WonderGuard,
+    Levitate,
+    #[strum(serialize="Effect Spore", serialize="EffectSpore")]
+    EffectSpore,
+    Synchronize,
+    #[strum(serialize="Clear Body", serialize="ClearBody")]
+    ClearBody,
+    #[strum(serialize="Natural Cure", serialize="NaturalCure")]
+    NaturalCure,
+    #[strum(serialize="Lightning Rod", serialize="LightningRod")]
+    LightningRod,
+    #[strum(serialize="Serene Grace", serialize="SereneGrace")]
+    SereneGrace,
+    #[strum(serialize="Swift Swim", serialize="SwiftSwim")]
+    SwiftSwim,
+    Chlorophyll,
+    Illuminate,
+    Trace,
+    #[strum(serialize="Huge Power", serialize="HugePower")]
+    HugePower,
+    #[strum(serialize="Poison Point", serialize="PoisonPoint")]
+    PoisonPoint,
+    #[strum(serialize="Inner Focus", serialize="InnerFocus")]
+    InnerFocus,
+    #[strum(serialize="Magma Armor", serialize="MagmaArmor")]
+    MagmaArmor,
+    #[strum(serialize="Water Veil", serialize="WaterVeil")]
+    WaterVeil,
+    #[strum(serialize="Magnet Pull", serialize="MagnetPull")]
+    MagnetPull,
+    Soundproof,
+    #[strum(serialize="Rain Dish", serialize="RainDish")]
+    RainDish,
+    #[strum(serialize="Sand Stream", serialize="SandStream")]
+    SandStream,
+    Pressure,
+    #[strum(serialize="Thick Fat", serialize="ThickFat")]
+    ThickFat,
+    #[strum(serialize="Early Bird", serialize="EarlyBird")]
+    EarlyBird,
+    #[strum(serialize="Flame Body", serialize="FlameBody")]
+    FlameBody,
+    #[strum(serialize="Run Away", serialize="RunAway")]
+    RunAway,
+    #[strum(serialize="Keen Eye", serialize="KeenEye")]
+    KeenEye,
+    #[strum(serialize="Hyper Cutter", serialize="HyperCutter")]
+    HyperCutter,
+    Pickup,
+    Truant,
+    Hustle,
+    #[strum(serialize="Cute Charm", serialize="CuteCharm")]
+    CuteCharm,
+    Plus,
+    Minus,
+    Forecast,
+    #[strum(serialize="Sticky Hold", serialize="StickyHold")]
+    StickyHold,
+    #[strum(serialize="Shed Skin", serialize="ShedSkin")]
+    ShedSkin,
+    Guts,
+    #[strum(serialize="Marvel Scale", serialize="MarvelScale")]
+    MarvelScale,
+    #[strum(serialize="Liquid Ooze", serialize="LiquidOoze")]
+    LiquidOoze,
+    Overgrow,
+    Blaze,
+    Torrent,
+    Swarm,
+    #[strum(serialize="Rock Head", serialize="RockHead")]
+    RockHead,
+    Drought,
+    #[strum(serialize="Arena Trap", serialize="ArenaTrap")]
+    ArenaTrap,
+    #[strum(serialize="Vital Spirit", serialize="VitalSpirit")]
+    VitalSpirit,
+    #[strum(serialize="White Smoke", serialize="WhiteSmoke")]
+    WhiteSmoke,
+    #[strum(serialize="Pure Power", serialize="PurePower")]
+    PurePower,
+    #[strum(serialize="Shell Armor", serialize="ShellArmor")]
+    ShellArmor,
+    #[strum(serialize="Air Lock", serialize="AirLock")]
+    AirLock,
+    #[strum(serialize="Tangled Feet", serialize="TangledFeet")]
+    TangledFeet,
+    #[strum(serialize="Motor Drive", serialize="MotorDrive")]
+    MotorDrive,
+    Rivalry,
+    Steadfast,
+    #[strum(serialize="Snow Cloak", serialize="SnowCloak")]
+    SnowCloak,
+    Gluttony,
+    #[strum(serialize="Anger Point", serialize="AngerPoint")]
+    AngerPoint,
+    Unburden,
+    Heatproof,
+    Simple,
+    #[strum(serialize="Dry Skin", serialize="DrySkin")]
+    DrySkin,
+    Download,
+    #[strum(serialize="Iron Fist", serialize="IronFist")]
+    IronFist,
+    #[strum(serialize="Poison Heal", serialize="PoisonHeal")]
+    PoisonHeal,
+    Adaptability,
+    #[strum(serialize="Skill Link", serialize="SkillLink")]
+    SkillLink,
+    Hydration,
+    #[strum(serialize="Solar Power", serialize="SolarPower")]
+    SolarPower,
+    #[strum(serialize="Quick Feet", serialize="QuickFeet")]
+    QuickFeet,
+    Normalize,
+    Sniper,
+    #[strum(serialize="Magic Guard", serialize="MagicGuard")]
+    MagicGuard,
+    #[strum(serialize="No Guard", serialize="NoGuard")]
+    NoGuard,
+    Stall,
+    Technician,
+    #[strum(serialize="Leaf Guard", serialize="LeafGuard")]
+    LeafGuard,
+    Klutz,
+    #[strum(serialize="Mold Breaker", serialize="MoldBreaker")]
+    MoldBreaker,
+    #[strum(serialize="Super Luck", serialize="SuperLuck")]
+    SuperLuck,
+    Aftermath,
+    Anticipation,
+    Forewarn,
+    Unaware,
+    #[strum(serialize="Tinted Lens", serialize="TintedLens")]
+    TintedLens,
+    Filter,
+    #[strum(serialize="Slow Start", serialize="SlowStart")]
+    SlowStart,
+    Scrappy,
+    #[strum(serialize="Storm Drain", serialize="StormDrain")]
+    StormDrain,
+    #[strum(serialize="Ice Body", serialize="IceBody")]
+    IceBody,
+    #[strum(serialize="Solid Rock", serialize="SolidRock")]
+    SolidRock,
+    #[strum(serialize="Snow Warning", serialize="SnowWarning")]
+    SnowWarning,
+    #[strum(serialize="Honey Gather", serialize="HoneyGather")]
+    HoneyGather,
+    Frisk,
+    Reckless,
+    Multitype,
+    #[strum(serialize="Flower Gift", serialize="FlowerGift")]
+    FlowerGift,
+    #[strum(serialize="Bad Dreams", serialize="BadDreams")]
+    BadDreams,
+    Pickpocket,
+    #[strum(serialize="Sheer Force", serialize="SheerForce")]
+    SheerForce,
+    Contrary,
+    Unnerve,
+    Defiant,
+    Defeatist,
+    #[strum(serialize="Cursed Body", serialize="CursedBody")]
+    CursedBody,
+    Healer,
+    #[strum(serialize="Friend Guard", serialize="FriendGuard")]
+    FriendGuard,
+    #[strum(serialize="Weak Armor", serialize="WeakArmor")]
+    WeakArmor,
+    #[strum(serialize="Heavy Metal", serialize="HeavyMetal")]
+    HeavyMetal,
+    #[strum(serialize="Light Metal", serialize="LightMetal")]
+    LightMetal,
+    Multiscale,
+    #[strum(serialize="Toxic Boost", serialize="ToxicBoost")]
+    ToxicBoost,
+    #[strum(serialize="Flare Boost", serialize="FlareBoost")]
+    FlareBoost,
+    Harvest,
+    Telepathy,
+    Moody,
+    Overcoat,
+    #[strum(serialize="Poison Touch", serialize="PoisonTouch")]
+    PoisonTouch,
+    Regenerator,
+    #[strum(serialize="Big Pecks", serialize="BigPecks")]
+    BigPecks,
+    #[strum(serialize="Sand Rush", serialize="SandRush")]
+    SandRush,
+    #[strum(serialize="Wonder Skin", serialize="WonderSkin")]
+    WonderSkin,
+    Analytic,
+    Illusion,
+    Imposter,
+    Infiltrator,
+    Mummy,
+    Moxie,
+    Justified,
+    Rattled,
+    #[strum(serialize="Magic Bounce", serialize="MagicBounce")]
+    MagicBounce,
+    #[strum(serialize="Sap Sipper", serialize="SapSipper")]
+    SapSipper,
+    Prankster,
+    #[strum(serialize="Sand Force", serialize="SandForce")]
+    SandForce,
+    #[strum(serialize="Iron Barbs", serialize="IronBarbs")]
+    IronBarbs,
+    #[strum(serialize="Zen Mode", serialize="ZenMode")]
+    ZenMode,
+    #[strum(serialize="Victory Star", serialize="VictoryStar")]
+    VictoryStar,
+    Turboblaze,
+    Teravolt,
+    #[strum(serialize="Aroma Veil", serialize="AromaVeil")]
+    AromaVeil,
+    #[strum(serialize="Flower Veil", serialize="FlowerVeil")]
+    FlowerVeil,
+    #[strum(serialize="Cheek Pouch", serialize="CheekPouch")]
+    CheekPouch,
+    Protean,
+    #[strum(serialize="Fur Coat", serialize="FurCoat")]
+    FurCoat,
+    Magician,
+    Bulletproof,
+    Competitive,
+    #[strum(serialize="Strong Jaw", serialize="StrongJaw")]
+    StrongJaw,
+    Refrigerate,
+    #[strum(serialize="Sweet Veil", serialize="SweetVeil")]
+    SweetVeil,
+    #[strum(serialize="Stance Change", serialize="StanceChange")]
+    StanceChange,
+    #[strum(serialize="Gale Wings", serialize="GaleWings")]
+    GaleWings,
+    #[strum(serialize="Mega Launcher", serialize="MegaLauncher")]
+    MegaLauncher,
+    #[strum(serialize="Grass Pelt", serialize="GrassPelt")]
+    GrassPelt,
+    Symbiosis,
+    #[strum(serialize="Tough Claws", serialize="ToughClaws")]
+    ToughClaws,
+    Pixilate,
+    Gooey,
+    Aerilate,
+    #[strum(serialize="Parental Bond", serialize="ParentalBond")]
+    ParentalBond,
+    #[strum(serialize="Dark Aura", serialize="DarkAura")]
+    DarkAura,
+    #[strum(serialize="Fairy Aura", serialize="FairyAura")]
+    FairyAura,
+    #[strum(serialize="Aura Break", serialize="AuraBreak")]
+    AuraBreak,
+    #[strum(serialize="Primordial Sea", serialize="PrimordialSea")]
+    PrimordialSea,
+    #[strum(serialize="Desolate Land", serialize="DesolateLand")]
+    DesolateLand,
+    #[strum(serialize="Delta Stream", serialize="DeltaStream")]
+    DeltaStream,
+    Stamina,
+    #[strum(serialize="Wimp Out", serialize="WimpOut")]
+    WimpOut,
+    #[strum(serialize="Emergency Exit", serialize="EmergencyExit")]
+    EmergencyExit,
+    #[strum(serialize="Water Compaction", serialize="WaterCompaction")]
+    WaterCompaction,
+    Merciless,
+    #[strum(serialize="Shields Down", serialize="ShieldsDown")]
+    ShieldsDown,
+    Stakeout,
+    #[strum(serialize="Water Bubble", serialize="WaterBubble")]
+    WaterBubble,
+    Steelworker,
+    Berserk,
+    #[strum(serialize="Slush Rush", serialize="SlushRush")]
+    SlushRush,
+    #[strum(serialize="Long Reach", serialize="LongReach")]
+    LongReach,
+    #[strum(serialize="Liquid Voice", serialize="LiquidVoice")]
+    LiquidVoice,
+    Triage,
+    Galvanize,
+    #[strum(serialize="Surge Surfer", serialize="SurgeSurfer")]
+    SurgeSurfer,
+    Schooling,
+    Disguise,
+    #[strum(serialize="Battle Bond", serialize="BattleBond")]
+    BattleBond,
+    #[strum(serialize="Power Construct", serialize="PowerConstruct")]
+    PowerConstruct,
+    Corrosion,
+    Comatose,
+    #[strum(serialize="Queenly Majesty", serialize="QueenlyMajesty")]
+    QueenlyMajesty,
+    #[strum(serialize="Innards Out", serialize="InnardsOut")]
+    InnardsOut,
+    Dancer,
+    Battery,
+    Fluffy,
+    Dazzling,
+    #[strum(serialize="Soul-Heart", serialize="SoulHeart")]
+    SoulHeart,
+    #[strum(serialize="Tangling Hair", serialize="TanglingHair")]
+    TanglingHair,
+    Receiver,
+    #[strum(serialize="Power of Alchemy", serialize="PowerOfAlchemy")]
+    PowerOfAlchemy,
+    #[strum(serialize="Beast Boost", serialize="BeastBoost")]
+    BeastBoost,
+    #[strum(serialize="RKS System", serialize="RKSSystem")]
+    RKSSystem,
+    #[strum(serialize="Electric Surge", serialize="ElectricSurge")]
+    ElectricSurge,
+    #[strum(serialize="Psychic Surge", serialize="PsychicSurge")]
+    PsychicSurge,
+    #[strum(serialize="Misty Surge", serialize="MistySurge")]
+    MistySurge,
+    #[strum(serialize="Grassy Surge", serialize="GrassySurge")]
+    GrassySurge,
+    #[strum(serialize="Full Metal Body", serialize="FullMetalBody")]
+    FullMetalBody,
+    #[strum(serialize="Shadow Shield", serialize="ShadowShield")]
+    ShadowShield,
+    #[strum(serialize="Prism Armor", serialize="PrismArmor")]
+    PrismArmor,
+    Neuroforce,
+}
+
+
+/// Returns every ability a Pokemon of the given species and forme can legally have. The ordinary
+/// abilities come first, in their in-game slot order, and the hidden ability, if there is one, is
+/// always last. Formes are respected: Giratina-Altered has Pressure and Telepathy, but
+/// Giratina-Origin only ever has Levitate, and Mega Evolutions only have their single Mega ability.
+///
+/// Meowstic's hidden ability depends on its gender, which this library doesn't track, so the male
+/// one (Prankster) is used.
+pub fn abilities(species: Species) -> Vec<Ability> {
+    let (regular, hidden) = ability_slots(species);
+    let mut all = regular.to_vec();
+    if let Some(ability) = hidden {
+        all.push(ability);
+    }
+    all
+}
+
+/// Returns the hidden ability of the given species and forme, or None if it doesn't have one, as is
+/// the case for most legendaries and every Mega Evolution.
+pub fn hidden_ability(species: Species) -> Option<Ability> {
+    ability_slots(species).1
+}
+
+/// Returns the ordinary abilities and the hidden ability of a species separately.
+fn ability_slots(species: Species) -> (&'static [Ability], Option<Ability>) {
+    match species {
+        // in National Pokedex order
+        Species::Bulbasaur => (&[Ability::Overgrow], Some(Ability::Chlorophyll)),
+        Species::Ivysaur => (&[Ability::Overgrow], Some(Ability::Chlorophyll)),
+        Species::Venusaur(MegaEvolution::Normal) => (&[Ability::Overgrow], Some(Ability::Chlorophyll)),
+        Species::Venusaur(MegaEvolution::Mega) => (&[Ability::ThickFat], None),
+        Species::Charmander => (&[Ability::Blaze], Some(Ability::SolarPower)),
+        Species::Charmeleon => (&[Ability::Blaze], Some(Ability::SolarPower)),
+        Species::Charizard(XYMegaEvolution::Normal) => (&[Ability::Blaze], Some(Ability::SolarPower)),
+        Species::Charizard(XYMegaEvolution::MegaX) => (&[Ability::ToughClaws], None),
+        Species::Charizard(XYMegaEvolution::MegaY) => (&[Ability::Drought], None),
+        Species::Squirtle => (&[Ability::Torrent], Some(Ability::RainDish)),
+        Species::Wartortle => (&[Ability::Torrent], Some(Ability::RainDish)),
+        Species::Blastoise(MegaEvolution::Normal) => (&[Ability::Torrent], Some(Ability::RainDish)),
+        Species::Blastoise(MegaEvolution::Mega) => (&[Ability::MegaLauncher], None),
+        Species::Caterpie => (&[Ability::ShieldDust], Some(Ability::RunAway)),
+        Species::Metapod => (&[Ability::ShedSkin], None),
+        Species::Butterfree => (&[Ability::CompoundEyes], Some(Ability::TintedLens)),
+        Species::Weedle => (&[Ability::ShieldDust], Some(Ability::RunAway)),
+        Species::Kakuna => (&[Ability::ShedSkin], None),
+        Species::Beedrill(MegaEvolution::Normal) => (&[Ability::Swarm], Some(Ability::Sniper)),
+        Species::Beedrill(MegaEvolution::Mega) => (&[Ability::Adaptability], None),
+        Species::Pidgey => (&[Ability::KeenEye, Ability::TangledFeet], Some(Ability::BigPecks)),
+        Species::Pidgeotto => (&[Ability::KeenEye, Ability::TangledFeet], Some(Ability::BigPecks)),
+        Species::Pidgeot(MegaEvolution::Normal) => (&[Ability::KeenEye, Ability::TangledFeet], Some(Ability::BigPecks)),
+        Species::Pidgeot(MegaEvolution::Mega) => (&[Ability::NoGuard], None),
+        Species::Rattata(AlolaForme::Normal) => (&[Ability::RunAway, Ability::Guts], Some(Ability::Hustle)),
+        Species::Rattata(AlolaForme::Alola) => (&[Ability::Gluttony, Ability::Hustle], Some(Ability::ThickFat)),
+        Species::Raticate(AlolaForme::Normal) => (&[Ability::RunAway, Ability::Guts], Some(Ability::Hustle)),
+        Species::Raticate(AlolaForme::Alola) => (&[Ability::Gluttony, Ability::Hustle], Some(Ability::ThickFat)),
+        Species::Spearow => (&[Ability::KeenEye], Some(Ability::Sniper)),
+        Species::Fearow => (&[Ability::KeenEye], Some(Ability::Sniper)),
+        Species::Ekans => (&[Ability::Intimidate, Ability::ShedSkin], Some(Ability::Unnerve)),
+        Species::Arbok => (&[Ability::Intimidate, Ability::ShedSkin], Some(Ability::Unnerve)),
+        Species::Pikachu => (&[Ability::Static], Some(Ability::LightningRod)),
+        Species::Raichu(AlolaForme::Normal) => (&[Ability::Static], Some(Ability::LightningRod)),
+        Species::Raichu(AlolaForme::Alola) => (&[Ability::SurgeSurfer], None),
+        Species::Sandshrew(AlolaForme::Normal) => (&[Ability::SandVeil], Some(Ability::SandRush)),
+        Species::Sandshrew(AlolaForme::Alola) => (&[Ability::SnowCloak], Some(Ability::SlushRush)),
+        Species::Sandslash(AlolaForme::Normal) => (&[Ability::SandVeil], Some(Ability::SandRush)),
+        Species::Sandslash(AlolaForme::Alola) => (&[Ability::SnowCloak], Some(Ability::SlushRush)),
+        Species::NidoranF => (&[Ability::PoisonPoint, Ability::Rivalry], Some(Ability::Hustle)),
+        Species::Nidorina => (&[Ability::PoisonPoint, Ability::Rivalry], Some(Ability::Hustle)),
+        Species::Nidoqueen => (&[Ability::PoisonPoint, Ability::Rivalry], Some(Ability::SheerForce)),
+        Species::NidoranM => (&[Ability::PoisonPoint, Ability::Rivalry], Some(Ability::Hustle)),
+        Species::Nidorino => (&[Ability::PoisonPoint, Ability::Rivalry], Some(Ability::Hustle)),
+        Species::Nidoking => (&[Ability::PoisonPoint, Ability::Rivalry], Some(Ability::SheerForce)),
+        Species::Clefairy => (&[Ability::CuteCharm, Ability::MagicGuard], Some(Ability::FriendGuard)),
+        Species::Clefable => (&[Ability::CuteCharm, Ability::MagicGuard], Some(Ability::Unaware)),
+        Species::Vulpix(AlolaForme::Normal) => (&[Ability::FlashFire], Some(Ability::Drought)),
+        Species::Vulpix(AlolaForme::Alola) => (&[Ability::SnowCloak], Some(Ability::SnowWarning)),
+        Species::Ninetales(AlolaForme::Normal) => (&[Ability::FlashFire], Some(Ability::Drought)),
+        Species::Ninetales(AlolaForme::Alola) => (&[Ability::SnowCloak], Some(Ability::SnowWarning)),
+        Species::Jigglypuff => (&[Ability::CuteCharm, Ability::Competitive], Some(Ability::FriendGuard)),
+        Species::Wigglytuff => (&[Ability::CuteCharm, Ability::Competitive], Some(Ability::Frisk)),
+        Species::Zubat => (&[Ability::InnerFocus], Some(Ability::Infiltrator)),
+        Species::Golbat => (&[Ability::InnerFocus], Some(Ability::Infiltrator)),
+        Species::Oddish => (&[Ability::Chlorophyll], Some(Ability::RunAway)),
+        Species::Gloom => (&[Ability::Chlorophyll], Some(Ability::Stench)),
+        Species::Vileplume => (&[Ability::Chlorophyll], Some(Ability::EffectSpore)),
+        Species::Paras => (&[Ability::EffectSpore, Ability::DrySkin], Some(Ability::Damp)),
+        Species::Parasect => (&[Ability::EffectSpore, Ability::DrySkin], Some(Ability::Damp)),
+        Species::Venonat => (&[Ability::CompoundEyes, Ability::TintedLens], Some(Ability::RunAway)),
+        Species::Venomoth => (&[Ability::ShieldDust, Ability::TintedLens], Some(Ability::WonderSkin)),
+        Species::Diglett(AlolaForme::Normal) => (&[Ability::SandVeil, Ability::ArenaTrap], Some(Ability::SandForce)),
+        Species::Diglett(AlolaForme::Alola) => (&[Ability::SandVeil, Ability::TanglingHair], Some(Ability::SandForce)),
+        Species::Dugtrio(AlolaForme::Normal) => (&[Ability::SandVeil, Ability::ArenaTrap], Some(Ability::SandForce)),
+        Species::Dugtrio(AlolaForme::Alola) => (&[Ability::SandVeil, Ability::TanglingHair], Some(Ability::SandForce)),
+        Species::Meowth => (&[Ability::Pickup, Ability::Technician], Some(Ability::Unnerve)),
+        Species::Persian(AlolaForme::Normal) => (&[Ability::Limber, Ability::Technician], Some(Ability::Unnerve)),
+        Species::Persian(AlolaForme::Alola) => (&[Ability::FurCoat, Ability::Technician], Some(Ability::Rattled)),
+        Species::Psyduck => (&[Ability::Damp, Ability::CloudNine], Some(Ability::SwiftSwim)),
+        Species::Golduck => (&[Ability::Damp, Ability::CloudNine], Some(Ability::SwiftSwim)),
+        Species::Mankey => (&[Ability::VitalSpirit, Ability::AngerPoint], Some(Ability::Defiant)),
+        Species::Primeape => (&[Ability::VitalSpirit, Ability::AngerPoint], Some(Ability::Defiant)),
+        Species::Growlithe => (&[Ability::Intimidate, Ability::FlashFire], Some(Ability::Justified)),
+        Species::Arcanine => (&[Ability::Intimidate, Ability::FlashFire], Some(Ability::Justified)),
+        Species::Poliwag => (&[Ability::WaterAbsorb, Ability::Damp], Some(Ability::SwiftSwim)),
+        Species::Poliwhirl => (&[Ability::WaterAbsorb, Ability::Damp], Some(Ability::SwiftSwim)),
+        Species::Poliwrath => (&[Ability::WaterAbsorb, Ability::Damp], Some(Ability::SwiftSwim)),
+        Species::Abra => (&[Ability::Synchronize, Ability::InnerFocus], Some(Ability::MagicGuard)),
+        Species::Kadabra => (&[Ability::Synchronize, Ability::InnerFocus], Some(Ability::MagicGuard)),
+        Species::Alakazam(MegaEvolution::Normal) => (&[Ability::Synchronize, Ability::InnerFocus], Some(Ability::MagicGuard)),
+        Species::Alakazam(MegaEvolution::Mega) => (&[Ability::Trace], None),
+        Species::Machop => (&[Ability::Guts, Ability::NoGuard], Some(Ability::Steadfast)),
+        Species::Machoke => (&[Ability::Guts, Ability::NoGuard], Some(Ability::Steadfast)),
+        Species::Machamp => (&[Ability::Guts, Ability::NoGuard], Some(Ability::Steadfast)),
+        Species::Bellsprout => (&[Ability::Chlorophyll], Some(Ability::Gluttony)),
+        Species::Weepinbell => (&[Ability::Chlorophyll], Some(Ability::Gluttony)),
+        Species::Victreebel => (&[Ability::Chlorophyll], Some(Ability::Gluttony)),
+        Species::Tentacool => (&[Ability::ClearBody, Ability::LiquidOoze], Some(Ability::RainDish)),
+        Species::Tentacruel => (&[Ability::ClearBody, Ability::LiquidOoze], Some(Ability::RainDish)),
+        Species::Geodude(AlolaForme::Normal) => (&[Ability::RockHead, Ability::Sturdy], Some(Ability::SandVeil)),
+        Species::Geodude(AlolaForme::Alola) => (&[Ability::MagnetPull, Ability::Sturdy], Some(Ability::Galvanize)),
+        Species::Graveler(AlolaForme::Normal) => (&[Ability::RockHead, Ability::Sturdy], Some(Ability::SandVeil)),
+        Species::Graveler(AlolaForme::Alola) => (&[Ability::MagnetPull, Ability::Sturdy], Some(Ability::Galvanize)),
+        Species::Golem(AlolaForme::Normal) => (&[Ability::RockHead, Ability::Sturdy], Some(Ability::SandVeil)),
+        Species::Golem(AlolaForme::Alola) => (&[Ability::MagnetPull, Ability::Sturdy], Some(Ability::Galvanize)),
+        Species::Ponyta => (&[Ability::RunAway, Ability::FlashFire], Some(Ability::FlameBody)),
+        Species::Rapidash => (&[Ability::RunAway, Ability::FlashFire], Some(Ability::FlameBody)),
+        Species::Slowpoke => (&[Ability::Oblivious, Ability::OwnTempo], Some(Ability::Regenerator)),
+        Species::Slowbro => (&[Ability::Oblivious, Ability::OwnTempo], Some(Ability::Regenerator)),
+        Species::Magnemite => (&[Ability::MagnetPull, Ability::Sturdy], Some(Ability::Analytic)),
+        Species::Magneton => (&[Ability::MagnetPull, Ability::Sturdy], Some(Ability::Analytic)),
+        Species::Farfetchd => (&[Ability::KeenEye, Ability::InnerFocus], Some(Ability::Defiant)),
+        Species::Doduo => (&[Ability::RunAway, Ability::EarlyBird], Some(Ability::TangledFeet)),
+        Species::Dodrio => (&[Ability::RunAway, Ability::EarlyBird], Some(Ability::TangledFeet)),
+        Species::Seel => (&[Ability::ThickFat, Ability::Hydration], Some(Ability::IceBody)),
+        Species::Dewgong => (&[Ability::ThickFat, Ability::Hydration], Some(Ability::IceBody)),
+        Species::Grimer(AlolaForme::Normal) => (&[Ability::Stench, Ability::StickyHold], Some(Ability::PoisonTouch)),
+        Species::Grimer(AlolaForme::Alola) => (&[Ability::PoisonTouch, Ability::Gluttony], Some(Ability::PowerOfAlchemy)),
+        Species::Muk(AlolaForme::Normal) => (&[Ability::Stench, Ability::StickyHold], Some(Ability::PoisonTouch)),
+        Species::Muk(AlolaForme::Alola) => (&[Ability::PoisonTouch, Ability::Gluttony], Some(Ability::PowerOfAlchemy)),
+        Species::Shellder => (&[Ability::ShellArmor, Ability::SkillLink], Some(Ability::Overcoat)),
+        Species::Cloyster => (&[Ability::ShellArmor, Ability::SkillLink], Some(Ability::Overcoat)),
+        Species::Gastly => (&[Ability::Levitate], None),
+        Species::Haunter => (&[Ability::Levitate], None),
+        Species::Gengar(MegaEvolution::Normal) => (&[Ability::CursedBody], None),
+        Species::Gengar(MegaEvolution::Mega) => (&[Ability::ShadowTag], None),
+        Species::Onix => (&[Ability::RockHead, Ability::Sturdy], Some(Ability::WeakArmor)),
+        Species::Drowzee => (&[Ability::Insomnia, Ability::Forewarn], Some(Ability::InnerFocus)),
+        Species::Hypno => (&[Ability::Insomnia, Ability::Forewarn], Some(Ability::InnerFocus)),
+        Species::Krabby => (&[Ability::HyperCutter, Ability::ShellArmor], Some(Ability::SheerForce)),
+        Species::Kingler => (&[Ability::HyperCutter, Ability::ShellArmor], Some(Ability::SheerForce)),
+        Species::Voltorb => (&[Ability::Soundproof, Ability::Static], Some(Ability::Aftermath)),
+        Species::Electrode => (&[Ability::Soundproof, Ability::Static], Some(Ability::Aftermath)),
+        Species::Exeggcute => (&[Ability::Chlorophyll], Some(Ability::Harvest)),
+        Species::Exeggutor(AlolaForme::Normal) => (&[Ability::Chlorophyll], Some(Ability::Harvest)),
+        Species::Exeggutor(AlolaForme::Alola) => (&[Ability::Frisk], Some(Ability::Harvest)),
+        Species::Cubone => (&[Ability::RockHead, Ability::LightningRod], Some(Ability::BattleArmor)),
+        Species::Marowak(AlolaForme::Normal) => (&[Ability::RockHead, Ability::LightningRod], Some(Ability::BattleArmor)),
+        Species::Marowak(AlolaForme::Alola) => (&[Ability::CursedBody, Ability::LightningRod], Some(Ability::RockHead)),
+        Species::Hitmonlee => (&[Ability::Limber, Ability::Reckless], Some(Ability::Unburden)),
+        Species::Hitmonchan => (&[Ability::KeenEye, Ability::IronFist], Some(Ability::InnerFocus)),
+        Species::Lickitung => (&[Ability::OwnTempo, Ability::Oblivious], Some(Ability::CloudNine)),
+        Species::Koffing => (&[Ability::Levitate], None),
+        Species::Weezing => (&[Ability::Levitate], None),
+        Species::Rhyhorn => (&[Ability::LightningRod, Ability::RockHead], Some(Ability::Reckless)),
+        Species::Rhydon => (&[Ability::LightningRod, Ability::RockHead], Some(Ability::Reckless)),
+        Species::Chansey => (&[Ability::NaturalCure, Ability::SereneGrace], Some(Ability::Healer)),
+        Species::Tangela => (&[Ability::Chlorophyll, Ability::LeafGuard], Some(Ability::Regenerator)),
+        Species::Kangaskhan(MegaEvolution::Normal) => (&[Ability::EarlyBird, Ability::Scrappy], Some(Ability::InnerFocus)),
+        Species::Kangaskhan(MegaEvolution::Mega) => (&[Ability::ParentalBond], None),
+        Species::Horsea => (&[Ability::SwiftSwim, Ability::Sniper], Some(Ability::Damp)),
+        Species::Seadra => (&[Ability::PoisonPoint, Ability::Sniper], Some(Ability::Damp)),
+        Species::Goldeen => (&[Ability::SwiftSwim, Ability::WaterVeil], Some(Ability::LightningRod)),
+        Species::Seaking => (&[Ability::SwiftSwim, Ability::WaterVeil], Some(Ability::LightningRod)),
+        Species::Staryu => (&[Ability::Illuminate, Ability::NaturalCure], Some(Ability::Analytic)),
+        Species::Starmie => (&[Ability::Illuminate, Ability::NaturalCure], Some(Ability::Analytic)),
+        Species::MrMime => (&[Ability::Soundproof, Ability::Filter], Some(Ability::Technician)),
+        Species::Scyther => (&[Ability::Swarm, Ability::Technician], Some(Ability::Steadfast)),
+        Species::Jynx => (&[Ability::Oblivious, Ability::Forewarn], Some(Ability::DrySkin)),
+        Species::Electabuzz => (&[Ability::Static], Some(Ability::VitalSpirit)),
+        Species::Magmar => (&[Ability::FlameBody], Some(Ability::VitalSpirit)),
+        Species::Pinsir(MegaEvolution::Normal) => (&[Ability::HyperCutter, Ability::MoldBreaker], Some(Ability::Moxie)),
+        Species::Pinsir(MegaEvolution::Mega) => (&[Ability::Aerilate], None),
+        Species::Tauros => (&[Ability::Intimidate, Ability::AngerPoint], Some(Ability::SheerForce)),
+        Species::Magikarp => (&[Ability::SwiftSwim], Some(Ability::Rattled)),
+        Species::Gyarados(MegaEvolution::Normal) => (&[Ability::Intimidate], Some(Ability::Moxie)),
+        Species::Gyarados(MegaEvolution::Mega) => (&[Ability::MoldBreaker], None),
+        Species::Lapras => (&[Ability::WaterAbsorb, Ability::ShellArmor], Some(Ability::Hydration)),
+        Species::Ditto => (&[Ability::Limber], Some(Ability::Imposter)),
+        Species::Eevee => (&[Ability::RunAway, Ability::Adaptability], Some(Ability::Anticipation)),
+        Species::Vaporeon => (&[Ability::WaterAbsorb], Some(Ability::Hydration)),
+        Species::Jolteon => (&[Ability::VoltAbsorb], Some(Ability::QuickFeet)),
+        Species::Flareon => (&[Ability::FlashFire], Some(Ability::Guts)),
+        Species::Porygon => (&[Ability::Trace, Ability::Download], Some(Ability::Analytic)),
+        Species::Omanyte => (&[Ability::SwiftSwim, Ability::ShellArmor], Some(Ability::WeakArmor)),
+        Species::Omastar => (&[Ability::SwiftSwim, Ability::ShellArmor], Some(Ability::WeakArmor)),
+        Species::Kabuto => (&[Ability::SwiftSwim, Ability::BattleArmor], Some(Ability::WeakArmor)),
+        Species::Kabutops => (&[Ability::SwiftSwim, Ability::BattleArmor], Some(Ability::WeakArmor)),
+        Species::Aerodactyl(MegaEvolution::Normal) => (&[Ability::RockHead, Ability::Pressure], Some(Ability::Unnerve)),
+        Species::Aerodactyl(MegaEvolution::Mega) => (&[Ability::ToughClaws], None),
+        Species::Snorlax => (&[Ability::Immunity, Ability::ThickFat], Some(Ability::Gluttony)),
+        Species::Articuno => (&[Ability::Pressure], Some(Ability::SnowCloak)),
+        Species::Zapdos => (&[Ability::Pressure], Some(Ability::Static)),
+        Species::Moltres => (&[Ability::Pressure], Some(Ability::FlameBody)),
+        Species::Dratini => (&[Ability::ShedSkin], Some(Ability::Multiscale)),
+        Species::Dragonair => (&[Ability::ShedSkin], Some(Ability::Multiscale)),
+        Species::Dragonite => (&[Ability::InnerFocus], Some(Ability::Multiscale)),
+        Species::Mewtwo(XYMegaEvolution::Normal) => (&[Ability::Pressure], Some(Ability::Unnerve)),
+        Species::Mewtwo(XYMegaEvolution::MegaX) => (&[Ability::Steadfast], None),
+        Species::Mewtwo(XYMegaEvolution::MegaY) => (&[Ability::Insomnia], None),
+        Species::Mew => (&[Ability::Synchronize], None),
+        // gen 2 starts here
+        Species::Chikorita => (&[Ability::Overgrow], Some(Ability::LeafGuard)),
+        Species::Bayleef => (&[Ability::Overgrow], Some(Ability::LeafGuard)),
+        Species::Meganium => (&[Ability::Overgrow], Some(Ability::LeafGuard)),
+        Species::Cyndaquil => (&[Ability::Blaze], Some(Ability::FlashFire)),
+        Species::Quilava => (&[Ability::Blaze], Some(Ability::FlashFire)),
+        Species::Typhlosion => (&[Ability::Blaze], Some(Ability::FlashFire)),
+        Species::Totodile => (&[Ability::Torrent], Some(Ability::SheerForce)),
+        Species::Croconaw => (&[Ability::Torrent], Some(Ability::SheerForce)),
+        Species::Feraligatr => (&[Ability::Torrent], Some(Ability::SheerForce)),
+        Species::Sentret => (&[Ability::RunAway, Ability::KeenEye], Some(Ability::Frisk)),
+        Species::Furret => (&[Ability::RunAway, Ability::KeenEye], Some(Ability::Frisk)),
+        Species::Hoothoot => (&[Ability::Insomnia, Ability::KeenEye], Some(Ability::TintedLens)),
+        Species::Noctowl => (&[Ability::Insomnia, Ability::KeenEye], Some(Ability::TintedLens)),
+        Species::Ledyba => (&[Ability::Swarm, Ability::EarlyBird], Some(Ability::Rattled)),
+        Species::Ledian => (&[Ability::Swarm, Ability::EarlyBird], Some(Ability::IronFist)),
+        Species::Spinarak => (&[Ability::Swarm, Ability::Insomnia], Some(Ability::Sniper)),
+        Species::Ariados => (&[Ability::Swarm, Ability::Insomnia], Some(Ability::Sniper)),
+        Species::Crobat => (&[Ability::InnerFocus], Some(Ability::Infiltrator)),
+        Species::Chinchou => (&[Ability::VoltAbsorb, Ability::Illuminate], Some(Ability::WaterAbsorb)),
+        Species::Lanturn => (&[Ability::VoltAbsorb, Ability::Illuminate], Some(Ability::WaterAbsorb)),
+        Species::Pichu => (&[Ability::Static], Some(Ability::LightningRod)),
+        Species::Cleffa => (&[Ability::CuteCharm, Ability::MagicGuard], Some(Ability::FriendGuard)),
+        Species::Igglybuff => (&[Ability::CuteCharm, Ability::Competitive], Some(Ability::FriendGuard)),
+        Species::Togepi => (&[Ability::Hustle, Ability::SereneGrace], Some(Ability::SuperLuck)),
+        Species::Togetic => (&[Ability::Hustle, Ability::SereneGrace], Some(Ability::SuperLuck)),
+        Species::Natu => (&[Ability::Synchronize, Ability::EarlyBird], Some(Ability::MagicBounce)),
+        Species::Xatu => (&[Ability::Synchronize, Ability::EarlyBird], Some(Ability::MagicBounce)),
+        Species::Mareep => (&[Ability::Static], Some(Ability::Plus)),
+        Species::Flaaffy => (&[Ability::Static], Some(Ability::Plus)),
+        Species::Ampharos => (&[Ability::Static], Some(Ability::Plus)),
+        Species::Bellossom => (&[Ability::Chlorophyll], Some(Ability::Healer)),
+        Species::Marill => (&[Ability::ThickFat, Ability::HugePower], Some(Ability::SapSipper)),
+        Species::Azumarill => (&[Ability::ThickFat, Ability::HugePower], Some(Ability::SapSipper)),
+        Species::Sudowoodo => (&[Ability::Sturdy, Ability::RockHead], Some(Ability::Rattled)),
+        Species::Politoed => (&[Ability::WaterAbsorb, Ability::Damp], Some(Ability::Drizzle)),
+        Species::Hoppip => (&[Ability::Chlorophyll, Ability::LeafGuard], Some(Ability::Infiltrator)),
+        Species::Skiploom => (&[Ability::Chlorophyll, Ability::LeafGuard], Some(Ability::Infiltrator)),
+        Species::Jumpluff => (&[Ability::Chlorophyll, Ability::LeafGuard], Some(Ability::Infiltrator)),
+        Species::Aipom => (&[Ability::RunAway, Ability::Pickup], Some(Ability::SkillLink)),
+        Species::Sunkern => (&[Ability::Chlorophyll, Ability::SolarPower], Some(Ability::EarlyBird)),
+        Species::Sunflora => (&[Ability::Chlorophyll, Ability::SolarPower], Some(Ability::EarlyBird)),
+        Species::Yanma => (&[Ability::SpeedBoost, Ability::CompoundEyes], Some(Ability::Frisk)),
+        Species::Wooper => (&[Ability::Damp, Ability::WaterAbsorb], Some(Ability::Unaware)),
+        Species::Quagsire => (&[Ability::Damp, Ability::WaterAbsorb], Some(Ability::Unaware)),
+        Species::Espeon => (&[Ability::Synchronize], Some(Ability::MagicBounce)),
+        Species::Umbreon => (&[Ability::Synchronize], Some(Ability::InnerFocus)),
+        Species::Murkrow => (&[Ability::Insomnia, Ability::SuperLuck], Some(Ability::Prankster)),
+        Species::Slowking => (&[Ability::Oblivious, Ability::OwnTempo], Some(Ability::Regenerator)),
+        Species::Misdreavus => (&[Ability::Levitate], None),
+        Species::Unown => (&[Ability::Levitate], None),
+        Species::Wobbuffet => (&[Ability::ShadowTag], Some(Ability::Telepathy)),
+        Species::Girafarig => (&[Ability::InnerFocus, Ability::EarlyBird], Some(Ability::SapSipper)),
+        Species::Pineco => (&[Ability::Sturdy], Some(Ability::Overcoat)),
+        Species::Forretress => (&[Ability::Sturdy], Some(Ability::Overcoat)),
+        Species::Dunsparce => (&[Ability::SereneGrace, Ability::RunAway], Some(Ability::Rattled)),
+        Species::Gligar => (&[Ability::HyperCutter, Ability::SandVeil], Some(Ability::Immunity)),
+        Species::Steelix(MegaEvolution::Normal) => (&[Ability::RockHead, Ability::Sturdy], Some(Ability::SheerForce)),
+        Species::Steelix(MegaEvolution::Mega) => (&[Ability::SandForce], None),
+        Species::Snubbull => (&[Ability::Intimidate, Ability::RunAway], Some(Ability::Rattled)),
+        Species::Granbull => (&[Ability::Intimidate, Ability::QuickFeet], Some(Ability::Rattled)),
+        Species::Qwilfish => (&[Ability::PoisonPoint, Ability::SwiftSwim], Some(Ability::Intimidate)),
+        Species::Scizor(MegaEvolution::Normal) => (&[Ability::Swarm, Ability::Technician], Some(Ability::LightMetal)),
+        Species::Scizor(MegaEvolution::Mega) => (&[Ability::Technician], None),
+        Species::Shuckle => (&[Ability::Sturdy, Ability::Gluttony], Some(Ability::Contrary)),
+        Species::Heracross(MegaEvolution::Normal) => (&[Ability::Swarm, Ability::Guts], Some(Ability::Moxie)),
+        Species::Heracross(MegaEvolution::Mega) => (&[Ability::SkillLink], None),
+        Species::Sneasel => (&[Ability::InnerFocus, Ability::KeenEye], Some(Ability::Pickpocket)),
+        Species::Teddiursa => (&[Ability::Pickup, Ability::QuickFeet], Some(Ability::HoneyGather)),
+        Species::Ursaring => (&[Ability::Guts, Ability::QuickFeet], Some(Ability::Unnerve)),
+        Species::Slugma => (&[Ability::MagmaArmor, Ability::FlameBody], Some(Ability::WeakArmor)),
+        Species::Magcargo => (&[Ability::MagmaArmor, Ability::FlameBody], Some(Ability::WeakArmor)),
+        Species::Swinub => (&[Ability::Oblivious, Ability::SnowCloak], Some(Ability::ThickFat)),
+        Species::Piloswine => (&[Ability::Oblivious, Ability::SnowCloak], Some(Ability::ThickFat)),
+        Species::Corsola => (&[Ability::Hustle, Ability::NaturalCure], Some(Ability::Regenerator)),
+        Species::Remoraid => (&[Ability::Hustle, Ability::Sniper], Some(Ability::Moody)),
+        Species::Octillery => (&[Ability::SuctionCups, Ability::Sniper], Some(Ability::Moody)),
+        Species::Delibird => (&[Ability::VitalSpirit, Ability::Hustle], Some(Ability::Insomnia)),
+        Species::Mantine => (&[Ability::SwiftSwim, Ability::WaterAbsorb], Some(Ability::WaterVeil)),
+        Species::Skarmory => (&[Ability::KeenEye, Ability::Sturdy], Some(Ability::WeakArmor)),
+        Species::Houndour => (&[Ability::EarlyBird, Ability::FlashFire], Some(Ability::Unnerve)),
+        Species::Houndoom(MegaEvolution::Normal) => (&[Ability::EarlyBird, Ability::FlashFire], Some(Ability::Unnerve)),
+        Species::Houndoom(MegaEvolution::Mega) => (&[Ability::SolarPower], None),
+        Species::Kingdra => (&[Ability::SwiftSwim, Ability::Sniper], Some(Ability::Damp)),
+        Species::Phanpy => (&[Ability::Pickup], Some(Ability::SandVeil)),
+        Species::Donphan => (&[Ability::Sturdy], Some(Ability::SandVeil)),
+        Species::Porygon2 => (&[Ability::Trace, Ability::Download], Some(Ability::Analytic)),
+        Species::Stantler => (&[Ability::Intimidate, Ability::Frisk], Some(Ability::SapSipper)),
+        Species::Smeargle => (&[Ability::OwnTempo, Ability::Technician], Some(Ability::Moody)),
+        Species::Tyrogue => (&[Ability::Guts, Ability::Steadfast], Some(Ability::VitalSpirit)),
+        Species::Hitmontop => (&[Ability::Intimidate, Ability::Technician], Some(Ability::Steadfast)),
+        Species::Smoochum => (&[Ability::Oblivious, Ability::Forewarn], Some(Ability::Hydration)),
+        Species::Elekid => (&[Ability::Static], Some(Ability::VitalSpirit)),
+        Species::Magby => (&[Ability::FlameBody], Some(Ability::VitalSpirit)),
+        Species::Miltank => (&[Ability::ThickFat, Ability::Scrappy], Some(Ability::SapSipper)),
+        Species::Blissey => (&[Ability::NaturalCure, Ability::SereneGrace], Some(Ability::Healer)),
+        Species::Raikou => (&[Ability::Pressure], Some(Ability::InnerFocus)),
+        Species::Entei => (&[Ability::Pressure], Some(Ability::InnerFocus)),
+        Species::Suicune => (&[Ability::Pressure], Some(Ability::InnerFocus)),
+        Species::Larvitar => (&[Ability::Guts], Some(Ability::SandVeil)),
+        Species::Pupitar => (&[Ability::ShedSkin], None),
+        Species::Tyranitar(MegaEvolution::Normal) => (&[Ability::SandStream], Some(Ability::Unnerve)),
+        Species::Tyranitar(MegaEvolution::Mega) => (&[Ability::SandStream], None),
+        Species::Lugia => (&[Ability::Pressure], Some(Ability::Multiscale)),
+        Species::HoOh => (&[Ability::Pressure], Some(Ability::Regenerator)),
+        Species::Celebi => (&[Ability::NaturalCure], None),
+        // gen 3 starts here
+        Species::Treecko => (&[Ability::Overgrow], Some(Ability::Unburden)),
+        Species::Grovyle => (&[Ability::Overgrow], Some(Ability::Unburden)),
+        Species::Sceptile(MegaEvolution::Normal) => (&[Ability::Overgrow], Some(Ability::Unburden)),
+        Species::Sceptile(MegaEvolution::Mega) => (&[Ability::LightningRod], None),
+        Species::Torchic => (&[Ability::Blaze], Some(Ability::SpeedBoost)),
+        Species::Combusken => (&[Ability::Blaze], Some(Ability::SpeedBoost)),
+        Species::Blaziken(MegaEvolution::Normal) => (&[Ability::Blaze], Some(Ability::SpeedBoost)),
+        Species::Blaziken(MegaEvolution::Mega) => (&[Ability::SpeedBoost], None),
+        Species::Mudkip => (&[Ability::Torrent], Some(Ability::Damp)),
+        Species::Marshtomp => (&[Ability::Torrent], Some(Ability::Damp)),
+        Species::Swampert(MegaEvolution::Normal) => (&[Ability::Torrent], Some(Ability::Damp)),
+        Species::Swampert(MegaEvolution::Mega) => (&[Ability::SwiftSwim], None),
+        Species::Poochyena => (&[Ability::RunAway, Ability::QuickFeet], Some(Ability::Rattled)),
+        Species::Mightyena => (&[Ability::Intimidate, Ability::QuickFeet], Some(Ability::Moxie)),
+        Species::Zigzagoon => (&[Ability::Pickup, Ability::Gluttony], Some(Ability::QuickFeet)),
+        Species::Linoone => (&[Ability::Pickup, Ability::Gluttony], Some(Ability::QuickFeet)),
+        Species::Wurmple => (&[Ability::ShieldDust], Some(Ability::RunAway)),
+        Species::Silcoon => (&[Ability::ShedSkin], None),
+        Species::Beautifly => (&[Ability::Swarm], Some(Ability::Rivalry)),
+        Species::Cascoon => (&[Ability::ShedSkin], None),
+        Species::Dustox => (&[Ability::ShieldDust], Some(Ability::CompoundEyes)),
+        Species::Lotad => (&[Ability::SwiftSwim, Ability::RainDish], Some(Ability::OwnTempo)),
+        Species::Lombre => (&[Ability::SwiftSwim, Ability::RainDish], Some(Ability::OwnTempo)),
+        Species::Ludicolo => (&[Ability::SwiftSwim, Ability::RainDish], Some(Ability::OwnTempo)),
+        Species::Seedot => (&[Ability::Chlorophyll, Ability::EarlyBird], Some(Ability::Pickpocket)),
+        Species::Nuzleaf => (&[Ability::Chlorophyll, Ability::EarlyBird], Some(Ability::Pickpocket)),
+        Species::Shiftry => (&[Ability::Chlorophyll, Ability::EarlyBird], Some(Ability::Pickpocket)),
+        Species::Taillow => (&[Ability::Guts], Some(Ability::Scrappy)),
+        Species::Swellow => (&[Ability::Guts], Some(Ability::Scrappy)),
+        Species::Wingull => (&[Ability::KeenEye, Ability::Hydration], Some(Ability::RainDish)),
+        Species::Pelipper => (&[Ability::KeenEye, Ability::Drizzle], Some(Ability::RainDish)),
+        Species::Ralts => (&[Ability::Synchronize, Ability::Trace], Some(Ability::Telepathy)),
+        Species::Kirlia => (&[Ability::Synchronize, Ability::Trace], Some(Ability::Telepathy)),
+        Species::Gardevoir(MegaEvolution::Normal) => (&[Ability::Synchronize, Ability::Trace], Some(Ability::Telepathy)),
+        Species::Gardevoir(MegaEvolution::Mega) => (&[Ability::Pixilate], None),
+        Species::Surskit => (&[Ability::SwiftSwim], Some(Ability::RainDish)),
+        Species::Masquerain => (&[Ability::Intimidate], Some(Ability::Unnerve)),
+        Species::Shroomish => (&[Ability::EffectSpore, Ability::PoisonHeal], Some(Ability::QuickFeet)),
+        Species::Breloom => (&[Ability::EffectSpore, Ability::PoisonHeal], Some(Ability::Technician)),
+        Species::Slakoth => (&[Ability::Truant], None),
+        Species::Vigoroth => (&[Ability::VitalSpirit], None),
+        Species::Slaking => (&[Ability::Truant], None),
+        Species::Nincada => (&[Ability::CompoundEyes], Some(Ability::RunAway)),
+        Species::Ninjask => (&[Ability::SpeedBoost], Some(Ability::Infiltrator)),
+        Species::Shedinja => (&[Ability::WonderGuard], None),
+        Species::Whismur => (&[Ability::Soundproof], Some(Ability::Rattled)),
+        Species::Loudred => (&[Ability::Soundproof], Some(Ability::Scrappy)),
+        Species::Exploud => (&[Ability::Soundproof], Some(Ability::Scrappy)),
+        Species::Makuhita => (&[Ability::ThickFat, Ability::Guts], Some(Ability::SheerForce)),
+        Species::Hariyama => (&[Ability::ThickFat, Ability::Guts], Some(Ability::SheerForce)),
+        Species::Azurill => (&[Ability::ThickFat, Ability::HugePower], Some(Ability::SapSipper)),
+        Species::Nosepass => (&[Ability::Sturdy, Ability::MagnetPull], Some(Ability::SandForce)),
+        Species::Skitty => (&[Ability::CuteCharm, Ability::Normalize], Some(Ability::WonderSkin)),
+        Species::Delcatty => (&[Ability::CuteCharm, Ability::Normalize], Some(Ability::WonderSkin)),
+        Species::Sableye(MegaEvolution::Normal) => (&[Ability::KeenEye, Ability::Stall], Some(Ability::Prankster)),
+        Species::Sableye(MegaEvolution::Mega) => (&[Ability::MagicBounce], None),
+        Species::Mawile(MegaEvolution::Normal) => (&[Ability::HyperCutter, Ability::Intimidate], Some(Ability::SheerForce)),
+        Species::Mawile(MegaEvolution::Mega) => (&[Ability::HugePower], None),
+        Species::Aron => (&[Ability::Sturdy, Ability::RockHead], Some(Ability::HeavyMetal)),
+        Species::Lairon => (&[Ability::Sturdy, Ability::RockHead], Some(Ability::HeavyMetal)),
+        Species::Aggron(MegaEvolution::Normal) => (&[Ability::Sturdy, Ability::RockHead], Some(Ability::HeavyMetal)),
+        Species::Aggron(MegaEvolution::Mega) => (&[Ability::Filter], None),
+        Species::Meditite => (&[Ability::PurePower], Some(Ability::Telepathy)),
+        Species::Medicham(MegaEvolution::Normal) => (&[Ability::PurePower], Some(Ability::Telepathy)),
+        Species::Medicham(MegaEvolution::Mega) => (&[Ability::PurePower], None),
+        Species::Electrike => (&[Ability::Static, Ability::LightningRod], Some(Ability::Minus)),
+        Species::Manectric(MegaEvolution::Normal) => (&[Ability::Static, Ability::LightningRod], Some(Ability::Minus)),
+        Species::Manectric(MegaEvolution::Mega) => (&[Ability::Intimidate], None),
+        Species::Plusle => (&[Ability::Plus], Some(Ability::LightningRod)),
+        Species::Minun => (&[Ability::Minus], Some(Ability::VoltAbsorb)),
+        Species::Volbeat => (&[Ability::Illuminate, Ability::Swarm], Some(Ability::Prankster)),
+        Species::Illumise => (&[Ability::Oblivious, Ability::TintedLens], Some(Ability::Prankster)),
+        Species::Roselia => (&[Ability::NaturalCure, Ability::PoisonPoint], Some(Ability::LeafGuard)),
+        Species::Gulpin => (&[Ability::LiquidOoze, Ability::StickyHold], Some(Ability::Gluttony)),
+        Species::Swalot => (&[Ability::LiquidOoze, Ability::StickyHold], Some(Ability::Gluttony)),
+        Species::Carvanha => (&[Ability::RoughSkin], Some(Ability::SpeedBoost)),
+        Species::Sharpedo(MegaEvolution::Normal) => (&[Ability::RoughSkin], Some(Ability::SpeedBoost)),
+        Species::Sharpedo(MegaEvolution::Mega) => (&[Ability::StrongJaw], None),
+        Species::Wailmer => (&[Ability::WaterVeil, Ability::Oblivious], Some(Ability::Pressure)),
+        Species::Wailord => (&[Ability::WaterVeil, Ability::Oblivious], Some(Ability::Pressure)),
+        Species::Numel => (&[Ability::Oblivious, Ability::Simple], Some(Ability::OwnTempo)),
+        Species::Camerupt(MegaEvolution::Normal) => (&[Ability::MagmaArmor, Ability::SolidRock], Some(Ability::AngerPoint)),
+        Species::Camerupt(MegaEvolution::Mega) => (&[Ability::SheerForce], None),
+        Species::Torkoal => (&[Ability::WhiteSmoke, Ability::Drought], Some(Ability::ShellArmor)),
+        Species::Spoink => (&[Ability::ThickFat, Ability::OwnTempo], Some(Ability::Gluttony)),
+        Species::Grumpig => (&[Ability::ThickFat, Ability::OwnTempo], Some(Ability::Gluttony)),
+        Species::Spinda => (&[Ability::OwnTempo, Ability::TangledFeet], Some(Ability::Contrary)),
+        Species::Trapinch => (&[Ability::HyperCutter, Ability::ArenaTrap], Some(Ability::SheerForce)),
+        Species::Vibrava => (&[Ability::Levitate], None),
+        Species::Flygon => (&[Ability::Levitate], None),
+        Species::Cacnea => (&[Ability::SandVeil], Some(Ability::WaterAbsorb)),
+        Species::Cacturne => (&[Ability::SandVeil], Some(Ability::WaterAbsorb)),
+        Species::Swablu => (&[Ability::NaturalCure], Some(Ability::CloudNine)),
+        Species::Altaria(MegaEvolution::Normal) => (&[Ability::NaturalCure], Some(Ability::CloudNine)),
+        Species::Altaria(MegaEvolution::Mega) => (&[Ability::Pixilate], None),
+        Species::Zangoose => (&[Ability::Immunity], Some(Ability::ToxicBoost)),
+        Species::Seviper => (&[Ability::ShedSkin], Some(Ability::Infiltrator)),
+        Species::Lunatone => (&[Ability::Levitate], None),
+        Species::Solrock => (&[Ability::Levitate], None),
+        Species::Barboach => (&[Ability::Oblivious, Ability::Anticipation], Some(Ability::Hydration)),
+        Species::Whiscash => (&[Ability::Oblivious, Ability::Anticipation], Some(Ability::Hydration)),
+        Species::Corphish => (&[Ability::HyperCutter, Ability::ShellArmor], Some(Ability::Adaptability)),
+        Species::Crawdaunt => (&[Ability::HyperCutter, Ability::ShellArmor], Some(Ability::Adaptability)),
+        Species::Baltoy => (&[Ability::Levitate], None),
+        Species::Claydol => (&[Ability::Levitate], None),
+        Species::Lileep => (&[Ability::SuctionCups], Some(Ability::StormDrain)),
+        Species::Cradily => (&[Ability::SuctionCups], Some(Ability::StormDrain)),
+        Species::Anorith => (&[Ability::BattleArmor], Some(Ability::SwiftSwim)),
+        Species::Armaldo => (&[Ability::BattleArmor], Some(Ability::SwiftSwim)),
+        Species::Feebas => (&[Ability::SwiftSwim, Ability::Oblivious], Some(Ability::Adaptability)),
+        Species::Milotic => (&[Ability::MarvelScale, Ability::Competitive], Some(Ability::CuteCharm)),
+        Species::Castform(_) => (&[Ability::Forecast], None),
+        Species::Kecleon => (&[Ability::ColorChange], Some(Ability::Protean)),
+        Species::Shuppet => (&[Ability::Insomnia, Ability::Frisk], Some(Ability::CursedBody)),
+        Species::Banette => (&[Ability::Insomnia, Ability::Frisk], Some(Ability::CursedBody)),
+        Species::Duskull => (&[Ability::Levitate], Some(Ability::Frisk)),
+        Species::Dusclops => (&[Ability::Pressure], Some(Ability::Frisk)),
+        Species::Tropius => (&[Ability::Chlorophyll, Ability::SolarPower], Some(Ability::Harvest)),
+        Species::Chimecho => (&[Ability::Levitate], None),
+        Species::Absol => (&[Ability::Pressure, Ability::SuperLuck], Some(Ability::Justified)),
+        Species::Wynaut => (&[Ability::ShadowTag], Some(Ability::Telepathy)),
+        Species::Snorunt => (&[Ability::InnerFocus, Ability::IceBody], Some(Ability::Moody)),
+        Species::Glalie => (&[Ability::InnerFocus, Ability::IceBody], Some(Ability::Moody)),
+        Species::Spheal => (&[Ability::ThickFat, Ability::IceBody], Some(Ability::Oblivious)),
+        Species::Sealeo => (&[Ability::ThickFat, Ability::IceBody], Some(Ability::Oblivious)),
+        Species::Walrein => (&[Ability::ThickFat, Ability::IceBody], Some(Ability::Oblivious)),
+        Species::Clamperl => (&[Ability::ShellArmor], Some(Ability::Rattled)),
+        Species::Huntail => (&[Ability::SwiftSwim], Some(Ability::WaterVeil)),
+        Species::Gorebyss => (&[Ability::SwiftSwim], Some(Ability::Hydration)),
+        Species::Relicanth => (&[Ability::SwiftSwim, Ability::RockHead], Some(Ability::Sturdy)),
+        Species::Luvdisc => (&[Ability::SwiftSwim], Some(Ability::Hydration)),
+        Species::Bagon => (&[Ability::RockHead], Some(Ability::SheerForce)),
+        Species::Shelgon => (&[Ability::RockHead], Some(Ability::Overcoat)),
+        Species::Salamence(MegaEvolution::Normal) => (&[Ability::Intimidate], Some(Ability::Moxie)),
+        Species::Salamence(MegaEvolution::Mega) => (&[Ability::Aerilate], None),
+        Species::Beldum => (&[Ability::ClearBody], Some(Ability::LightMetal)),
+        Species::Metang => (&[Ability::ClearBody], Some(Ability::LightMetal)),
+        Species::Metagross(MegaEvolution::Normal) => (&[Ability::ClearBody], Some(Ability::LightMetal)),
+        Species::Metagross(MegaEvolution::Mega) => (&[Ability::ToughClaws], None),
+        Species::Regirock => (&[Ability::ClearBody], Some(Ability::Sturdy)),
+        Species::Regice => (&[Ability::ClearBody], Some(Ability::IceBody)),
+        Species::Registeel => (&[Ability::ClearBody], Some(Ability::LightMetal)),
+        Species::Latias => (&[Ability::Levitate], None),
+        Species::Latios => (&[Ability::Levitate], None),
+        Species::Kyogre(PrimalReversion::Normal) => (&[Ability::Drizzle], None),
+        Species::Kyogre(PrimalReversion::Primal) => (&[Ability::PrimordialSea], None),
+        Species::Groudon(PrimalReversion::Normal) => (&[Ability::Drought], None),
+        Species::Groudon(PrimalReversion::Primal) => (&[Ability::DesolateLand], None),
+        Species::Rayquaza(MegaEvolution::Normal) => (&[Ability::AirLock], None),
+        Species::Rayquaza(MegaEvolution::Mega) => (&[Ability::DeltaStream], None),
+        Species::Jirachi => (&[Ability::SereneGrace], None),
+        Species::Deoxys(_) => (&[Ability::Pressure], None),
+        // gen 4 starts here
+        Species::Turtwig => (&[Ability::Overgrow], Some(Ability::ShellArmor)),
+        Species::Grotle => (&[Ability::Overgrow], Some(Ability::ShellArmor)),
+        Species::Torterra => (&[Ability::Overgrow], Some(Ability::ShellArmor)),
+        Species::Chimchar => (&[Ability::Blaze], Some(Ability::IronFist)),
+        Species::Monferno => (&[Ability::Blaze], Some(Ability::IronFist)),
+        Species::Infernape => (&[Ability::Blaze], Some(Ability::IronFist)),
+        Species::Piplup => (&[Ability::Torrent], Some(Ability::Defiant)),
+        Species::Prinplup => (&[Ability::Torrent], Some(Ability::Defiant)),
+        Species::Empoleon => (&[Ability::Torrent], Some(Ability::Defiant)),
+        Species::Starly => (&[Ability::KeenEye], Some(Ability::Reckless)),
+        Species::Staravia => (&[Ability::Intimidate], Some(Ability::Reckless)),
+        Species::Staraptor => (&[Ability::Intimidate], Some(Ability::Reckless)),
+        Species::Bidoof => (&[Ability::Simple, Ability::Unaware], Some(Ability::Moody)),
+        Species::Bibarel => (&[Ability::Simple, Ability::Unaware], Some(Ability::Moody)),
+        Species::Kricketot => (&[Ability::ShedSkin], Some(Ability::RunAway)),
+        Species::Kricketune => (&[Ability::Swarm], Some(Ability::Technician)),
+        Species::Shinx => (&[Ability::Rivalry, Ability::Intimidate], Some(Ability::Guts)),
+        Species::Luxio => (&[Ability::Rivalry, Ability::Intimidate], Some(Ability::Guts)),
+        Species::Luxray => (&[Ability::Rivalry, Ability::Intimidate], Some(Ability::Guts)),
+        Species::Budew => (&[Ability::NaturalCure, Ability::PoisonPoint], Some(Ability::LeafGuard)),
+        Species::Roserade => (&[Ability::NaturalCure, Ability::PoisonPoint], Some(Ability::Technician)),
+        Species::Cranidos => (&[Ability::MoldBreaker], Some(Ability::SheerForce)),
+        Species::Rampardos => (&[Ability::MoldBreaker], Some(Ability::SheerForce)),
+        Species::Shieldon => (&[Ability::Sturdy], Some(Ability::Soundproof)),
+        Species::Bastiodon => (&[Ability::Sturdy], Some(Ability::Soundproof)),
+        Species::Burmy => (&[Ability::ShedSkin], Some(Ability::Overcoat)),
+        Species::Wormadam(_) => (&[Ability::Anticipation], Some(Ability::Overcoat)),
+        Species::Mothim => (&[Ability::Swarm], Some(Ability::TintedLens)),
+        Species::Combee => (&[Ability::HoneyGather], Some(Ability::Hustle)),
+        Species::Vespiquen => (&[Ability::Pressure], Some(Ability::Unnerve)),
+        Species::Pachirisu => (&[Ability::RunAway, Ability::Pickup], Some(Ability::VoltAbsorb)),
+        Species::Buizel => (&[Ability::SwiftSwim], Some(Ability::WaterVeil)),
+        Species::Floatzel => (&[Ability::SwiftSwim], Some(Ability::WaterVeil)),
+        Species::Cherubi => (&[Ability::Chlorophyll], None),
+        Species::Cherrim => (&[Ability::FlowerGift], None),
+        Species::Shellos => (&[Ability::StickyHold, Ability::StormDrain], Some(Ability::SandForce)),
+        Species::Gastrodon => (&[Ability::StickyHold, Ability::StormDrain], Some(Ability::SandForce)),
+        Species::Ambipom => (&[Ability::Technician, Ability::Pickup], Some(Ability::SkillLink)),
+        Species::Drifloon => (&[Ability::Aftermath, Ability::Unburden], Some(Ability::FlareBoost)),
+        Species::Drifblim => (&[Ability::Aftermath, Ability::Unburden], Some(Ability::FlareBoost)),
+        Species::Buneary => (&[Ability::RunAway, Ability::Klutz], Some(Ability::Limber)),
+        Species::Lopunny(MegaEvolution::Normal) => (&[Ability::CuteCharm, Ability::Klutz], Some(Ability::Limber)),
+        Species::Lopunny(MegaEvolution::Mega) => (&[Ability::Scrappy], None),
+        Species::Mismagius => (&[Ability::Levitate], None),
+        Species::Honchkrow => (&[Ability::Insomnia, Ability::SuperLuck], Some(Ability::Moxie)),
+        Species::Glameow => (&[Ability::Limber, Ability::OwnTempo], Some(Ability::KeenEye)),
+        Species::Purugly => (&[Ability::ThickFat, Ability::OwnTempo], Some(Ability::Defiant)),
+        Species::Chingling => (&[Ability::Levitate], None),
+        Species::Stunky => (&[Ability::Stench, Ability::Aftermath], Some(Ability::KeenEye)),
+        Species::Skuntank => (&[Ability::Stench, Ability::Aftermath], Some(Ability::KeenEye)),
+        Species::Bronzor => (&[Ability::Levitate, Ability::Heatproof], Some(Ability::HeavyMetal)),
+        Species::Bronzong => (&[Ability::Levitate, Ability::Heatproof], Some(Ability::HeavyMetal)),
+        Species::Bonsly => (&[Ability::Sturdy, Ability::RockHead], Some(Ability::Rattled)),
+        Species::MimeJr => (&[Ability::Soundproof, Ability::Filter], Some(Ability::Technician)),
+        Species::Happiny => (&[Ability::NaturalCure, Ability::SereneGrace], Some(Ability::FriendGuard)),
+        Species::Chatot => (&[Ability::KeenEye, Ability::TangledFeet], Some(Ability::BigPecks)),
+        Species::Spiritomb => (&[Ability::Pressure], Some(Ability::Infiltrator)),
+        Species::Gible => (&[Ability::SandVeil], Some(Ability::RoughSkin)),
+        Species::Gabite => (&[Ability::SandVeil], Some(Ability::RoughSkin)),
+        Species::Garchomp(MegaEvolution::Normal) => (&[Ability::SandVeil], Some(Ability::RoughSkin)),
+        Species::Garchomp(MegaEvolution::Mega) => (&[Ability::SandForce], None),
+        Species::Munchlax => (&[Ability::Pickup, Ability::ThickFat], Some(Ability::Gluttony)),
+        Species::Riolu => (&[Ability::Steadfast, Ability::InnerFocus], Some(Ability::Prankster)),
+        Species::Lucario(MegaEvolution::Normal) => (&[Ability::Steadfast, Ability::InnerFocus], Some(Ability::Justified)),
+        Species::Lucario(MegaEvolution::Mega) => (&[Ability::Adaptability], None),
+        Species::Hippopotas => (&[Ability::SandStream], Some(Ability::SandForce)),
+        Species::Hippowdon => (&[Ability::SandStream], Some(Ability::SandForce)),
+        Species::Skorupi => (&[Ability::BattleArmor, Ability::Sniper], Some(Ability::KeenEye)),
+        Species::Drapion => (&[Ability::BattleArmor, Ability::Sniper], Some(Ability::KeenEye)),
+        Species::Croagunk => (&[Ability::Anticipation, Ability::DrySkin], Some(Ability::PoisonTouch)),
+        Species::Toxicroak => (&[Ability::Anticipation, Ability::DrySkin], Some(Ability::PoisonTouch)),
+        Species::Carnivine => (&[Ability::Levitate], None),
+        Species::Finneon => (&[Ability::SwiftSwim, Ability::StormDrain], Some(Ability::WaterVeil)),
+        Species::Lumineon => (&[Ability::SwiftSwim, Ability::StormDrain], Some(Ability::WaterVeil)),
+        Species::Mantyke => (&[Ability::SwiftSwim, Ability::WaterAbsorb], Some(Ability::WaterVeil)),
+        Species::Snover => (&[Ability::SnowWarning], Some(Ability::Soundproof)),
+        Species::Abomasnow(MegaEvolution::Normal) => (&[Ability::SnowWarning], Some(Ability::Soundproof)),
+        Species::Abomasnow(MegaEvolution::Mega) => (&[Ability::SnowWarning], None),
+        Species::Weavile => (&[Ability::Pressure], Some(Ability::Pickpocket)),
+        Species::Magnezone => (&[Ability::MagnetPull, Ability::Sturdy], Some(Ability::Analytic)),
+        Species::Lickilicky => (&[Ability::OwnTempo, Ability::Oblivious], Some(Ability::CloudNine)),
+        Species::Rhyperior => (&[Ability::LightningRod, Ability::SolidRock], Some(Ability::Reckless)),
+        Species::Tangrowth => (&[Ability::Chlorophyll, Ability::LeafGuard], Some(Ability::Regenerator)),
+        Species::Electivire => (&[Ability::MotorDrive], Some(Ability::VitalSpirit)),
+        Species::Magmortar => (&[Ability::FlameBody], Some(Ability::VitalSpirit)),
+        Species::Togekiss => (&[Ability::Hustle, Ability::SereneGrace], Some(Ability::SuperLuck)),
+        Species::Yanmega => (&[Ability::SpeedBoost, Ability::TintedLens], Some(Ability::Frisk)),
+        Species::Leafeon => (&[Ability::LeafGuard], Some(Ability::Chlorophyll)),
+        Species::Glaceon => (&[Ability::SnowCloak], Some(Ability::IceBody)),
+        Species::Gliscor => (&[Ability::HyperCutter, Ability::SandVeil], Some(Ability::PoisonHeal)),
+        Species::Mamoswine => (&[Ability::Oblivious, Ability::SnowCloak], Some(Ability::ThickFat)),
+        Species::PorygonZ => (&[Ability::Adaptability, Ability::Download], Some(Ability::Analytic)),
+        Species::Gallade(MegaEvolution::Normal) => (&[Ability::Steadfast], Some(Ability::Justified)),
+        Species::Gallade(MegaEvolution::Mega) => (&[Ability::InnerFocus], None),
+        Species::Probopass => (&[Ability::Sturdy, Ability::MagnetPull], Some(Ability::SandForce)),
+        Species::Dusknoir => (&[Ability::Pressure], Some(Ability::Frisk)),
+        Species::Froslass => (&[Ability::SnowCloak], Some(Ability::CursedBody)),
+        Species::Rotom(_) => (&[Ability::Levitate], None),
+        Species::Uxie => (&[Ability::Levitate], None),
+        Species::Mesprit => (&[Ability::Levitate], None),
+        Species::Azelf => (&[Ability::Levitate], None),
+        Species::Dialga => (&[Ability::Pressure], Some(Ability::Telepathy)),
+        Species::Palkia => (&[Ability::Pressure], Some(Ability::Telepathy)),
+        Species::Heatran => (&[Ability::FlashFire], Some(Ability::FlameBody)),
+        Species::Regigigas => (&[Ability::SlowStart], None),
+        Species::Giratina(GiratinaForme::Altered) => (&[Ability::Pressure], Some(Ability::Telepathy)),
+        Species::Giratina(GiratinaForme::Origin) => (&[Ability::Levitate], None),
+        Species::Cresselia => (&[Ability::Levitate], None),
+        Species::Phione => (&[Ability::Hydration], None),
+        Species::Manaphy => (&[Ability::Hydration], None),
+        Species::Darkrai => (&[Ability::BadDreams], None),
+        Species::Shaymin(ShayminForme::Land) => (&[Ability::NaturalCure], None),
+        Species::Shaymin(ShayminForme::Sky) => (&[Ability::SereneGrace], None),
+        Species::Arceus(_) => (&[Ability::Multitype], None),
+        // gen 5 starts here
+        Species::Victini => (&[Ability::VictoryStar], None),
+        Species::Snivy => (&[Ability::Overgrow], Some(Ability::Contrary)),
+        Species::Servine => (&[Ability::Overgrow], Some(Ability::Contrary)),
+        Species::Serperior => (&[Ability::Overgrow], Some(Ability::Contrary)),
+        Species::Tepig => (&[Ability::Blaze], Some(Ability::ThickFat)),
+        Species::Pignite => (&[Ability::Blaze], Some(Ability::ThickFat)),
+        Species::Emboar => (&[Ability::Blaze], Some(Ability::Reckless)),
+        Species::Oshawott => (&[Ability::Torrent], Some(Ability::ShellArmor)),
+        Species::Dewott => (&[Ability::Torrent], Some(Ability::ShellArmor)),
+        Species::Samurott => (&[Ability::Torrent], Some(Ability::ShellArmor)),
+        Species::Patrat => (&[Ability::RunAway, Ability::KeenEye], Some(Ability::Analytic)),
+        Species::Watchog => (&[Ability::Illuminate, Ability::KeenEye], Some(Ability::Analytic)),
+        Species::Lillipup => (&[Ability::VitalSpirit, Ability::Pickup], Some(Ability::RunAway)),
+        Species::Herdier => (&[Ability::Intimidate, Ability::SandRush], Some(Ability::Scrappy)),
+        Species::Stoutland => (&[Ability::Intimidate, Ability::SandRush], Some(Ability::Scrappy)),
+        Species::Purrloin => (&[Ability::Limber, Ability::Unburden], Some(Ability::Prankster)),
+        Species::Liepard => (&[Ability::Limber, Ability::Unburden], Some(Ability::Prankster)),
+        Species::Pansage => (&[Ability::Gluttony], Some(Ability::Overgrow)),
+        Species::Simisage => (&[Ability::Gluttony], Some(Ability::Overgrow)),
+        Species::Pansear => (&[Ability::Gluttony], Some(Ability::Blaze)),
+        Species::Simisear => (&[Ability::Gluttony], Some(Ability::Blaze)),
+        Species::Panpour => (&[Ability::Gluttony], Some(Ability::Torrent)),
+        Species::Simipour => (&[Ability::Gluttony], Some(Ability::Torrent)),
+        Species::Munna => (&[Ability::Forewarn, Ability::Synchronize], Some(Ability::Telepathy)),
+        Species::Musharna => (&[Ability::Forewarn, Ability::Synchronize], Some(Ability::Telepathy)),
+        Species::Pidove => (&[Ability::BigPecks, Ability::SuperLuck], Some(Ability::Rivalry)),
+        Species::Tranquill => (&[Ability::BigPecks, Ability::SuperLuck], Some(Ability::Rivalry)),
+        Species::Unfezant => (&[Ability::BigPecks, Ability::SuperLuck], Some(Ability::Rivalry)),
+        Species::Blitzle => (&[Ability::LightningRod, Ability::MotorDrive], Some(Ability::SapSipper)),
+        Species::Zebstrika => (&[Ability::LightningRod, Ability::MotorDrive], Some(Ability::SapSipper)),
+        Species::Roggenrola => (&[Ability::Sturdy, Ability::WeakArmor], Some(Ability::SandForce)),
+        Species::Boldore => (&[Ability::Sturdy, Ability::WeakArmor], Some(Ability::SandForce)),
+        Species::Gigalith => (&[Ability::Sturdy, Ability::SandStream], Some(Ability::SandForce)),
+        Species::Woobat => (&[Ability::Unaware, Ability::Klutz], Some(Ability::Simple)),
+        Species::Swoobat => (&[Ability::Unaware, Ability::Klutz], Some(Ability::Simple)),
+        Species::Drilbur => (&[Ability::SandRush, Ability::SandForce], Some(Ability::MoldBreaker)),
+        Species::Excadrill => (&[Ability::SandRush, Ability::SandForce], Some(Ability::MoldBreaker)),
+        Species::Audino => (&[Ability::Healer, Ability::Regenerator], Some(Ability::Klutz)),
+        Species::Timburr => (&[Ability::Guts, Ability::SheerForce], Some(Ability::IronFist)),
+        Species::Gurdurr => (&[Ability::Guts, Ability::SheerForce], Some(Ability::IronFist)),
+        Species::Conkeldurr => (&[Ability::Guts, Ability::SheerForce], Some(Ability::IronFist)),
+        Species::Tympole => (&[Ability::SwiftSwim, Ability::Hydration], Some(Ability::WaterAbsorb)),
+        Species::Palpitoad => (&[Ability::SwiftSwim, Ability::Hydration], Some(Ability::WaterAbsorb)),
+        Species::Seismitoad => (&[Ability::SwiftSwim, Ability::PoisonTouch], Some(Ability::WaterAbsorb)),
+        Species::Throh => (&[Ability::Guts, Ability::InnerFocus], Some(Ability::MoldBreaker)),
+        Species::Sawk => (&[Ability::Sturdy, Ability::InnerFocus], Some(Ability::MoldBreaker)),
+        Species::Sewaddle => (&[Ability::Swarm, Ability::Chlorophyll], Some(Ability::Overcoat)),
+        Species::Swadloon => (&[Ability::LeafGuard, Ability::Chlorophyll], Some(Ability::Overcoat)),
+        Species::Leavanny => (&[Ability::Swarm, Ability::Chlorophyll], Some(Ability::Overcoat)),
+        Species::Venipede => (&[Ability::PoisonPoint, Ability::Swarm], Some(Ability::SpeedBoost)),
+        Species::Whirlipede => (&[Ability::PoisonPoint, Ability::Swarm], Some(Ability::SpeedBoost)),
+        Species::Scolipede => (&[Ability::PoisonPoint, Ability::Swarm], Some(Ability::SpeedBoost)),
+        Species::Cottonee => (&[Ability::Prankster, Ability::Infiltrator], Some(Ability::Chlorophyll)),
+        Species::Whimsicott => (&[Ability::Prankster, Ability::Infiltrator], Some(Ability::Chlorophyll)),
+        Species::Petilil => (&[Ability::Chlorophyll, Ability::OwnTempo], Some(Ability::LeafGuard)),
+        Species::Lilligant => (&[Ability::Chlorophyll, Ability::OwnTempo], Some(Ability::LeafGuard)),
+        Species::Basculin => (&[Ability::Reckless, Ability::Adaptability], Some(Ability::MoldBreaker)),
+        Species::Sandile => (&[Ability::Intimidate, Ability::Moxie], Some(Ability::AngerPoint)),
+        Species::Krokorok => (&[Ability::Intimidate, Ability::Moxie], Some(Ability::AngerPoint)),
+        Species::Krookodile => (&[Ability::Intimidate, Ability::Moxie], Some(Ability::AngerPoint)),
+        Species::Darumaka => (&[Ability::Hustle], Some(Ability::InnerFocus)),
+        Species::Darmanitan(DarmanitanForme::Standard) => (&[Ability::SheerForce], Some(Ability::ZenMode)),
+        Species::Darmanitan(DarmanitanForme::ZenMode) => (&[Ability::ZenMode], None),
+        Species::Maractus => (&[Ability::WaterAbsorb, Ability::Chlorophyll], Some(Ability::StormDrain)),
+        Species::Dwebble => (&[Ability::Sturdy, Ability::ShellArmor], Some(Ability::WeakArmor)),
+        Species::Crustle => (&[Ability::Sturdy, Ability::ShellArmor], Some(Ability::WeakArmor)),
+        Species::Scraggy => (&[Ability::ShedSkin, Ability::Moxie], Some(Ability::Intimidate)),
+        Species::Scrafty => (&[Ability::ShedSkin, Ability::Moxie], Some(Ability::Intimidate)),
+        Species::Sigilyph => (&[Ability::WonderSkin, Ability::MagicGuard], Some(Ability::TintedLens)),
+        Species::Yamask => (&[Ability::Mummy], None),
+        Species::Cofagrigus => (&[Ability::Mummy], None),
+        Species::Tirtouga => (&[Ability::SolidRock, Ability::Sturdy], Some(Ability::SwiftSwim)),
+        Species::Carracosta => (&[Ability::SolidRock, Ability::Sturdy], Some(Ability::SwiftSwim)),
+        Species::Archen => (&[Ability::Defeatist], None),
+        Species::Archeops => (&[Ability::Defeatist], None),
+        Species::Trubbish => (&[Ability::Stench, Ability::StickyHold], Some(Ability::Aftermath)),
+        Species::Garbodor => (&[Ability::Stench, Ability::WeakArmor], Some(Ability::Aftermath)),
+        Species::Zorua => (&[Ability::Illusion], None),
+        Species::Zoroark => (&[Ability::Illusion], None),
+        Species::Minccino => (&[Ability::CuteCharm, Ability::Technician], Some(Ability::SkillLink)),
+        Species::Cinccino => (&[Ability::CuteCharm, Ability::Technician], Some(Ability::SkillLink)),
+        Species::Gothita => (&[Ability::Frisk, Ability::Competitive], Some(Ability::ShadowTag)),
+        Species::Gothorita => (&[Ability::Frisk, Ability::Competitive], Some(Ability::ShadowTag)),
+        Species::Gothitelle => (&[Ability::Frisk, Ability::Competitive], Some(Ability::ShadowTag)),
+        Species::Solosis => (&[Ability::Overcoat, Ability::MagicGuard], Some(Ability::Regenerator)),
+        Species::Duosion => (&[Ability::Overcoat, Ability::MagicGuard], Some(Ability::Regenerator)),
+        Species::Reuniclus => (&[Ability::Overcoat, Ability::MagicGuard], Some(Ability::Regenerator)),
+        Species::Ducklett => (&[Ability::KeenEye, Ability::BigPecks], Some(Ability::Hydration)),
+        Species::Swanna => (&[Ability::KeenEye, Ability::BigPecks], Some(Ability::Hydration)),
+        Species::Vanillite => (&[Ability::IceBody, Ability::SnowCloak], Some(Ability::WeakArmor)),
+        Species::Vanillish => (&[Ability::IceBody, Ability::SnowCloak], Some(Ability::WeakArmor)),
+        Species::Vanilluxe => (&[Ability::IceBody, Ability::SnowWarning], Some(Ability::WeakArmor)),
+        Species::Deerling => (&[Ability::Chlorophyll, Ability::SapSipper], Some(Ability::SereneGrace)),
+        Species::Sawsbuck => (&[Ability::Chlorophyll, Ability::SapSipper], Some(Ability::SereneGrace)),
+        Species::Emolga => (&[Ability::Static], Some(Ability::MotorDrive)),
+        Species::Karrablast => (&[Ability::Swarm, Ability::ShedSkin], Some(Ability::NoGuard)),
+        Species::Escavalier => (&[Ability::Swarm, Ability::ShellArmor], Some(Ability::Overcoat)),
+        Species::Foongus => (&[Ability::EffectSpore], Some(Ability::Regenerator)),
+        Species::Amoonguss => (&[Ability::EffectSpore], Some(Ability::Regenerator)),
+        Species::Frillish => (&[Ability::WaterAbsorb, Ability::CursedBody], Some(Ability::Damp)),
+        Species::Jellicent => (&[Ability::WaterAbsorb, Ability::CursedBody], Some(Ability::Damp)),
+        Species::Alomomola => (&[Ability::Healer, Ability::Hydration], Some(Ability::Regenerator)),
+        Species::Joltik => (&[Ability::CompoundEyes, Ability::Unnerve], Some(Ability::Swarm)),
+        Species::Galvantula => (&[Ability::CompoundEyes, Ability::Unnerve], Some(Ability::Swarm)),
+        Species::Ferroseed => (&[Ability::IronBarbs], None),
+        Species::Ferrothorn => (&[Ability::IronBarbs], Some(Ability::Anticipation)),
+        Species::Klink => (&[Ability::Plus, Ability::Minus], Some(Ability::ClearBody)),
+        Species::Klang => (&[Ability::Plus, Ability::Minus], Some(Ability::ClearBody)),
+        Species::Klinklang => (&[Ability::Plus, Ability::Minus], Some(Ability::ClearBody)),
+        Species::Tynamo => (&[Ability::Levitate], None),
+        Species::Eelektrik => (&[Ability::Levitate], None),
+        Species::Eelektross => (&[Ability::Levitate], None),
+        Species::Elgyem => (&[Ability::Telepathy, Ability::Synchronize], Some(Ability::Analytic)),
+        Species::Beheeyem => (&[Ability::Telepathy, Ability::Synchronize], Some(Ability::Analytic)),
+        Species::Litwick => (&[Ability::FlashFire, Ability::FlameBody], Some(Ability::Infiltrator)),
+        Species::Lampent => (&[Ability::FlashFire, Ability::FlameBody], Some(Ability::Infiltrator)),
+        Species::Chandelure => (&[Ability::FlashFire, Ability::FlameBody], Some(Ability::Infiltrator)),
+        Species::Axew => (&[Ability::Rivalry, Ability::MoldBreaker], Some(Ability::Unnerve)),
+        Species::Fraxure => (&[Ability::Rivalry, Ability::MoldBreaker], Some(Ability::Unnerve)),
+        Species::Haxorus => (&[Ability::Rivalry, Ability::MoldBreaker], Some(Ability::Unnerve)),
+        Species::Cubchoo => (&[Ability::SnowCloak, Ability::SlushRush], Some(Ability::Rattled)),
+        Species::Beartic => (&[Ability::SnowCloak, Ability::SlushRush], Some(Ability::SwiftSwim)),
+        Species::Cryogonal => (&[Ability::Levitate], None),
+        Species::Shelmet => (&[Ability::Hydration, Ability::ShellArmor], Some(Ability::Overcoat)),
+        Species::Accelgor => (&[Ability::Hydration, Ability::StickyHold], Some(Ability::Unburden)),
+        Species::Stunfisk => (&[Ability::Static, Ability::Limber], Some(Ability::SandVeil)),
+        Species::Mienfoo => (&[Ability::InnerFocus, Ability::Regenerator], Some(Ability::Reckless)),
+        Species::Mienshao => (&[Ability::InnerFocus, Ability::Regenerator], Some(Ability::Reckless)),
+        Species::Druddigon => (&[Ability::RoughSkin, Ability::SheerForce], Some(Ability::MoldBreaker)),
+        Species::Golett => (&[Ability::IronFist, Ability::Klutz], Some(Ability::NoGuard)),
+        Species::Golurk => (&[Ability::IronFist, Ability::Klutz], Some(Ability::NoGuard)),
+        Species::Pawniard => (&[Ability::Defiant, Ability::InnerFocus], Some(Ability::Pressure)),
+        Species::Bisharp => (&[Ability::Defiant, Ability::InnerFocus], Some(Ability::Pressure)),
+        Species::Bouffalant => (&[Ability::Reckless, Ability::SapSipper], Some(Ability::Soundproof)),
+        Species::Rufflet => (&[Ability::KeenEye, Ability::SheerForce], Some(Ability::Hustle)),
+        Species::Braviary => (&[Ability::KeenEye, Ability::SheerForce], Some(Ability::Defiant)),
+        Species::Vullaby => (&[Ability::BigPecks, Ability::Overcoat], Some(Ability::WeakArmor)),
+        Species::Mandibuzz => (&[Ability::BigPecks, Ability::Overcoat], Some(Ability::WeakArmor)),
+        Species::Heatmor => (&[Ability::Gluttony, Ability::FlashFire], Some(Ability::WhiteSmoke)),
+        Species::Durant => (&[Ability::Swarm, Ability::Hustle], Some(Ability::Truant)),
+        Species::Deino => (&[Ability::Hustle], None),
+        Species::Zweilous => (&[Ability::Hustle], None),
+        Species::Hydreigon => (&[Ability::Levitate], None),
+        Species::Larvesta => (&[Ability::FlameBody], Some(Ability::Swarm)),
+        Species::Volcarona => (&[Ability::FlameBody], Some(Ability::Swarm)),
+        Species::Cobalion => (&[Ability::Justified], None),
+        Species::Terrakion => (&[Ability::Justified], None),
+        Species::Virizion => (&[Ability::Justified], None),
+        Species::Tornadus(GenieForme::Incarnate) => (&[Ability::Prankster], Some(Ability::Defiant)),
+        Species::Tornadus(GenieForme::Therian) => (&[Ability::Regenerator], None),
+        Species::Thundurus(GenieForme::Incarnate) => (&[Ability::Prankster], Some(Ability::Defiant)),
+        Species::Thundurus(GenieForme::Therian) => (&[Ability::VoltAbsorb], None),
+        Species::Reshiram => (&[Ability::Turboblaze], None),
+        Species::Zekrom => (&[Ability::Teravolt], None),
+        Species::Landorus(GenieForme::Incarnate) => (&[Ability::SandForce], Some(Ability::SheerForce)),
+        Species::Landorus(GenieForme::Therian) => (&[Ability::Intimidate], None),
+        Species::Kyurem(KyuremForme::Normal) => (&[Ability::Pressure], None),
+        Species::Kyurem(KyuremForme::Black) => (&[Ability::Teravolt], None),
+        Species::Kyurem(KyuremForme::White) => (&[Ability::Turboblaze], None),
+        Species::Keldeo => (&[Ability::Justified], None),
+        Species::Meloetta(_) => (&[Ability::SereneGrace], None),
+        Species::Genesect => (&[Ability::Download], None),
+        // gen 6 starts here
+        Species::Chespin => (&[Ability::Overgrow], Some(Ability::Bulletproof)),
+        Species::Quilladin => (&[Ability::Overgrow], Some(Ability::Bulletproof)),
+        Species::Chesnaught => (&[Ability::Overgrow], Some(Ability::Bulletproof)),
+        Species::Fennekin => (&[Ability::Blaze], Some(Ability::Magician)),
+        Species::Braixen => (&[Ability::Blaze], Some(Ability::Magician)),
+        Species::Delphox => (&[Ability::Blaze], Some(Ability::Magician)),
+        Species::Froakie => (&[Ability::Torrent], Some(Ability::Protean)),
+        Species::Frogadier => (&[Ability::Torrent], Some(Ability::Protean)),
+        Species::Greninja(GreninjaForme::Normal) => (&[Ability::Torrent], Some(Ability::Protean)),
+        Species::Greninja(GreninjaForme::BattleBond) => (&[Ability::BattleBond], None),
+        Species::Greninja(GreninjaForme::Ash) => (&[Ability::BattleBond], None),
+        Species::Bunnelby => (&[Ability::Pickup, Ability::CheekPouch], Some(Ability::HugePower)),
+        Species::Diggersby => (&[Ability::Pickup, Ability::CheekPouch], Some(Ability::HugePower)),
+        Species::Fletchling => (&[Ability::BigPecks], Some(Ability::GaleWings)),
+        Species::Fletchinder => (&[Ability::FlameBody], Some(Ability::GaleWings)),
+        Species::Talonflame => (&[Ability::FlameBody], Some(Ability::GaleWings)),
+        Species::Scatterbug => (&[Ability::ShieldDust, Ability::CompoundEyes], Some(Ability::FriendGuard)),
+        Species::Spewpa => (&[Ability::ShedSkin], Some(Ability::FriendGuard)),
+        Species::Vivillon => (&[Ability::ShieldDust, Ability::CompoundEyes], Some(Ability::FriendGuard)),
+        Species::Litleo => (&[Ability::Rivalry, Ability::Unnerve], Some(Ability::Moxie)),
+        Species::Pyroar => (&[Ability::Rivalry, Ability::Unnerve], Some(Ability::Moxie)),
+        Species::Flabebe => (&[Ability::FlowerVeil], Some(Ability::Symbiosis)),
+        Species::Floette => (&[Ability::FlowerVeil], Some(Ability::Symbiosis)),
+        Species::Florges => (&[Ability::FlowerVeil], Some(Ability::Symbiosis)),
+        Species::Skiddo => (&[Ability::SapSipper], Some(Ability::GrassPelt)),
+        Species::Gogoat => (&[Ability::SapSipper], Some(Ability::GrassPelt)),
+        Species::Pancham => (&[Ability::IronFist, Ability::MoldBreaker], Some(Ability::Scrappy)),
+        Species::Pangoro => (&[Ability::IronFist, Ability::MoldBreaker], Some(Ability::Scrappy)),
+        Species::Furfrou => (&[Ability::FurCoat], None),
+        Species::Espurr => (&[Ability::KeenEye, Ability::Infiltrator], Some(Ability::OwnTempo)),
+        Species::Meowstic => (&[Ability::KeenEye, Ability::Infiltrator], Some(Ability::Prankster)),
+        Species::Honedge => (&[Ability::NoGuard], None),
+        Species::Doublade => (&[Ability::NoGuard], None),
+        Species::Aegislash(_) => (&[Ability::StanceChange], None),
+        Species::Spritzee => (&[Ability::Healer], Some(Ability::AromaVeil)),
+        Species::Aromatisse => (&[Ability::Healer], Some(Ability::AromaVeil)),
+        Species::Swirlix => (&[Ability::SweetVeil], Some(Ability::Unburden)),
+        Species::Slurpuff => (&[Ability::SweetVeil], Some(Ability::Unburden)),
+        Species::Inkay => (&[Ability::Contrary, Ability::SuctionCups], Some(Ability::Infiltrator)),
+        Species::Malamar => (&[Ability::Contrary, Ability::SuctionCups], Some(Ability::Infiltrator)),
+        Species::Binacle => (&[Ability::ToughClaws, Ability::Sniper], Some(Ability::Pickpocket)),
+        Species::Barbaracle => (&[Ability::ToughClaws, Ability::Sniper], Some(Ability::Pickpocket)),
+        Species::Skrelp => (&[Ability::PoisonPoint, Ability::PoisonTouch], Some(Ability::Adaptability)),
+        Species::Dragalge => (&[Ability::PoisonPoint, Ability::PoisonTouch], Some(Ability::Adaptability)),
+        Species::Clauncher => (&[Ability::MegaLauncher], None),
+        Species::Clawitzer => (&[Ability::MegaLauncher], None),
+        Species::Helioptile => (&[Ability::DrySkin, Ability::SandVeil], Some(Ability::SolarPower)),
+        Species::Heliolisk => (&[Ability::DrySkin, Ability::SandVeil], Some(Ability::SolarPower)),
+        Species::Tyrunt => (&[Ability::StrongJaw], Some(Ability::Sturdy)),
+        Species::Tyrantrum => (&[Ability::StrongJaw], Some(Ability::RockHead)),
+        Species::Amaura => (&[Ability::Refrigerate], Some(Ability::SnowWarning)),
+        Species::Aurorus => (&[Ability::Refrigerate], Some(Ability::SnowWarning)),
+        Species::Sylveon => (&[Ability::CuteCharm], Some(Ability::Pixilate)),
+        Species::Hawlucha => (&[Ability::Limber, Ability::Unburden], Some(Ability::MoldBreaker)),
+        Species::Dedenne => (&[Ability::CheekPouch, Ability::Pickup], Some(Ability::Plus)),
+        Species::Carbink => (&[Ability::ClearBody], Some(Ability::Sturdy)),
+        Species::Goomy => (&[Ability::SapSipper, Ability::Hydration], Some(Ability::Gooey)),
+        Species::Sliggoo => (&[Ability::SapSipper, Ability::Hydration], Some(Ability::Gooey)),
+        Species::Goodra => (&[Ability::SapSipper, Ability::Hydration], Some(Ability::Gooey)),
+        Species::Klefki => (&[Ability::Prankster], Some(Ability::Magician)),
+        Species::Phantump => (&[Ability::NaturalCure, Ability::Frisk], Some(Ability::Harvest)),
+        Species::Trevenant => (&[Ability::NaturalCure, Ability::Frisk], Some(Ability::Harvest)),
+        Species::Pumpkaboo(_) => (&[Ability::Pickup, Ability::Frisk], Some(Ability::Insomnia)),
+        Species::Gourgeist(_) => (&[Ability::Pickup, Ability::Frisk], Some(Ability::Insomnia)),
+        Species::Bergmite => (&[Ability::OwnTempo, Ability::IceBody], Some(Ability::Sturdy)),
+        Species::Avalugg => (&[Ability::OwnTempo, Ability::IceBody], Some(Ability::Sturdy)),
+        Species::Noibat => (&[Ability::Frisk, Ability::Infiltrator], Some(Ability::Telepathy)),
+        Species::Noivern => (&[Ability::Frisk, Ability::Infiltrator], Some(Ability::Telepathy)),
+        Species::Xerneas => (&[Ability::FairyAura], None),
+        Species::Yveltal => (&[Ability::DarkAura], None),
+        Species::Zygarde(ZygardeForme::TenPercent) => (&[Ability::AuraBreak, Ability::PowerConstruct], None),
+        Species::Zygarde(ZygardeForme::FiftyPercent) => (&[Ability::AuraBreak, Ability::PowerConstruct], None),
+        Species::Zygarde(ZygardeForme::Complete) => (&[Ability::PowerConstruct], None),
+        Species::Diancie(MegaEvolution::Normal) => (&[Ability::ClearBody], None),
+        Species::Diancie(MegaEvolution::Mega) => (&[Ability::MagicBounce], None),
+        Species::Hoopa(_) => (&[Ability::Magician], None),
+        Species::Volcanion => (&[Ability::WaterAbsorb], None),
+        // gen 7 starts here
+        Species::Rowlet => (&[Ability::Overgrow], Some(Ability::LongReach)),
+        Species::Dartrix => (&[Ability::Overgrow], Some(Ability::LongReach)),
+        Species::Decidueye => (&[Ability::Overgrow], Some(Ability::LongReach)),
+        Species::Litten => (&[Ability::Blaze], Some(Ability::Intimidate)),
+        Species::Torracat => (&[Ability::Blaze], Some(Ability::Intimidate)),
+        Species::Incineroar => (&[Ability::Blaze], Some(Ability::Intimidate)),
+        Species::Popplio => (&[Ability::Torrent], Some(Ability::LiquidVoice)),
+        Species::Brionne => (&[Ability::Torrent], Some(Ability::LiquidVoice)),
+        Species::Primarina => (&[Ability::Torrent], Some(Ability::LiquidVoice)),
+        Species::Pikipek => (&[Ability::KeenEye, Ability::SkillLink], Some(Ability::Pickup)),
+        Species::Trumbeak => (&[Ability::KeenEye, Ability::SkillLink], Some(Ability::Pickup)),
+        Species::Toucannon => (&[Ability::KeenEye, Ability::SkillLink], Some(Ability::SheerForce)),
+        Species::Yungoos => (&[Ability::Stakeout, Ability::StrongJaw], Some(Ability::Adaptability)),
+        Species::Gumshoos => (&[Ability::Stakeout, Ability::StrongJaw], Some(Ability::Adaptability)),
+        Species::Grubbin => (&[Ability::Swarm], None),
+        Species::Charjabug => (&[Ability::Battery], None),
+        Species::Vikavolt => (&[Ability::Levitate], None),
+        Species::Crabrawler => (&[Ability::HyperCutter, Ability::IronFist], Some(Ability::AngerPoint)),
+        Species::Crabominable => (&[Ability::HyperCutter, Ability::IronFist], Some(Ability::AngerPoint)),
+        Species::Oricorio(_) => (&[Ability::Dancer], None),
+        Species::Cutiefly => (&[Ability::HoneyGather, Ability::ShieldDust], Some(Ability::SweetVeil)),
+        Species::Ribombee => (&[Ability::HoneyGather, Ability::ShieldDust], Some(Ability::SweetVeil)),
+        Species::Rockruff => (&[Ability::KeenEye, Ability::VitalSpirit], Some(Ability::Steadfast)),
+        Species::Lycanroc(LycanrocForme::Midday) => (&[Ability::KeenEye, Ability::SandRush], Some(Ability::Steadfast)),
+        Species::Lycanroc(LycanrocForme::Midnight) => (&[Ability::KeenEye, Ability::VitalSpirit], Some(Ability::NoGuard)),
+        Species::Lycanroc(LycanrocForme::Dusk) => (&[Ability::ToughClaws], None),
+        Species::Wishiwashi(_) => (&[Ability::Schooling], None),
+        Species::Mareanie => (&[Ability::Merciless, Ability::Limber], Some(Ability::Regenerator)),
+        Species::Toxapex => (&[Ability::Merciless, Ability::Limber], Some(Ability::Regenerator)),
+        Species::Mudbray => (&[Ability::OwnTempo, Ability::Stamina], Some(Ability::InnerFocus)),
+        Species::Mudsdale => (&[Ability::OwnTempo, Ability::Stamina], Some(Ability::InnerFocus)),
+        Species::Dewpider => (&[Ability::WaterBubble], Some(Ability::WaterAbsorb)),
+        Species::Araquanid => (&[Ability::WaterBubble], Some(Ability::WaterAbsorb)),
+        Species::Fomantis => (&[Ability::LeafGuard], Some(Ability::Contrary)),
+        Species::Lurantis => (&[Ability::LeafGuard], Some(Ability::Contrary)),
+        Species::Morelull => (&[Ability::Illuminate, Ability::EffectSpore], Some(Ability::RainDish)),
+        Species::Shiinotic => (&[Ability::Illuminate, Ability::EffectSpore], Some(Ability::RainDish)),
+        Species::Salandit => (&[Ability::Corrosion], Some(Ability::Oblivious)),
+        Species::Salazzle => (&[Ability::Corrosion], Some(Ability::Oblivious)),
+        Species::Stufful => (&[Ability::Fluffy, Ability::Klutz], Some(Ability::CuteCharm)),
+        Species::Bewear => (&[Ability::Fluffy, Ability::Klutz], Some(Ability::Unnerve)),
+        Species::Bounsweet => (&[Ability::LeafGuard, Ability::Oblivious], Some(Ability::SweetVeil)),
+        Species::Steenee => (&[Ability::LeafGuard, Ability::Oblivious], Some(Ability::SweetVeil)),
+        Species::Tsareena => (&[Ability::LeafGuard, Ability::QueenlyMajesty], Some(Ability::SweetVeil)),
+        Species::Comfey => (&[Ability::FlowerVeil, Ability::Triage], Some(Ability::NaturalCure)),
+        Species::Oranguru => (&[Ability::InnerFocus, Ability::Telepathy], Some(Ability::Symbiosis)),
+        Species::Passimian => (&[Ability::Receiver], Some(Ability::Defiant)),
+        Species::Wimpod => (&[Ability::WimpOut], None),
+        Species::Golisopod => (&[Ability::EmergencyExit], None),
+        Species::Sandygast => (&[Ability::WaterCompaction], Some(Ability::SandVeil)),
+        Species::Palossand => (&[Ability::WaterCompaction], Some(Ability::SandVeil)),
+        Species::Pyukumuku => (&[Ability::InnardsOut], Some(Ability::Unaware)),
+        Species::TypeNull(_) => (&[Ability::BattleArmor], None),
+        Species::Silvally(_) => (&[Ability::RKSSystem], None),
+        Species::Minior(_) => (&[Ability::ShieldsDown], None),
+        Species::Komala => (&[Ability::Comatose], None),
+        Species::Turtonator => (&[Ability::ShellArmor], None),
+        Species::Togedemaru => (&[Ability::IronBarbs, Ability::LightningRod], Some(Ability::Sturdy)),
+        Species::Mimikyu(_) => (&[Ability::Disguise], None),
+        Species::Bruxish => (&[Ability::Dazzling, Ability::StrongJaw], Some(Ability::WonderSkin)),
+        Species::Drampa => (&[Ability::Berserk, Ability::SapSipper], Some(Ability::CloudNine)),
+        Species::Dhelmise => (&[Ability::Steelworker], None),
+        Species::JangmoO => (&[Ability::Bulletproof, Ability::Soundproof], Some(Ability::Overcoat)),
+        Species::HakamoO => (&[Ability::Bulletproof, Ability::Soundproof], Some(Ability::Overcoat)),
+        Species::KommoO => (&[Ability::Bulletproof, Ability::Soundproof], Some(Ability::Overcoat)),
+        Species::TapuKoko => (&[Ability::ElectricSurge], Some(Ability::Telepathy)),
+        Species::TapuLele => (&[Ability::PsychicSurge], Some(Ability::Telepathy)),
+        Species::TapuBulu => (&[Ability::GrassySurge], Some(Ability::Telepathy)),
+        Species::TapuFini => (&[Ability::MistySurge], Some(Ability::Telepathy)),
+        Species::Cosmog => (&[Ability::Unaware], None),
+        Species::Cosmoem => (&[Ability::Sturdy], None),
+        Species::Solgaleo => (&[Ability::FullMetalBody], None),
+        Species::Lunala => (&[Ability::ShadowShield], None),
+        Species::Nihilego => (&[Ability::BeastBoost], None),
+        Species::Buzzwole => (&[Ability::BeastBoost], None),
+        Species::Pheromosa => (&[Ability::BeastBoost], None),
+        Species::Xurkitree => (&[Ability::BeastBoost], None),
+        Species::Celesteela => (&[Ability::BeastBoost], None),
+        Species::Kartana => (&[Ability::BeastBoost], None),
+        Species::Guzzlord => (&[Ability::BeastBoost], None),
+        Species::Necrozma(NecrozmaForme::Normal) => (&[Ability::PrismArmor], None),
+        Species::Necrozma(NecrozmaForme::DuskMane) => (&[Ability::PrismArmor], None),
+        Species::Necrozma(NecrozmaForme::DuskWings) => (&[Ability::PrismArmor], None),
+        Species::Necrozma(NecrozmaForme::Ultra) => (&[Ability::Neuroforce], None),
+        Species::Magearna => (&[Ability::SoulHeart], None),
+        Species::Marshadow => (&[Ability::Technician], None),
+        Species::Poipole => (&[Ability::BeastBoost], None),
+        Species::Naganadel => (&[Ability::BeastBoost], None),
+        Species::Stakataka => (&[Ability::BeastBoost], None),
+        Species::Blacephalon => (&[Ability::BeastBoost], None),
+        Species::Zeraora => (&[Ability::VoltAbsorb], None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_multi_word_round_trip() {
+        for ability in &[Ability::BattleBond, Ability::ShieldsDown, Ability::PowerOfAlchemy,
+                         Ability::RKSSystem, Ability::FullMetalBody] {
+            assert_eq!(Ability::from_str(&ability.to_string()).unwrap(), *ability);
+        }
+        assert_eq!(Ability::BattleBond.to_string(), "Battle Bond");
+        assert_eq!(Ability::from_str("ShieldsDown").unwrap(), Ability::ShieldsDown);
+        assert_eq!(Ability::from_str("Shields Down").unwrap(), Ability::ShieldsDown);
+    }
+    #[test]
+    fn test_punctuation_round_trip() {
+        assert_eq!(Ability::SoulHeart.to_string(), "Soul-Heart");
+        assert_eq!(Ability::from_str("Soul-Heart").unwrap(), Ability::SoulHeart);
+        assert_eq!(Ability::from_str("SoulHeart").unwrap(), Ability::SoulHeart);
+    }
+    #[test]
+    fn test_single_word() {
+        assert_eq!(Ability::Levitate.to_string(), "Levitate");
+        assert_eq!(Ability::from_str("Pressure").unwrap(), Ability::Pressure);
+        assert!(Ability::from_str("Libero").is_err());
+    }
+    #[test]
+    fn test_giratina_formes() {
+        assert_eq!(abilities(Species::Giratina(GiratinaForme::Altered)),
+                   vec![Ability::Pressure, Ability::Telepathy]);
+        assert_eq!(abilities(Species::Giratina(GiratinaForme::Origin)), vec![Ability::Levitate]);
+        assert_eq!(hidden_ability(Species::Giratina(GiratinaForme::Origin)), None);
+    }
+    #[test]
+    fn test_hidden_abilities() {
+        assert_eq!(abilities(Species::Garchomp(MegaEvolution::Normal)),
+                   vec![Ability::SandVeil, Ability::RoughSkin]);
+        assert_eq!(hidden_ability(Species::Garchomp(MegaEvolution::Normal)), Some(Ability::RoughSkin));
+        assert_eq!(abilities(Species::Garchomp(MegaEvolution::Mega)), vec![Ability::SandForce]);
+        assert_eq!(hidden_ability(Species::Ferrothorn), Some(Ability::Anticipation));
+        assert_eq!(*abilities(Species::Salazzle).last().unwrap(), Ability::Oblivious);
+        assert_eq!(hidden_ability(Species::Arceus(ArceusForme::Fire)), None);
+    }
+}
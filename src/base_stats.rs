@@ -0,0 +1,1062 @@
+//! This file defines the base stats of every Pokemon species and forme: the six numbers that, along
+//! with level, nature, IVs, and EVs, determine a Pokemon's actual stats. The values are current to
+//! Gen VII, and use
+//! [Bulbapedia](https://bulbapedia.bulbagarden.net/wiki/List_of_Pokémon_by_base_stats_(Generation_VII-present))
+//! as a source. Because every `Species` value fully determines its forme, every `Species` has
+//! exactly one set of base stats.
+
+use crate::species::*;
+use crate::stat::Stat;
+
+/// The base stats of a Pokemon species, in the usual order: HP, Attack, Defense, Special Attack,
+/// Special Defense, and Speed. Base stats never exceed 255, but they are stored as `u16` to make
+/// arithmetic on them more convenient.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BaseStats {
+    pub hp: u16,
+    pub atk: u16,
+    pub def: u16,
+    pub spa: u16,
+    pub spd: u16,
+    pub spe: u16,
+}
+
+impl BaseStats {
+    /// Returns the base stat corresponding to the given `Stat`.
+    pub fn get(self, stat: Stat) -> u16 {
+        match stat {
+            Stat::HP => self.hp,
+            Stat::Atk => self.atk,
+            Stat::Def => self.def,
+            Stat::SpA => self.spa,
+            Stat::SpD => self.spd,
+            Stat::Spe => self.spe,
+        }
+    }
+    /// Returns the base stat total (BST), the sum of all six base stats. This is a rough but widely
+    /// used measure of how strong a Pokemon is: most fully-evolved Pokemon are between 450 and 600.
+    pub fn total(self) -> u16 {
+        self.hp + self.atk + self.def + self.spa + self.spd + self.spe
+    }
+}
+
+impl From<[u16; 6]> for BaseStats {
+    /// Converts from an array of stats in the usual order: HP, Atk, Def, SpA, SpD, Spe.
+    fn from(stats: [u16; 6]) -> BaseStats {
+        BaseStats {
+            hp: stats[0],
+            atk: stats[1],
+            def: stats[2],
+            spa: stats[3],
+            spd: stats[4],
+            spe: stats[5],
+        }
+    }
+}
+
+/// Returns the base stats of the given species.
+pub fn base_stats(species: Species) -> BaseStats {
+    BaseStats::from(base_stat_array(species))
+}
+
+/// Returns the base stat total of the given species. Note that formes can have different base stat
+/// totals: Mega Evolutions add exactly 100, for instance.
+pub fn base_stats_total(species: Species) -> u16 {
+    base_stats(species).total()
+}
+
+/// Returns true if the given species is a pseudo-legendary: the last stage of a three-stage
+/// evolution family with a base stat total of exactly 600, like Dragonite or Garchomp. Mega
+/// Evolutions of pseudo-legendaries have 700 base stat total, and so aren't counted.
+pub fn is_pseudo_legendary(species: Species) -> bool {
+    match species {
+        Species::Dragonite | Species::Tyranitar(_) | Species::Salamence(_) | Species::Metagross(_)
+            | Species::Garchomp(_) | Species::Hydreigon | Species::Goodra
+            | Species::KommoO => base_stats_total(species) == 600,
+        _ => false
+    }
+}
+
+/// Returns how much each base stat changes between two formes of a species, in the usual stat order:
+/// the stats of `mega` minus the stats of `base`. This is meant for Mega Evolutions and Primal
+/// Reversions, which always add 100 to the base stat total and never change HP, but works for any two
+/// species.
+pub fn stat_change_on_mega(base: Species, mega: Species) -> [i16; 6] {
+    let before = base_stat_array(base);
+    let after = base_stat_array(mega);
+    let mut delta = [0; 6];
+    for i in 0..6 {
+        delta[i] = after[i] as i16 - before[i] as i16;
+    }
+    delta
+}
+
+/// The actual base stat table, as an array in the usual stat order.
+fn base_stat_array(species: Species) -> [u16; 6] {
+    match species {
+        // in National Pokedex order
+        Species::Bulbasaur => [45, 49, 49, 65, 65, 45],
+        Species::Ivysaur => [60, 62, 63, 80, 80, 60],
+        Species::Venusaur(MegaEvolution::Normal) => [80, 82, 83, 100, 100, 80],
+        Species::Venusaur(MegaEvolution::Mega) => [80, 100, 123, 122, 120, 80],
+        Species::Charmander => [39, 52, 43, 60, 50, 65],
+        Species::Charmeleon => [58, 64, 58, 80, 65, 80],
+        Species::Charizard(XYMegaEvolution::Normal) => [78, 84, 78, 109, 85, 100],
+        Species::Charizard(XYMegaEvolution::MegaX) => [78, 130, 111, 130, 85, 100],
+        Species::Charizard(XYMegaEvolution::MegaY) => [78, 104, 78, 159, 115, 100],
+        Species::Squirtle => [44, 48, 65, 50, 64, 43],
+        Species::Wartortle => [59, 63, 80, 65, 80, 58],
+        Species::Blastoise(MegaEvolution::Normal) => [79, 83, 100, 85, 105, 78],
+        Species::Blastoise(MegaEvolution::Mega) => [79, 103, 120, 135, 115, 78],
+        Species::Caterpie => [45, 30, 35, 20, 20, 45],
+        Species::Metapod => [50, 20, 55, 25, 25, 30],
+        Species::Butterfree => [60, 45, 50, 90, 80, 70],
+        Species::Weedle => [40, 35, 30, 20, 20, 50],
+        Species::Kakuna => [45, 25, 50, 25, 25, 35],
+        Species::Beedrill(MegaEvolution::Normal) => [65, 90, 40, 45, 80, 75],
+        Species::Beedrill(MegaEvolution::Mega) => [65, 150, 40, 15, 80, 145],
+        Species::Pidgey => [40, 45, 40, 35, 35, 56],
+        Species::Pidgeotto => [63, 60, 55, 50, 50, 71],
+        Species::Pidgeot(MegaEvolution::Normal) => [83, 80, 75, 70, 70, 101],
+        Species::Pidgeot(MegaEvolution::Mega) => [83, 80, 80, 135, 80, 121],
+        Species::Rattata(_) => [30, 56, 35, 25, 35, 72],
+        Species::Raticate(AlolaForme::Normal) => [55, 81, 60, 50, 70, 97],
+        Species::Raticate(AlolaForme::Alola) => [75, 71, 70, 40, 80, 77],
+        Species::Spearow => [40, 60, 30, 31, 31, 70],
+        Species::Fearow => [65, 90, 65, 61, 61, 100],
+        Species::Ekans => [35, 60, 44, 40, 54, 55],
+        Species::Arbok => [60, 95, 69, 65, 79, 80],
+        Species::Pikachu => [35, 55, 40, 50, 50, 90],
+        Species::Raichu(AlolaForme::Normal) => [60, 90, 55, 90, 80, 110],
+        Species::Raichu(AlolaForme::Alola) => [60, 85, 50, 95, 85, 110],
+        Species::Sandshrew(AlolaForme::Normal) => [50, 75, 85, 20, 30, 40],
+        Species::Sandshrew(AlolaForme::Alola) => [50, 75, 90, 10, 35, 40],
+        Species::Sandslash(AlolaForme::Normal) => [75, 100, 110, 45, 55, 65],
+        Species::Sandslash(AlolaForme::Alola) => [75, 100, 120, 25, 65, 65],
+        Species::NidoranF => [55, 47, 52, 40, 40, 41],
+        Species::Nidorina => [70, 62, 67, 55, 55, 56],
+        Species::Nidoqueen => [90, 92, 87, 75, 85, 76],
+        Species::NidoranM => [46, 57, 40, 40, 40, 50],
+        Species::Nidorino => [61, 72, 57, 55, 55, 65],
+        Species::Nidoking => [81, 102, 77, 85, 75, 85],
+        Species::Clefairy => [70, 45, 48, 60, 65, 35],
+        Species::Clefable => [95, 70, 73, 95, 90, 60],
+        Species::Vulpix(_) => [38, 41, 40, 50, 65, 65],
+        Species::Ninetales(AlolaForme::Normal) => [73, 76, 75, 81, 100, 100],
+        Species::Ninetales(AlolaForme::Alola) => [73, 67, 75, 81, 100, 109],
+        Species::Jigglypuff => [115, 45, 20, 45, 25, 20],
+        Species::Wigglytuff => [140, 70, 45, 85, 50, 45],
+        Species::Zubat => [40, 45, 35, 30, 40, 55],
+        Species::Golbat => [75, 80, 70, 65, 75, 90],
+        Species::Oddish => [45, 50, 55, 75, 65, 30],
+        Species::Gloom => [60, 65, 70, 85, 75, 40],
+        Species::Vileplume => [75, 80, 85, 110, 90, 50],
+        Species::Paras => [35, 70, 55, 45, 55, 25],
+        Species::Parasect => [60, 95, 80, 60, 80, 30],
+        Species::Venonat => [60, 55, 50, 40, 55, 45],
+        Species::Venomoth => [70, 65, 60, 90, 75, 90],
+        Species::Diglett(AlolaForme::Normal) => [10, 55, 25, 35, 45, 95],
+        Species::Diglett(AlolaForme::Alola) => [10, 55, 30, 35, 45, 90],
+        Species::Dugtrio(AlolaForme::Normal) => [35, 100, 50, 50, 70, 120],
+        Species::Dugtrio(AlolaForme::Alola) => [35, 100, 60, 50, 70, 110],
+        Species::Meowth => [40, 45, 35, 40, 40, 90],
+        Species::Persian(AlolaForme::Normal) => [65, 70, 60, 65, 65, 115],
+        Species::Persian(AlolaForme::Alola) => [65, 60, 60, 75, 65, 115],
+        Species::Psyduck => [50, 52, 48, 65, 50, 55],
+        Species::Golduck => [80, 82, 78, 95, 80, 85],
+        Species::Mankey => [40, 80, 35, 35, 45, 70],
+        Species::Primeape => [65, 105, 60, 60, 70, 95],
+        Species::Growlithe => [55, 70, 45, 70, 50, 60],
+        Species::Arcanine => [90, 110, 80, 100, 80, 95],
+        Species::Poliwag => [40, 50, 40, 40, 40, 90],
+        Species::Poliwhirl => [65, 65, 65, 50, 50, 90],
+        Species::Poliwrath => [90, 95, 95, 70, 90, 70],
+        Species::Abra => [25, 20, 15, 105, 55, 90],
+        Species::Kadabra => [40, 35, 30, 120, 70, 105],
+        Species::Alakazam(MegaEvolution::Normal) => [55, 50, 45, 135, 95, 120],
+        Species::Alakazam(MegaEvolution::Mega) => [55, 50, 65, 175, 105, 150],
+        Species::Machop => [70, 80, 50, 35, 35, 35],
+        Species::Machoke => [80, 100, 70, 50, 60, 45],
+        Species::Machamp => [90, 130, 80, 65, 85, 55],
+        Species::Bellsprout => [50, 75, 35, 70, 30, 40],
+        Species::Weepinbell => [65, 90, 50, 85, 45, 55],
+        Species::Victreebel => [80, 105, 65, 100, 70, 70],
+        Species::Tentacool => [40, 40, 35, 50, 100, 70],
+        Species::Tentacruel => [80, 70, 65, 80, 120, 100],
+        Species::Geodude(_) => [40, 80, 100, 30, 30, 20],
+        Species::Graveler(_) => [55, 95, 115, 45, 45, 35],
+        Species::Golem(_) => [80, 120, 130, 55, 65, 45],
+        Species::Ponyta => [50, 85, 55, 65, 65, 90],
+        Species::Rapidash => [65, 100, 70, 80, 80, 105],
+        Species::Slowpoke => [90, 65, 65, 40, 40, 15],
+        Species::Slowbro => [95, 75, 110, 100, 80, 30],
+        Species::Magnemite => [25, 35, 70, 95, 55, 45],
+        Species::Magneton => [50, 60, 95, 120, 70, 70],
+        Species::Farfetchd => [52, 90, 55, 58, 62, 60],
+        Species::Doduo => [35, 85, 45, 35, 35, 75],
+        Species::Dodrio => [60, 110, 70, 60, 60, 110],
+        Species::Seel => [65, 45, 55, 45, 70, 45],
+        Species::Dewgong => [90, 70, 80, 70, 95, 70],
+        Species::Grimer(_) => [80, 80, 50, 40, 50, 25],
+        Species::Muk(_) => [105, 105, 75, 65, 100, 50],
+        Species::Shellder => [30, 65, 100, 45, 25, 40],
+        Species::Cloyster => [50, 95, 180, 85, 45, 70],
+        Species::Gastly => [30, 35, 30, 100, 35, 80],
+        Species::Haunter => [45, 50, 45, 115, 55, 95],
+        Species::Gengar(MegaEvolution::Normal) => [60, 65, 60, 130, 75, 110],
+        Species::Gengar(MegaEvolution::Mega) => [60, 65, 80, 170, 95, 130],
+        Species::Onix => [35, 45, 160, 30, 45, 70],
+        Species::Drowzee => [60, 48, 45, 43, 90, 42],
+        Species::Hypno => [85, 73, 70, 73, 115, 67],
+        Species::Krabby => [30, 105, 90, 25, 25, 50],
+        Species::Kingler => [55, 130, 115, 50, 50, 75],
+        Species::Voltorb => [40, 30, 50, 55, 55, 100],
+        Species::Electrode => [60, 50, 70, 80, 80, 150],
+        Species::Exeggcute => [60, 40, 80, 60, 45, 40],
+        Species::Exeggutor(AlolaForme::Normal) => [95, 95, 85, 125, 75, 55],
+        Species::Exeggutor(AlolaForme::Alola) => [95, 105, 85, 125, 75, 45],
+        Species::Cubone => [50, 50, 95, 40, 50, 35],
+        Species::Marowak(_) => [60, 80, 110, 50, 80, 45],
+        Species::Hitmonlee => [50, 120, 53, 35, 110, 87],
+        Species::Hitmonchan => [50, 105, 79, 35, 110, 76],
+        Species::Lickitung => [90, 55, 75, 60, 75, 30],
+        Species::Koffing => [40, 65, 95, 60, 45, 35],
+        Species::Weezing => [65, 90, 120, 85, 70, 60],
+        Species::Rhyhorn => [80, 85, 95, 30, 30, 25],
+        Species::Rhydon => [105, 130, 120, 45, 45, 40],
+        Species::Chansey => [250, 5, 5, 35, 105, 50],
+        Species::Tangela => [65, 55, 115, 100, 40, 60],
+        Species::Kangaskhan(MegaEvolution::Normal) => [105, 95, 80, 40, 80, 90],
+        Species::Kangaskhan(MegaEvolution::Mega) => [105, 125, 100, 60, 100, 100],
+        Species::Horsea => [30, 40, 70, 70, 25, 60],
+        Species::Seadra => [55, 65, 95, 95, 45, 85],
+        Species::Goldeen => [45, 67, 60, 35, 50, 63],
+        Species::Seaking => [80, 92, 65, 65, 80, 68],
+        Species::Staryu => [30, 45, 55, 70, 55, 85],
+        Species::Starmie => [60, 75, 85, 100, 85, 115],
+        Species::MrMime => [40, 45, 65, 100, 120, 90],
+        Species::Scyther => [70, 110, 80, 55, 80, 105],
+        Species::Jynx => [65, 50, 35, 115, 95, 95],
+        Species::Electabuzz => [65, 83, 57, 95, 85, 105],
+        Species::Magmar => [65, 95, 57, 100, 85, 93],
+        Species::Pinsir(MegaEvolution::Normal) => [65, 125, 100, 55, 70, 85],
+        Species::Pinsir(MegaEvolution::Mega) => [65, 155, 120, 65, 90, 105],
+        Species::Tauros => [75, 100, 95, 40, 70, 110],
+        Species::Magikarp => [20, 10, 55, 15, 20, 80],
+        Species::Gyarados(MegaEvolution::Normal) => [95, 125, 79, 60, 100, 81],
+        Species::Gyarados(MegaEvolution::Mega) => [95, 155, 109, 70, 130, 81],
+        Species::Lapras => [130, 85, 80, 85, 95, 60],
+        Species::Ditto => [48, 48, 48, 48, 48, 48],
+        Species::Eevee => [55, 55, 50, 45, 65, 55],
+        Species::Vaporeon => [130, 65, 60, 110, 95, 65],
+        Species::Jolteon => [65, 65, 60, 110, 95, 130],
+        Species::Flareon => [65, 130, 60, 95, 110, 65],
+        Species::Porygon => [65, 60, 70, 85, 75, 40],
+        Species::Omanyte => [35, 40, 100, 90, 55, 35],
+        Species::Omastar => [70, 60, 125, 115, 70, 55],
+        Species::Kabuto => [30, 80, 90, 55, 45, 55],
+        Species::Kabutops => [60, 115, 105, 65, 70, 80],
+        Species::Aerodactyl(MegaEvolution::Normal) => [80, 105, 65, 60, 75, 130],
+        Species::Aerodactyl(MegaEvolution::Mega) => [80, 135, 85, 70, 95, 150],
+        Species::Snorlax => [160, 110, 65, 65, 110, 30],
+        Species::Articuno => [90, 85, 100, 95, 125, 85],
+        Species::Zapdos => [90, 90, 85, 125, 90, 100],
+        Species::Moltres => [90, 100, 90, 125, 85, 90],
+        Species::Dratini => [41, 64, 45, 50, 50, 50],
+        Species::Dragonair => [61, 84, 65, 70, 70, 70],
+        Species::Dragonite => [91, 134, 95, 100, 100, 80],
+        Species::Mewtwo(XYMegaEvolution::Normal) => [106, 110, 90, 154, 90, 130],
+        Species::Mewtwo(XYMegaEvolution::MegaX) => [106, 190, 100, 154, 100, 130],
+        Species::Mewtwo(XYMegaEvolution::MegaY) => [106, 150, 70, 194, 120, 140],
+        Species::Mew => [100, 100, 100, 100, 100, 100],
+        // gen 2 starts here
+        Species::Chikorita => [45, 49, 65, 49, 65, 45],
+        Species::Bayleef => [60, 62, 80, 63, 80, 60],
+        Species::Meganium => [80, 82, 100, 83, 100, 80],
+        Species::Cyndaquil => [39, 52, 43, 60, 50, 65],
+        Species::Quilava => [58, 64, 58, 80, 65, 80],
+        Species::Typhlosion => [78, 84, 78, 109, 85, 100],
+        Species::Totodile => [50, 65, 64, 44, 48, 43],
+        Species::Croconaw => [65, 80, 80, 59, 63, 58],
+        Species::Feraligatr => [85, 105, 100, 79, 83, 78],
+        Species::Sentret => [35, 46, 34, 35, 45, 20],
+        Species::Furret => [85, 76, 64, 45, 55, 90],
+        Species::Hoothoot => [60, 30, 30, 36, 56, 50],
+        Species::Noctowl => [100, 50, 50, 86, 96, 70],
+        Species::Ledyba => [40, 20, 30, 40, 80, 55],
+        Species::Ledian => [55, 35, 50, 55, 110, 85],
+        Species::Spinarak => [40, 60, 40, 40, 40, 30],
+        Species::Ariados => [70, 90, 70, 60, 70, 40],
+        Species::Crobat => [85, 90, 80, 70, 80, 130],
+        Species::Chinchou => [75, 38, 38, 56, 56, 67],
+        Species::Lanturn => [125, 58, 58, 76, 76, 67],
+        Species::Pichu => [20, 40, 15, 35, 35, 60],
+        Species::Cleffa => [50, 25, 28, 45, 55, 15],
+        Species::Igglybuff => [90, 30, 15, 40, 20, 15],
+        Species::Togepi => [35, 20, 65, 40, 65, 20],
+        Species::Togetic => [55, 40, 85, 80, 105, 40],
+        Species::Natu => [40, 50, 45, 70, 45, 70],
+        Species::Xatu => [65, 75, 70, 95, 70, 95],
+        Species::Mareep => [55, 40, 40, 65, 45, 35],
+        Species::Flaaffy => [70, 55, 55, 80, 60, 45],
+        Species::Ampharos => [90, 75, 85, 115, 90, 55],
+        Species::Bellossom => [75, 80, 95, 90, 100, 50],
+        Species::Marill => [70, 20, 50, 20, 50, 40],
+        Species::Azumarill => [100, 50, 80, 60, 80, 50],
+        Species::Sudowoodo => [70, 100, 115, 30, 65, 30],
+        Species::Politoed => [90, 75, 75, 90, 100, 70],
+        Species::Hoppip => [35, 35, 40, 35, 55, 50],
+        Species::Skiploom => [55, 45, 50, 45, 65, 80],
+        Species::Jumpluff => [75, 55, 70, 55, 95, 110],
+        Species::Aipom => [55, 70, 55, 40, 55, 85],
+        Species::Sunkern => [30, 30, 30, 30, 30, 30],
+        Species::Sunflora => [75, 75, 55, 105, 85, 30],
+        Species::Yanma => [65, 65, 45, 75, 45, 95],
+        Species::Wooper => [55, 45, 45, 25, 25, 15],
+        Species::Quagsire => [95, 85, 85, 65, 65, 35],
+        Species::Espeon => [65, 65, 60, 130, 95, 110],
+        Species::Umbreon => [95, 65, 110, 60, 130, 65],
+        Species::Murkrow => [60, 85, 42, 85, 42, 91],
+        Species::Slowking => [95, 75, 80, 100, 110, 30],
+        Species::Misdreavus => [60, 60, 60, 85, 85, 85],
+        Species::Unown => [48, 72, 48, 72, 48, 48],
+        Species::Wobbuffet => [190, 33, 58, 33, 58, 33],
+        Species::Girafarig => [70, 80, 65, 90, 65, 85],
+        Species::Pineco => [50, 65, 90, 35, 35, 15],
+        Species::Forretress => [75, 90, 140, 60, 60, 40],
+        Species::Dunsparce => [100, 70, 70, 65, 65, 45],
+        Species::Gligar => [65, 75, 105, 35, 65, 85],
+        Species::Steelix(MegaEvolution::Normal) => [75, 85, 200, 55, 65, 30],
+        Species::Steelix(MegaEvolution::Mega) => [75, 125, 230, 55, 95, 30],
+        Species::Snubbull => [60, 80, 50, 40, 40, 30],
+        Species::Granbull => [90, 120, 75, 60, 60, 45],
+        Species::Qwilfish => [65, 95, 85, 55, 55, 85],
+        Species::Scizor(MegaEvolution::Normal) => [70, 130, 100, 55, 80, 65],
+        Species::Scizor(MegaEvolution::Mega) => [70, 150, 140, 65, 100, 75],
+        Species::Shuckle => [20, 10, 230, 10, 230, 5],
+        Species::Heracross(MegaEvolution::Normal) => [80, 125, 75, 40, 95, 85],
+        Species::Heracross(MegaEvolution::Mega) => [80, 185, 115, 40, 105, 75],
+        Species::Sneasel => [55, 95, 55, 35, 75, 115],
+        Species::Teddiursa => [60, 80, 50, 50, 50, 40],
+        Species::Ursaring => [90, 130, 75, 75, 75, 55],
+        Species::Slugma => [40, 40, 40, 70, 40, 20],
+        Species::Magcargo => [60, 50, 120, 90, 80, 30],
+        Species::Swinub => [50, 50, 40, 30, 30, 50],
+        Species::Piloswine => [100, 100, 80, 60, 60, 50],
+        Species::Corsola => [65, 55, 95, 65, 95, 35],
+        Species::Remoraid => [35, 65, 35, 65, 35, 65],
+        Species::Octillery => [75, 105, 75, 105, 75, 45],
+        Species::Delibird => [45, 55, 45, 65, 45, 75],
+        Species::Mantine => [85, 40, 70, 80, 140, 70],
+        Species::Skarmory => [65, 80, 140, 40, 70, 70],
+        Species::Houndour => [45, 60, 30, 80, 50, 65],
+        Species::Houndoom(MegaEvolution::Normal) => [75, 90, 50, 110, 80, 95],
+        Species::Houndoom(MegaEvolution::Mega) => [75, 90, 90, 140, 90, 115],
+        Species::Kingdra => [75, 95, 95, 95, 95, 85],
+        Species::Phanpy => [90, 60, 60, 40, 40, 40],
+        Species::Donphan => [90, 120, 120, 60, 60, 50],
+        Species::Porygon2 => [85, 80, 90, 105, 95, 60],
+        Species::Stantler => [73, 95, 62, 85, 65, 85],
+        Species::Smeargle => [55, 20, 35, 20, 45, 75],
+        Species::Tyrogue => [35, 35, 35, 35, 35, 35],
+        Species::Hitmontop => [50, 95, 95, 35, 110, 70],
+        Species::Smoochum => [45, 30, 15, 85, 65, 65],
+        Species::Elekid => [45, 63, 37, 65, 55, 95],
+        Species::Magby => [45, 75, 37, 70, 55, 83],
+        Species::Miltank => [95, 80, 105, 40, 70, 100],
+        Species::Blissey => [255, 10, 10, 75, 135, 55],
+        Species::Raikou => [90, 85, 75, 115, 100, 115],
+        Species::Entei => [115, 115, 85, 90, 75, 100],
+        Species::Suicune => [100, 75, 115, 90, 115, 85],
+        Species::Larvitar => [50, 64, 50, 45, 50, 41],
+        Species::Pupitar => [70, 84, 70, 65, 70, 51],
+        Species::Tyranitar(MegaEvolution::Normal) => [100, 134, 110, 95, 100, 61],
+        Species::Tyranitar(MegaEvolution::Mega) => [100, 164, 150, 95, 120, 71],
+        Species::Lugia => [106, 90, 130, 90, 154, 110],
+        Species::HoOh => [106, 130, 90, 110, 154, 90],
+        Species::Celebi => [100, 100, 100, 100, 100, 100],
+        // gen 3 starts here
+        Species::Treecko => [40, 45, 35, 65, 55, 70],
+        Species::Grovyle => [50, 65, 45, 85, 65, 95],
+        Species::Sceptile(MegaEvolution::Normal) => [70, 85, 65, 105, 85, 120],
+        Species::Sceptile(MegaEvolution::Mega) => [70, 110, 75, 145, 85, 145],
+        Species::Torchic => [45, 60, 40, 70, 50, 45],
+        Species::Combusken => [60, 85, 60, 85, 60, 55],
+        Species::Blaziken(MegaEvolution::Normal) => [80, 120, 70, 110, 70, 80],
+        Species::Blaziken(MegaEvolution::Mega) => [80, 160, 80, 130, 80, 100],
+        Species::Mudkip => [50, 70, 50, 50, 50, 40],
+        Species::Marshtomp => [70, 85, 70, 60, 70, 50],
+        Species::Swampert(MegaEvolution::Normal) => [100, 110, 90, 85, 90, 60],
+        Species::Swampert(MegaEvolution::Mega) => [100, 150, 110, 95, 110, 70],
+        Species::Poochyena => [35, 55, 35, 30, 30, 35],
+        Species::Mightyena => [70, 90, 70, 60, 60, 70],
+        Species::Zigzagoon => [38, 30, 41, 30, 41, 60],
+        Species::Linoone => [78, 70, 61, 50, 61, 100],
+        Species::Wurmple => [45, 45, 35, 20, 30, 20],
+        Species::Silcoon => [50, 35, 55, 25, 25, 15],
+        Species::Beautifly => [60, 70, 50, 100, 50, 65],
+        Species::Cascoon => [50, 35, 55, 25, 25, 15],
+        Species::Dustox => [60, 50, 70, 50, 90, 65],
+        Species::Lotad => [40, 30, 30, 40, 50, 30],
+        Species::Lombre => [60, 50, 50, 60, 70, 50],
+        Species::Ludicolo => [80, 70, 70, 90, 100, 70],
+        Species::Seedot => [40, 40, 50, 30, 30, 30],
+        Species::Nuzleaf => [70, 70, 40, 60, 40, 60],
+        Species::Shiftry => [90, 100, 60, 90, 60, 80],
+        Species::Taillow => [40, 55, 30, 30, 30, 85],
+        Species::Swellow => [60, 85, 60, 75, 50, 125],
+        Species::Wingull => [40, 30, 30, 55, 30, 85],
+        Species::Pelipper => [60, 50, 100, 95, 70, 65],
+        Species::Ralts => [28, 25, 25, 45, 35, 40],
+        Species::Kirlia => [38, 35, 35, 65, 55, 50],
+        Species::Gardevoir(MegaEvolution::Normal) => [68, 65, 65, 125, 115, 80],
+        Species::Gardevoir(MegaEvolution::Mega) => [68, 85, 65, 165, 135, 100],
+        Species::Surskit => [40, 30, 32, 50, 52, 65],
+        Species::Masquerain => [70, 60, 62, 100, 82, 80],
+        Species::Shroomish => [60, 40, 60, 40, 60, 35],
+        Species::Breloom => [60, 130, 80, 60, 60, 70],
+        Species::Slakoth => [60, 60, 60, 35, 35, 30],
+        Species::Vigoroth => [80, 80, 80, 55, 55, 90],
+        Species::Slaking => [150, 160, 100, 95, 65, 100],
+        Species::Nincada => [31, 45, 90, 30, 30, 40],
+        Species::Ninjask => [61, 90, 45, 50, 50, 160],
+        Species::Shedinja => [1, 90, 45, 30, 30, 40],
+        Species::Whismur => [64, 51, 23, 51, 23, 28],
+        Species::Loudred => [84, 71, 43, 71, 43, 48],
+        Species::Exploud => [104, 91, 63, 91, 73, 68],
+        Species::Makuhita => [72, 60, 30, 20, 30, 25],
+        Species::Hariyama => [144, 120, 60, 40, 60, 50],
+        Species::Azurill => [50, 20, 40, 20, 40, 20],
+        Species::Nosepass => [30, 45, 135, 45, 90, 30],
+        Species::Skitty => [50, 45, 45, 35, 35, 50],
+        Species::Delcatty => [70, 65, 65, 55, 55, 90],
+        Species::Sableye(MegaEvolution::Normal) => [50, 75, 75, 65, 65, 50],
+        Species::Sableye(MegaEvolution::Mega) => [50, 85, 125, 85, 115, 20],
+        Species::Mawile(MegaEvolution::Normal) => [50, 85, 85, 55, 55, 50],
+        Species::Mawile(MegaEvolution::Mega) => [50, 105, 125, 55, 95, 50],
+        Species::Aron => [50, 70, 100, 40, 40, 30],
+        Species::Lairon => [60, 90, 140, 50, 50, 40],
+        Species::Aggron(MegaEvolution::Normal) => [70, 110, 180, 60, 60, 50],
+        Species::Aggron(MegaEvolution::Mega) => [70, 140, 230, 60, 80, 50],
+        Species::Meditite => [30, 40, 55, 40, 55, 60],
+        Species::Medicham(MegaEvolution::Normal) => [60, 60, 75, 60, 75, 80],
+        Species::Medicham(MegaEvolution::Mega) => [60, 100, 85, 80, 85, 100],
+        Species::Electrike => [40, 45, 40, 65, 40, 65],
+        Species::Manectric(MegaEvolution::Normal) => [70, 75, 60, 105, 60, 105],
+        Species::Manectric(MegaEvolution::Mega) => [70, 75, 80, 135, 80, 135],
+        Species::Plusle => [60, 50, 40, 85, 75, 95],
+        Species::Minun => [60, 40, 50, 75, 85, 95],
+        Species::Volbeat => [65, 73, 75, 47, 85, 85],
+        Species::Illumise => [65, 47, 75, 73, 85, 85],
+        Species::Roselia => [50, 60, 45, 100, 80, 65],
+        Species::Gulpin => [70, 43, 53, 43, 53, 40],
+        Species::Swalot => [100, 73, 83, 73, 83, 55],
+        Species::Carvanha => [45, 90, 20, 65, 20, 65],
+        Species::Sharpedo(MegaEvolution::Normal) => [70, 120, 40, 95, 40, 95],
+        Species::Sharpedo(MegaEvolution::Mega) => [70, 140, 70, 110, 65, 105],
+        Species::Wailmer => [130, 70, 35, 70, 35, 60],
+        Species::Wailord => [170, 90, 45, 90, 45, 60],
+        Species::Numel => [60, 60, 40, 65, 45, 35],
+        Species::Camerupt(MegaEvolution::Normal) => [70, 100, 70, 105, 75, 40],
+        Species::Camerupt(MegaEvolution::Mega) => [70, 120, 100, 145, 105, 20],
+        Species::Torkoal => [70, 85, 140, 85, 70, 20],
+        Species::Spoink => [60, 25, 35, 70, 80, 60],
+        Species::Grumpig => [80, 45, 65, 90, 110, 80],
+        Species::Spinda => [60, 60, 60, 60, 60, 60],
+        Species::Trapinch => [45, 100, 45, 45, 45, 10],
+        Species::Vibrava => [50, 70, 50, 50, 50, 70],
+        Species::Flygon => [80, 100, 80, 80, 80, 100],
+        Species::Cacnea => [50, 85, 40, 85, 40, 35],
+        Species::Cacturne => [70, 115, 60, 115, 60, 55],
+        Species::Swablu => [45, 40, 60, 40, 75, 50],
+        Species::Altaria(MegaEvolution::Normal) => [75, 70, 90, 70, 105, 80],
+        Species::Altaria(MegaEvolution::Mega) => [75, 110, 110, 110, 105, 80],
+        Species::Zangoose => [73, 115, 60, 60, 60, 90],
+        Species::Seviper => [73, 100, 60, 100, 60, 65],
+        Species::Lunatone => [90, 55, 65, 95, 85, 70],
+        Species::Solrock => [90, 95, 85, 55, 65, 70],
+        Species::Barboach => [50, 48, 43, 46, 41, 60],
+        Species::Whiscash => [110, 78, 73, 76, 71, 60],
+        Species::Corphish => [43, 80, 65, 50, 35, 35],
+        Species::Crawdaunt => [63, 120, 85, 90, 55, 55],
+        Species::Baltoy => [40, 40, 55, 40, 70, 55],
+        Species::Claydol => [60, 70, 105, 70, 120, 75],
+        Species::Lileep => [66, 41, 77, 61, 87, 23],
+        Species::Cradily => [86, 81, 97, 81, 107, 43],
+        Species::Anorith => [45, 95, 50, 40, 50, 75],
+        Species::Armaldo => [75, 125, 100, 70, 80, 45],
+        Species::Feebas => [20, 15, 20, 10, 55, 80],
+        Species::Milotic => [95, 60, 79, 100, 125, 81],
+        Species::Castform(_) => [70, 70, 70, 70, 70, 70],
+        Species::Kecleon => [60, 90, 70, 60, 120, 40],
+        Species::Shuppet => [44, 75, 35, 63, 33, 45],
+        Species::Banette => [64, 115, 65, 83, 63, 65],
+        Species::Duskull => [20, 40, 90, 30, 90, 25],
+        Species::Dusclops => [40, 70, 130, 60, 130, 25],
+        Species::Tropius => [99, 68, 83, 72, 87, 51],
+        Species::Chimecho => [75, 50, 80, 95, 90, 65],
+        Species::Absol => [65, 130, 60, 75, 60, 75],
+        Species::Wynaut => [95, 23, 48, 23, 48, 23],
+        Species::Snorunt => [50, 50, 50, 50, 50, 50],
+        Species::Glalie => [80, 80, 80, 80, 80, 80],
+        Species::Spheal => [70, 40, 50, 55, 50, 25],
+        Species::Sealeo => [90, 60, 70, 75, 70, 45],
+        Species::Walrein => [110, 80, 90, 95, 90, 65],
+        Species::Clamperl => [35, 64, 85, 74, 55, 32],
+        Species::Huntail => [55, 104, 105, 94, 75, 52],
+        Species::Gorebyss => [55, 84, 105, 114, 75, 52],
+        Species::Relicanth => [100, 90, 130, 45, 65, 55],
+        Species::Luvdisc => [43, 30, 55, 40, 65, 97],
+        Species::Bagon => [45, 75, 60, 40, 30, 50],
+        Species::Shelgon => [65, 95, 100, 60, 50, 50],
+        Species::Salamence(MegaEvolution::Normal) => [95, 135, 80, 110, 80, 100],
+        Species::Salamence(MegaEvolution::Mega) => [95, 145, 130, 120, 90, 120],
+        Species::Beldum => [40, 55, 80, 35, 60, 30],
+        Species::Metang => [60, 75, 100, 55, 80, 50],
+        Species::Metagross(MegaEvolution::Normal) => [80, 135, 130, 95, 90, 70],
+        Species::Metagross(MegaEvolution::Mega) => [80, 145, 150, 105, 110, 110],
+        Species::Regirock => [80, 100, 200, 50, 100, 50],
+        Species::Regice => [80, 50, 100, 100, 200, 50],
+        Species::Registeel => [80, 75, 150, 75, 150, 50],
+        Species::Latias => [80, 80, 90, 110, 130, 110],
+        Species::Latios => [80, 90, 80, 130, 110, 110],
+        Species::Kyogre(PrimalReversion::Normal) => [100, 100, 90, 150, 140, 90],
+        Species::Kyogre(PrimalReversion::Primal) => [100, 150, 90, 180, 160, 90],
+        Species::Groudon(PrimalReversion::Normal) => [100, 150, 140, 100, 90, 90],
+        Species::Groudon(PrimalReversion::Primal) => [100, 180, 160, 150, 90, 90],
+        Species::Rayquaza(MegaEvolution::Normal) => [105, 150, 90, 150, 90, 95],
+        Species::Rayquaza(MegaEvolution::Mega) => [105, 180, 100, 180, 100, 115],
+        Species::Jirachi => [100, 100, 100, 100, 100, 100],
+        Species::Deoxys(DeoxysForme::Normal) => [50, 150, 50, 150, 50, 150],
+        Species::Deoxys(DeoxysForme::Attack) => [50, 180, 20, 180, 20, 150],
+        Species::Deoxys(DeoxysForme::Defense) => [50, 70, 160, 70, 160, 90],
+        Species::Deoxys(DeoxysForme::Speed) => [50, 95, 90, 95, 90, 180],
+        // gen 4 starts here
+        Species::Turtwig => [55, 68, 64, 45, 55, 31],
+        Species::Grotle => [75, 89, 85, 55, 65, 36],
+        Species::Torterra => [95, 109, 105, 75, 85, 56],
+        Species::Chimchar => [44, 58, 44, 58, 44, 61],
+        Species::Monferno => [64, 78, 52, 78, 52, 81],
+        Species::Infernape => [76, 104, 71, 104, 71, 108],
+        Species::Piplup => [53, 51, 53, 61, 56, 40],
+        Species::Prinplup => [64, 66, 68, 81, 76, 50],
+        Species::Empoleon => [84, 86, 88, 111, 101, 60],
+        Species::Starly => [40, 55, 30, 30, 30, 60],
+        Species::Staravia => [55, 75, 50, 40, 40, 80],
+        Species::Staraptor => [85, 120, 70, 50, 60, 100],
+        Species::Bidoof => [59, 45, 40, 35, 40, 31],
+        Species::Bibarel => [79, 85, 60, 55, 60, 71],
+        Species::Kricketot => [37, 25, 41, 25, 41, 25],
+        Species::Kricketune => [77, 85, 51, 55, 51, 65],
+        Species::Shinx => [45, 65, 34, 40, 34, 45],
+        Species::Luxio => [60, 85, 49, 60, 49, 60],
+        Species::Luxray => [80, 120, 79, 95, 79, 70],
+        Species::Budew => [40, 30, 35, 50, 70, 55],
+        Species::Roserade => [60, 70, 65, 125, 105, 90],
+        Species::Cranidos => [67, 125, 40, 30, 30, 58],
+        Species::Rampardos => [97, 165, 60, 65, 50, 58],
+        Species::Shieldon => [30, 42, 118, 42, 88, 30],
+        Species::Bastiodon => [60, 52, 168, 47, 138, 30],
+        Species::Burmy => [40, 29, 45, 29, 45, 36],
+        Species::Wormadam(WormadamForme::Plant) => [60, 59, 85, 79, 105, 36],
+        Species::Wormadam(WormadamForme::Sandy) => [60, 79, 105, 59, 85, 36],
+        Species::Wormadam(WormadamForme::Trash) => [60, 69, 95, 69, 95, 36],
+        Species::Mothim => [70, 94, 50, 94, 50, 66],
+        Species::Combee => [30, 30, 42, 30, 42, 70],
+        Species::Vespiquen => [70, 80, 102, 80, 102, 40],
+        Species::Pachirisu => [60, 45, 70, 45, 90, 95],
+        Species::Buizel => [55, 65, 35, 60, 30, 85],
+        Species::Floatzel => [85, 105, 55, 85, 50, 115],
+        Species::Cherubi => [45, 35, 45, 62, 53, 35],
+        Species::Cherrim => [70, 60, 70, 87, 78, 85],
+        Species::Shellos => [76, 48, 48, 57, 62, 34],
+        Species::Gastrodon => [111, 83, 68, 92, 82, 39],
+        Species::Ambipom => [75, 100, 66, 60, 66, 115],
+        Species::Drifloon => [90, 50, 34, 60, 44, 70],
+        Species::Drifblim => [150, 80, 44, 90, 54, 80],
+        Species::Buneary => [55, 66, 44, 44, 56, 85],
+        Species::Lopunny(MegaEvolution::Normal) => [65, 76, 84, 54, 96, 105],
+        Species::Lopunny(MegaEvolution::Mega) => [65, 136, 94, 54, 96, 135],
+        Species::Mismagius => [60, 60, 60, 105, 105, 105],
+        Species::Honchkrow => [100, 125, 52, 105, 52, 71],
+        Species::Glameow => [49, 55, 42, 42, 37, 85],
+        Species::Purugly => [71, 82, 64, 64, 59, 112],
+        Species::Chingling => [45, 30, 50, 65, 50, 45],
+        Species::Stunky => [63, 63, 47, 41, 41, 74],
+        Species::Skuntank => [103, 93, 67, 71, 61, 84],
+        Species::Bronzor => [57, 24, 86, 24, 86, 23],
+        Species::Bronzong => [67, 89, 116, 79, 116, 33],
+        Species::Bonsly => [50, 80, 95, 10, 45, 10],
+        Species::MimeJr => [20, 25, 45, 70, 90, 60],
+        Species::Happiny => [100, 5, 5, 15, 65, 30],
+        Species::Chatot => [76, 65, 45, 92, 42, 91],
+        Species::Spiritomb => [50, 92, 108, 92, 108, 35],
+        Species::Gible => [58, 70, 45, 40, 45, 42],
+        Species::Gabite => [68, 90, 65, 50, 55, 82],
+        Species::Garchomp(MegaEvolution::Normal) => [108, 130, 95, 80, 85, 102],
+        Species::Garchomp(MegaEvolution::Mega) => [108, 170, 115, 120, 95, 92],
+        Species::Munchlax => [135, 85, 40, 40, 85, 5],
+        Species::Riolu => [40, 70, 40, 35, 40, 60],
+        Species::Lucario(MegaEvolution::Normal) => [70, 110, 70, 115, 70, 90],
+        Species::Lucario(MegaEvolution::Mega) => [70, 145, 88, 140, 70, 112],
+        Species::Hippopotas => [68, 72, 78, 38, 42, 32],
+        Species::Hippowdon => [108, 112, 118, 68, 72, 47],
+        Species::Skorupi => [40, 50, 90, 30, 55, 65],
+        Species::Drapion => [70, 90, 110, 60, 75, 95],
+        Species::Croagunk => [48, 61, 40, 61, 40, 50],
+        Species::Toxicroak => [83, 106, 65, 86, 65, 85],
+        Species::Carnivine => [74, 100, 72, 90, 72, 46],
+        Species::Finneon => [49, 49, 56, 49, 61, 66],
+        Species::Lumineon => [69, 69, 76, 69, 86, 91],
+        Species::Mantyke => [45, 20, 50, 60, 120, 50],
+        Species::Snover => [60, 62, 50, 62, 60, 40],
+        Species::Abomasnow(MegaEvolution::Normal) => [90, 92, 75, 92, 85, 60],
+        Species::Abomasnow(MegaEvolution::Mega) => [90, 132, 105, 132, 105, 30],
+        Species::Weavile => [70, 120, 65, 45, 85, 125],
+        Species::Magnezone => [70, 70, 115, 130, 90, 60],
+        Species::Lickilicky => [110, 85, 95, 80, 95, 50],
+        Species::Rhyperior => [115, 140, 130, 55, 55, 40],
+        Species::Tangrowth => [100, 100, 125, 110, 50, 50],
+        Species::Electivire => [75, 123, 67, 95, 85, 95],
+        Species::Magmortar => [75, 95, 67, 125, 95, 83],
+        Species::Togekiss => [85, 50, 95, 120, 115, 80],
+        Species::Yanmega => [86, 76, 86, 116, 56, 95],
+        Species::Leafeon => [65, 110, 130, 60, 65, 95],
+        Species::Glaceon => [65, 60, 110, 130, 95, 65],
+        Species::Gliscor => [75, 95, 125, 45, 75, 95],
+        Species::Mamoswine => [110, 130, 80, 70, 60, 80],
+        Species::PorygonZ => [85, 80, 70, 135, 75, 90],
+        Species::Gallade(MegaEvolution::Normal) => [68, 125, 65, 65, 115, 80],
+        Species::Gallade(MegaEvolution::Mega) => [68, 165, 95, 65, 115, 110],
+        Species::Probopass => [60, 55, 145, 75, 150, 40],
+        Species::Dusknoir => [45, 100, 135, 65, 135, 45],
+        Species::Froslass => [70, 80, 70, 80, 70, 110],
+        Species::Rotom(RotomForme::Ghost) => [50, 50, 77, 95, 77, 91],
+        Species::Rotom(_) => [50, 65, 107, 105, 107, 86],
+        Species::Uxie => [75, 75, 130, 75, 130, 95],
+        Species::Mesprit => [80, 105, 105, 105, 105, 80],
+        Species::Azelf => [75, 125, 70, 125, 70, 115],
+        Species::Dialga => [100, 120, 120, 150, 100, 90],
+        Species::Palkia => [90, 120, 100, 150, 120, 100],
+        Species::Heatran => [91, 90, 106, 130, 106, 77],
+        Species::Regigigas => [110, 160, 110, 80, 110, 100],
+        Species::Giratina(GiratinaForme::Altered) => [150, 100, 120, 100, 120, 90],
+        Species::Giratina(GiratinaForme::Origin) => [150, 120, 100, 120, 100, 90],
+        Species::Cresselia => [120, 70, 110, 75, 120, 85],
+        Species::Phione => [80, 80, 80, 80, 80, 80],
+        Species::Manaphy => [100, 100, 100, 100, 100, 100],
+        Species::Darkrai => [70, 90, 90, 135, 90, 125],
+        Species::Shaymin(ShayminForme::Land) => [100, 100, 100, 100, 100, 100],
+        Species::Shaymin(ShayminForme::Sky) => [100, 103, 75, 120, 75, 127],
+        Species::Arceus(_) => [120, 120, 120, 120, 120, 120],
+        // gen 5 starts here
+        Species::Victini => [100, 100, 100, 100, 100, 100],
+        Species::Snivy => [45, 45, 55, 45, 55, 63],
+        Species::Servine => [60, 60, 75, 60, 75, 83],
+        Species::Serperior => [75, 75, 95, 75, 95, 113],
+        Species::Tepig => [65, 63, 45, 45, 45, 45],
+        Species::Pignite => [90, 93, 55, 70, 55, 55],
+        Species::Emboar => [110, 123, 65, 100, 65, 65],
+        Species::Oshawott => [55, 55, 45, 63, 45, 45],
+        Species::Dewott => [75, 75, 60, 83, 60, 60],
+        Species::Samurott => [95, 100, 85, 108, 70, 70],
+        Species::Patrat => [45, 55, 39, 35, 39, 42],
+        Species::Watchog => [60, 85, 69, 60, 69, 77],
+        Species::Lillipup => [45, 60, 45, 25, 45, 55],
+        Species::Herdier => [65, 80, 65, 35, 65, 60],
+        Species::Stoutland => [85, 110, 90, 45, 90, 80],
+        Species::Purrloin => [41, 50, 37, 50, 37, 66],
+        Species::Liepard => [64, 88, 50, 88, 50, 106],
+        Species::Pansage => [50, 53, 48, 53, 48, 64],
+        Species::Simisage => [75, 98, 63, 98, 63, 101],
+        Species::Pansear => [50, 53, 48, 53, 48, 64],
+        Species::Simisear => [75, 98, 63, 98, 63, 101],
+        Species::Panpour => [50, 53, 48, 53, 48, 64],
+        Species::Simipour => [75, 98, 63, 98, 63, 101],
+        Species::Munna => [76, 25, 45, 67, 55, 24],
+        Species::Musharna => [116, 55, 85, 107, 95, 29],
+        Species::Pidove => [50, 55, 50, 36, 30, 43],
+        Species::Tranquill => [62, 77, 62, 50, 42, 65],
+        Species::Unfezant => [80, 115, 80, 65, 55, 93],
+        Species::Blitzle => [45, 60, 32, 50, 32, 76],
+        Species::Zebstrika => [75, 100, 63, 80, 63, 116],
+        Species::Roggenrola => [55, 75, 85, 25, 25, 15],
+        Species::Boldore => [70, 105, 105, 50, 40, 20],
+        Species::Gigalith => [85, 135, 130, 60, 80, 25],
+        Species::Woobat => [65, 45, 43, 55, 43, 72],
+        Species::Swoobat => [67, 57, 55, 77, 55, 114],
+        Species::Drilbur => [60, 85, 40, 30, 45, 68],
+        Species::Excadrill => [110, 135, 60, 50, 65, 88],
+        Species::Audino => [103, 60, 86, 60, 86, 50],
+        Species::Timburr => [75, 80, 55, 25, 35, 35],
+        Species::Gurdurr => [85, 105, 85, 40, 50, 40],
+        Species::Conkeldurr => [105, 140, 95, 55, 65, 45],
+        Species::Tympole => [50, 50, 40, 50, 40, 64],
+        Species::Palpitoad => [75, 65, 55, 65, 55, 69],
+        Species::Seismitoad => [105, 95, 75, 85, 75, 74],
+        Species::Throh => [120, 100, 85, 30, 85, 45],
+        Species::Sawk => [75, 125, 75, 30, 75, 85],
+        Species::Sewaddle => [45, 53, 70, 40, 60, 42],
+        Species::Swadloon => [55, 63, 90, 50, 80, 42],
+        Species::Leavanny => [75, 103, 80, 70, 80, 92],
+        Species::Venipede => [30, 45, 59, 30, 39, 57],
+        Species::Whirlipede => [40, 55, 99, 40, 79, 47],
+        Species::Scolipede => [60, 100, 89, 55, 69, 112],
+        Species::Cottonee => [40, 27, 60, 37, 50, 66],
+        Species::Whimsicott => [60, 67, 85, 77, 75, 116],
+        Species::Petilil => [45, 35, 50, 70, 50, 30],
+        Species::Lilligant => [70, 60, 75, 110, 75, 90],
+        Species::Basculin => [70, 92, 65, 80, 55, 98],
+        Species::Sandile => [50, 72, 35, 35, 35, 65],
+        Species::Krokorok => [60, 82, 45, 45, 45, 74],
+        Species::Krookodile => [95, 117, 80, 65, 70, 92],
+        Species::Darumaka => [70, 90, 45, 15, 45, 50],
+        Species::Darmanitan(DarmanitanForme::Standard) => [105, 140, 55, 30, 55, 95],
+        Species::Darmanitan(DarmanitanForme::ZenMode) => [105, 30, 105, 140, 105, 55],
+        Species::Maractus => [75, 86, 67, 106, 67, 60],
+        Species::Dwebble => [50, 65, 85, 35, 35, 55],
+        Species::Crustle => [70, 105, 125, 65, 75, 45],
+        Species::Scraggy => [50, 75, 70, 35, 70, 48],
+        Species::Scrafty => [65, 90, 115, 45, 115, 58],
+        Species::Sigilyph => [72, 58, 80, 103, 80, 97],
+        Species::Yamask => [38, 30, 85, 55, 65, 30],
+        Species::Cofagrigus => [58, 50, 145, 95, 105, 30],
+        Species::Tirtouga => [54, 78, 103, 53, 45, 22],
+        Species::Carracosta => [74, 108, 133, 83, 65, 32],
+        Species::Archen => [55, 112, 45, 74, 45, 70],
+        Species::Archeops => [75, 140, 65, 112, 65, 110],
+        Species::Trubbish => [50, 50, 62, 40, 62, 65],
+        Species::Garbodor => [80, 95, 82, 60, 82, 75],
+        Species::Zorua => [40, 65, 40, 80, 40, 65],
+        Species::Zoroark => [60, 105, 60, 120, 60, 105],
+        Species::Minccino => [55, 50, 40, 40, 40, 75],
+        Species::Cinccino => [75, 95, 60, 65, 60, 115],
+        Species::Gothita => [45, 30, 50, 55, 65, 45],
+        Species::Gothorita => [60, 45, 70, 75, 85, 55],
+        Species::Gothitelle => [70, 55, 95, 95, 110, 65],
+        Species::Solosis => [45, 30, 40, 105, 50, 20],
+        Species::Duosion => [65, 40, 50, 125, 60, 30],
+        Species::Reuniclus => [110, 65, 75, 125, 85, 30],
+        Species::Ducklett => [62, 44, 50, 44, 50, 55],
+        Species::Swanna => [75, 87, 63, 87, 63, 98],
+        Species::Vanillite => [36, 50, 50, 65, 60, 44],
+        Species::Vanillish => [51, 65, 65, 80, 75, 59],
+        Species::Vanilluxe => [71, 95, 85, 110, 95, 79],
+        Species::Deerling => [60, 60, 50, 40, 50, 75],
+        Species::Sawsbuck => [80, 100, 70, 60, 70, 95],
+        Species::Emolga => [55, 75, 60, 75, 60, 103],
+        Species::Karrablast => [50, 75, 45, 40, 45, 60],
+        Species::Escavalier => [70, 135, 105, 60, 105, 20],
+        Species::Foongus => [69, 55, 45, 55, 55, 15],
+        Species::Amoonguss => [114, 85, 70, 85, 80, 30],
+        Species::Frillish => [55, 40, 50, 65, 85, 40],
+        Species::Jellicent => [100, 60, 70, 85, 105, 60],
+        Species::Alomomola => [165, 75, 80, 40, 45, 65],
+        Species::Joltik => [50, 47, 50, 57, 50, 65],
+        Species::Galvantula => [70, 77, 60, 97, 60, 108],
+        Species::Ferroseed => [44, 50, 91, 24, 86, 10],
+        Species::Ferrothorn => [74, 94, 131, 54, 116, 20],
+        Species::Klink => [40, 55, 70, 45, 60, 30],
+        Species::Klang => [60, 80, 95, 70, 85, 50],
+        Species::Klinklang => [60, 100, 115, 70, 85, 90],
+        Species::Tynamo => [35, 55, 40, 45, 40, 60],
+        Species::Eelektrik => [65, 85, 70, 75, 70, 40],
+        Species::Eelektross => [85, 115, 80, 105, 80, 50],
+        Species::Elgyem => [55, 55, 55, 85, 55, 30],
+        Species::Beheeyem => [75, 75, 75, 125, 95, 40],
+        Species::Litwick => [50, 30, 55, 65, 55, 20],
+        Species::Lampent => [60, 40, 60, 95, 60, 55],
+        Species::Chandelure => [60, 55, 90, 145, 90, 80],
+        Species::Axew => [46, 87, 60, 30, 40, 57],
+        Species::Fraxure => [66, 117, 70, 40, 50, 67],
+        Species::Haxorus => [76, 147, 90, 60, 70, 97],
+        Species::Cubchoo => [55, 70, 40, 60, 40, 40],
+        Species::Beartic => [95, 130, 80, 70, 80, 50],
+        Species::Cryogonal => [80, 50, 50, 95, 135, 105],
+        Species::Shelmet => [50, 40, 85, 40, 65, 25],
+        Species::Accelgor => [80, 70, 40, 100, 60, 145],
+        Species::Stunfisk => [109, 66, 84, 81, 99, 32],
+        Species::Mienfoo => [45, 85, 50, 55, 50, 65],
+        Species::Mienshao => [65, 125, 60, 95, 60, 105],
+        Species::Druddigon => [77, 120, 90, 60, 90, 48],
+        Species::Golett => [59, 74, 50, 35, 50, 35],
+        Species::Golurk => [89, 124, 80, 55, 80, 55],
+        Species::Pawniard => [45, 85, 70, 40, 40, 60],
+        Species::Bisharp => [65, 125, 100, 60, 70, 70],
+        Species::Bouffalant => [95, 110, 95, 40, 95, 55],
+        Species::Rufflet => [70, 83, 50, 37, 50, 60],
+        Species::Braviary => [100, 123, 75, 57, 75, 80],
+        Species::Vullaby => [70, 55, 75, 45, 65, 60],
+        Species::Mandibuzz => [110, 65, 105, 55, 95, 80],
+        Species::Heatmor => [85, 97, 66, 105, 66, 65],
+        Species::Durant => [58, 109, 112, 48, 48, 109],
+        Species::Deino => [52, 65, 50, 45, 50, 38],
+        Species::Zweilous => [72, 85, 70, 65, 70, 58],
+        Species::Hydreigon => [92, 105, 90, 125, 90, 98],
+        Species::Larvesta => [55, 85, 55, 50, 55, 60],
+        Species::Volcarona => [85, 60, 65, 135, 105, 100],
+        Species::Cobalion => [91, 90, 129, 90, 72, 108],
+        Species::Terrakion => [91, 129, 90, 72, 90, 108],
+        Species::Virizion => [91, 90, 72, 90, 129, 108],
+        Species::Tornadus(GenieForme::Incarnate) => [79, 115, 70, 125, 80, 111],
+        Species::Tornadus(GenieForme::Therian) => [79, 100, 80, 110, 90, 121],
+        Species::Thundurus(GenieForme::Incarnate) => [79, 115, 70, 125, 80, 111],
+        Species::Thundurus(GenieForme::Therian) => [79, 105, 70, 145, 80, 101],
+        Species::Reshiram => [100, 120, 100, 150, 120, 90],
+        Species::Zekrom => [100, 150, 120, 120, 100, 90],
+        Species::Landorus(GenieForme::Incarnate) => [89, 125, 90, 115, 80, 101],
+        Species::Landorus(GenieForme::Therian) => [89, 145, 90, 105, 80, 91],
+        Species::Kyurem(KyuremForme::Normal) => [125, 130, 90, 130, 90, 95],
+        Species::Kyurem(KyuremForme::Black) => [125, 170, 100, 120, 90, 95],
+        Species::Kyurem(KyuremForme::White) => [125, 120, 90, 170, 100, 95],
+        Species::Keldeo => [91, 72, 90, 129, 90, 108],
+        Species::Meloetta(MeloettaForme::Aria) => [100, 77, 77, 128, 128, 90],
+        Species::Meloetta(MeloettaForme::Pirouette) => [100, 128, 90, 77, 77, 128],
+        Species::Genesect => [71, 120, 95, 120, 95, 99],
+        // gen 6 starts here
+        Species::Chespin => [56, 61, 65, 48, 45, 38],
+        Species::Quilladin => [61, 78, 95, 56, 58, 57],
+        Species::Chesnaught => [88, 107, 122, 74, 75, 64],
+        Species::Fennekin => [40, 45, 40, 62, 60, 60],
+        Species::Braixen => [59, 59, 58, 90, 70, 73],
+        Species::Delphox => [75, 69, 72, 114, 100, 104],
+        Species::Froakie => [41, 56, 40, 62, 44, 71],
+        Species::Frogadier => [54, 63, 52, 83, 56, 97],
+        Species::Greninja(GreninjaForme::Ash) => [72, 145, 67, 153, 71, 132],
+        Species::Greninja(_) => [72, 95, 67, 103, 71, 122],
+        Species::Bunnelby => [38, 36, 38, 32, 36, 57],
+        Species::Diggersby => [85, 56, 77, 50, 77, 78],
+        Species::Fletchling => [45, 50, 43, 40, 38, 62],
+        Species::Fletchinder => [62, 73, 55, 56, 52, 84],
+        Species::Talonflame => [78, 81, 71, 74, 69, 126],
+        Species::Scatterbug => [38, 35, 40, 27, 25, 35],
+        Species::Spewpa => [45, 22, 60, 27, 30, 29],
+        Species::Vivillon => [80, 52, 50, 90, 50, 89],
+        Species::Litleo => [62, 50, 58, 73, 54, 72],
+        Species::Pyroar => [86, 68, 72, 109, 66, 106],
+        Species::Flabebe => [44, 38, 39, 61, 79, 42],
+        Species::Floette => [54, 45, 47, 75, 98, 52],
+        Species::Florges => [78, 65, 68, 112, 154, 75],
+        Species::Skiddo => [66, 65, 48, 62, 57, 52],
+        Species::Gogoat => [123, 100, 62, 97, 81, 68],
+        Species::Pancham => [67, 82, 62, 46, 48, 43],
+        Species::Pangoro => [95, 124, 78, 69, 71, 58],
+        Species::Furfrou => [75, 80, 60, 65, 90, 102],
+        Species::Espurr => [62, 48, 54, 63, 60, 68],
+        Species::Meowstic => [74, 48, 76, 83, 81, 104],
+        Species::Honedge => [45, 80, 100, 35, 37, 28],
+        Species::Doublade => [59, 110, 150, 45, 49, 35],
+        Species::Aegislash(AegislashForme::Shield) => [60, 50, 150, 50, 150, 60],
+        Species::Aegislash(AegislashForme::Sword) => [60, 150, 50, 150, 50, 60],
+        Species::Spritzee => [78, 52, 60, 63, 65, 23],
+        Species::Aromatisse => [101, 72, 72, 99, 89, 29],
+        Species::Swirlix => [62, 48, 66, 59, 57, 49],
+        Species::Slurpuff => [82, 80, 86, 85, 75, 72],
+        Species::Inkay => [53, 54, 53, 37, 46, 45],
+        Species::Malamar => [86, 92, 88, 68, 75, 73],
+        Species::Binacle => [42, 52, 67, 39, 56, 50],
+        Species::Barbaracle => [72, 105, 115, 54, 86, 68],
+        Species::Skrelp => [50, 60, 60, 60, 60, 30],
+        Species::Dragalge => [65, 75, 90, 97, 123, 44],
+        Species::Clauncher => [50, 53, 62, 58, 63, 44],
+        Species::Clawitzer => [71, 73, 88, 120, 89, 59],
+        Species::Helioptile => [44, 38, 33, 61, 43, 70],
+        Species::Heliolisk => [62, 55, 52, 109, 94, 109],
+        Species::Tyrunt => [58, 89, 77, 45, 45, 48],
+        Species::Tyrantrum => [82, 121, 119, 69, 59, 71],
+        Species::Amaura => [77, 59, 50, 67, 63, 46],
+        Species::Aurorus => [123, 77, 72, 99, 92, 58],
+        Species::Sylveon => [95, 65, 65, 110, 130, 60],
+        Species::Hawlucha => [78, 92, 75, 74, 63, 118],
+        Species::Dedenne => [67, 58, 57, 81, 67, 101],
+        Species::Carbink => [50, 50, 150, 50, 150, 50],
+        Species::Goomy => [45, 50, 35, 55, 75, 40],
+        Species::Sliggoo => [68, 75, 53, 83, 113, 60],
+        Species::Goodra => [90, 100, 70, 110, 150, 80],
+        Species::Klefki => [57, 80, 91, 80, 87, 75],
+        Species::Phantump => [43, 70, 48, 50, 60, 38],
+        Species::Trevenant => [85, 110, 76, 65, 82, 56],
+        Species::Pumpkaboo(GourgeistForme::Small) => [44, 66, 70, 44, 55, 56],
+        Species::Pumpkaboo(GourgeistForme::Average) => [49, 66, 70, 44, 55, 51],
+        Species::Pumpkaboo(GourgeistForme::Large) => [54, 66, 70, 44, 55, 46],
+        Species::Pumpkaboo(GourgeistForme::Super) => [59, 66, 70, 44, 55, 41],
+        Species::Gourgeist(GourgeistForme::Small) => [55, 85, 122, 58, 75, 99],
+        Species::Gourgeist(GourgeistForme::Average) => [65, 90, 122, 58, 75, 84],
+        Species::Gourgeist(GourgeistForme::Large) => [75, 95, 122, 58, 75, 69],
+        Species::Gourgeist(GourgeistForme::Super) => [85, 100, 122, 58, 75, 54],
+        Species::Bergmite => [55, 69, 85, 32, 35, 28],
+        Species::Avalugg => [95, 117, 184, 44, 46, 28],
+        Species::Noibat => [40, 30, 35, 45, 40, 55],
+        Species::Noivern => [85, 70, 80, 97, 80, 123],
+        Species::Xerneas => [126, 131, 95, 131, 98, 99],
+        Species::Yveltal => [126, 131, 95, 131, 98, 99],
+        Species::Zygarde(ZygardeForme::TenPercent) => [54, 100, 71, 61, 85, 115],
+        Species::Zygarde(ZygardeForme::FiftyPercent) => [108, 100, 121, 81, 95, 95],
+        Species::Zygarde(ZygardeForme::Complete) => [216, 100, 121, 91, 95, 85],
+        Species::Diancie(MegaEvolution::Normal) => [50, 100, 150, 100, 150, 50],
+        Species::Diancie(MegaEvolution::Mega) => [50, 160, 110, 160, 110, 110],
+        Species::Hoopa(HoopaForme::Confined) => [80, 110, 60, 150, 130, 70],
+        Species::Hoopa(HoopaForme::Unbound) => [80, 160, 60, 170, 130, 80],
+        Species::Volcanion => [80, 110, 120, 130, 90, 70],
+        // gen 7 starts here
+        Species::Rowlet => [68, 55, 55, 50, 50, 42],
+        Species::Dartrix => [78, 75, 75, 70, 70, 52],
+        Species::Decidueye => [78, 107, 75, 100, 100, 70],
+        Species::Litten => [45, 65, 40, 60, 40, 70],
+        Species::Torracat => [65, 85, 50, 80, 50, 90],
+        Species::Incineroar => [95, 115, 90, 80, 90, 60],
+        Species::Popplio => [50, 54, 54, 66, 56, 40],
+        Species::Brionne => [60, 69, 69, 91, 81, 50],
+        Species::Primarina => [80, 74, 74, 126, 116, 60],
+        Species::Pikipek => [35, 75, 30, 30, 30, 65],
+        Species::Trumbeak => [55, 85, 50, 40, 50, 75],
+        Species::Toucannon => [80, 120, 75, 75, 75, 60],
+        Species::Yungoos => [48, 70, 30, 30, 30, 45],
+        Species::Gumshoos => [88, 110, 60, 55, 60, 45],
+        Species::Grubbin => [47, 62, 45, 55, 45, 46],
+        Species::Charjabug => [57, 82, 95, 55, 75, 36],
+        Species::Vikavolt => [77, 70, 90, 145, 75, 43],
+        Species::Crabrawler => [47, 82, 57, 42, 47, 63],
+        Species::Crabominable => [97, 132, 77, 62, 67, 43],
+        Species::Oricorio(_) => [75, 70, 70, 98, 70, 93],
+        Species::Cutiefly => [40, 45, 40, 55, 40, 84],
+        Species::Ribombee => [60, 55, 60, 95, 70, 124],
+        Species::Rockruff => [45, 65, 40, 30, 40, 60],
+        Species::Lycanroc(LycanrocForme::Midday) => [75, 115, 65, 55, 65, 112],
+        Species::Lycanroc(LycanrocForme::Midnight) => [85, 115, 75, 55, 75, 82],
+        Species::Lycanroc(LycanrocForme::Dusk) => [75, 117, 65, 55, 65, 110],
+        Species::Wishiwashi(WishiwashiForme::School) => [45, 140, 130, 140, 135, 30],
+        Species::Wishiwashi(WishiwashiForme::Solo) => [45, 20, 20, 25, 25, 40],
+        Species::Mareanie => [50, 53, 62, 43, 52, 45],
+        Species::Toxapex => [50, 63, 152, 53, 142, 35],
+        Species::Mudbray => [70, 100, 70, 45, 55, 45],
+        Species::Mudsdale => [100, 125, 100, 55, 85, 35],
+        Species::Dewpider => [38, 40, 52, 40, 72, 27],
+        Species::Araquanid => [68, 70, 92, 50, 132, 42],
+        Species::Fomantis => [40, 55, 35, 50, 35, 35],
+        Species::Lurantis => [70, 105, 90, 80, 90, 45],
+        Species::Morelull => [40, 35, 55, 65, 75, 15],
+        Species::Shiinotic => [60, 45, 80, 90, 100, 30],
+        Species::Salandit => [48, 44, 40, 71, 40, 77],
+        Species::Salazzle => [68, 64, 60, 111, 60, 117],
+        Species::Stufful => [70, 75, 50, 45, 50, 50],
+        Species::Bewear => [120, 125, 80, 55, 60, 60],
+        Species::Bounsweet => [42, 30, 38, 30, 38, 32],
+        Species::Steenee => [52, 40, 48, 40, 48, 62],
+        Species::Tsareena => [72, 120, 98, 50, 98, 72],
+        Species::Comfey => [51, 52, 90, 82, 110, 100],
+        Species::Oranguru => [90, 60, 80, 90, 110, 60],
+        Species::Passimian => [100, 120, 90, 40, 60, 80],
+        Species::Wimpod => [25, 35, 40, 20, 30, 80],
+        Species::Golisopod => [75, 125, 140, 60, 90, 40],
+        Species::Sandygast => [55, 55, 80, 70, 45, 15],
+        Species::Palossand => [85, 75, 110, 100, 75, 35],
+        Species::Pyukumuku => [55, 60, 130, 30, 130, 5],
+        Species::TypeNull(_) => [95, 95, 95, 95, 95, 59],
+        Species::Silvally(_) => [95, 95, 95, 95, 95, 95],
+        Species::Minior(MiniorForme::Meteor) => [60, 60, 100, 60, 100, 60],
+        Species::Minior(MiniorForme::Core) => [60, 100, 60, 100, 60, 120],
+        Species::Komala => [65, 115, 65, 75, 95, 65],
+        Species::Turtonator => [60, 78, 135, 91, 85, 36],
+        Species::Togedemaru => [65, 98, 63, 40, 73, 96],
+        Species::Mimikyu(_) => [55, 90, 80, 50, 105, 96],
+        Species::Bruxish => [68, 105, 70, 70, 70, 92],
+        Species::Drampa => [78, 60, 85, 135, 91, 36],
+        Species::Dhelmise => [70, 131, 100, 86, 90, 40],
+        Species::JangmoO => [45, 55, 65, 45, 45, 45],
+        Species::HakamoO => [55, 75, 90, 65, 70, 65],
+        Species::KommoO => [75, 110, 125, 100, 105, 85],
+        Species::TapuKoko => [70, 115, 85, 95, 75, 130],
+        Species::TapuLele => [70, 85, 75, 130, 115, 95],
+        Species::TapuBulu => [70, 130, 115, 85, 95, 75],
+        Species::TapuFini => [70, 75, 115, 95, 130, 85],
+        Species::Cosmog => [43, 29, 31, 29, 31, 37],
+        Species::Cosmoem => [43, 29, 131, 29, 131, 37],
+        Species::Solgaleo => [137, 137, 107, 113, 89, 97],
+        Species::Lunala => [137, 113, 89, 137, 107, 97],
+        Species::Nihilego => [109, 53, 47, 127, 131, 103],
+        Species::Buzzwole => [107, 139, 139, 53, 53, 79],
+        Species::Pheromosa => [71, 137, 37, 137, 37, 151],
+        Species::Xurkitree => [83, 89, 71, 173, 71, 83],
+        Species::Celesteela => [97, 101, 103, 107, 101, 61],
+        Species::Kartana => [59, 181, 131, 59, 31, 109],
+        Species::Guzzlord => [223, 101, 53, 97, 53, 43],
+        Species::Necrozma(NecrozmaForme::Normal) => [97, 107, 101, 127, 89, 79],
+        Species::Necrozma(NecrozmaForme::DuskMane) => [97, 157, 127, 113, 109, 77],
+        Species::Necrozma(NecrozmaForme::DuskWings) => [97, 113, 109, 157, 127, 77],
+        Species::Necrozma(NecrozmaForme::Ultra) => [97, 167, 97, 167, 97, 129],
+        Species::Magearna => [80, 95, 115, 130, 115, 65],
+        Species::Marshadow => [90, 125, 80, 90, 90, 125],
+        Species::Poipole => [67, 73, 67, 73, 67, 73],
+        Species::Naganadel => [73, 73, 73, 127, 73, 121],
+        Species::Stakataka => [61, 131, 211, 53, 101, 13],
+        Species::Blacephalon => [53, 127, 53, 151, 79, 107],
+        Species::Zeraora => [88, 112, 75, 102, 80, 143],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn test_base_stats() {
+        let garchomp = base_stats(Species::Garchomp(MegaEvolution::Normal));
+        assert_eq!(garchomp, BaseStats::from([108, 130, 95, 80, 85, 102]));
+        assert_eq!(garchomp.get(Stat::Spe), 102);
+        assert_eq!(base_stats(Species::Blissey).get(Stat::HP), 255);
+    }
+
+    #[test]
+    fn test_totals() {
+        assert_eq!(base_stats_total(Species::Garchomp(MegaEvolution::Normal)), 600);
+        assert_eq!(base_stats_total(Species::Arceus(ArceusForme::Fire)), 720);
+        assert_eq!(base_stats_total(Species::Shedinja), 236);
+    }
+
+    #[test]
+    fn test_mega_totals() {
+        assert_eq!(base_stats_total(Species::Garchomp(MegaEvolution::Mega)),
+                   base_stats_total(Species::Garchomp(MegaEvolution::Normal)) + 100);
+        assert_eq!(base_stats_total(Species::Mawile(MegaEvolution::Mega)),
+                   base_stats_total(Species::Mawile(MegaEvolution::Normal)) + 100);
+        assert_eq!(base_stats_total(Species::Charizard(XYMegaEvolution::MegaX)),
+                   base_stats_total(Species::Charizard(XYMegaEvolution::Normal)) + 100);
+        assert_eq!(base_stats_total(Species::Charizard(XYMegaEvolution::MegaY)),
+                   base_stats_total(Species::Charizard(XYMegaEvolution::Normal)) + 100);
+        assert_eq!(base_stats_total(Species::Kyogre(PrimalReversion::Primal)),
+                   base_stats_total(Species::Kyogre(PrimalReversion::Normal)) + 100);
+    }
+
+    #[test]
+    fn test_pseudo_legendaries() {
+        assert!(is_pseudo_legendary(Species::Garchomp(MegaEvolution::Normal)));
+        assert!(is_pseudo_legendary(Species::KommoO));
+        assert!(!is_pseudo_legendary(Species::Garchomp(MegaEvolution::Mega)));
+        assert!(!is_pseudo_legendary(Species::Slaking));
+        assert!(!is_pseudo_legendary(Species::Mew));
+    }
+
+    #[test]
+    fn test_stat_change_on_mega() {
+        assert_eq!(stat_change_on_mega(Species::Charizard(XYMegaEvolution::Normal),
+                                       Species::Charizard(XYMegaEvolution::MegaY)),
+                   [0, 20, 0, 50, 30, 0]);
+        assert_eq!(stat_change_on_mega(Species::Mawile(MegaEvolution::Normal),
+                                       Species::Mawile(MegaEvolution::Mega)),
+                   [0, 20, 40, 0, 40, 0]);
+        assert_eq!(stat_change_on_mega(Species::Groudon(PrimalReversion::Normal),
+                                       Species::Groudon(PrimalReversion::Primal)).iter().sum::<i16>(), 100);
+    }
+
+    #[test]
+    fn test_every_mega_adds_100() {
+        for mega in all_species_with_formes().filter(|&s| is_mega(s)) {
+            let base = Species::from(SpeciesDiscriminant::from(mega));
+            let delta = stat_change_on_mega(base, mega);
+            assert_eq!(delta.iter().sum::<i16>(), 100, "{:?}", mega);
+            assert_eq!(delta[0], 0, "{:?}", mega);
+        }
+    }
+}
@@ -1 +1,179 @@
-//! This file defines a struct that encodes all of the state information in a Pokemon battle. 
+//! This file describes the state of the battlefield that affects calculations, like the weather and
+//! terrain, and uses it to combine the many stat multipliers that items and abilities provide.
+//! Multipliers that depend on more than the field, like Guts or Eviolite, aren't handled here.
+//!
+//! It also adds up the damage and healing a Pokemon gets at the end of each turn, so a battle loop
+//! only has one thing to call.
+
+use crate::ability::Ability;
+use crate::item::{passive_heal, Item};
+use crate::stat::Stat;
+use crate::stat_stage::StatStage;
+use crate::status::Status;
+use crate::terrain::Terrain;
+use crate::typing::Typing;
+use crate::weather::{hail_damage, sandstorm_damage, Weather};
+
+/// The conditions on the field that can affect a calculation. `BattleContext::default()` gives a
+/// field without any weather or terrain.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BattleContext {
+    pub weather: Weather,
+    pub terrain: Option<Terrain>,
+}
+
+impl Default for BattleContext {
+    fn default() -> BattleContext {
+        BattleContext{weather: Weather::Normal, terrain: None}
+    }
+}
+
+impl BattleContext {
+    /// Returns true if the sun is out, either normally or from Primal Groudon.
+    fn is_sunny(self) -> bool {
+        self.weather == Weather::Sun || self.weather == Weather::HarshSun
+    }
+
+    /// Returns true if it's raining, either normally or from Primal Kyogre.
+    fn is_rainy(self) -> bool {
+        self.weather == Weather::Rain || self.weather == Weather::HeavyRain
+    }
+}
+
+/// Returns the multiplier the given held item applies to the given stat: 1.5x for the Choice items on
+/// their respective stats and for Assault Vest on Special Defense, and 0.5x Speed for Iron Ball. Any
+/// other item, or no item, gives 1.
+pub fn item_stat_multiplier(item: Option<Item>, stat: Stat) -> f64 {
+    match (item, stat) {
+        (Some(Item::ChoiceBand), Stat::Atk) => 1.5,
+        (Some(Item::ChoiceSpecs), Stat::SpA) => 1.5,
+        (Some(Item::ChoiceScarf), Stat::Spe) => 1.5,
+        (Some(Item::AssaultVest), Stat::SpD) => 1.5,
+        (Some(Item::IronBall), Stat::Spe) => 0.5,
+        _ => 1.0,
+    }
+}
+
+/// Returns the multiplier the given ability applies to the given stat under the given conditions. This
+/// covers the abilities that always boost a stat, like Huge Power, and the ones that depend on the
+/// weather or terrain, like Swift Swim. Any other ability gives 1.
+pub fn ability_stat_multiplier(ability: Ability, stat: Stat, context: &BattleContext) -> f64 {
+    match (ability, stat) {
+        (Ability::HugePower, Stat::Atk) | (Ability::PurePower, Stat::Atk) => 2.0,
+        (Ability::Hustle, Stat::Atk) => 1.5,
+        (Ability::SwiftSwim, Stat::Spe) if context.is_rainy() => 2.0,
+        (Ability::Chlorophyll, Stat::Spe) if context.is_sunny() => 2.0,
+        (Ability::SandRush, Stat::Spe) if context.weather == Weather::Sand => 2.0,
+        (Ability::SlushRush, Stat::Spe) if context.weather == Weather::Hail => 2.0,
+        (Ability::SurgeSurfer, Stat::Spe) if context.terrain == Some(Terrain::Electric) => 2.0,
+        (Ability::SolarPower, Stat::SpA) if context.is_sunny() => 1.5,
+        (Ability::FlowerGift, Stat::Atk) | (Ability::FlowerGift, Stat::SpD) if context.is_sunny() => 1.5,
+        _ => 1.0,
+    }
+}
+
+/// Returns the stat a Pokemon actually has in battle, given its computed stat (as from `calc_stat`)
+/// and its stat stage, held item, and ability. The stage multiplier is applied first, then the item,
+/// then the ability, truncating after each step as the games do. HP doesn't have stages or
+/// multipliers, so it's returned unchanged.
+pub fn effective_stat(computed: u16, stat: Stat, stage: StatStage, item: Option<Item>,
+                      ability: Ability, context: &BattleContext) -> u16 {
+    if stat == Stat::HP {
+        return computed;
+    }
+    let truncate = |value: u16, multiplier: f64| (f64::from(value) * multiplier).floor() as u16;
+    let staged = truncate(computed, stage.normal_multiplier());
+    let with_item = truncate(staged, item_stat_multiplier(item, stat));
+    truncate(with_item, ability_stat_multiplier(ability, stat, context))
+}
+
+/// Returns the net damage a Pokemon with the given typing and max HP takes at the end of a turn from
+/// the weather, its status, the terrain, and its held item. Sand and hail chip it, a damaging status
+/// like poison hurts it, Grassy Terrain heals 1/16 of max HP if it's grounded, and Leftovers or Black
+/// Sludge heal or hurt it as described in `passive_heal`. A negative result means the Pokemon
+/// recovers HP overall.
+pub fn residual_damage(typing: (Typing, Option<Typing>), max_hp: u16, weather: Weather,
+                       status: Option<Status>, grounded: bool, terrain: Option<Terrain>,
+                       item: Option<Item>) -> i32 {
+    let weather_damage = match weather {
+        Weather::Sand => sandstorm_damage(typing, max_hp),
+        Weather::Hail => hail_damage(typing, max_hp),
+        _ => 0,
+    };
+    let status_damage = status.map_or(0, |status| status.end_of_turn_damage(max_hp));
+    let healing = if grounded && terrain == Some(Terrain::Grassy) {
+        (max_hp / 16).max(1)
+    } else {
+        0
+    };
+    let item_healing = item.map_or(0, |item| passive_heal(item, typing, max_hp));
+    i32::from(weather_damage) + i32::from(status_damage) - i32::from(healing) - item_healing
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn test_item_stat_multiplier() {
+        assert_eq!(item_stat_multiplier(Some(Item::ChoiceScarf), Stat::Spe), 1.5);
+        assert_eq!(item_stat_multiplier(Some(Item::ChoiceScarf), Stat::Atk), 1.0);
+        assert_eq!(item_stat_multiplier(Some(Item::AssaultVest), Stat::SpD), 1.5);
+        assert_eq!(item_stat_multiplier(None, Stat::Spe), 1.0);
+    }
+    #[test]
+    fn test_ability_stat_multiplier() {
+        let clear = BattleContext::default();
+        assert_eq!(ability_stat_multiplier(Ability::HugePower, Stat::Atk, &clear), 2.0);
+        assert_eq!(ability_stat_multiplier(Ability::HugePower, Stat::SpA, &clear), 1.0);
+        assert_eq!(ability_stat_multiplier(Ability::SwiftSwim, Stat::Spe, &clear), 1.0);
+        let rain = BattleContext{weather: Weather::Rain, ..Default::default()};
+        assert_eq!(ability_stat_multiplier(Ability::SwiftSwim, Stat::Spe, &rain), 2.0);
+        assert_eq!(ability_stat_multiplier(Ability::Chlorophyll, Stat::Spe, &rain), 1.0);
+        let electric = BattleContext{terrain: Some(Terrain::Electric), ..Default::default()};
+        assert_eq!(ability_stat_multiplier(Ability::SurgeSurfer, Stat::Spe, &electric), 2.0);
+    }
+    #[test]
+    fn test_residual_damage() {
+        let vaporeon = (Typing::Water, None);
+        assert_eq!(residual_damage(vaporeon, 464, Weather::Normal, None, true, None, None), 0);
+        assert_eq!(residual_damage(vaporeon, 464, Weather::Sand, None, true, None, None), 29);
+        let grassy = Some(Terrain::Grassy);
+        // sand chip cancels out Grassy Terrain recovery
+        assert_eq!(residual_damage(vaporeon, 464, Weather::Sand, None, true, grassy, None), 0);
+        assert_eq!(residual_damage(vaporeon, 464, Weather::Normal, None, true, grassy, None), -29);
+        // an ungrounded Pokemon doesn't get healed
+        assert_eq!(residual_damage((Typing::Water, Some(Typing::Flying)), 464, Weather::Normal, None, false,
+                                   Some(Terrain::Grassy), None), 0);
+        // poison does 1/8, and Grassy Terrain heals back 1/16
+        assert_eq!(residual_damage(vaporeon, 464, Weather::Normal, Some(Status::Poison), true,
+                                   Some(Terrain::Grassy), None), 29);
+        assert_eq!(residual_damage(vaporeon, 464, Weather::Hail, Some(Status::Burn), true, None, None), 58);
+    }
+    #[test]
+    fn test_residual_items() {
+        let vaporeon = (Typing::Water, None);
+        let leftovers = Some(Item::Leftovers);
+        let black_sludge = Some(Item::BlackSludge);
+        // Leftovers cancels out sand
+        assert_eq!(residual_damage(vaporeon, 464, Weather::Sand, None, true, None, leftovers), 0);
+        assert_eq!(residual_damage(vaporeon, 464, Weather::Normal, None, true, None, black_sludge), 58);
+        let toxapex = (Typing::Poison, Some(Typing::Water));
+        assert_eq!(residual_damage(toxapex, 304, Weather::Normal, None, true, Some(Terrain::Grassy),
+                                   black_sludge), -38);
+    }
+    #[test]
+    fn test_effective_stat() {
+        let context = BattleContext::default();
+        assert_eq!(effective_stat(359, Stat::Atk, StatStage::Z0, None, Ability::RoughSkin, &context),
+                   359);
+        // +2 Choice Band Garchomp
+        assert_eq!(effective_stat(359, Stat::Atk, StatStage::P2, Some(Item::ChoiceBand),
+                                  Ability::RoughSkin, &context), 1077);
+        assert_eq!(effective_stat(333, Stat::Spe, StatStage::N1, Some(Item::ChoiceScarf),
+                                  Ability::RoughSkin, &context), 333);
+        assert_eq!(effective_stat(358, Stat::HP, StatStage::P6, Some(Item::ChoiceBand),
+                                  Ability::HugePower, &context), 358);
+    }
+}
@@ -0,0 +1,418 @@
+//! This file implements the damage formula, which brings together stats, base power, and the many
+//! multipliers that can apply to a single hit. The formula used is the one from Gen V onward, as
+//! described on [Bulbapedia](https://bulbapedia.bulbagarden.net/wiki/Damage).
+
+use std::convert::Into;
+
+use crate::ability::Ability;
+use crate::item::{berry_resist_multiplier, Item};
+use crate::matchup::ability_effectiveness_override;
+use crate::stat_stage::StatStage;
+use crate::typing::{defense_multiplier_for, Multiplier, Typing};
+
+/// The multipliers that apply to a single hit beyond the attacker's level, the relevant stats, and the
+/// move's base power. `DamageModifiers::default()` gives a hit with no modifiers at all: no STAB,
+/// neutral effectiveness, and no weather or terrain.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DamageModifiers {
+    /// The same-type attack bonus: 1.5 if the move matches one of the attacker's types, and 1.0
+    /// otherwise.
+    pub stab: f64,
+    /// The type effectiveness of the move against the defender.
+    pub effectiveness: Multiplier,
+    /// The weather multiplier: 1.5 for boosted moves (Water in rain, Fire in sun), 0.5 for weakened
+    /// ones, and 1.0 otherwise.
+    pub weather: f64,
+    /// The terrain multiplier, which applies to the move's base power: 1.5 for Electric, Grassy, and
+    /// Psychic terrain boosts, 0.5 for Misty terrain against Dragon moves, and 1.0 otherwise.
+    pub terrain: f64,
+    /// Any other multiplier, like the 1.3x from a Life Orb, applied last.
+    pub other: f64,
+    /// The attacker's stat stage in the attacking stat.
+    pub attack_stage: StatStage,
+    /// The defender's stat stage in the defending stat.
+    pub defense_stage: StatStage,
+    /// The number of targets the move hits. A spread move that hits more than one target does less
+    /// damage to each; see `spread_multiplier`. This is always 1 in singles.
+    pub targets: u8,
+}
+
+impl Default for DamageModifiers {
+    fn default() -> DamageModifiers {
+        DamageModifiers {
+            stab: 1.0,
+            effectiveness: Multiplier::Regular,
+            weather: 1.0,
+            terrain: 1.0,
+            other: 1.0,
+            attack_stage: StatStage::Z0,
+            defense_stage: StatStage::Z0,
+            targets: 1,
+        }
+    }
+}
+
+/// Multiplies an integer damage value by a modifier, flooring the result.
+fn apply(damage: u32, modifier: f64) -> u32 {
+    (f64::from(damage) * modifier).floor() as u32
+}
+
+/// Returns the multiplier a critical hit applies to damage in the given generation: 1.5x from Gen VI
+/// onward, and 2x before that.
+pub fn crit_damage_multiplier(generation: u8) -> f64 {
+    if generation >= 6 {
+        1.5
+    } else {
+        2.0
+    }
+}
+
+/// Returns the multiplier for a move that hits the given number of targets at once: 0.75 when a
+/// spread move (see `Move::is_spread`) hits more than one Pokemon in doubles, and 1 otherwise. A
+/// spread move that only hits one target, because its partner fainted or protected, does full damage.
+pub fn spread_multiplier(targets: u8) -> f64 {
+    if targets > 1 {
+        0.75
+    } else {
+        1.0
+    }
+}
+
+/// Applies each of the 16 random factors, 85% through 100%, to the damage before the random roll,
+/// flooring each one. The multiplication is done in a `u64` so it can't overflow; every roll is at
+/// most `base_damage`, so it always fits back in a `u32`.
+fn random_rolls(base_damage: u32) -> [u32; 16] {
+    let mut rolls = [0; 16];
+    for (i, roll) in rolls.iter_mut().enumerate() {
+        *roll = (u64::from(base_damage) * (85 + i as u64) / 100) as u32;
+    }
+    rolls
+}
+
+/// Returns all 16 possible damage values for a hit with the given damage before the random roll, from
+/// the 85% roll to the 100% roll, floored as in the games. Each is equally likely. These are the
+/// values before STAB, type effectiveness, and the other modifiers applied after the roll, so
+/// `calc_damage`'s minimum and maximum come from the first and last of these. Values too big for a
+/// `u16` are capped.
+pub fn damage_rolls(base_damage: u32) -> [u16; 16] {
+    let mut rolls = [0; 16];
+    for (roll, &damage) in rolls.iter_mut().zip(random_rolls(base_damage).iter()) {
+        *roll = damage.min(u32::from(u16::max_value())) as u16;
+    }
+    rolls
+}
+
+/// Calculates the minimum and maximum damage a single hit does, corresponding to the lowest (85%) and
+/// highest (100%) random rolls. The attacking and defending stats should already include items like
+/// Choice Band, but not stat stages, which are given in `modifiers` and applied here. As in the games,
+/// the result is floored after each step. A hit that isn't blocked by an immunity always does at
+/// least 1 damage; one that is does none.
+///
+/// If `crit` is true, the hit is a critical hit: the attacker's negative stat stages and the
+/// defender's positive ones are ignored, and the damage is multiplied by the current (Gen VI onward)
+/// critical hit multiplier.
+pub fn calc_damage(attacker_level: u8, attack_stat: u16, defense_stat: u16, base_power: u8,
+                   modifiers: DamageModifiers, crit: bool) -> (u16, u16) {
+    if modifiers.effectiveness == Multiplier::Immunity {
+        return (0, 0);
+    }
+    let (attack_stage, defense_stage) = if crit {
+        (modifiers.attack_stage.max(StatStage::Z0), modifiers.defense_stage.min(StatStage::Z0))
+    } else {
+        (modifiers.attack_stage, modifiers.defense_stage)
+    };
+    let attack = apply(u32::from(attack_stat), attack_stage.normal_multiplier());
+    let defense = apply(u32::from(defense_stat), defense_stage.normal_multiplier());
+    let power = apply(u32::from(base_power), modifiers.terrain);
+    let level_factor = 2 * u32::from(attacker_level) / 5 + 2;
+    let base = u64::from(level_factor) * u64::from(power) * u64::from(attack);
+    let base = base / u64::from(defense.max(1)) / 50 + 2;
+    let base = base.min(u64::from(u32::max_value())) as u32;
+    let base = apply(base, spread_multiplier(modifiers.targets));
+    let base = apply(base, modifiers.weather);
+    let base = if crit { apply(base, crit_damage_multiplier(7)) } else { base };
+    let effectiveness: f32 = modifiers.effectiveness.into();
+    let rolls = random_rolls(base);
+    let finish = |damage: u32| {
+        let damage = apply(damage, modifiers.stab);
+        let damage = apply(damage, f64::from(effectiveness));
+        let damage = apply(damage, modifiers.other);
+        damage.max(1).min(u32::from(u16::max_value())) as u16
+    };
+    (finish(rolls[0]), finish(rolls[15]))
+}
+
+/// The parts of the defending Pokemon that can change how much damage a hit does to it beyond its
+/// stats: what type it is, and the ability and item that can block or soften a hit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DefenderContext {
+    pub ability: Ability,
+    pub item: Option<Item>,
+    pub typing: (Typing, Option<Typing>),
+    /// Whether the defender is grounded, as given by `grounded::is_grounded`.
+    pub grounded: bool,
+}
+
+/// The parts of the attacking side of a hit that `calc_damage_against` needs: the attacker's level
+/// and attacking stat, and the base power and type of the move it uses. As with `calc_damage`, the
+/// attacking stat should already include items like Choice Band, but not stat stages.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AttackContext {
+    pub level: u8,
+    pub attack_stat: u16,
+    pub base_power: u8,
+    pub move_type: Typing,
+}
+
+/// Replaces any Flying type in the given typing with Normal, which is neutral to Ground, so a Flying
+/// type that has been grounded takes Ground-type hits from its other type alone.
+fn without_flying(typing: (Typing, Option<Typing>)) -> (Typing, Option<Typing>) {
+    let ground = |t: Typing| if t == Typing::Flying { Typing::Normal } else { t };
+    (ground(typing.0), typing.1.map(ground))
+}
+
+/// Calculates the minimum and maximum damage a single hit does to the given defender, like
+/// `calc_damage`. Instead of using the effectiveness in `modifiers`, this works it out from the type
+/// of the attack and the defender's typing and ability, so Levitate or Wonder Guard are accounted for.
+/// A held type-resist berry that activates halves the damage.
+///
+/// Ground-type hits go by `defender.grounded` alone, which `grounded::is_grounded` works out from the
+/// defender's typing, ability, and item: they do nothing to an ungrounded defender, and a defender
+/// grounded by Gravity or an Iron Ball loses its Flying-type and Levitate immunities to them.
+pub fn calc_damage_against(attack: &AttackContext, defense_stat: u16, modifiers: DamageModifiers,
+                           crit: bool, defender: &DefenderContext) -> (u16, u16) {
+    let move_type = attack.move_type;
+    let effectiveness = if move_type == Typing::Ground {
+        if !defender.grounded {
+            return (0, 0);
+        }
+        let chart = defense_multiplier_for(move_type, without_flying(defender.typing));
+        if defender.ability == Ability::Levitate {
+            chart
+        } else {
+            ability_effectiveness_override(defender.ability, move_type, chart)
+        }
+    } else {
+        let chart = defense_multiplier_for(move_type, defender.typing);
+        ability_effectiveness_override(defender.ability, move_type, chart)
+    };
+    let berry = defender.item.map_or(1.0, |item| berry_resist_multiplier(item, move_type, effectiveness));
+    let modifiers = DamageModifiers{effectiveness, other: modifiers.other * berry, ..modifiers};
+    calc_damage(attack.level, attack.attack_stat, defense_stat, attack.base_power, modifiers, crit)
+}
+
+/// Returns the minimum and maximum damage of each hit a move with the given damage range does when
+/// used by a Pokemon with the given ability. Normally that's just the one hit, but Parental Bond adds
+/// a second hit at a quarter of the damage (half before Gen VII), rounded down but at least 1. Moves
+/// that already hit more than once don't get the extra hit, so they shouldn't be passed here.
+pub fn parental_bond_hits(base_damage: (u16, u16), ability: Ability) -> Vec<(u16, u16)> {
+    if ability == Ability::ParentalBond {
+        let second = |damage: u16| if damage == 0 { 0 } else { (damage / 4).max(1) };
+        vec![base_damage, (second(base_damage.0), second(base_damage.1))]
+    } else {
+        vec![base_damage]
+    }
+}
+
+/// Returns the minimum and maximum damage of a hit as percentages of the defender's max HP, the way
+/// damage calculators report them: (45.2, 53.1) means the hit takes off 45.2% to 53.1% of the
+/// defender's health. A defender with 0 max HP gives (0, 0).
+pub fn damage_percent(damage: (u16, u16), defender_max_hp: u16) -> (f32, f32) {
+    if defender_max_hp == 0 {
+        return (0.0, 0.0);
+    }
+    let percent = |dealt: u16| f32::from(dealt) * 100.0 / f32::from(defender_max_hp);
+    (percent(damage.0), percent(damage.1))
+}
+
+/// Returns the chance that a defender with the given HP is knocked out within the given number of
+/// turns by a hit that's repeated once per turn. Each turn's damage is one of `damage_rolls` picked
+/// at random, so this should be given all 16 rolls for the hit, with repeats. This only considers
+/// the damage: accuracy, critical hits, and recovery like Leftovers aren't accounted for.
+pub fn ko_chance(damage_rolls: &[u16], hp: u16, turns: u8) -> f32 {
+    if hp == 0 {
+        return 1.0;
+    }
+    if damage_rolls.is_empty() {
+        return 0.0;
+    }
+    // chance[d] is the chance of having dealt exactly d damage so far without a KO
+    let hp = usize::from(hp);
+    let roll_chance = 1.0 / damage_rolls.len() as f32;
+    let mut chance = vec![0.0; hp];
+    chance[0] = 1.0;
+    let mut ko = 0.0;
+    for _ in 0..turns {
+        let mut next = vec![0.0; hp];
+        for (dealt, &p) in chance.iter().enumerate().filter(|&(_, &p)| p > 0.0) {
+            for &roll in damage_rolls {
+                let total = dealt + usize::from(roll);
+                if total >= hp {
+                    ko += p * roll_chance;
+                } else {
+                    next[total] += p * roll_chance;
+                }
+            }
+        }
+        chance = next;
+    }
+    ko
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn test_garchomp_earthquake() {
+        // 252+ Atk Choice Band Garchomp (591 Atk) Earthquake vs. 252 HP / 252+ Def Ferrothorn (397 Def)
+        let modifiers = DamageModifiers{stab: 1.5, ..Default::default()};
+        assert_eq!(calc_damage(100, 591, 397, 100, modifiers, false), (160, 190));
+    }
+    #[test]
+    fn test_effectiveness() {
+        let neutral = calc_damage(100, 300, 300, 80, Default::default(), false);
+        let super_effective = calc_damage(100, 300, 300, 80, DamageModifiers{
+            effectiveness: Multiplier::Weakness, ..Default::default()}, false);
+        assert_eq!(super_effective, (neutral.0 * 2, neutral.1 * 2));
+        assert_eq!(calc_damage(100, 300, 300, 80, DamageModifiers{
+            effectiveness: Multiplier::Immunity, ..Default::default()}, false), (0, 0));
+    }
+    #[test]
+    fn test_minimum_damage() {
+        assert_eq!(calc_damage(1, 5, 600, 10, DamageModifiers{
+            effectiveness: Multiplier::DoubleResistance, ..Default::default()}, false), (1, 1));
+    }
+    #[test]
+    fn test_huge_damage() {
+        // big enough to overflow a u32 before the random roll
+        let modifiers = DamageModifiers{attack_stage: StatStage::P6, terrain: 1.5, ..Default::default()};
+        assert_eq!(calc_damage(100, u16::max_value(), 1, 255, modifiers, false),
+                   (u16::max_value(), u16::max_value()));
+    }
+    #[test]
+    fn test_crits() {
+        assert_eq!(crit_damage_multiplier(7), 1.5);
+        assert_eq!(crit_damage_multiplier(5), 2.0);
+        let modifiers = DamageModifiers{stab: 1.5, ..Default::default()};
+        assert_eq!(calc_damage(100, 591, 397, 100, modifiers, true), (241, 285));
+        // a crit ignores the attacker's drops and the defender's boosts, but not the reverse
+        let intimidated = DamageModifiers{attack_stage: StatStage::N1, ..modifiers};
+        assert!(calc_damage(100, 591, 397, 100, intimidated, false).1 < 190);
+        assert_eq!(calc_damage(100, 591, 397, 100, intimidated, true), (241, 285));
+        let boosted = DamageModifiers{defense_stage: StatStage::P2, ..modifiers};
+        assert_eq!(calc_damage(100, 591, 397, 100, boosted, true), (241, 285));
+        let swords_dance = DamageModifiers{attack_stage: StatStage::P2, ..modifiers};
+        assert!(calc_damage(100, 591, 397, 100, swords_dance, true).1 > 285);
+    }
+    #[test]
+    fn test_air_balloon() {
+        let heatran = DefenderContext{ability: Ability::FlashFire, item: Some(Item::AirBalloon),
+                                      typing: (Typing::Fire, Some(Typing::Steel)), grounded: false};
+        let popped = DefenderContext{item: None, grounded: true, ..heatran};
+        let modifiers = DamageModifiers{stab: 1.5, ..Default::default()};
+        let earthquake = AttackContext{level: 100, attack_stat: 591, base_power: 100,
+                                       move_type: Typing::Ground};
+        let earthquake = |defender| calc_damage_against(&earthquake, 317, modifiers, false, defender);
+        assert_eq!(earthquake(&heatran), (0, 0));
+        assert!(earthquake(&popped).0 > 0);
+        // Flash Fire still blocks Fire moves
+        let flamethrower = AttackContext{level: 100, attack_stat: 359, base_power: 90,
+                                         move_type: Typing::Fire};
+        assert_eq!(calc_damage_against(&flamethrower, 317, Default::default(), false, &popped), (0, 0));
+    }
+    #[test]
+    fn test_grounded_by_gravity() {
+        let earthquake = AttackContext{level: 100, attack_stat: 359, base_power: 100,
+                                       move_type: Typing::Ground};
+        let hit = |defender: &DefenderContext| {
+            calc_damage_against(&earthquake, 317, Default::default(), false, defender)
+        };
+        let expected = |effectiveness| calc_damage(100, 359, 317, 100, DamageModifiers{
+            effectiveness, ..Default::default()}, false);
+        // Skarmory loses its Flying-type immunity and takes super-effective damage from its Steel type
+        let skarmory = DefenderContext{ability: Ability::Sturdy, item: None,
+                                       typing: (Typing::Steel, Some(Typing::Flying)), grounded: false};
+        assert_eq!(hit(&skarmory), (0, 0));
+        assert_eq!(hit(&DefenderContext{grounded: true, ..skarmory}), expected(Multiplier::Weakness));
+        // Gengar loses Levitate
+        let gengar = DefenderContext{ability: Ability::Levitate, item: None,
+                                     typing: (Typing::Ghost, Some(Typing::Poison)), grounded: false};
+        assert_eq!(hit(&gengar), (0, 0));
+        assert_eq!(hit(&DefenderContext{grounded: true, ..gengar}), expected(Multiplier::Weakness));
+        // an Air Balloon doesn't help a Heatran that's been grounded
+        let heatran = DefenderContext{ability: Ability::FlashFire, item: Some(Item::AirBalloon),
+                                      typing: (Typing::Fire, Some(Typing::Steel)), grounded: true};
+        assert_eq!(hit(&heatran), expected(Multiplier::DoubleWeakness));
+    }
+    #[test]
+    fn test_resist_berry() {
+        let ferrothorn = DefenderContext{ability: Ability::IronBarbs, item: None,
+                                         typing: (Typing::Grass, Some(Typing::Steel)), grounded: true};
+        let with_occa = DefenderContext{item: Some(Item::OccaBerry), ..ferrothorn};
+        let hit = |move_type, defender| {
+            let attack = AttackContext{level: 100, attack_stat: 359, base_power: 90, move_type};
+            calc_damage_against(&attack, 317, Default::default(), false, defender)
+        };
+        let full = hit(Typing::Fire, &ferrothorn);
+        assert_eq!(full, calc_damage(100, 359, 317, 90, DamageModifiers{
+            effectiveness: Multiplier::DoubleWeakness, ..Default::default()}, false));
+        assert_eq!(hit(Typing::Fire, &with_occa), (full.0 / 2, full.1 / 2));
+        // the berry doesn't do anything against a hit that isn't super effective
+        assert_eq!(hit(Typing::Water, &with_occa), hit(Typing::Water, &ferrothorn));
+    }
+    #[test]
+    fn test_parental_bond() {
+        // Mega Kangaskhan Return vs. Ferrothorn
+        let modifiers = DamageModifiers{stab: 1.5, effectiveness: Multiplier::Resistance,
+                                        ..Default::default()};
+        let damage = calc_damage(100, 383, 397, 102, modifiers, false);
+        let hits = parental_bond_hits(damage, Ability::ParentalBond);
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0], damage);
+        assert_eq!(hits[1], (damage.0 / 4, damage.1 / 4));
+        assert_eq!(parental_bond_hits(damage, Ability::Scrappy), vec![damage]);
+        assert_eq!(parental_bond_hits((0, 0), Ability::ParentalBond), vec![(0, 0), (0, 0)]);
+        assert_eq!(parental_bond_hits((2, 3), Ability::ParentalBond), vec![(2, 3), (1, 1)]);
+    }
+    #[test]
+    fn test_spread_damage() {
+        let single = calc_damage(100, 591, 397, 100, Default::default(), false);
+        let spread = calc_damage(100, 591, 397, 100, DamageModifiers{targets: 2, ..Default::default()},
+                                 false);
+        assert!(spread.1 < single.1);
+        assert_eq!(spread_multiplier(2), 0.75);
+        assert_eq!(spread_multiplier(1), 1.0);
+        assert_eq!(calc_damage(100, 591, 397, 100, DamageModifiers{targets: 1, ..Default::default()},
+                               false), single);
+    }
+    #[test]
+    fn test_damage_percent() {
+        assert_eq!(damage_percent((160, 190), 400), (40.0, 47.5));
+        assert_eq!(damage_percent((10, 20), 0), (0.0, 0.0));
+    }
+    #[test]
+    fn test_ko_chance() {
+        // every roll does between half and all of the defender's HP: a guaranteed 2HKO
+        let rolls: Vec<u16> = (0..16).map(|i| 170 + i * 2).collect();
+        assert_eq!(ko_chance(&rolls, 300, 1), 0.0);
+        assert_eq!(ko_chance(&rolls, 300, 2), 1.0);
+        // the top 4 rolls out of 16 knock out
+        let rolls: Vec<u16> = (0..16).map(|i| 88 + i).collect();
+        assert_eq!(ko_chance(&rolls, 100, 1), 0.25);
+        assert_eq!(ko_chance(&rolls, 100, 2), 1.0);
+        assert_eq!(ko_chance(&rolls, 100, 0), 0.0);
+        assert_eq!(ko_chance(&[], 100, 3), 0.0);
+    }
+    #[test]
+    fn test_damage_rolls() {
+        let rolls = damage_rolls(190);
+        assert_eq!(rolls[0], 161);
+        assert_eq!(rolls[15], 190);
+        assert!(rolls.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(damage_rolls(1), [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(damage_rolls(u32::max_value() / 100)[15], u16::max_value());
+        assert_eq!(damage_rolls(u32::max_value()), [u16::max_value(); 16]);
+    }
+}
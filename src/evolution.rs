@@ -0,0 +1,457 @@
+//! This file describes which Pokemon evolve into which. Evolution is a property of the species as a
+//! whole, not any particular forme, so everything here uses `SpeciesDiscriminant`. Baby Pokemon
+//! introduced after their evolutions, like Pichu and Munchlax, are treated as the first stage of the
+//! family, as they are in the games.
+
+use strum::IntoEnumIterator;
+
+use crate::species::SpeciesDiscriminant;
+
+/// Returns the species the given species evolves from, or None if it doesn't evolve from anything.
+pub fn pre_evolution(species: SpeciesDiscriminant) -> Option<SpeciesDiscriminant> {
+    match species {
+        // in National Pokedex order
+        SpeciesDiscriminant::Ivysaur => Some(SpeciesDiscriminant::Bulbasaur),
+        SpeciesDiscriminant::Venusaur => Some(SpeciesDiscriminant::Ivysaur),
+        SpeciesDiscriminant::Charmeleon => Some(SpeciesDiscriminant::Charmander),
+        SpeciesDiscriminant::Charizard => Some(SpeciesDiscriminant::Charmeleon),
+        SpeciesDiscriminant::Wartortle => Some(SpeciesDiscriminant::Squirtle),
+        SpeciesDiscriminant::Blastoise => Some(SpeciesDiscriminant::Wartortle),
+        SpeciesDiscriminant::Metapod => Some(SpeciesDiscriminant::Caterpie),
+        SpeciesDiscriminant::Butterfree => Some(SpeciesDiscriminant::Metapod),
+        SpeciesDiscriminant::Kakuna => Some(SpeciesDiscriminant::Weedle),
+        SpeciesDiscriminant::Beedrill => Some(SpeciesDiscriminant::Kakuna),
+        SpeciesDiscriminant::Pidgeotto => Some(SpeciesDiscriminant::Pidgey),
+        SpeciesDiscriminant::Pidgeot => Some(SpeciesDiscriminant::Pidgeotto),
+        SpeciesDiscriminant::Raticate => Some(SpeciesDiscriminant::Rattata),
+        SpeciesDiscriminant::Fearow => Some(SpeciesDiscriminant::Spearow),
+        SpeciesDiscriminant::Arbok => Some(SpeciesDiscriminant::Ekans),
+        SpeciesDiscriminant::Pikachu => Some(SpeciesDiscriminant::Pichu),
+        SpeciesDiscriminant::Raichu => Some(SpeciesDiscriminant::Pikachu),
+        SpeciesDiscriminant::Sandslash => Some(SpeciesDiscriminant::Sandshrew),
+        SpeciesDiscriminant::Nidorina => Some(SpeciesDiscriminant::NidoranF),
+        SpeciesDiscriminant::Nidoqueen => Some(SpeciesDiscriminant::Nidorina),
+        SpeciesDiscriminant::Nidorino => Some(SpeciesDiscriminant::NidoranM),
+        SpeciesDiscriminant::Nidoking => Some(SpeciesDiscriminant::Nidorino),
+        SpeciesDiscriminant::Clefairy => Some(SpeciesDiscriminant::Cleffa),
+        SpeciesDiscriminant::Clefable => Some(SpeciesDiscriminant::Clefairy),
+        SpeciesDiscriminant::Ninetales => Some(SpeciesDiscriminant::Vulpix),
+        SpeciesDiscriminant::Jigglypuff => Some(SpeciesDiscriminant::Igglybuff),
+        SpeciesDiscriminant::Wigglytuff => Some(SpeciesDiscriminant::Jigglypuff),
+        SpeciesDiscriminant::Golbat => Some(SpeciesDiscriminant::Zubat),
+        SpeciesDiscriminant::Gloom => Some(SpeciesDiscriminant::Oddish),
+        SpeciesDiscriminant::Vileplume => Some(SpeciesDiscriminant::Gloom),
+        SpeciesDiscriminant::Parasect => Some(SpeciesDiscriminant::Paras),
+        SpeciesDiscriminant::Venomoth => Some(SpeciesDiscriminant::Venonat),
+        SpeciesDiscriminant::Dugtrio => Some(SpeciesDiscriminant::Diglett),
+        SpeciesDiscriminant::Persian => Some(SpeciesDiscriminant::Meowth),
+        SpeciesDiscriminant::Golduck => Some(SpeciesDiscriminant::Psyduck),
+        SpeciesDiscriminant::Primeape => Some(SpeciesDiscriminant::Mankey),
+        SpeciesDiscriminant::Arcanine => Some(SpeciesDiscriminant::Growlithe),
+        SpeciesDiscriminant::Poliwhirl => Some(SpeciesDiscriminant::Poliwag),
+        SpeciesDiscriminant::Poliwrath => Some(SpeciesDiscriminant::Poliwhirl),
+        SpeciesDiscriminant::Kadabra => Some(SpeciesDiscriminant::Abra),
+        SpeciesDiscriminant::Alakazam => Some(SpeciesDiscriminant::Kadabra),
+        SpeciesDiscriminant::Machoke => Some(SpeciesDiscriminant::Machop),
+        SpeciesDiscriminant::Machamp => Some(SpeciesDiscriminant::Machoke),
+        SpeciesDiscriminant::Weepinbell => Some(SpeciesDiscriminant::Bellsprout),
+        SpeciesDiscriminant::Victreebel => Some(SpeciesDiscriminant::Weepinbell),
+        SpeciesDiscriminant::Tentacruel => Some(SpeciesDiscriminant::Tentacool),
+        SpeciesDiscriminant::Graveler => Some(SpeciesDiscriminant::Geodude),
+        SpeciesDiscriminant::Golem => Some(SpeciesDiscriminant::Graveler),
+        SpeciesDiscriminant::Rapidash => Some(SpeciesDiscriminant::Ponyta),
+        SpeciesDiscriminant::Slowbro => Some(SpeciesDiscriminant::Slowpoke),
+        SpeciesDiscriminant::Magneton => Some(SpeciesDiscriminant::Magnemite),
+        SpeciesDiscriminant::Dodrio => Some(SpeciesDiscriminant::Doduo),
+        SpeciesDiscriminant::Dewgong => Some(SpeciesDiscriminant::Seel),
+        SpeciesDiscriminant::Muk => Some(SpeciesDiscriminant::Grimer),
+        SpeciesDiscriminant::Cloyster => Some(SpeciesDiscriminant::Shellder),
+        SpeciesDiscriminant::Haunter => Some(SpeciesDiscriminant::Gastly),
+        SpeciesDiscriminant::Gengar => Some(SpeciesDiscriminant::Haunter),
+        SpeciesDiscriminant::Hypno => Some(SpeciesDiscriminant::Drowzee),
+        SpeciesDiscriminant::Kingler => Some(SpeciesDiscriminant::Krabby),
+        SpeciesDiscriminant::Electrode => Some(SpeciesDiscriminant::Voltorb),
+        SpeciesDiscriminant::Exeggutor => Some(SpeciesDiscriminant::Exeggcute),
+        SpeciesDiscriminant::Marowak => Some(SpeciesDiscriminant::Cubone),
+        SpeciesDiscriminant::Hitmonlee => Some(SpeciesDiscriminant::Tyrogue),
+        SpeciesDiscriminant::Hitmonchan => Some(SpeciesDiscriminant::Tyrogue),
+        SpeciesDiscriminant::Weezing => Some(SpeciesDiscriminant::Koffing),
+        SpeciesDiscriminant::Rhydon => Some(SpeciesDiscriminant::Rhyhorn),
+        SpeciesDiscriminant::Chansey => Some(SpeciesDiscriminant::Happiny),
+        SpeciesDiscriminant::Seadra => Some(SpeciesDiscriminant::Horsea),
+        SpeciesDiscriminant::Seaking => Some(SpeciesDiscriminant::Goldeen),
+        SpeciesDiscriminant::Starmie => Some(SpeciesDiscriminant::Staryu),
+        SpeciesDiscriminant::MrMime => Some(SpeciesDiscriminant::MimeJr),
+        SpeciesDiscriminant::Jynx => Some(SpeciesDiscriminant::Smoochum),
+        SpeciesDiscriminant::Electabuzz => Some(SpeciesDiscriminant::Elekid),
+        SpeciesDiscriminant::Magmar => Some(SpeciesDiscriminant::Magby),
+        SpeciesDiscriminant::Gyarados => Some(SpeciesDiscriminant::Magikarp),
+        SpeciesDiscriminant::Vaporeon => Some(SpeciesDiscriminant::Eevee),
+        SpeciesDiscriminant::Jolteon => Some(SpeciesDiscriminant::Eevee),
+        SpeciesDiscriminant::Flareon => Some(SpeciesDiscriminant::Eevee),
+        SpeciesDiscriminant::Omastar => Some(SpeciesDiscriminant::Omanyte),
+        SpeciesDiscriminant::Kabutops => Some(SpeciesDiscriminant::Kabuto),
+        SpeciesDiscriminant::Snorlax => Some(SpeciesDiscriminant::Munchlax),
+        SpeciesDiscriminant::Dragonair => Some(SpeciesDiscriminant::Dratini),
+        SpeciesDiscriminant::Dragonite => Some(SpeciesDiscriminant::Dragonair),
+        SpeciesDiscriminant::Bayleef => Some(SpeciesDiscriminant::Chikorita),
+        SpeciesDiscriminant::Meganium => Some(SpeciesDiscriminant::Bayleef),
+        SpeciesDiscriminant::Quilava => Some(SpeciesDiscriminant::Cyndaquil),
+        SpeciesDiscriminant::Typhlosion => Some(SpeciesDiscriminant::Quilava),
+        SpeciesDiscriminant::Croconaw => Some(SpeciesDiscriminant::Totodile),
+        SpeciesDiscriminant::Feraligatr => Some(SpeciesDiscriminant::Croconaw),
+        SpeciesDiscriminant::Furret => Some(SpeciesDiscriminant::Sentret),
+        SpeciesDiscriminant::Noctowl => Some(SpeciesDiscriminant::Hoothoot),
+        SpeciesDiscriminant::Ledian => Some(SpeciesDiscriminant::Ledyba),
+        SpeciesDiscriminant::Ariados => Some(SpeciesDiscriminant::Spinarak),
+        SpeciesDiscriminant::Crobat => Some(SpeciesDiscriminant::Golbat),
+        SpeciesDiscriminant::Lanturn => Some(SpeciesDiscriminant::Chinchou),
+        SpeciesDiscriminant::Togetic => Some(SpeciesDiscriminant::Togepi),
+        SpeciesDiscriminant::Xatu => Some(SpeciesDiscriminant::Natu),
+        SpeciesDiscriminant::Flaaffy => Some(SpeciesDiscriminant::Mareep),
+        SpeciesDiscriminant::Ampharos => Some(SpeciesDiscriminant::Flaaffy),
+        SpeciesDiscriminant::Bellossom => Some(SpeciesDiscriminant::Gloom),
+        SpeciesDiscriminant::Marill => Some(SpeciesDiscriminant::Azurill),
+        SpeciesDiscriminant::Azumarill => Some(SpeciesDiscriminant::Marill),
+        SpeciesDiscriminant::Sudowoodo => Some(SpeciesDiscriminant::Bonsly),
+        SpeciesDiscriminant::Politoed => Some(SpeciesDiscriminant::Poliwhirl),
+        SpeciesDiscriminant::Skiploom => Some(SpeciesDiscriminant::Hoppip),
+        SpeciesDiscriminant::Jumpluff => Some(SpeciesDiscriminant::Skiploom),
+        SpeciesDiscriminant::Sunflora => Some(SpeciesDiscriminant::Sunkern),
+        SpeciesDiscriminant::Quagsire => Some(SpeciesDiscriminant::Wooper),
+        SpeciesDiscriminant::Espeon => Some(SpeciesDiscriminant::Eevee),
+        SpeciesDiscriminant::Umbreon => Some(SpeciesDiscriminant::Eevee),
+        SpeciesDiscriminant::Slowking => Some(SpeciesDiscriminant::Slowpoke),
+        SpeciesDiscriminant::Wobbuffet => Some(SpeciesDiscriminant::Wynaut),
+        SpeciesDiscriminant::Forretress => Some(SpeciesDiscriminant::Pineco),
+        SpeciesDiscriminant::Steelix => Some(SpeciesDiscriminant::Onix),
+        SpeciesDiscriminant::Granbull => Some(SpeciesDiscriminant::Snubbull),
+        SpeciesDiscriminant::Scizor => Some(SpeciesDiscriminant::Scyther),
+        SpeciesDiscriminant::Ursaring => Some(SpeciesDiscriminant::Teddiursa),
+        SpeciesDiscriminant::Magcargo => Some(SpeciesDiscriminant::Slugma),
+        SpeciesDiscriminant::Piloswine => Some(SpeciesDiscriminant::Swinub),
+        SpeciesDiscriminant::Octillery => Some(SpeciesDiscriminant::Remoraid),
+        SpeciesDiscriminant::Mantine => Some(SpeciesDiscriminant::Mantyke),
+        SpeciesDiscriminant::Houndoom => Some(SpeciesDiscriminant::Houndour),
+        SpeciesDiscriminant::Kingdra => Some(SpeciesDiscriminant::Seadra),
+        SpeciesDiscriminant::Donphan => Some(SpeciesDiscriminant::Phanpy),
+        SpeciesDiscriminant::Porygon2 => Some(SpeciesDiscriminant::Porygon),
+        SpeciesDiscriminant::Hitmontop => Some(SpeciesDiscriminant::Tyrogue),
+        SpeciesDiscriminant::Blissey => Some(SpeciesDiscriminant::Chansey),
+        SpeciesDiscriminant::Pupitar => Some(SpeciesDiscriminant::Larvitar),
+        SpeciesDiscriminant::Tyranitar => Some(SpeciesDiscriminant::Pupitar),
+        SpeciesDiscriminant::Grovyle => Some(SpeciesDiscriminant::Treecko),
+        SpeciesDiscriminant::Sceptile => Some(SpeciesDiscriminant::Grovyle),
+        SpeciesDiscriminant::Combusken => Some(SpeciesDiscriminant::Torchic),
+        SpeciesDiscriminant::Blaziken => Some(SpeciesDiscriminant::Combusken),
+        SpeciesDiscriminant::Marshtomp => Some(SpeciesDiscriminant::Mudkip),
+        SpeciesDiscriminant::Swampert => Some(SpeciesDiscriminant::Marshtomp),
+        SpeciesDiscriminant::Mightyena => Some(SpeciesDiscriminant::Poochyena),
+        SpeciesDiscriminant::Linoone => Some(SpeciesDiscriminant::Zigzagoon),
+        SpeciesDiscriminant::Silcoon => Some(SpeciesDiscriminant::Wurmple),
+        SpeciesDiscriminant::Beautifly => Some(SpeciesDiscriminant::Silcoon),
+        SpeciesDiscriminant::Cascoon => Some(SpeciesDiscriminant::Wurmple),
+        SpeciesDiscriminant::Dustox => Some(SpeciesDiscriminant::Cascoon),
+        SpeciesDiscriminant::Lombre => Some(SpeciesDiscriminant::Lotad),
+        SpeciesDiscriminant::Ludicolo => Some(SpeciesDiscriminant::Lombre),
+        SpeciesDiscriminant::Nuzleaf => Some(SpeciesDiscriminant::Seedot),
+        SpeciesDiscriminant::Shiftry => Some(SpeciesDiscriminant::Nuzleaf),
+        SpeciesDiscriminant::Swellow => Some(SpeciesDiscriminant::Taillow),
+        SpeciesDiscriminant::Pelipper => Some(SpeciesDiscriminant::Wingull),
+        SpeciesDiscriminant::Kirlia => Some(SpeciesDiscriminant::Ralts),
+        SpeciesDiscriminant::Gardevoir => Some(SpeciesDiscriminant::Kirlia),
+        SpeciesDiscriminant::Masquerain => Some(SpeciesDiscriminant::Surskit),
+        SpeciesDiscriminant::Breloom => Some(SpeciesDiscriminant::Shroomish),
+        SpeciesDiscriminant::Vigoroth => Some(SpeciesDiscriminant::Slakoth),
+        SpeciesDiscriminant::Slaking => Some(SpeciesDiscriminant::Vigoroth),
+        SpeciesDiscriminant::Ninjask => Some(SpeciesDiscriminant::Nincada),
+        SpeciesDiscriminant::Shedinja => Some(SpeciesDiscriminant::Nincada),
+        SpeciesDiscriminant::Loudred => Some(SpeciesDiscriminant::Whismur),
+        SpeciesDiscriminant::Exploud => Some(SpeciesDiscriminant::Loudred),
+        SpeciesDiscriminant::Hariyama => Some(SpeciesDiscriminant::Makuhita),
+        SpeciesDiscriminant::Delcatty => Some(SpeciesDiscriminant::Skitty),
+        SpeciesDiscriminant::Lairon => Some(SpeciesDiscriminant::Aron),
+        SpeciesDiscriminant::Aggron => Some(SpeciesDiscriminant::Lairon),
+        SpeciesDiscriminant::Medicham => Some(SpeciesDiscriminant::Meditite),
+        SpeciesDiscriminant::Manectric => Some(SpeciesDiscriminant::Electrike),
+        SpeciesDiscriminant::Roselia => Some(SpeciesDiscriminant::Budew),
+        SpeciesDiscriminant::Swalot => Some(SpeciesDiscriminant::Gulpin),
+        SpeciesDiscriminant::Sharpedo => Some(SpeciesDiscriminant::Carvanha),
+        SpeciesDiscriminant::Wailord => Some(SpeciesDiscriminant::Wailmer),
+        SpeciesDiscriminant::Camerupt => Some(SpeciesDiscriminant::Numel),
+        SpeciesDiscriminant::Grumpig => Some(SpeciesDiscriminant::Spoink),
+        SpeciesDiscriminant::Vibrava => Some(SpeciesDiscriminant::Trapinch),
+        SpeciesDiscriminant::Flygon => Some(SpeciesDiscriminant::Vibrava),
+        SpeciesDiscriminant::Cacturne => Some(SpeciesDiscriminant::Cacnea),
+        SpeciesDiscriminant::Altaria => Some(SpeciesDiscriminant::Swablu),
+        SpeciesDiscriminant::Whiscash => Some(SpeciesDiscriminant::Barboach),
+        SpeciesDiscriminant::Crawdaunt => Some(SpeciesDiscriminant::Corphish),
+        SpeciesDiscriminant::Claydol => Some(SpeciesDiscriminant::Baltoy),
+        SpeciesDiscriminant::Cradily => Some(SpeciesDiscriminant::Lileep),
+        SpeciesDiscriminant::Armaldo => Some(SpeciesDiscriminant::Anorith),
+        SpeciesDiscriminant::Milotic => Some(SpeciesDiscriminant::Feebas),
+        SpeciesDiscriminant::Banette => Some(SpeciesDiscriminant::Shuppet),
+        SpeciesDiscriminant::Dusclops => Some(SpeciesDiscriminant::Duskull),
+        SpeciesDiscriminant::Chimecho => Some(SpeciesDiscriminant::Chingling),
+        SpeciesDiscriminant::Glalie => Some(SpeciesDiscriminant::Snorunt),
+        SpeciesDiscriminant::Sealeo => Some(SpeciesDiscriminant::Spheal),
+        SpeciesDiscriminant::Walrein => Some(SpeciesDiscriminant::Sealeo),
+        SpeciesDiscriminant::Huntail => Some(SpeciesDiscriminant::Clamperl),
+        SpeciesDiscriminant::Gorebyss => Some(SpeciesDiscriminant::Clamperl),
+        SpeciesDiscriminant::Shelgon => Some(SpeciesDiscriminant::Bagon),
+        SpeciesDiscriminant::Salamence => Some(SpeciesDiscriminant::Shelgon),
+        SpeciesDiscriminant::Metang => Some(SpeciesDiscriminant::Beldum),
+        SpeciesDiscriminant::Metagross => Some(SpeciesDiscriminant::Metang),
+        SpeciesDiscriminant::Grotle => Some(SpeciesDiscriminant::Turtwig),
+        SpeciesDiscriminant::Torterra => Some(SpeciesDiscriminant::Grotle),
+        SpeciesDiscriminant::Monferno => Some(SpeciesDiscriminant::Chimchar),
+        SpeciesDiscriminant::Infernape => Some(SpeciesDiscriminant::Monferno),
+        SpeciesDiscriminant::Prinplup => Some(SpeciesDiscriminant::Piplup),
+        SpeciesDiscriminant::Empoleon => Some(SpeciesDiscriminant::Prinplup),
+        SpeciesDiscriminant::Staravia => Some(SpeciesDiscriminant::Starly),
+        SpeciesDiscriminant::Staraptor => Some(SpeciesDiscriminant::Staravia),
+        SpeciesDiscriminant::Bibarel => Some(SpeciesDiscriminant::Bidoof),
+        SpeciesDiscriminant::Kricketune => Some(SpeciesDiscriminant::Kricketot),
+        SpeciesDiscriminant::Luxio => Some(SpeciesDiscriminant::Shinx),
+        SpeciesDiscriminant::Luxray => Some(SpeciesDiscriminant::Luxio),
+        SpeciesDiscriminant::Roserade => Some(SpeciesDiscriminant::Roselia),
+        SpeciesDiscriminant::Rampardos => Some(SpeciesDiscriminant::Cranidos),
+        SpeciesDiscriminant::Bastiodon => Some(SpeciesDiscriminant::Shieldon),
+        SpeciesDiscriminant::Wormadam => Some(SpeciesDiscriminant::Burmy),
+        SpeciesDiscriminant::Mothim => Some(SpeciesDiscriminant::Burmy),
+        SpeciesDiscriminant::Vespiquen => Some(SpeciesDiscriminant::Combee),
+        SpeciesDiscriminant::Floatzel => Some(SpeciesDiscriminant::Buizel),
+        SpeciesDiscriminant::Cherrim => Some(SpeciesDiscriminant::Cherubi),
+        SpeciesDiscriminant::Gastrodon => Some(SpeciesDiscriminant::Shellos),
+        SpeciesDiscriminant::Ambipom => Some(SpeciesDiscriminant::Aipom),
+        SpeciesDiscriminant::Drifblim => Some(SpeciesDiscriminant::Drifloon),
+        SpeciesDiscriminant::Lopunny => Some(SpeciesDiscriminant::Buneary),
+        SpeciesDiscriminant::Mismagius => Some(SpeciesDiscriminant::Misdreavus),
+        SpeciesDiscriminant::Honchkrow => Some(SpeciesDiscriminant::Murkrow),
+        SpeciesDiscriminant::Purugly => Some(SpeciesDiscriminant::Glameow),
+        SpeciesDiscriminant::Skuntank => Some(SpeciesDiscriminant::Stunky),
+        SpeciesDiscriminant::Bronzong => Some(SpeciesDiscriminant::Bronzor),
+        SpeciesDiscriminant::Gabite => Some(SpeciesDiscriminant::Gible),
+        SpeciesDiscriminant::Garchomp => Some(SpeciesDiscriminant::Gabite),
+        SpeciesDiscriminant::Lucario => Some(SpeciesDiscriminant::Riolu),
+        SpeciesDiscriminant::Hippowdon => Some(SpeciesDiscriminant::Hippopotas),
+        SpeciesDiscriminant::Drapion => Some(SpeciesDiscriminant::Skorupi),
+        SpeciesDiscriminant::Toxicroak => Some(SpeciesDiscriminant::Croagunk),
+        SpeciesDiscriminant::Lumineon => Some(SpeciesDiscriminant::Finneon),
+        SpeciesDiscriminant::Abomasnow => Some(SpeciesDiscriminant::Snover),
+        SpeciesDiscriminant::Weavile => Some(SpeciesDiscriminant::Sneasel),
+        SpeciesDiscriminant::Magnezone => Some(SpeciesDiscriminant::Magneton),
+        SpeciesDiscriminant::Lickilicky => Some(SpeciesDiscriminant::Lickitung),
+        SpeciesDiscriminant::Rhyperior => Some(SpeciesDiscriminant::Rhydon),
+        SpeciesDiscriminant::Tangrowth => Some(SpeciesDiscriminant::Tangela),
+        SpeciesDiscriminant::Electivire => Some(SpeciesDiscriminant::Electabuzz),
+        SpeciesDiscriminant::Magmortar => Some(SpeciesDiscriminant::Magmar),
+        SpeciesDiscriminant::Togekiss => Some(SpeciesDiscriminant::Togetic),
+        SpeciesDiscriminant::Yanmega => Some(SpeciesDiscriminant::Yanma),
+        SpeciesDiscriminant::Leafeon => Some(SpeciesDiscriminant::Eevee),
+        SpeciesDiscriminant::Glaceon => Some(SpeciesDiscriminant::Eevee),
+        SpeciesDiscriminant::Gliscor => Some(SpeciesDiscriminant::Gligar),
+        SpeciesDiscriminant::Mamoswine => Some(SpeciesDiscriminant::Piloswine),
+        SpeciesDiscriminant::PorygonZ => Some(SpeciesDiscriminant::Porygon2),
+        SpeciesDiscriminant::Gallade => Some(SpeciesDiscriminant::Kirlia),
+        SpeciesDiscriminant::Probopass => Some(SpeciesDiscriminant::Nosepass),
+        SpeciesDiscriminant::Dusknoir => Some(SpeciesDiscriminant::Dusclops),
+        SpeciesDiscriminant::Froslass => Some(SpeciesDiscriminant::Snorunt),
+        SpeciesDiscriminant::Servine => Some(SpeciesDiscriminant::Snivy),
+        SpeciesDiscriminant::Serperior => Some(SpeciesDiscriminant::Servine),
+        SpeciesDiscriminant::Pignite => Some(SpeciesDiscriminant::Tepig),
+        SpeciesDiscriminant::Emboar => Some(SpeciesDiscriminant::Pignite),
+        SpeciesDiscriminant::Dewott => Some(SpeciesDiscriminant::Oshawott),
+        SpeciesDiscriminant::Samurott => Some(SpeciesDiscriminant::Dewott),
+        SpeciesDiscriminant::Watchog => Some(SpeciesDiscriminant::Patrat),
+        SpeciesDiscriminant::Herdier => Some(SpeciesDiscriminant::Lillipup),
+        SpeciesDiscriminant::Stoutland => Some(SpeciesDiscriminant::Herdier),
+        SpeciesDiscriminant::Liepard => Some(SpeciesDiscriminant::Purrloin),
+        SpeciesDiscriminant::Simisage => Some(SpeciesDiscriminant::Pansage),
+        SpeciesDiscriminant::Simisear => Some(SpeciesDiscriminant::Pansear),
+        SpeciesDiscriminant::Simipour => Some(SpeciesDiscriminant::Panpour),
+        SpeciesDiscriminant::Musharna => Some(SpeciesDiscriminant::Munna),
+        SpeciesDiscriminant::Tranquill => Some(SpeciesDiscriminant::Pidove),
+        SpeciesDiscriminant::Unfezant => Some(SpeciesDiscriminant::Tranquill),
+        SpeciesDiscriminant::Zebstrika => Some(SpeciesDiscriminant::Blitzle),
+        SpeciesDiscriminant::Boldore => Some(SpeciesDiscriminant::Roggenrola),
+        SpeciesDiscriminant::Gigalith => Some(SpeciesDiscriminant::Boldore),
+        SpeciesDiscriminant::Swoobat => Some(SpeciesDiscriminant::Woobat),
+        SpeciesDiscriminant::Excadrill => Some(SpeciesDiscriminant::Drilbur),
+        SpeciesDiscriminant::Gurdurr => Some(SpeciesDiscriminant::Timburr),
+        SpeciesDiscriminant::Conkeldurr => Some(SpeciesDiscriminant::Gurdurr),
+        SpeciesDiscriminant::Palpitoad => Some(SpeciesDiscriminant::Tympole),
+        SpeciesDiscriminant::Seismitoad => Some(SpeciesDiscriminant::Palpitoad),
+        SpeciesDiscriminant::Swadloon => Some(SpeciesDiscriminant::Sewaddle),
+        SpeciesDiscriminant::Leavanny => Some(SpeciesDiscriminant::Swadloon),
+        SpeciesDiscriminant::Whirlipede => Some(SpeciesDiscriminant::Venipede),
+        SpeciesDiscriminant::Scolipede => Some(SpeciesDiscriminant::Whirlipede),
+        SpeciesDiscriminant::Whimsicott => Some(SpeciesDiscriminant::Cottonee),
+        SpeciesDiscriminant::Lilligant => Some(SpeciesDiscriminant::Petilil),
+        SpeciesDiscriminant::Krokorok => Some(SpeciesDiscriminant::Sandile),
+        SpeciesDiscriminant::Krookodile => Some(SpeciesDiscriminant::Krokorok),
+        SpeciesDiscriminant::Darmanitan => Some(SpeciesDiscriminant::Darumaka),
+        SpeciesDiscriminant::Crustle => Some(SpeciesDiscriminant::Dwebble),
+        SpeciesDiscriminant::Scrafty => Some(SpeciesDiscriminant::Scraggy),
+        SpeciesDiscriminant::Cofagrigus => Some(SpeciesDiscriminant::Yamask),
+        SpeciesDiscriminant::Carracosta => Some(SpeciesDiscriminant::Tirtouga),
+        SpeciesDiscriminant::Archeops => Some(SpeciesDiscriminant::Archen),
+        SpeciesDiscriminant::Garbodor => Some(SpeciesDiscriminant::Trubbish),
+        SpeciesDiscriminant::Zoroark => Some(SpeciesDiscriminant::Zorua),
+        SpeciesDiscriminant::Cinccino => Some(SpeciesDiscriminant::Minccino),
+        SpeciesDiscriminant::Gothorita => Some(SpeciesDiscriminant::Gothita),
+        SpeciesDiscriminant::Gothitelle => Some(SpeciesDiscriminant::Gothorita),
+        SpeciesDiscriminant::Duosion => Some(SpeciesDiscriminant::Solosis),
+        SpeciesDiscriminant::Reuniclus => Some(SpeciesDiscriminant::Duosion),
+        SpeciesDiscriminant::Swanna => Some(SpeciesDiscriminant::Ducklett),
+        SpeciesDiscriminant::Vanillish => Some(SpeciesDiscriminant::Vanillite),
+        SpeciesDiscriminant::Vanilluxe => Some(SpeciesDiscriminant::Vanillish),
+        SpeciesDiscriminant::Sawsbuck => Some(SpeciesDiscriminant::Deerling),
+        SpeciesDiscriminant::Escavalier => Some(SpeciesDiscriminant::Karrablast),
+        SpeciesDiscriminant::Amoonguss => Some(SpeciesDiscriminant::Foongus),
+        SpeciesDiscriminant::Jellicent => Some(SpeciesDiscriminant::Frillish),
+        SpeciesDiscriminant::Galvantula => Some(SpeciesDiscriminant::Joltik),
+        SpeciesDiscriminant::Ferrothorn => Some(SpeciesDiscriminant::Ferroseed),
+        SpeciesDiscriminant::Klang => Some(SpeciesDiscriminant::Klink),
+        SpeciesDiscriminant::Klinklang => Some(SpeciesDiscriminant::Klang),
+        SpeciesDiscriminant::Eelektrik => Some(SpeciesDiscriminant::Tynamo),
+        SpeciesDiscriminant::Eelektross => Some(SpeciesDiscriminant::Eelektrik),
+        SpeciesDiscriminant::Beheeyem => Some(SpeciesDiscriminant::Elgyem),
+        SpeciesDiscriminant::Lampent => Some(SpeciesDiscriminant::Litwick),
+        SpeciesDiscriminant::Chandelure => Some(SpeciesDiscriminant::Lampent),
+        SpeciesDiscriminant::Fraxure => Some(SpeciesDiscriminant::Axew),
+        SpeciesDiscriminant::Haxorus => Some(SpeciesDiscriminant::Fraxure),
+        SpeciesDiscriminant::Beartic => Some(SpeciesDiscriminant::Cubchoo),
+        SpeciesDiscriminant::Accelgor => Some(SpeciesDiscriminant::Shelmet),
+        SpeciesDiscriminant::Mienshao => Some(SpeciesDiscriminant::Mienfoo),
+        SpeciesDiscriminant::Golurk => Some(SpeciesDiscriminant::Golett),
+        SpeciesDiscriminant::Bisharp => Some(SpeciesDiscriminant::Pawniard),
+        SpeciesDiscriminant::Braviary => Some(SpeciesDiscriminant::Rufflet),
+        SpeciesDiscriminant::Mandibuzz => Some(SpeciesDiscriminant::Vullaby),
+        SpeciesDiscriminant::Zweilous => Some(SpeciesDiscriminant::Deino),
+        SpeciesDiscriminant::Hydreigon => Some(SpeciesDiscriminant::Zweilous),
+        SpeciesDiscriminant::Volcarona => Some(SpeciesDiscriminant::Larvesta),
+        SpeciesDiscriminant::Quilladin => Some(SpeciesDiscriminant::Chespin),
+        SpeciesDiscriminant::Chesnaught => Some(SpeciesDiscriminant::Quilladin),
+        SpeciesDiscriminant::Braixen => Some(SpeciesDiscriminant::Fennekin),
+        SpeciesDiscriminant::Delphox => Some(SpeciesDiscriminant::Braixen),
+        SpeciesDiscriminant::Frogadier => Some(SpeciesDiscriminant::Froakie),
+        SpeciesDiscriminant::Greninja => Some(SpeciesDiscriminant::Frogadier),
+        SpeciesDiscriminant::Diggersby => Some(SpeciesDiscriminant::Bunnelby),
+        SpeciesDiscriminant::Fletchinder => Some(SpeciesDiscriminant::Fletchling),
+        SpeciesDiscriminant::Talonflame => Some(SpeciesDiscriminant::Fletchinder),
+        SpeciesDiscriminant::Spewpa => Some(SpeciesDiscriminant::Scatterbug),
+        SpeciesDiscriminant::Vivillon => Some(SpeciesDiscriminant::Spewpa),
+        SpeciesDiscriminant::Pyroar => Some(SpeciesDiscriminant::Litleo),
+        SpeciesDiscriminant::Floette => Some(SpeciesDiscriminant::Flabebe),
+        SpeciesDiscriminant::Florges => Some(SpeciesDiscriminant::Floette),
+        SpeciesDiscriminant::Gogoat => Some(SpeciesDiscriminant::Skiddo),
+        SpeciesDiscriminant::Pangoro => Some(SpeciesDiscriminant::Pancham),
+        SpeciesDiscriminant::Meowstic => Some(SpeciesDiscriminant::Espurr),
+        SpeciesDiscriminant::Doublade => Some(SpeciesDiscriminant::Honedge),
+        SpeciesDiscriminant::Aegislash => Some(SpeciesDiscriminant::Doublade),
+        SpeciesDiscriminant::Aromatisse => Some(SpeciesDiscriminant::Spritzee),
+        SpeciesDiscriminant::Slurpuff => Some(SpeciesDiscriminant::Swirlix),
+        SpeciesDiscriminant::Malamar => Some(SpeciesDiscriminant::Inkay),
+        SpeciesDiscriminant::Barbaracle => Some(SpeciesDiscriminant::Binacle),
+        SpeciesDiscriminant::Dragalge => Some(SpeciesDiscriminant::Skrelp),
+        SpeciesDiscriminant::Clawitzer => Some(SpeciesDiscriminant::Clauncher),
+        SpeciesDiscriminant::Heliolisk => Some(SpeciesDiscriminant::Helioptile),
+        SpeciesDiscriminant::Tyrantrum => Some(SpeciesDiscriminant::Tyrunt),
+        SpeciesDiscriminant::Aurorus => Some(SpeciesDiscriminant::Amaura),
+        SpeciesDiscriminant::Sylveon => Some(SpeciesDiscriminant::Eevee),
+        SpeciesDiscriminant::Sliggoo => Some(SpeciesDiscriminant::Goomy),
+        SpeciesDiscriminant::Goodra => Some(SpeciesDiscriminant::Sliggoo),
+        SpeciesDiscriminant::Trevenant => Some(SpeciesDiscriminant::Phantump),
+        SpeciesDiscriminant::Gourgeist => Some(SpeciesDiscriminant::Pumpkaboo),
+        SpeciesDiscriminant::Avalugg => Some(SpeciesDiscriminant::Bergmite),
+        SpeciesDiscriminant::Noivern => Some(SpeciesDiscriminant::Noibat),
+        SpeciesDiscriminant::Dartrix => Some(SpeciesDiscriminant::Rowlet),
+        SpeciesDiscriminant::Decidueye => Some(SpeciesDiscriminant::Dartrix),
+        SpeciesDiscriminant::Torracat => Some(SpeciesDiscriminant::Litten),
+        SpeciesDiscriminant::Incineroar => Some(SpeciesDiscriminant::Torracat),
+        SpeciesDiscriminant::Brionne => Some(SpeciesDiscriminant::Popplio),
+        SpeciesDiscriminant::Primarina => Some(SpeciesDiscriminant::Brionne),
+        SpeciesDiscriminant::Trumbeak => Some(SpeciesDiscriminant::Pikipek),
+        SpeciesDiscriminant::Toucannon => Some(SpeciesDiscriminant::Trumbeak),
+        SpeciesDiscriminant::Gumshoos => Some(SpeciesDiscriminant::Yungoos),
+        SpeciesDiscriminant::Charjabug => Some(SpeciesDiscriminant::Grubbin),
+        SpeciesDiscriminant::Vikavolt => Some(SpeciesDiscriminant::Charjabug),
+        SpeciesDiscriminant::Crabominable => Some(SpeciesDiscriminant::Crabrawler),
+        SpeciesDiscriminant::Ribombee => Some(SpeciesDiscriminant::Cutiefly),
+        SpeciesDiscriminant::Lycanroc => Some(SpeciesDiscriminant::Rockruff),
+        SpeciesDiscriminant::Toxapex => Some(SpeciesDiscriminant::Mareanie),
+        SpeciesDiscriminant::Mudsdale => Some(SpeciesDiscriminant::Mudbray),
+        SpeciesDiscriminant::Araquanid => Some(SpeciesDiscriminant::Dewpider),
+        SpeciesDiscriminant::Lurantis => Some(SpeciesDiscriminant::Fomantis),
+        SpeciesDiscriminant::Shiinotic => Some(SpeciesDiscriminant::Morelull),
+        SpeciesDiscriminant::Salazzle => Some(SpeciesDiscriminant::Salandit),
+        SpeciesDiscriminant::Bewear => Some(SpeciesDiscriminant::Stufful),
+        SpeciesDiscriminant::Steenee => Some(SpeciesDiscriminant::Bounsweet),
+        SpeciesDiscriminant::Tsareena => Some(SpeciesDiscriminant::Steenee),
+        SpeciesDiscriminant::Golisopod => Some(SpeciesDiscriminant::Wimpod),
+        SpeciesDiscriminant::Palossand => Some(SpeciesDiscriminant::Sandygast),
+        SpeciesDiscriminant::Silvally => Some(SpeciesDiscriminant::TypeNull),
+        SpeciesDiscriminant::HakamoO => Some(SpeciesDiscriminant::JangmoO),
+        SpeciesDiscriminant::KommoO => Some(SpeciesDiscriminant::HakamoO),
+        SpeciesDiscriminant::Cosmoem => Some(SpeciesDiscriminant::Cosmog),
+        SpeciesDiscriminant::Solgaleo => Some(SpeciesDiscriminant::Cosmoem),
+        SpeciesDiscriminant::Lunala => Some(SpeciesDiscriminant::Cosmoem),
+        SpeciesDiscriminant::Naganadel => Some(SpeciesDiscriminant::Poipole),
+        _ => None,
+    }
+}
+
+/// Returns the species the given species can evolve into directly, in National Pokedex order. This is
+/// empty for fully evolved species, and has more than one entry for branching evolutions like Eevee's.
+pub fn evolutions(species: SpeciesDiscriminant) -> Vec<SpeciesDiscriminant> {
+    SpeciesDiscriminant::iter().filter(|&s| pre_evolution(s) == Some(species)).collect()
+}
+
+/// Returns every species in the same evolutionary family as the given species, including itself. The
+/// family starts with its first stage, followed by each later stage in turn: for the Ralts family,
+/// that's Ralts, Kirlia, Gardevoir, and Gallade.
+pub fn evolution_family(species: SpeciesDiscriminant) -> Vec<SpeciesDiscriminant> {
+    let mut root = species;
+    while let Some(pre) = pre_evolution(root) {
+        root = pre;
+    }
+    let mut family = vec![root];
+    let mut i = 0;
+    while i < family.len() {
+        let next = evolutions(family[i]);
+        family.extend(next);
+        i += 1;
+    }
+    family
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn test_charmander_line() {
+        assert_eq!(pre_evolution(SpeciesDiscriminant::Charmander), None);
+        assert_eq!(pre_evolution(SpeciesDiscriminant::Charizard), Some(SpeciesDiscriminant::Charmeleon));
+        assert_eq!(evolutions(SpeciesDiscriminant::Charmander), vec![SpeciesDiscriminant::Charmeleon]);
+        assert_eq!(evolutions(SpeciesDiscriminant::Charizard), vec![]);
+        let family = vec![SpeciesDiscriminant::Charmander, SpeciesDiscriminant::Charmeleon,
+                          SpeciesDiscriminant::Charizard];
+        assert_eq!(evolution_family(SpeciesDiscriminant::Charmeleon), family);
+        assert_eq!(evolution_family(SpeciesDiscriminant::Charizard), family);
+    }
+    #[test]
+    fn test_branching_evolutions() {
+        assert_eq!(evolutions(SpeciesDiscriminant::Eevee).len(), 8);
+        assert!(evolutions(SpeciesDiscriminant::Eevee).contains(&SpeciesDiscriminant::Sylveon));
+        assert_eq!(evolutions(SpeciesDiscriminant::Tyrogue),
+                   vec![SpeciesDiscriminant::Hitmonlee, SpeciesDiscriminant::Hitmonchan,
+                        SpeciesDiscriminant::Hitmontop]);
+        assert_eq!(evolution_family(SpeciesDiscriminant::Gallade),
+                   vec![SpeciesDiscriminant::Ralts, SpeciesDiscriminant::Kirlia,
+                        SpeciesDiscriminant::Gardevoir, SpeciesDiscriminant::Gallade]);
+        assert_eq!(evolution_family(SpeciesDiscriminant::Zeraora), vec![SpeciesDiscriminant::Zeraora]);
+    }
+}
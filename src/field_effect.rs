@@ -0,0 +1,85 @@
+//! This file defines the turn-limited effects a Pokemon can set up on its side of the field:
+//! Safeguard, which blocks status, and the screens (Reflect, Light Screen, and Aurora Veil), which
+//! weaken incoming attacks. The rules are current to Gen VII, with
+//! [Bulbapedia](https://bulbapedia.bulbagarden.net/wiki/Reflect_(move)) as a source.
+
+use crate::r#move::MoveCategory;
+
+/// An effect that protects one side of the field for a few turns.
+#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
+pub enum FieldEffect {
+    /// Protects the side from major status conditions and confusion.
+    Safeguard,
+    /// Weakens special moves used against the side.
+    LightScreen,
+    /// Weakens physical moves used against the side.
+    Reflect,
+    /// Weakens both physical and special moves used against the side. It can only be set up in hail.
+    AuroraVeil,
+}
+
+impl FieldEffect {
+    /// Returns the number of turns this effect lasts after being set up. Everything lasts 5 turns, but
+    /// the screens last 8 if the user holds Light Clay. Safeguard isn't affected by Light Clay.
+    pub fn duration(self, light_clay: bool) -> u8 {
+        match self {
+            FieldEffect::Safeguard => 5,
+            _ if light_clay => 8,
+            _ => 5,
+        }
+    }
+
+    /// Returns true if this effect weakens moves of the given category.
+    pub fn weakens(self, category: MoveCategory) -> bool {
+        match (self, category) {
+            (_, MoveCategory::Status) => false,
+            (FieldEffect::Reflect, MoveCategory::Physical) => true,
+            (FieldEffect::LightScreen, MoveCategory::Special) => true,
+            (FieldEffect::AuroraVeil, _) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Returns the damage multiplier the given effect applies to a move of the given category used against
+/// the side it protects. A matching screen halves the damage in singles, or cuts it to 2/3 in doubles
+/// (where it's shared between two targets), but critical hits go right through screens. Safeguard and
+/// non-matching screens leave the damage alone.
+pub fn screen_damage_multiplier(effect: FieldEffect, category: MoveCategory, crit: bool,
+                                doubles: bool) -> f64 {
+    if crit || !effect.weakens(category) {
+        1.0
+    } else if doubles {
+        2.0 / 3.0
+    } else {
+        0.5
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn test_screen_damage_multiplier() {
+        assert_eq!(screen_damage_multiplier(FieldEffect::Reflect, MoveCategory::Physical, false, false),
+                   0.5);
+        assert_eq!(screen_damage_multiplier(FieldEffect::Reflect, MoveCategory::Physical, true, false),
+                   1.0);
+        assert_eq!(screen_damage_multiplier(FieldEffect::Reflect, MoveCategory::Special, false, false),
+                   1.0);
+        assert_eq!(screen_damage_multiplier(FieldEffect::LightScreen, MoveCategory::Special, false, true),
+                   2.0 / 3.0);
+        assert_eq!(screen_damage_multiplier(FieldEffect::AuroraVeil, MoveCategory::Physical, false, false),
+                   0.5);
+        assert_eq!(screen_damage_multiplier(FieldEffect::Safeguard, MoveCategory::Physical, false, false),
+                   1.0);
+    }
+    #[test]
+    fn test_duration() {
+        assert_eq!(FieldEffect::Reflect.duration(false), 5);
+        assert_eq!(FieldEffect::AuroraVeil.duration(true), 8);
+        assert_eq!(FieldEffect::Safeguard.duration(true), 5);
+    }
+}
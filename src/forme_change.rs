@@ -0,0 +1,158 @@
+//! This file handles the formes Pokemon change into in the middle of a battle: the ones that depend on
+//! how much HP is left at the end of a turn, like Darmanitan's Zen Mode or Wishiwashi's Schooling.
+//! Mimikyu's Disguise, Aegislash's Stance Change, and Meloetta's Relic Song are here too. The forme
+//! enums in `species` describe each of these mechanics, and this applies them. Formes that come from
+//! held items, like Mega Evolutions, are handled in `item` instead.
+
+use crate::ability::Ability;
+use crate::r#move::MoveCategory;
+use crate::species::*;
+
+/// Returns the forme the given species is in at the end of a turn with the given HP, if it has the
+/// ability that makes its forme depend on HP. Otherwise, the species is returned unchanged.
+///
+/// - Darmanitan with Zen Mode is in Zen Mode at half HP or less, and Standard Mode otherwise.
+/// - Zygarde with Power Construct becomes Zygarde-Complete at half HP or less, and never changes back.
+/// - Wishiwashi with Schooling is Solo at a quarter of its HP or less, and School otherwise.
+/// - Minior with Shields Down is in Core forme at half HP or less, and Meteor forme otherwise.
+///
+/// Wishiwashi also has to be at least level 20 to form a school, which this doesn't check.
+pub fn hp_triggered_forme(species: Species, current_hp: u16, max_hp: u16, ability: Ability) -> Species {
+    let at_most = |denominator: u16| current_hp <= max_hp / denominator;
+    match (species, ability) {
+        (Species::Darmanitan(_), Ability::ZenMode) => if at_most(2) {
+            Species::Darmanitan(DarmanitanForme::ZenMode)
+        } else {
+            Species::Darmanitan(DarmanitanForme::Standard)
+        },
+        (Species::Zygarde(_), Ability::PowerConstruct) if at_most(2) => {
+            Species::Zygarde(ZygardeForme::Complete)
+        },
+        (Species::Wishiwashi(_), Ability::Schooling) => if at_most(4) {
+            Species::Wishiwashi(WishiwashiForme::Solo)
+        } else {
+            Species::Wishiwashi(WishiwashiForme::School)
+        },
+        (Species::Minior(_), Ability::ShieldsDown) => if at_most(2) {
+            Species::Minior(MiniorForme::Core)
+        } else {
+            Species::Minior(MiniorForme::Meteor)
+        },
+        _ => species,
+    }
+}
+
+/// Returns true if the given species still has a Disguise that will take the next damaging hit for
+/// it, which is only true of Mimikyu before its disguise is busted.
+pub fn disguise_absorbs(species: Species) -> bool {
+    species == Species::Mimikyu(MimikyuForme::Disguised)
+}
+
+/// Returns the forme the given species takes after its Disguise absorbs a hit: Mimikyu-Busted for
+/// Mimikyu. Any other species is returned unchanged.
+pub fn bust_disguise(species: Species) -> Species {
+    match species {
+        Species::Mimikyu(_) => Species::Mimikyu(MimikyuForme::Busted),
+        _ => species,
+    }
+}
+
+/// Returns the damage Mimikyu takes when its Disguise is busted, given its max HP. Through Gen VII,
+/// the disguise takes the whole hit and Mimikyu takes nothing; from Gen VIII on, busting it costs 1/8
+/// of Mimikyu's max HP.
+pub fn disguise_bust_damage(max_hp: u16, generation: u8) -> u16 {
+    if generation >= 8 {
+        (max_hp / 8).max(1)
+    } else {
+        0
+    }
+}
+
+/// Returns the forme Aegislash takes when using a move of the given category: Blade Forme (`Sword`)
+/// for a damaging move, and no change for a status move. The only status move that changes its
+/// forme is King's Shield, which switches it back to Shield Forme: that has to be handled by the
+/// caller. Any other species is returned unchanged.
+pub fn stance_change(species: Species, category: MoveCategory) -> Species {
+    match species {
+        Species::Aegislash(_) if category != MoveCategory::Status => Species::Aegislash(AegislashForme::Sword),
+        _ => species,
+    }
+}
+
+/// Returns the forme Meloetta switches to after using Relic Song: Pirouette from Aria, and Aria from
+/// Pirouette. Meloetta always starts a battle in Aria Forme, and goes back to it after the battle,
+/// so a battle engine should reset it rather than keep whatever forme it ended in. Any other species
+/// is returned unchanged.
+pub fn toggle_meloetta(species: Species) -> Species {
+    match species {
+        Species::Meloetta(MeloettaForme::Aria) => Species::Meloetta(MeloettaForme::Pirouette),
+        Species::Meloetta(MeloettaForme::Pirouette) => Species::Meloetta(MeloettaForme::Aria),
+        _ => species,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn test_zen_mode() {
+        let darmanitan = Species::Darmanitan(DarmanitanForme::Standard);
+        let zen = Species::Darmanitan(DarmanitanForme::ZenMode);
+        assert_eq!(hp_triggered_forme(darmanitan, 49, 100, Ability::ZenMode), zen);
+        assert_eq!(hp_triggered_forme(darmanitan, 51, 100, Ability::ZenMode), darmanitan);
+        assert_eq!(hp_triggered_forme(zen, 80, 100, Ability::ZenMode), darmanitan);
+        assert_eq!(hp_triggered_forme(darmanitan, 10, 100, Ability::SheerForce), darmanitan);
+    }
+    #[test]
+    fn test_schooling() {
+        let school = Species::Wishiwashi(WishiwashiForme::School);
+        let solo = Species::Wishiwashi(WishiwashiForme::Solo);
+        assert_eq!(hp_triggered_forme(school, 30, 100, Ability::Schooling), school);
+        assert_eq!(hp_triggered_forme(school, 20, 100, Ability::Schooling), solo);
+        assert_eq!(hp_triggered_forme(solo, 30, 100, Ability::Schooling), school);
+    }
+    #[test]
+    fn test_power_construct() {
+        let zygarde = Species::Zygarde(ZygardeForme::FiftyPercent);
+        let complete = Species::Zygarde(ZygardeForme::Complete);
+        assert_eq!(hp_triggered_forme(zygarde, 200, 300, Ability::PowerConstruct), zygarde);
+        assert_eq!(hp_triggered_forme(zygarde, 150, 300, Ability::PowerConstruct), complete);
+        assert_eq!(hp_triggered_forme(complete, 400, 400, Ability::PowerConstruct), complete);
+        assert_eq!(hp_triggered_forme(Species::Minior(MiniorForme::Meteor), 50, 100, Ability::ShieldsDown),
+                   Species::Minior(MiniorForme::Core));
+    }
+    #[test]
+    fn test_disguise() {
+        let disguised = Species::Mimikyu(MimikyuForme::Disguised);
+        let busted = Species::Mimikyu(MimikyuForme::Busted);
+        assert!(disguise_absorbs(disguised));
+        assert_eq!(bust_disguise(disguised), busted);
+        assert!(!disguise_absorbs(busted));
+        assert_eq!(bust_disguise(busted), busted);
+        assert!(!disguise_absorbs(Species::Pikachu));
+        assert_eq!(disguise_bust_damage(251, 7), 0);
+        assert_eq!(disguise_bust_damage(251, 8), 31);
+    }
+    #[test]
+    fn test_stance_change() {
+        let shield = Species::Aegislash(AegislashForme::Shield);
+        let sword = Species::Aegislash(AegislashForme::Sword);
+        assert_eq!(stance_change(shield, MoveCategory::Physical), sword);
+        assert_eq!(stance_change(shield, MoveCategory::Special), sword);
+        assert_eq!(stance_change(shield, MoveCategory::Status), shield);
+        assert_eq!(stance_change(sword, MoveCategory::Status), sword);
+        assert_eq!(stance_change(Species::Pikachu, MoveCategory::Special), Species::Pikachu);
+    }
+    #[test]
+    fn test_toggle_meloetta() {
+        let aria = Species::Meloetta(MeloettaForme::Aria);
+        let pirouette = Species::Meloetta(MeloettaForme::Pirouette);
+        assert_eq!(toggle_meloetta(aria), pirouette);
+        assert_eq!(toggle_meloetta(pirouette), aria);
+        assert_eq!(toggle_meloetta(toggle_meloetta(aria)), aria);
+        assert_eq!(toggle_meloetta(toggle_meloetta(pirouette)), pirouette);
+        assert_eq!(toggle_meloetta(Species::Pikachu), Species::Pikachu);
+    }
+}
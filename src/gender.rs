@@ -0,0 +1,88 @@
+//! This file deals with Pokemon genders. Most species can be either male or female, but some are
+//! always one or the other, and many legendaries and artificial Pokemon have no gender at all. Gender
+//! matters in battle for moves like Attract and the ability Rivalry. The gender ratios are current to
+//! Gen VII, with [Bulbapedia](https://bulbapedia.bulbagarden.net/wiki/List_of_Pokémon_by_gender_ratio)
+//! as a source.
+
+use crate::species::*;
+
+/// A Pokemon's gender. Genderless Pokemon are neither male nor female.
+#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+pub enum Gender {
+    Male,
+    Female,
+    Genderless,
+}
+
+/// Returns the genders a Pokemon of the given species can have. This is `[Genderless]` for genderless
+/// species, a single gender for species that are always male or always female, and `[Male, Female]`
+/// for everything else, regardless of how lopsided the actual ratio is. Greninja with Battle Bond,
+/// and so Greninja-Ash, are always male.
+pub fn possible_genders(species: Species) -> Vec<Gender> {
+    match species {
+        Species::Magnemite | Species::Magneton | Species::Voltorb | Species::Electrode
+            | Species::Staryu | Species::Starmie | Species::Ditto | Species::Porygon
+            | Species::Articuno | Species::Zapdos | Species::Moltres | Species::Mewtwo(_)
+            | Species::Mew | Species::Unown | Species::Porygon2 | Species::Raikou | Species::Entei
+            | Species::Suicune | Species::Lugia | Species::HoOh | Species::Celebi
+            | Species::Shedinja | Species::Lunatone | Species::Solrock | Species::Baltoy
+            | Species::Claydol | Species::Beldum | Species::Metang | Species::Metagross(_)
+            | Species::Regirock | Species::Regice | Species::Registeel | Species::Kyogre(_)
+            | Species::Groudon(_) | Species::Rayquaza(_) | Species::Jirachi | Species::Deoxys(_)
+            | Species::Bronzor | Species::Bronzong | Species::Magnezone | Species::PorygonZ
+            | Species::Rotom(_) | Species::Uxie | Species::Mesprit | Species::Azelf
+            | Species::Dialga | Species::Palkia | Species::Regigigas | Species::Giratina(_)
+            | Species::Phione | Species::Manaphy | Species::Darkrai | Species::Shaymin(_)
+            | Species::Arceus(_) | Species::Victini | Species::Klink | Species::Klang
+            | Species::Klinklang | Species::Cryogonal | Species::Golett | Species::Golurk
+            | Species::Cobalion | Species::Terrakion | Species::Virizion | Species::Reshiram
+            | Species::Zekrom | Species::Kyurem(_) | Species::Keldeo | Species::Meloetta(_)
+            | Species::Genesect | Species::Carbink | Species::Xerneas | Species::Yveltal
+            | Species::Zygarde(_) | Species::Diancie(_) | Species::Hoopa(_) | Species::Volcanion
+            | Species::TypeNull(_) | Species::Silvally(_) | Species::Minior(_) | Species::Dhelmise
+            | Species::TapuKoko | Species::TapuLele | Species::TapuBulu | Species::TapuFini
+            | Species::Cosmog | Species::Cosmoem | Species::Solgaleo | Species::Lunala
+            | Species::Nihilego | Species::Buzzwole | Species::Pheromosa | Species::Xurkitree
+            | Species::Celesteela | Species::Kartana | Species::Guzzlord | Species::Necrozma(_)
+            | Species::Magearna | Species::Marshadow | Species::Poipole | Species::Naganadel
+            | Species::Stakataka | Species::Blacephalon | Species::Zeraora => vec![Gender::Genderless],
+        Species::NidoranM | Species::Nidorino | Species::Nidoking | Species::Hitmonlee
+            | Species::Hitmonchan | Species::Tauros | Species::Tyrogue | Species::Hitmontop
+            | Species::Volbeat | Species::Latios | Species::Gallade(_) | Species::Mothim
+            | Species::Throh | Species::Sawk | Species::Rufflet | Species::Braviary
+            | Species::Tornadus(_) | Species::Thundurus(_) | Species::Landorus(_)
+            | Species::Greninja(GreninjaForme::BattleBond)
+            | Species::Greninja(GreninjaForme::Ash) => vec![Gender::Male],
+        Species::NidoranF | Species::Nidorina | Species::Nidoqueen | Species::Chansey
+            | Species::Kangaskhan(_) | Species::Jynx | Species::Smoochum | Species::Miltank
+            | Species::Blissey | Species::Illumise | Species::Latias | Species::Wormadam(_)
+            | Species::Vespiquen | Species::Froslass | Species::Cresselia | Species::Happiny
+            | Species::Petilil | Species::Lilligant | Species::Vullaby | Species::Mandibuzz
+            | Species::Flabebe | Species::Floette | Species::Florges | Species::Bounsweet
+            | Species::Steenee | Species::Tsareena | Species::Salazzle => vec![Gender::Female],
+        _ => vec![Gender::Male, Gender::Female],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn test_possible_genders() {
+        assert_eq!(possible_genders(Species::Zekrom), vec![Gender::Genderless]);
+        assert_eq!(possible_genders(Species::Magearna), vec![Gender::Genderless]);
+        assert_eq!(possible_genders(Species::NidoranF), vec![Gender::Female]);
+        assert_eq!(possible_genders(Species::Tauros), vec![Gender::Male]);
+        assert_eq!(possible_genders(Species::Pikachu), vec![Gender::Male, Gender::Female]);
+        // Heatran is a legendary, but it has a gender
+        assert_eq!(possible_genders(Species::Heatran), vec![Gender::Male, Gender::Female]);
+    }
+    #[test]
+    fn test_greninja() {
+        assert_eq!(possible_genders(Species::Greninja(GreninjaForme::Normal)), vec![Gender::Male, Gender::Female]);
+        assert_eq!(possible_genders(Species::Greninja(GreninjaForme::BattleBond)), vec![Gender::Male]);
+        assert_eq!(possible_genders(Species::Greninja(GreninjaForme::Ash)), vec![Gender::Male]);
+    }
+}
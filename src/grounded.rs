@@ -0,0 +1,53 @@
+//! This file determines whether a Pokemon is grounded. Terrain, Spikes, Toxic Spikes, and Ground-type
+//! moves only affect grounded Pokemon, so this check comes up in many places. The rules are described
+//! on [Bulbapedia](https://bulbapedia.bulbagarden.net/wiki/Grounded).
+
+use crate::ability::Ability;
+use crate::item::Item;
+use crate::typing::Typing;
+
+/// Returns true if a Pokemon with the given typing, ability, and held item is grounded. Flying types,
+/// Levitate users, Air Balloon holders, and Pokemon under the effect of Magnet Rise are all
+/// ungrounded, but holding an Iron Ball or being under the effect of Gravity grounds a Pokemon no
+/// matter what. Pass None for the item if the Pokemon has no item or its item is suppressed (for
+/// example, by Klutz or Embargo).
+pub fn is_grounded(typing: (Typing, Option<Typing>), ability: Ability, item: Option<Item>,
+                   magnet_rise: bool, gravity: bool) -> bool {
+    if gravity || item == Some(Item::IronBall) {
+        return true;
+    }
+    let flying = typing.0 == Typing::Flying || typing.1 == Some(Typing::Flying);
+    !(flying || ability == Ability::Levitate || item == Some(Item::AirBalloon) || magnet_rise)
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn test_is_grounded() {
+        // Charizard
+        assert!(!is_grounded((Typing::Fire, Some(Typing::Flying)), Ability::Blaze, None, false, false));
+        // Rotom-Wash
+        assert!(!is_grounded((Typing::Electric, Some(Typing::Water)), Ability::Levitate, None, false, false));
+        // Heatran, with and without an Air Balloon
+        let heatran = (Typing::Fire, Some(Typing::Steel));
+        assert!(is_grounded(heatran, Ability::FlashFire, None, false, false));
+        assert!(!is_grounded(heatran, Ability::FlashFire, Some(Item::AirBalloon), false, false));
+        assert!(!is_grounded(heatran, Ability::FlashFire, None, true, false));
+    }
+    #[test]
+    fn test_iron_ball() {
+        assert!(is_grounded((Typing::Normal, Some(Typing::Flying)), Ability::KeenEye, Some(Item::IronBall), false, false));
+        assert!(is_grounded((Typing::Ghost, None), Ability::Levitate, Some(Item::IronBall), true, false));
+    }
+    #[test]
+    fn test_gravity() {
+        let skarmory = (Typing::Steel, Some(Typing::Flying));
+        assert!(!is_grounded(skarmory, Ability::Sturdy, None, false, false));
+        assert!(is_grounded(skarmory, Ability::Sturdy, None, false, true));
+        assert!(is_grounded((Typing::Ghost, Some(Typing::Poison)), Ability::Levitate, Some(Item::AirBalloon),
+                            true, true));
+    }
+}
@@ -0,0 +1,75 @@
+//! This file implements entry hazards: Stealth Rock, Spikes, and Toxic Spikes, which damage or poison
+//! Pokemon as they switch in. [Bulbapedia](https://bulbapedia.bulbagarden.net/wiki/Entry_hazard) is
+//! used as a source.
+
+use crate::typing::{defense_multiplier_for, Typing};
+
+/// An entry hazard on one side of the field. Spikes and Toxic Spikes can be stacked, so they carry
+/// their number of layers: up to 3 for Spikes and up to 2 for Toxic Spikes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Hazard {
+    /// Deals 1/8 of the target's max HP, scaled by the effectiveness of Rock against it. Hits every
+    /// Pokemon, grounded or not.
+    StealthRock,
+    /// Deals 1/8, 1/6, or 1/4 of the target's max HP with 1, 2, or 3 layers. Only hits grounded
+    /// Pokemon.
+    Spikes(u8),
+    /// Poisons grounded Pokemon (badly, with 2 layers) instead of dealing damage. Grounded Poison
+    /// types absorb it.
+    ToxicSpikes(u8),
+}
+
+impl Hazard {
+    /// The damage this hazard alone deals to a Pokemon on switching in. Any damaging hazard that
+    /// actually affects the Pokemon deals at least 1 HP.
+    fn damage(self, typing: (Typing, Option<Typing>), max_hp: u16, grounded: bool) -> u16 {
+        let hp = u32::from(max_hp);
+        let damage = match self {
+            Hazard::StealthRock => {
+                let effectiveness: f32 = defense_multiplier_for(Typing::Rock, typing).into();
+                (f64::from(max_hp) * f64::from(effectiveness) / 8.0) as u32
+            },
+            Hazard::Spikes(0) => return 0,
+            Hazard::Spikes(_) if !grounded => return 0,
+            Hazard::Spikes(1) => hp / 8,
+            Hazard::Spikes(2) => hp / 6,
+            Hazard::Spikes(_) => hp / 4,
+            Hazard::ToxicSpikes(_) => return 0,
+        };
+        damage.max(1) as u16
+    }
+}
+
+/// Returns the total damage a Pokemon with the given typing and max HP takes from the hazards on its
+/// side of the field when it switches in. Toxic Spikes don't deal damage directly, so they're ignored
+/// here. The result never exceeds the Pokemon's max HP.
+pub fn switch_in_damage(hazards: &[Hazard], typing: (Typing, Option<Typing>), max_hp: u16,
+                        grounded: bool) -> u16 {
+    let total: u32 = hazards.iter().map(|h| u32::from(h.damage(typing, max_hp, grounded))).sum();
+    total.min(u32::from(max_hp)) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn test_stealth_rock() {
+        // Charizard is 4x weak to Rock, so Stealth Rock takes half its HP
+        let charizard = (Typing::Fire, Some(Typing::Flying));
+        assert_eq!(switch_in_damage(&[Hazard::StealthRock], charizard, 360, false), 180);
+        // Ferrothorn resists it
+        assert_eq!(switch_in_damage(&[Hazard::StealthRock], (Typing::Grass, Some(Typing::Steel)), 352, true), 22);
+    }
+    #[test]
+    fn test_spikes() {
+        let landorus = (Typing::Ground, Some(Typing::Flying));
+        assert_eq!(switch_in_damage(&[Hazard::Spikes(3)], landorus, 319, false), 0);
+        assert_eq!(switch_in_damage(&[Hazard::Spikes(3), Hazard::StealthRock], landorus, 319, false), 39);
+        let garchomp = (Typing::Dragon, Some(Typing::Ground));
+        assert_eq!(switch_in_damage(&[Hazard::Spikes(1)], garchomp, 358, true), 44);
+        assert_eq!(switch_in_damage(&[Hazard::Spikes(2)], garchomp, 358, true), 59);
+        assert_eq!(switch_in_damage(&[Hazard::Spikes(3), Hazard::ToxicSpikes(2)], garchomp, 358, true), 89);
+    }
+}
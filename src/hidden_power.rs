@@ -0,0 +1,100 @@
+//! This file implements Hidden Power, whose type depends on the user's IVs. The formulas are the ones
+//! used from Gen III through Gen VII, as described on
+//! [Bulbapedia](https://bulbapedia.bulbagarden.net/wiki/Hidden_Power_(move)/Calculation). IV spreads
+//! are given in the order of `Stat`: HP, Atk, Def, SpA, SpD, Spe.
+
+use std::convert::TryFrom;
+
+use crate::stat::Stat;
+use crate::typing::Typing;
+
+/// The order in which IVs contribute bits to the Hidden Power formulas: notably, this has Speed
+/// before the special stats, which isn't the order `Stat` uses.
+const HIDDEN_POWER_ORDER: [Stat; 6] = [Stat::HP, Stat::Atk, Stat::Def, Stat::Spe, Stat::SpA, Stat::SpD];
+
+/// Builds a number from one bit of each IV, with HP as the least significant bit.
+fn iv_bits(ivs: [u8; 6], shift: u8) -> u16 {
+    HIDDEN_POWER_ORDER.iter().enumerate().map(|(i, &stat)| {
+        u16::from((ivs[stat as usize] >> shift) & 1) << i
+    }).sum()
+}
+
+/// Gets the type of Hidden Power for a Pokemon with the given IVs. Hidden Power can be any type but
+/// Normal and Fairy.
+pub fn hidden_power_type(ivs: [u8; 6]) -> Typing {
+    let index = iv_bits(ivs, 0) * 15 / 63;
+    // skip Normal, which comes first
+    Typing::try_from(index as u8 + 1).unwrap()
+}
+
+/// Gets a spread of IVs that gives Hidden Power the given type, or None if Hidden Power can't be
+/// that type. The spreads are the standard ones: every IV is 31 or 30, with as few 30s as possible.
+pub fn ivs_for_hidden_power(target: Typing) -> Option<[u8; 6]> {
+    // HP, Atk, Def, SpA, SpD, Spe
+    match target {
+        Typing::Normal | Typing::Fairy => None,
+        Typing::Fighting => Some([31, 31, 30, 30, 30, 30]),
+        Typing::Flying => Some([30, 30, 30, 30, 30, 31]),
+        Typing::Poison => Some([31, 31, 30, 30, 30, 31]),
+        Typing::Ground => Some([31, 31, 31, 30, 30, 31]),
+        Typing::Rock => Some([31, 31, 30, 31, 30, 30]),
+        Typing::Bug => Some([31, 30, 31, 31, 30, 30]),
+        Typing::Ghost => Some([31, 31, 30, 31, 30, 31]),
+        Typing::Steel => Some([31, 31, 31, 31, 30, 31]),
+        Typing::Fire => Some([31, 30, 31, 30, 31, 30]),
+        Typing::Water => Some([31, 30, 30, 30, 31, 31]),
+        Typing::Grass => Some([31, 30, 31, 30, 31, 31]),
+        Typing::Electric => Some([31, 31, 31, 30, 31, 31]),
+        Typing::Psychic => Some([31, 30, 31, 31, 31, 30]),
+        Typing::Ice => Some([31, 30, 30, 31, 31, 31]),
+        Typing::Dragon => Some([31, 30, 31, 31, 31, 31]),
+        Typing::Dark => Some([31, 31, 31, 31, 31, 31]),
+    }
+}
+
+/// Gets the base power of Hidden Power for a Pokemon with the given IVs in the given generation.
+/// From Gen VI onward, it's always 60: before that, it ranged from 30 to 70 depending on the
+/// second-lowest bit of each IV.
+pub fn hidden_power_base_power(ivs: [u8; 6], generation: u8) -> u8 {
+    if generation >= 6 {
+        60
+    } else {
+        (iv_bits(ivs, 1) * 40 / 63 + 30) as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn test_hidden_power_type() {
+        // the classic HP Ice spread: 31/30/30/31/31/31
+        assert_eq!(hidden_power_type([31, 30, 30, 31, 31, 31]), Typing::Ice);
+        assert_eq!(hidden_power_type([31; 6]), Typing::Dark);
+        assert_eq!(hidden_power_type([0; 6]), Typing::Fighting);
+        // HP Fire: 31/30/31/30/31/30
+        assert_eq!(hidden_power_type([31, 30, 31, 30, 31, 30]), Typing::Fire);
+    }
+    #[test]
+    fn test_ivs_for_hidden_power() {
+        assert_eq!(ivs_for_hidden_power(Typing::Normal), None);
+        assert_eq!(ivs_for_hidden_power(Typing::Fairy), None);
+        for t in Typing::all_typings() {
+            if let Some(ivs) = ivs_for_hidden_power(t) {
+                assert_eq!(hidden_power_type(ivs), t);
+            }
+        }
+    }
+    #[test]
+    fn test_hidden_power_base_power() {
+        assert_eq!(hidden_power_base_power([31, 30, 30, 31, 31, 31], 7), 60);
+        assert_eq!(hidden_power_base_power([0; 6], 7), 60);
+        // 30 and 31 both have the second bit set, so the usual spreads all got the old maximum
+        assert_eq!(hidden_power_base_power([31; 6], 4), 70);
+        assert_eq!(hidden_power_base_power([31, 30, 30, 31, 31, 31], 4), 70);
+        assert_eq!(hidden_power_base_power([31, 31, 31, 31, 31, 29], 4), 64);
+        assert_eq!(hidden_power_base_power([0; 6], 3), 30);
+    }
+}
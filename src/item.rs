@@ -0,0 +1,770 @@
+//! This file defines held items. For now, these are the items commonly seen in competitive play, like
+//! Leftovers and the Choice items, and the items that determine a Pokemon's forme: the Arceus plates,
+//! the Silvally memories, the type-specific Z-Crystals (which Arceus also responds to), the orbs that
+//! trigger Primal Reversion and Giratina-Origin, the Genesect drives, and the Mega Stones. The berries
+//! used in battle are included as well: status and healing berries, pinch berries, and the berries
+//! that weaken a super effective hit. Names and effects are current to
+//! Gen VII, with [Bulbapedia](https://bulbapedia.bulbagarden.net/wiki/Item) as a source.
+//!
+//! Mega Stones are only included for the Mega Evolutions that exist in `Species`.
+
+use strum::IntoEnumIterator;
+
+use crate::species::*;
+use crate::typing::{Multiplier, Typing};
+
+/// A held item. Like `Ability`, these serialize to their in-game names, and can be parsed from either
+/// the in-game name or the name with spaces removed: both "Flame Plate" and "FlamePlate" parse as
+/// `Item::FlamePlate`.
+#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+pub enum Item {
+    // common competitive items
+    Leftovers,
+    #[strum(serialize="Black Sludge", serialize="BlackSludge")]
+    BlackSludge,
+    #[strum(serialize="Choice Band", serialize="ChoiceBand")]
+    ChoiceBand,
+    #[strum(serialize="Choice Specs", serialize="ChoiceSpecs")]
+    ChoiceSpecs,
+    #[strum(serialize="Choice Scarf", serialize="ChoiceScarf")]
+    ChoiceScarf,
+    #[strum(serialize="Life Orb", serialize="LifeOrb")]
+    LifeOrb,
+    #[strum(serialize="Expert Belt", serialize="ExpertBelt")]
+    ExpertBelt,
+    #[strum(serialize="Focus Sash", serialize="FocusSash")]
+    FocusSash,
+    #[strum(serialize="Assault Vest", serialize="AssaultVest")]
+    AssaultVest,
+    #[strum(serialize="Rocky Helmet", serialize="RockyHelmet")]
+    RockyHelmet,
+    #[strum(serialize="Air Balloon", serialize="AirBalloon")]
+    AirBalloon,
+    #[strum(serialize="Weakness Policy", serialize="WeaknessPolicy")]
+    WeaknessPolicy,
+    Eviolite,
+    #[strum(serialize="Light Clay", serialize="LightClay")]
+    LightClay,
+    #[strum(serialize="Heat Rock", serialize="HeatRock")]
+    HeatRock,
+    #[strum(serialize="Damp Rock", serialize="DampRock")]
+    DampRock,
+    #[strum(serialize="Smooth Rock", serialize="SmoothRock")]
+    SmoothRock,
+    #[strum(serialize="Icy Rock", serialize="IcyRock")]
+    IcyRock,
+    #[strum(serialize="Terrain Extender", serialize="TerrainExtender")]
+    TerrainExtender,
+    #[strum(serialize="Toxic Orb", serialize="ToxicOrb")]
+    ToxicOrb,
+    #[strum(serialize="Flame Orb", serialize="FlameOrb")]
+    FlameOrb,
+    #[strum(serialize="Shed Shell", serialize="ShedShell")]
+    ShedShell,
+    #[strum(serialize="Red Card", serialize="RedCard")]
+    RedCard,
+    #[strum(serialize="Eject Button", serialize="EjectButton")]
+    EjectButton,
+    #[strum(serialize="Mental Herb", serialize="MentalHerb")]
+    MentalHerb,
+    #[strum(serialize="White Herb", serialize="WhiteHerb")]
+    WhiteHerb,
+    #[strum(serialize="Power Herb", serialize="PowerHerb")]
+    PowerHerb,
+    #[strum(serialize="Safety Goggles", serialize="SafetyGoggles")]
+    SafetyGoggles,
+    #[strum(serialize="Protective Pads", serialize="ProtectivePads")]
+    ProtectivePads,
+    #[strum(serialize="Iron Ball", serialize="IronBall")]
+    IronBall,
+    // Arceus plates
+    #[strum(serialize="Flame Plate", serialize="FlamePlate")]
+    FlamePlate,
+    #[strum(serialize="Splash Plate", serialize="SplashPlate")]
+    SplashPlate,
+    #[strum(serialize="Zap Plate", serialize="ZapPlate")]
+    ZapPlate,
+    #[strum(serialize="Meadow Plate", serialize="MeadowPlate")]
+    MeadowPlate,
+    #[strum(serialize="Icicle Plate", serialize="IciclePlate")]
+    IciclePlate,
+    #[strum(serialize="Fist Plate", serialize="FistPlate")]
+    FistPlate,
+    #[strum(serialize="Toxic Plate", serialize="ToxicPlate")]
+    ToxicPlate,
+    #[strum(serialize="Earth Plate", serialize="EarthPlate")]
+    EarthPlate,
+    #[strum(serialize="Sky Plate", serialize="SkyPlate")]
+    SkyPlate,
+    #[strum(serialize="Mind Plate", serialize="MindPlate")]
+    MindPlate,
+    #[strum(serialize="Insect Plate", serialize="InsectPlate")]
+    InsectPlate,
+    #[strum(serialize="Stone Plate", serialize="StonePlate")]
+    StonePlate,
+    #[strum(serialize="Spooky Plate", serialize="SpookyPlate")]
+    SpookyPlate,
+    #[strum(serialize="Draco Plate", serialize="DracoPlate")]
+    DracoPlate,
+    #[strum(serialize="Dread Plate", serialize="DreadPlate")]
+    DreadPlate,
+    #[strum(serialize="Iron Plate", serialize="IronPlate")]
+    IronPlate,
+    #[strum(serialize="Pixie Plate", serialize="PixiePlate")]
+    PixiePlate,
+    // Silvally memories
+    #[strum(serialize="Fire Memory", serialize="FireMemory")]
+    FireMemory,
+    #[strum(serialize="Water Memory", serialize="WaterMemory")]
+    WaterMemory,
+    #[strum(serialize="Electric Memory", serialize="ElectricMemory")]
+    ElectricMemory,
+    #[strum(serialize="Grass Memory", serialize="GrassMemory")]
+    GrassMemory,
+    #[strum(serialize="Ice Memory", serialize="IceMemory")]
+    IceMemory,
+    #[strum(serialize="Fighting Memory", serialize="FightingMemory")]
+    FightingMemory,
+    #[strum(serialize="Poison Memory", serialize="PoisonMemory")]
+    PoisonMemory,
+    #[strum(serialize="Ground Memory", serialize="GroundMemory")]
+    GroundMemory,
+    #[strum(serialize="Flying Memory", serialize="FlyingMemory")]
+    FlyingMemory,
+    #[strum(serialize="Psychic Memory", serialize="PsychicMemory")]
+    PsychicMemory,
+    #[strum(serialize="Bug Memory", serialize="BugMemory")]
+    BugMemory,
+    #[strum(serialize="Rock Memory", serialize="RockMemory")]
+    RockMemory,
+    #[strum(serialize="Ghost Memory", serialize="GhostMemory")]
+    GhostMemory,
+    #[strum(serialize="Dragon Memory", serialize="DragonMemory")]
+    DragonMemory,
+    #[strum(serialize="Dark Memory", serialize="DarkMemory")]
+    DarkMemory,
+    #[strum(serialize="Steel Memory", serialize="SteelMemory")]
+    SteelMemory,
+    #[strum(serialize="Fairy Memory", serialize="FairyMemory")]
+    FairyMemory,
+    // type-specific Z-Crystals
+    #[strum(serialize="Normalium Z", serialize="NormaliumZ")]
+    NormaliumZ,
+    #[strum(serialize="Firium Z", serialize="FiriumZ")]
+    FiriumZ,
+    #[strum(serialize="Waterium Z", serialize="WateriumZ")]
+    WateriumZ,
+    #[strum(serialize="Electrium Z", serialize="ElectriumZ")]
+    ElectriumZ,
+    #[strum(serialize="Grassium Z", serialize="GrassiumZ")]
+    GrassiumZ,
+    #[strum(serialize="Icium Z", serialize="IciumZ")]
+    IciumZ,
+    #[strum(serialize="Fightinium Z", serialize="FightiniumZ")]
+    FightiniumZ,
+    #[strum(serialize="Poisonium Z", serialize="PoisoniumZ")]
+    PoisoniumZ,
+    #[strum(serialize="Groundium Z", serialize="GroundiumZ")]
+    GroundiumZ,
+    #[strum(serialize="Flyinium Z", serialize="FlyiniumZ")]
+    FlyiniumZ,
+    #[strum(serialize="Psychium Z", serialize="PsychiumZ")]
+    PsychiumZ,
+    #[strum(serialize="Buginium Z", serialize="BuginiumZ")]
+    BuginiumZ,
+    #[strum(serialize="Rockium Z", serialize="RockiumZ")]
+    RockiumZ,
+    #[strum(serialize="Ghostium Z", serialize="GhostiumZ")]
+    GhostiumZ,
+    #[strum(serialize="Dragonium Z", serialize="DragoniumZ")]
+    DragoniumZ,
+    #[strum(serialize="Darkinium Z", serialize="DarkiniumZ")]
+    DarkiniumZ,
+    #[strum(serialize="Steelium Z", serialize="SteeliumZ")]
+    SteeliumZ,
+    #[strum(serialize="Fairium Z", serialize="FairiumZ")]
+    FairiumZ,
+    #[strum(serialize="Ultranecrozium Z", serialize="UltranecroziumZ")]
+    UltranecroziumZ,
+    // orbs
+    #[strum(serialize="Red Orb", serialize="RedOrb")]
+    RedOrb,
+    #[strum(serialize="Blue Orb", serialize="BlueOrb")]
+    BlueOrb,
+    #[strum(serialize="Griseous Orb", serialize="GriseousOrb")]
+    GriseousOrb,
+    // berries
+    #[strum(serialize="Cheri Berry", serialize="CheriBerry")]
+    CheriBerry,
+    #[strum(serialize="Chesto Berry", serialize="ChestoBerry")]
+    ChestoBerry,
+    #[strum(serialize="Pecha Berry", serialize="PechaBerry")]
+    PechaBerry,
+    #[strum(serialize="Rawst Berry", serialize="RawstBerry")]
+    RawstBerry,
+    #[strum(serialize="Aspear Berry", serialize="AspearBerry")]
+    AspearBerry,
+    #[strum(serialize="Leppa Berry", serialize="LeppaBerry")]
+    LeppaBerry,
+    #[strum(serialize="Oran Berry", serialize="OranBerry")]
+    OranBerry,
+    #[strum(serialize="Persim Berry", serialize="PersimBerry")]
+    PersimBerry,
+    #[strum(serialize="Lum Berry", serialize="LumBerry")]
+    LumBerry,
+    #[strum(serialize="Sitrus Berry", serialize="SitrusBerry")]
+    SitrusBerry,
+    #[strum(serialize="Figy Berry", serialize="FigyBerry")]
+    FigyBerry,
+    #[strum(serialize="Wiki Berry", serialize="WikiBerry")]
+    WikiBerry,
+    #[strum(serialize="Mago Berry", serialize="MagoBerry")]
+    MagoBerry,
+    #[strum(serialize="Aguav Berry", serialize="AguavBerry")]
+    AguavBerry,
+    #[strum(serialize="Iapapa Berry", serialize="IapapaBerry")]
+    IapapaBerry,
+    #[strum(serialize="Occa Berry", serialize="OccaBerry")]
+    OccaBerry,
+    #[strum(serialize="Passho Berry", serialize="PasshoBerry")]
+    PasshoBerry,
+    #[strum(serialize="Wacan Berry", serialize="WacanBerry")]
+    WacanBerry,
+    #[strum(serialize="Rindo Berry", serialize="RindoBerry")]
+    RindoBerry,
+    #[strum(serialize="Yache Berry", serialize="YacheBerry")]
+    YacheBerry,
+    #[strum(serialize="Chople Berry", serialize="ChopleBerry")]
+    ChopleBerry,
+    #[strum(serialize="Kebia Berry", serialize="KebiaBerry")]
+    KebiaBerry,
+    #[strum(serialize="Shuca Berry", serialize="ShucaBerry")]
+    ShucaBerry,
+    #[strum(serialize="Coba Berry", serialize="CobaBerry")]
+    CobaBerry,
+    #[strum(serialize="Payapa Berry", serialize="PayapaBerry")]
+    PayapaBerry,
+    #[strum(serialize="Tanga Berry", serialize="TangaBerry")]
+    TangaBerry,
+    #[strum(serialize="Charti Berry", serialize="ChartiBerry")]
+    ChartiBerry,
+    #[strum(serialize="Kasib Berry", serialize="KasibBerry")]
+    KasibBerry,
+    #[strum(serialize="Haban Berry", serialize="HabanBerry")]
+    HabanBerry,
+    #[strum(serialize="Colbur Berry", serialize="ColburBerry")]
+    ColburBerry,
+    #[strum(serialize="Babiri Berry", serialize="BabiriBerry")]
+    BabiriBerry,
+    #[strum(serialize="Chilan Berry", serialize="ChilanBerry")]
+    ChilanBerry,
+    #[strum(serialize="Roseli Berry", serialize="RoseliBerry")]
+    RoseliBerry,
+    #[strum(serialize="Liechi Berry", serialize="LiechiBerry")]
+    LiechiBerry,
+    #[strum(serialize="Ganlon Berry", serialize="GanlonBerry")]
+    GanlonBerry,
+    #[strum(serialize="Salac Berry", serialize="SalacBerry")]
+    SalacBerry,
+    #[strum(serialize="Petaya Berry", serialize="PetayaBerry")]
+    PetayaBerry,
+    #[strum(serialize="Apicot Berry", serialize="ApicotBerry")]
+    ApicotBerry,
+    #[strum(serialize="Lansat Berry", serialize="LansatBerry")]
+    LansatBerry,
+    #[strum(serialize="Starf Berry", serialize="StarfBerry")]
+    StarfBerry,
+    #[strum(serialize="Micle Berry", serialize="MicleBerry")]
+    MicleBerry,
+    #[strum(serialize="Custap Berry", serialize="CustapBerry")]
+    CustapBerry,
+    #[strum(serialize="Jaboca Berry", serialize="JabocaBerry")]
+    JabocaBerry,
+    #[strum(serialize="Rowap Berry", serialize="RowapBerry")]
+    RowapBerry,
+    #[strum(serialize="Kee Berry", serialize="KeeBerry")]
+    KeeBerry,
+    #[strum(serialize="Maranga Berry", serialize="MarangaBerry")]
+    MarangaBerry,
+    // Genesect drives
+    #[strum(serialize="Douse Drive", serialize="DouseDrive")]
+    DouseDrive,
+    #[strum(serialize="Shock Drive", serialize="ShockDrive")]
+    ShockDrive,
+    #[strum(serialize="Burn Drive", serialize="BurnDrive")]
+    BurnDrive,
+    #[strum(serialize="Chill Drive", serialize="ChillDrive")]
+    ChillDrive,
+    // Mega Stones
+    Venusaurite,
+    #[strum(serialize="Charizardite X", serialize="CharizarditeX")]
+    CharizarditeX,
+    #[strum(serialize="Charizardite Y", serialize="CharizarditeY")]
+    CharizarditeY,
+    Blastoisinite,
+    Beedrillite,
+    Pidgeotite,
+    Alakazite,
+    Gengarite,
+    Kangaskhanite,
+    Pinsirite,
+    Gyaradosite,
+    Aerodactylite,
+    #[strum(serialize="Mewtwonite X", serialize="MewtwoniteX")]
+    MewtwoniteX,
+    #[strum(serialize="Mewtwonite Y", serialize="MewtwoniteY")]
+    MewtwoniteY,
+    Steelixite,
+    Scizorite,
+    Heracronite,
+    Houndoominite,
+    Tyranitarite,
+    Sceptilite,
+    Blazikenite,
+    Swampertite,
+    Gardevoirite,
+    Sablenite,
+    Mawilite,
+    Aggronite,
+    Medichamite,
+    Manectite,
+    Sharpedonite,
+    Cameruptite,
+    Altarianite,
+    Salamencite,
+    Metagrossite,
+    Lopunnite,
+    Garchompite,
+    Lucarionite,
+    Abomasite,
+    Galladite,
+    Diancite,
+}
+
+impl Item {
+    /// Returns the type of an Arceus plate, or None if the item isn't a plate. There's no Normal plate:
+    /// Arceus without a plate is Normal-type.
+    pub fn plate_type(self) -> Option<Typing> {
+        match self {
+            Item::FlamePlate => Some(Typing::Fire),
+            Item::SplashPlate => Some(Typing::Water),
+            Item::ZapPlate => Some(Typing::Electric),
+            Item::MeadowPlate => Some(Typing::Grass),
+            Item::IciclePlate => Some(Typing::Ice),
+            Item::FistPlate => Some(Typing::Fighting),
+            Item::ToxicPlate => Some(Typing::Poison),
+            Item::EarthPlate => Some(Typing::Ground),
+            Item::SkyPlate => Some(Typing::Flying),
+            Item::MindPlate => Some(Typing::Psychic),
+            Item::InsectPlate => Some(Typing::Bug),
+            Item::StonePlate => Some(Typing::Rock),
+            Item::SpookyPlate => Some(Typing::Ghost),
+            Item::DracoPlate => Some(Typing::Dragon),
+            Item::DreadPlate => Some(Typing::Dark),
+            Item::IronPlate => Some(Typing::Steel),
+            Item::PixiePlate => Some(Typing::Fairy),
+            _ => None
+        }
+    }
+    /// Returns the type of a Silvally memory, or None if the item isn't a memory. Like plates, there's
+    /// no Normal memory.
+    pub fn memory_type(self) -> Option<Typing> {
+        match self {
+            Item::FireMemory => Some(Typing::Fire),
+            Item::WaterMemory => Some(Typing::Water),
+            Item::ElectricMemory => Some(Typing::Electric),
+            Item::GrassMemory => Some(Typing::Grass),
+            Item::IceMemory => Some(Typing::Ice),
+            Item::FightingMemory => Some(Typing::Fighting),
+            Item::PoisonMemory => Some(Typing::Poison),
+            Item::GroundMemory => Some(Typing::Ground),
+            Item::FlyingMemory => Some(Typing::Flying),
+            Item::PsychicMemory => Some(Typing::Psychic),
+            Item::BugMemory => Some(Typing::Bug),
+            Item::RockMemory => Some(Typing::Rock),
+            Item::GhostMemory => Some(Typing::Ghost),
+            Item::DragonMemory => Some(Typing::Dragon),
+            Item::DarkMemory => Some(Typing::Dark),
+            Item::SteelMemory => Some(Typing::Steel),
+            Item::FairyMemory => Some(Typing::Fairy),
+            _ => None
+        }
+    }
+    /// Returns the type of a type-specific Z-Crystal like Firium Z, or None if the item isn't
+    /// one. Species-specific Z-Crystals, like Ultranecrozium Z, have no type in this sense.
+    pub fn z_crystal_type(self) -> Option<Typing> {
+        match self {
+            Item::NormaliumZ => Some(Typing::Normal),
+            Item::FiriumZ => Some(Typing::Fire),
+            Item::WateriumZ => Some(Typing::Water),
+            Item::ElectriumZ => Some(Typing::Electric),
+            Item::GrassiumZ => Some(Typing::Grass),
+            Item::IciumZ => Some(Typing::Ice),
+            Item::FightiniumZ => Some(Typing::Fighting),
+            Item::PoisoniumZ => Some(Typing::Poison),
+            Item::GroundiumZ => Some(Typing::Ground),
+            Item::FlyiniumZ => Some(Typing::Flying),
+            Item::PsychiumZ => Some(Typing::Psychic),
+            Item::BuginiumZ => Some(Typing::Bug),
+            Item::RockiumZ => Some(Typing::Rock),
+            Item::GhostiumZ => Some(Typing::Ghost),
+            Item::DragoniumZ => Some(Typing::Dragon),
+            Item::DarkiniumZ => Some(Typing::Dark),
+            Item::SteeliumZ => Some(Typing::Steel),
+            Item::FairiumZ => Some(Typing::Fairy),
+            _ => None
+        }
+    }
+    /// Returns the type a type-resist berry like the Occa Berry weakens, or None if the item isn't one.
+    pub fn resist_berry_type(self) -> Option<Typing> {
+        match self {
+            Item::OccaBerry => Some(Typing::Fire),
+            Item::PasshoBerry => Some(Typing::Water),
+            Item::WacanBerry => Some(Typing::Electric),
+            Item::RindoBerry => Some(Typing::Grass),
+            Item::YacheBerry => Some(Typing::Ice),
+            Item::ChopleBerry => Some(Typing::Fighting),
+            Item::KebiaBerry => Some(Typing::Poison),
+            Item::ShucaBerry => Some(Typing::Ground),
+            Item::CobaBerry => Some(Typing::Flying),
+            Item::PayapaBerry => Some(Typing::Psychic),
+            Item::TangaBerry => Some(Typing::Bug),
+            Item::ChartiBerry => Some(Typing::Rock),
+            Item::KasibBerry => Some(Typing::Ghost),
+            Item::HabanBerry => Some(Typing::Dragon),
+            Item::ColburBerry => Some(Typing::Dark),
+            Item::BabiriBerry => Some(Typing::Steel),
+            Item::ChilanBerry => Some(Typing::Normal),
+            Item::RoseliBerry => Some(Typing::Fairy),
+            _ => None
+        }
+    }
+    /// Returns the type of a Genesect drive, or None if the item isn't a drive. Drives only change the
+    /// type of Techno Blast: unlike plates and memories, they don't change Genesect's typing.
+    pub fn drive_type(self) -> Option<Typing> {
+        match self {
+            Item::DouseDrive => Some(Typing::Water),
+            Item::ShockDrive => Some(Typing::Electric),
+            Item::BurnDrive => Some(Typing::Fire),
+            Item::ChillDrive => Some(Typing::Ice),
+            _ => None
+        }
+    }
+
+    /// Returns true if the item can be knocked off or otherwise taken from its holder. Z-Crystals can
+    /// never be removed, and neither can the items that determine a species' forme when held by that
+    /// species: Mega Stones, the Arceus plates, the Silvally memories, the Genesect drives, and the
+    /// Red, Blue, and Griseous Orbs. This assumes those items are held by the species they belong to,
+    /// which is what happens in practice: a plate held by anything other than Arceus can be removed.
+    pub fn is_removable(self) -> bool {
+        let z_crystal = self.z_crystal_type().is_some() || self == Item::UltranecroziumZ;
+        let forme_item = self.is_plate() || self.memory_type().is_some() || self.drive_type().is_some() ||
+            self == Item::RedOrb || self == Item::BlueOrb || self == Item::GriseousOrb;
+        !(z_crystal || forme_item || self.is_mega_stone())
+    }
+
+    /// Returns true if the item is one of the Choice items: Choice Band, Choice Specs, or Choice
+    /// Scarf. These lock the holder into the first move it uses.
+    pub fn is_choice_item(self) -> bool {
+        matches!(self, Item::ChoiceBand | Item::ChoiceSpecs | Item::ChoiceScarf)
+    }
+
+    /// Returns true if the item is a Mega Stone, like Charizardite X.
+    pub fn is_mega_stone(self) -> bool {
+        SpeciesDiscriminant::iter().any(|species| mega_forme(species, self).is_some())
+    }
+
+    /// Returns true if the item is one of the Arceus plates.
+    pub fn is_plate(self) -> bool {
+        self.plate_type().is_some()
+    }
+}
+
+/// Returns the type and base power of Natural Gift when the user holds the given item, or None if the
+/// item isn't a berry (in which case Natural Gift fails). Powers are the ones from Gen VI onward.
+pub fn natural_gift(item: Item) -> Option<(Typing, u8)> {
+    match item {
+        Item::CheriBerry => Some((Typing::Fire, 80)),
+        Item::ChestoBerry => Some((Typing::Water, 80)),
+        Item::PechaBerry => Some((Typing::Electric, 80)),
+        Item::RawstBerry => Some((Typing::Grass, 80)),
+        Item::AspearBerry => Some((Typing::Ice, 80)),
+        Item::LeppaBerry => Some((Typing::Fighting, 80)),
+        Item::OranBerry => Some((Typing::Poison, 80)),
+        Item::PersimBerry => Some((Typing::Ground, 80)),
+        Item::LumBerry => Some((Typing::Flying, 80)),
+        Item::SitrusBerry => Some((Typing::Psychic, 80)),
+        Item::FigyBerry => Some((Typing::Bug, 80)),
+        Item::WikiBerry => Some((Typing::Rock, 80)),
+        Item::MagoBerry => Some((Typing::Ghost, 80)),
+        Item::AguavBerry => Some((Typing::Dragon, 80)),
+        Item::IapapaBerry => Some((Typing::Dark, 80)),
+        Item::OccaBerry => Some((Typing::Fire, 80)),
+        Item::PasshoBerry => Some((Typing::Water, 80)),
+        Item::WacanBerry => Some((Typing::Electric, 80)),
+        Item::RindoBerry => Some((Typing::Grass, 80)),
+        Item::YacheBerry => Some((Typing::Ice, 80)),
+        Item::ChopleBerry => Some((Typing::Fighting, 80)),
+        Item::KebiaBerry => Some((Typing::Poison, 80)),
+        Item::ShucaBerry => Some((Typing::Ground, 80)),
+        Item::CobaBerry => Some((Typing::Flying, 80)),
+        Item::PayapaBerry => Some((Typing::Psychic, 80)),
+        Item::TangaBerry => Some((Typing::Bug, 80)),
+        Item::ChartiBerry => Some((Typing::Rock, 80)),
+        Item::KasibBerry => Some((Typing::Ghost, 80)),
+        Item::HabanBerry => Some((Typing::Dragon, 80)),
+        Item::ColburBerry => Some((Typing::Dark, 80)),
+        Item::BabiriBerry => Some((Typing::Steel, 80)),
+        Item::ChilanBerry => Some((Typing::Normal, 80)),
+        Item::RoseliBerry => Some((Typing::Fairy, 80)),
+        Item::LiechiBerry => Some((Typing::Grass, 100)),
+        Item::GanlonBerry => Some((Typing::Ice, 100)),
+        Item::SalacBerry => Some((Typing::Fighting, 100)),
+        Item::PetayaBerry => Some((Typing::Poison, 100)),
+        Item::ApicotBerry => Some((Typing::Ground, 100)),
+        Item::LansatBerry => Some((Typing::Flying, 100)),
+        Item::StarfBerry => Some((Typing::Psychic, 100)),
+        Item::MicleBerry => Some((Typing::Rock, 100)),
+        Item::CustapBerry => Some((Typing::Ghost, 100)),
+        Item::JabocaBerry => Some((Typing::Dragon, 100)),
+        Item::RowapBerry => Some((Typing::Dark, 100)),
+        Item::KeeBerry => Some((Typing::Fairy, 100)),
+        Item::MarangaBerry => Some((Typing::Dark, 100)),
+        _ => None
+    }
+}
+
+/// Returns the HP a Pokemon with the given typing and max HP recovers from its held item at the end of
+/// each turn, with damage given as a negative number. Leftovers heals 1/16 of max HP, and Black Sludge
+/// heals 1/16 for Poison types but hurts anything else for 1/8. Each always changes HP by at least 1,
+/// and other items give 0.
+pub fn passive_heal(item: Item, typing: (Typing, Option<Typing>), max_hp: u16) -> i32 {
+    let poison = typing.0 == Typing::Poison || typing.1 == Some(Typing::Poison);
+    match item {
+        Item::Leftovers => i32::from((max_hp / 16).max(1)),
+        Item::BlackSludge if poison => i32::from((max_hp / 16).max(1)),
+        Item::BlackSludge => -i32::from((max_hp / 8).max(1)),
+        _ => 0,
+    }
+}
+
+/// Returns true if a hit of the given type and effectiveness meets the condition for the given held
+/// item to activate. Weakness Policy activates on any super effective hit. Type-resist berries
+/// activate on a super effective hit of their type, except the Chilan Berry, which activates on any
+/// Normal-type hit. Items that don't react to being hit always give false.
+pub fn triggers_on_hit(item: Item, effectiveness: Multiplier, move_type: Typing) -> bool {
+    match item {
+        Item::WeaknessPolicy => effectiveness > Multiplier::Regular,
+        Item::ChilanBerry => move_type == Typing::Normal && effectiveness != Multiplier::Immunity,
+        _ => match item.resist_berry_type() {
+            Some(berry_type) => berry_type == move_type && effectiveness > Multiplier::Regular,
+            None => false,
+        }
+    }
+}
+
+/// Returns the multiplier a held type-resist berry applies to the damage of a hit of the given type
+/// and effectiveness: 0.5 if the berry activates, and 1 otherwise. The berry is consumed after
+/// activating, so this only applies to the first such hit.
+pub fn berry_resist_multiplier(item: Item, move_type: Typing, effectiveness: Multiplier) -> f64 {
+    if item.resist_berry_type().is_some() && triggers_on_hit(item, effectiveness, move_type) {
+        0.5
+    } else {
+        1.0
+    }
+}
+
+/// Returns the Mega Evolution of the given species if it's holding the matching Mega Stone, like
+/// Charizard-Mega-X for Charizard holding Charizardite X. A Mega Evolution without its stone goes back
+/// to its base forme, and anything else is returned unchanged. Mega Rayquaza doesn't need a stone, so
+/// this can't produce it.
+pub fn mega_evolve(species: Species, item: Option<Item>) -> Species {
+    let discriminant = SpeciesDiscriminant::from(species);
+    match item.and_then(|item| mega_forme(discriminant, item)) {
+        Some(mega) => mega,
+        None if is_mega(species) => Species::from(discriminant),
+        None => species,
+    }
+}
+
+/// Returns the forme a species takes on when holding the given item, or its default forme if the item
+/// doesn't affect it. Arceus changes type with a plate or a type-specific Z-Crystal, Silvally with a
+/// memory, Giratina becomes Origin forme with the Griseous Orb, Kyogre and Groudon undergo Primal
+/// Reversion with the Blue and Red Orb respectively, and holding the matching Mega Stone gives the
+/// Mega Evolution. (Mega Evolution technically only happens once the player chooses to in battle,
+/// but the stone is what decides which Mega Evolution is available.)
+///
+/// Rayquaza Mega Evolves by knowing Dragon Ascent rather than holding anything, and Ultra Necrozma
+/// can only be reached from the fused Necrozma formes in battle, so neither is affected by items
+/// here.
+pub fn forme_from_item(species: SpeciesDiscriminant, item: Option<Item>) -> Species {
+    let item = match item {
+        Some(item) => item,
+        None => return Species::from(species),
+    };
+    match species {
+        SpeciesDiscriminant::Arceus => {
+            let typing = item.plate_type().or(item.z_crystal_type()).unwrap_or(Typing::Normal);
+            Species::Arceus(ArceusForme::from(typing))
+        },
+        SpeciesDiscriminant::Silvally => {
+            Species::Silvally(SilvallyForme::from(item.memory_type().unwrap_or(Typing::Normal)))
+        },
+        SpeciesDiscriminant::Giratina if item == Item::GriseousOrb => {
+            Species::Giratina(GiratinaForme::Origin)
+        },
+        SpeciesDiscriminant::Kyogre if item == Item::BlueOrb => Species::Kyogre(PrimalReversion::Primal),
+        SpeciesDiscriminant::Groudon if item == Item::RedOrb => Species::Groudon(PrimalReversion::Primal),
+        _ => mega_forme(species, item).unwrap_or(Species::from(species)),
+    }
+}
+
+/// Returns the Mega Evolution the given species reaches with the given Mega Stone, or None if the
+/// stone doesn't match or the item isn't a Mega Stone.
+fn mega_forme(species: SpeciesDiscriminant, item: Item) -> Option<Species> {
+    match (species, item) {
+        (SpeciesDiscriminant::Venusaur, Item::Venusaurite) => Some(Species::Venusaur(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Charizard, Item::CharizarditeX) => Some(Species::Charizard(XYMegaEvolution::MegaX)),
+        (SpeciesDiscriminant::Charizard, Item::CharizarditeY) => Some(Species::Charizard(XYMegaEvolution::MegaY)),
+        (SpeciesDiscriminant::Blastoise, Item::Blastoisinite) => Some(Species::Blastoise(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Beedrill, Item::Beedrillite) => Some(Species::Beedrill(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Pidgeot, Item::Pidgeotite) => Some(Species::Pidgeot(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Alakazam, Item::Alakazite) => Some(Species::Alakazam(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Gengar, Item::Gengarite) => Some(Species::Gengar(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Kangaskhan, Item::Kangaskhanite) => Some(Species::Kangaskhan(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Pinsir, Item::Pinsirite) => Some(Species::Pinsir(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Gyarados, Item::Gyaradosite) => Some(Species::Gyarados(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Aerodactyl, Item::Aerodactylite) => Some(Species::Aerodactyl(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Mewtwo, Item::MewtwoniteX) => Some(Species::Mewtwo(XYMegaEvolution::MegaX)),
+        (SpeciesDiscriminant::Mewtwo, Item::MewtwoniteY) => Some(Species::Mewtwo(XYMegaEvolution::MegaY)),
+        (SpeciesDiscriminant::Steelix, Item::Steelixite) => Some(Species::Steelix(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Scizor, Item::Scizorite) => Some(Species::Scizor(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Heracross, Item::Heracronite) => Some(Species::Heracross(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Houndoom, Item::Houndoominite) => Some(Species::Houndoom(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Tyranitar, Item::Tyranitarite) => Some(Species::Tyranitar(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Sceptile, Item::Sceptilite) => Some(Species::Sceptile(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Blaziken, Item::Blazikenite) => Some(Species::Blaziken(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Swampert, Item::Swampertite) => Some(Species::Swampert(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Gardevoir, Item::Gardevoirite) => Some(Species::Gardevoir(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Sableye, Item::Sablenite) => Some(Species::Sableye(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Mawile, Item::Mawilite) => Some(Species::Mawile(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Aggron, Item::Aggronite) => Some(Species::Aggron(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Medicham, Item::Medichamite) => Some(Species::Medicham(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Manectric, Item::Manectite) => Some(Species::Manectric(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Sharpedo, Item::Sharpedonite) => Some(Species::Sharpedo(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Camerupt, Item::Cameruptite) => Some(Species::Camerupt(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Altaria, Item::Altarianite) => Some(Species::Altaria(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Salamence, Item::Salamencite) => Some(Species::Salamence(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Metagross, Item::Metagrossite) => Some(Species::Metagross(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Lopunny, Item::Lopunnite) => Some(Species::Lopunny(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Garchomp, Item::Garchompite) => Some(Species::Garchomp(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Lucario, Item::Lucarionite) => Some(Species::Lucario(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Abomasnow, Item::Abomasite) => Some(Species::Abomasnow(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Gallade, Item::Galladite) => Some(Species::Gallade(MegaEvolution::Mega)),
+        (SpeciesDiscriminant::Diancie, Item::Diancite) => Some(Species::Diancie(MegaEvolution::Mega)),
+        _ => None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_item_names() {
+        assert_eq!(Item::FlamePlate.to_string(), "Flame Plate");
+        assert_eq!(Item::from_str("Charizardite X").unwrap(), Item::CharizarditeX);
+        assert_eq!(Item::from_str("GriseousOrb").unwrap(), Item::GriseousOrb);
+        assert_eq!(Item::from_str(&Item::UltranecroziumZ.to_string()).unwrap(), Item::UltranecroziumZ);
+    }
+    #[test]
+    fn test_arceus_plates() {
+        assert_eq!(forme_from_item(SpeciesDiscriminant::Arceus, Some(Item::FlamePlate)),
+                   Species::Arceus(ArceusForme::Fire));
+        assert_eq!(forme_from_item(SpeciesDiscriminant::Arceus, Some(Item::DreadPlate)),
+                   Species::Arceus(ArceusForme::Dark));
+        assert_eq!(forme_from_item(SpeciesDiscriminant::Arceus, Some(Item::FairiumZ)),
+                   Species::Arceus(ArceusForme::Fairy));
+        assert_eq!(forme_from_item(SpeciesDiscriminant::Arceus, None),
+                   Species::Arceus(ArceusForme::Normal));
+        assert_eq!(forme_from_item(SpeciesDiscriminant::Arceus, Some(Item::FireMemory)),
+                   Species::Arceus(ArceusForme::Normal));
+    }
+    #[test]
+    fn test_silvally_memories() {
+        assert_eq!(forme_from_item(SpeciesDiscriminant::Silvally, Some(Item::SteelMemory)),
+                   Species::Silvally(SilvallyForme::Steel));
+        assert_eq!(forme_from_item(SpeciesDiscriminant::Silvally, Some(Item::IronPlate)),
+                   Species::Silvally(SilvallyForme::Normal));
+    }
+    #[test]
+    fn test_orbs_and_stones() {
+        assert_eq!(forme_from_item(SpeciesDiscriminant::Giratina, Some(Item::GriseousOrb)),
+                   Species::Giratina(GiratinaForme::Origin));
+        assert_eq!(forme_from_item(SpeciesDiscriminant::Groudon, Some(Item::RedOrb)),
+                   Species::Groudon(PrimalReversion::Primal));
+        assert_eq!(forme_from_item(SpeciesDiscriminant::Groudon, Some(Item::BlueOrb)),
+                   Species::Groudon(PrimalReversion::Normal));
+        assert_eq!(forme_from_item(SpeciesDiscriminant::Charizard, Some(Item::CharizarditeY)),
+                   Species::Charizard(XYMegaEvolution::MegaY));
+        assert_eq!(forme_from_item(SpeciesDiscriminant::Venusaur, Some(Item::Gengarite)),
+                   Species::Venusaur(MegaEvolution::Normal));
+        assert_eq!(forme_from_item(SpeciesDiscriminant::Pikachu, Some(Item::FlamePlate)), Species::Pikachu);
+    }
+    #[test]
+    fn test_natural_gift() {
+        assert_eq!(natural_gift(Item::CheriBerry), Some((Typing::Fire, 80)));
+        assert_eq!(natural_gift(Item::OccaBerry), Some((Typing::Fire, 80)));
+        assert_eq!(natural_gift(Item::SitrusBerry), Some((Typing::Psychic, 80)));
+        assert_eq!(natural_gift(Item::LiechiBerry), Some((Typing::Grass, 100)));
+        assert_eq!(natural_gift(Item::Leftovers), None);
+        assert_eq!(Item::from_str("Sitrus Berry").unwrap(), Item::SitrusBerry);
+    }
+    #[test]
+    fn test_triggers_on_hit() {
+        assert!(triggers_on_hit(Item::WeaknessPolicy, Multiplier::Weakness, Typing::Ground));
+        assert!(triggers_on_hit(Item::WeaknessPolicy, Multiplier::DoubleWeakness, Typing::Ice));
+        assert!(!triggers_on_hit(Item::WeaknessPolicy, Multiplier::Regular, Typing::Ground));
+        assert!(triggers_on_hit(Item::OccaBerry, Multiplier::Weakness, Typing::Fire));
+        assert!(!triggers_on_hit(Item::OccaBerry, Multiplier::Weakness, Typing::Water));
+        assert!(triggers_on_hit(Item::ChilanBerry, Multiplier::Regular, Typing::Normal));
+        assert!(!triggers_on_hit(Item::Leftovers, Multiplier::DoubleWeakness, Typing::Fire));
+    }
+    #[test]
+    fn test_berry_resist_multiplier() {
+        assert_eq!(berry_resist_multiplier(Item::OccaBerry, Typing::Fire, Multiplier::DoubleWeakness), 0.5);
+        assert_eq!(berry_resist_multiplier(Item::OccaBerry, Typing::Fire, Multiplier::Resistance), 1.0);
+        assert_eq!(berry_resist_multiplier(Item::WeaknessPolicy, Typing::Fire, Multiplier::Weakness), 1.0);
+    }
+    #[test]
+    fn test_mega_evolve() {
+        let charizard = Species::Charizard(XYMegaEvolution::Normal);
+        assert_eq!(mega_evolve(charizard, Some(Item::CharizarditeX)), Species::Charizard(XYMegaEvolution::MegaX));
+        assert_eq!(mega_evolve(charizard, Some(Item::CharizarditeY)), Species::Charizard(XYMegaEvolution::MegaY));
+        assert_eq!(mega_evolve(charizard, Some(Item::Leftovers)), charizard);
+        assert_eq!(mega_evolve(charizard, Some(Item::Venusaurite)), charizard);
+        assert_eq!(mega_evolve(Species::Charizard(XYMegaEvolution::MegaX), None), charizard);
+        assert_eq!(mega_evolve(Species::Landorus(GenieForme::Therian), None), Species::Landorus(GenieForme::Therian));
+    }
+    #[test]
+    fn test_passive_heal() {
+        assert_eq!(passive_heal(Item::Leftovers, (Typing::Water, None), 464), 29);
+        // Toxapex
+        assert_eq!(passive_heal(Item::BlackSludge, (Typing::Poison, Some(Typing::Water)), 304), 19);
+        assert_eq!(passive_heal(Item::BlackSludge, (Typing::Water, None), 464), -58);
+        assert_eq!(passive_heal(Item::ChoiceScarf, (Typing::Water, None), 464), 0);
+    }
+    #[test]
+    fn test_classifiers() {
+        assert!(Item::ChoiceScarf.is_choice_item());
+        assert!(!Item::AssaultVest.is_choice_item());
+        assert!(Item::CharizarditeY.is_mega_stone());
+        assert!(Item::Diancite.is_mega_stone());
+        assert!(!Item::RedOrb.is_mega_stone());
+        assert!(Item::PixiePlate.is_plate());
+        assert!(!Item::FairyMemory.is_plate());
+        assert!(!Item::FairiumZ.is_plate());
+    }
+}
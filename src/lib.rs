@@ -3,6 +3,9 @@
 extern crate strum;
 #[macro_use]
 extern crate strum_macros;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
 
 pub mod stat;
 pub mod typing;
@@ -11,6 +14,27 @@ pub mod species;
 pub mod terrain;
 pub mod weather;
 pub mod stat_stage;
+pub mod base_stats;
+pub mod ability;
+pub mod item;
+pub mod r#move;
+pub mod damage;
+pub mod status;
+pub mod set;
+pub mod showdown;
+pub mod speed;
+pub mod hidden_power;
+pub mod grounded;
+pub mod hazards;
+pub mod battle;
+pub mod weight;
+pub mod power;
+pub mod species_typing;
+pub mod gender;
+pub mod evolution;
+pub mod matchup;
+pub mod forme_change;
+pub mod field_effect;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,126 @@
+//! This file handles the ways abilities change type matchups: the abilities that make a Pokemon
+//! immune to a type, like Levitate or Water Absorb, and Shedinja's Wonder Guard. The type chart
+//! itself lives in `typing`; this layers the ability on top of what the chart says. It also works
+//! out STAB, which Adaptability boosts and Protean guarantees.
+
+use crate::ability::Ability;
+use crate::typing::{Multiplier, Typing};
+
+/// Returns true if a Pokemon with the given ability takes no damage from attacks of the given type.
+/// This covers Levitate, the absorbing abilities (Water Absorb, Volt Absorb, Dry Skin, Sap Sipper),
+/// the redirecting abilities (Lightning Rod, Storm Drain), Motor Drive, and Flash Fire. Whatever
+/// extra effect the ability has on top of the immunity, like healing or a stat boost, is ignored.
+pub fn ability_type_immunity(ability: Ability, move_type: Typing) -> bool {
+    matches!((ability, move_type),
+             (Ability::Levitate, Typing::Ground) |
+             (Ability::FlashFire, Typing::Fire) |
+             (Ability::WaterAbsorb, Typing::Water) |
+             (Ability::StormDrain, Typing::Water) |
+             (Ability::DrySkin, Typing::Water) |
+             (Ability::VoltAbsorb, Typing::Electric) |
+             (Ability::LightningRod, Typing::Electric) |
+             (Ability::MotorDrive, Typing::Electric) |
+             (Ability::SapSipper, Typing::Grass))
+}
+
+/// Returns the multiplier an attack of the given type actually has against a Pokemon with the given
+/// ability, where `base` is what the type chart says. Abilities that grant an immunity turn the hit
+/// into `Immunity`, Wonder Guard filters out anything that isn't super effective, and every other
+/// ability leaves `base` alone.
+pub fn ability_effectiveness_override(ability: Ability, move_type: Typing, base: Multiplier) -> Multiplier {
+    if ability_type_immunity(ability, move_type) {
+        Multiplier::Immunity
+    } else if ability == Ability::WonderGuard {
+        wonder_guard_multiplier(base)
+    } else {
+        base
+    }
+}
+
+/// Applies Wonder Guard to a type-chart multiplier: super-effective hits go through unchanged, and
+/// everything else becomes `Immunity`. Shedinja only has 1 HP, so this is the difference between
+/// taking a hit and fainting from it.
+pub fn wonder_guard_multiplier(base: Multiplier) -> Multiplier {
+    match base {
+        Multiplier::Weakness | Multiplier::DoubleWeakness => base,
+        _ => Multiplier::Immunity,
+    }
+}
+
+/// Returns the same-type attack bonus for a move of the given type used by a Pokemon with the given
+/// typing and ability: 1.5x if the move shares one of the user's types, 2x instead if the user also
+/// has Adaptability, and 1x if the types don't match. The result is meant for `DamageModifiers::stab`.
+pub fn stab_multiplier(move_type: Typing, user_types: (Typing, Option<Typing>), ability: Ability) -> f64 {
+    let (primary, secondary) = user_types;
+    if move_type != primary && secondary != Some(move_type) {
+        1.0
+    } else if ability == Ability::Adaptability {
+        2.0
+    } else {
+        1.5
+    }
+}
+
+/// Returns the typing a Pokemon has when its move actually hits, which is what STAB should be
+/// worked out from. Protean changes the user to the pure type of the move it's using before
+/// attacking, so it always gets STAB; every other ability leaves `base` as it is. Libero, which does
+/// the same thing, is a Generation VIII ability and so isn't an `Ability` yet.
+pub fn effective_user_types(base: (Typing, Option<Typing>), move_type: Typing, ability: Ability) -> (Typing, Option<Typing>) {
+    if ability == Ability::Protean {
+        (move_type, None)
+    } else {
+        base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn test_ability_type_immunity() {
+        assert!(ability_type_immunity(Ability::Levitate, Typing::Ground));
+        assert!(ability_type_immunity(Ability::FlashFire, Typing::Fire));
+        assert!(!ability_type_immunity(Ability::FlashFire, Typing::Water));
+        assert!(!ability_type_immunity(Ability::Intimidate, Typing::Ground));
+    }
+
+    #[test]
+    fn test_ability_effectiveness_override() {
+        assert_eq!(ability_effectiveness_override(Ability::Levitate, Typing::Ground, Multiplier::Weakness),
+                   Multiplier::Immunity);
+        assert_eq!(ability_effectiveness_override(Ability::FlashFire, Typing::Fire, Multiplier::Regular),
+                   Multiplier::Immunity);
+        assert_eq!(ability_effectiveness_override(Ability::Levitate, Typing::Rock, Multiplier::Weakness),
+                   Multiplier::Weakness);
+    }
+
+    #[test]
+    fn test_wonder_guard_multiplier() {
+        assert_eq!(wonder_guard_multiplier(Multiplier::Regular), Multiplier::Immunity);
+        assert_eq!(wonder_guard_multiplier(Multiplier::Resistance), Multiplier::Immunity);
+        assert_eq!(wonder_guard_multiplier(Multiplier::Weakness), Multiplier::Weakness);
+        assert_eq!(wonder_guard_multiplier(Multiplier::DoubleWeakness), Multiplier::DoubleWeakness);
+        assert_eq!(ability_effectiveness_override(Ability::WonderGuard, Typing::Normal, Multiplier::Regular),
+                   Multiplier::Immunity);
+    }
+
+    #[test]
+    fn test_stab_multiplier() {
+        let porygon_z = (Typing::Normal, None);
+        assert_eq!(stab_multiplier(Typing::Normal, porygon_z, Ability::Adaptability), 2.0);
+        assert_eq!(stab_multiplier(Typing::Normal, porygon_z, Ability::Download), 1.5);
+        assert_eq!(stab_multiplier(Typing::Ice, porygon_z, Ability::Adaptability), 1.0);
+        assert_eq!(stab_multiplier(Typing::Ground, (Typing::Dragon, Some(Typing::Ground)), Ability::RoughSkin), 1.5);
+    }
+
+    #[test]
+    fn test_effective_user_types() {
+        let greninja = (Typing::Water, Some(Typing::Dark));
+        assert_eq!(effective_user_types(greninja, Typing::Ice, Ability::Protean), (Typing::Ice, None));
+        assert_eq!(stab_multiplier(Typing::Ice, effective_user_types(greninja, Typing::Ice, Ability::Protean),
+                                   Ability::Protean), 1.5);
+        assert_eq!(effective_user_types(greninja, Typing::Ice, Ability::Torrent), greninja);
+    }
+}
@@ -4,3 +4,1050 @@
 //! A Pokemon move, at its most basic, has a name, base Power Point, and some effect when used in the
 //! context of a `Battle`. For most moves, this is either dealing damage, boosting stats, inflicting
 //! status, or changing the environment.
+//!
+//! This file also keeps track of how many PP a Pokemon's moves have left, with `MoveSlot`.
+//!
+//! Because `Move` is a trait, a `Box<Move>` can't be serialized. `MoveKind` wraps each of the move
+//! structs here in an enum that can be, and that implements `Move` itself by passing the calls on.
+
+use std::cmp::Ordering;
+use std::error;
+use std::fmt;
+
+use crate::ability::Ability;
+use crate::item::Item;
+use crate::stat::Stat;
+use crate::stat_stage::StatStage;
+use crate::typing::{defense_multiplier_for, Multiplier, Typing};
+
+/// The damage category of a move. Since Gen IV, this is a property of each move rather than of its
+/// type: Physical moves use Attack and Defense, Special moves use Special Attack and Special Defense,
+/// and Status moves don't deal direct damage at all.
+#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString, Serialize, Deserialize)]
+pub enum MoveCategory {
+    Physical,
+    Special,
+    Status,
+}
+
+impl MoveCategory {
+    /// Returns the stat of the user that determines how much damage a move of this category does:
+    /// Attack for Physical moves and Special Attack for Special moves. Status moves don't deal
+    /// damage, so they give None.
+    pub fn attacking_stat(self) -> Option<Stat> {
+        match self {
+            MoveCategory::Physical => Some(Stat::Atk),
+            MoveCategory::Special => Some(Stat::SpA),
+            MoveCategory::Status => None,
+        }
+    }
+    /// Returns the stat of the target that reduces the damage a move of this category does: Defense
+    /// for Physical moves and Special Defense for Special moves. Status moves give None. (Psyshock and
+    /// friends break this rule, but they're the exception.)
+    pub fn defending_stat(self) -> Option<Stat> {
+        match self {
+            MoveCategory::Physical => Some(Stat::Def),
+            MoveCategory::Special => Some(Stat::SpD),
+            MoveCategory::Status => None,
+        }
+    }
+}
+
+/// The groups a move can belong to that abilities care about, like punching moves for Iron Fist.
+/// `MoveFlags::default()` gives a move in none of them.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MoveFlags {
+    /// Punching moves, like Mach Punch, boosted by Iron Fist.
+    pub punching: bool,
+    /// Biting moves, like Crunch, boosted by Strong Jaw.
+    pub biting: bool,
+    /// Pulse and aura moves, like Dark Pulse and Aura Sphere, boosted by Mega Launcher.
+    pub pulse: bool,
+    /// Sound-based moves, like Boomburst or Growl.
+    pub sound: bool,
+    /// Ball and bomb moves, like Shadow Ball or Sludge Bomb.
+    pub bullet: bool,
+    /// Moves that make contact with the target, like U-turn, which set off Rough Skin and Rocky
+    /// Helmet.
+    pub contact: bool,
+    /// Spread moves, which hit every adjacent foe (like Rock Slide) or every adjacent Pokemon (like
+    /// Earthquake) in doubles, and so do less damage when they hit more than one.
+    pub spread: bool,
+}
+
+/// Returns the multiplier the given ability applies to the base power of a move with the given
+/// flags: 1.2x for punching moves with Iron Fist, and 1.5x for biting moves with Strong Jaw and pulse
+/// moves with Mega Launcher. Any other combination gives 1.
+pub fn ability_flag_multiplier(ability: Ability, flags: MoveFlags) -> f64 {
+    match ability {
+        Ability::IronFist if flags.punching => 1.2,
+        Ability::StrongJaw if flags.biting => 1.5,
+        Ability::MegaLauncher if flags.pulse => 1.5,
+        _ => 1.0,
+    }
+}
+
+/// Returns true if a Pokemon with the given ability is immune to moves with the given flags:
+/// Soundproof blocks sound-based moves, and Bulletproof blocks ball and bomb moves. A damage
+/// calculation can skip straight to 0 when this is true.
+pub fn ability_flag_immunity(ability: Ability, flags: MoveFlags) -> bool {
+    match ability {
+        Ability::Soundproof => flags.sound,
+        Ability::Bulletproof => flags.bullet,
+        _ => false,
+    }
+}
+
+/// Returns the damage an attacker with the given max HP takes from hitting a target with the given
+/// ability and item using a contact move: 1/8 of its max HP from Rough Skin or Iron Barbs, and 1/6
+/// from Rocky Helmet, each at least 1 and added together if both apply. This should only be used for
+/// moves with the `contact` flag, and an attacker holding Protective Pads takes none of it.
+pub fn contact_recoil(attacker_max_hp: u16, defender_ability: Ability,
+                      defender_item: Option<Item>) -> u16 {
+    let ability_recoil = match defender_ability {
+        Ability::RoughSkin | Ability::IronBarbs => (attacker_max_hp / 8).max(1),
+        _ => 0,
+    };
+    let item_recoil = match defender_item {
+        Some(Item::RockyHelmet) => (attacker_max_hp / 6).max(1),
+        _ => 0,
+    };
+    ability_recoil + item_recoil
+}
+
+/// A Pokemon move. This describes the properties every move has, regardless of what it does when
+/// used.
+pub trait Move {
+    /// The in-game name of the move, like "Earthquake".
+    fn name(&self) -> &str;
+    /// The base power of the move. Moves that don't deal damage, or whose damage isn't determined by
+    /// base power, have a base power of 0.
+    fn base_power(&self) -> u8;
+    /// The type of the move.
+    fn move_type(&self) -> Typing;
+    /// The damage category of the move: Physical, Special, or Status.
+    fn category(&self) -> MoveCategory;
+    /// The accuracy of the move as a percentage, or None if the move never misses (like Aerial Ace or
+    /// Swift). Note that this is different from a move with 100% accuracy, which can still miss due
+    /// to evasion boosts.
+    fn accuracy(&self) -> Option<u8>;
+    /// The base number of Power Points the move has, before any PP Ups are applied.
+    fn pp(&self) -> u8;
+    /// The priority bracket of the move. Moves with higher priority always go before moves with lower
+    /// priority, regardless of Speed: Quick Attack has +1, Protect has +4, and Trick Room has -7. Most
+    /// moves have priority 0.
+    fn priority(&self) -> i8 {
+        0
+    }
+    /// The groups the move belongs to, like punching or sound-based moves. Most moves are in none of
+    /// them.
+    fn flags(&self) -> MoveFlags {
+        MoveFlags::default()
+    }
+    /// The fraction of the damage dealt that the user takes as recoil, as a numerator and denominator,
+    /// or None if the move has no recoil. Flare Blitz, for example, has 1/3 recoil.
+    fn recoil_fraction(&self) -> Option<(u8, u8)> {
+        None
+    }
+    /// The fraction of the damage dealt that the user recovers, as a numerator and denominator, or None
+    /// if the move doesn't drain HP. Giga Drain, for example, drains 1/2.
+    fn drain_fraction(&self) -> Option<(u8, u8)> {
+        None
+    }
+    /// Returns true if the move hits more than one target in doubles. This is the same as the `spread`
+    /// flag.
+    fn is_spread(&self) -> bool {
+        self.flags().spread
+    }
+}
+
+/// Multiplies a damage value by a fraction, rounding to the nearest integer with halves going up, but
+/// giving at least 1 if there was any damage.
+fn fraction_of(damage: u16, (numerator, denominator): (u8, u8)) -> u16 {
+    if damage == 0 || denominator == 0 {
+        return 0;
+    }
+    let numer = 2 * u32::from(damage) * u32::from(numerator) + u32::from(denominator);
+    (numer / (2 * u32::from(denominator))).max(1) as u16
+}
+
+/// Returns the recoil the user of a move takes after dealing the given damage, where `frac` is the
+/// move's `recoil_fraction`. The result is rounded to the nearest integer, but is at least 1.
+pub fn recoil_damage(damage_dealt: u16, frac: (u8, u8)) -> u16 {
+    fraction_of(damage_dealt, frac)
+}
+
+/// Returns the HP the user of a draining move recovers after dealing the given damage, where `frac`
+/// is the move's `drain_fraction`. Like recoil, this is rounded, but is at least 1. Big Root and
+/// Liquid Ooze aren't accounted for.
+pub fn drain_heal(damage_dealt: u16, frac: (u8, u8)) -> u16 {
+    fraction_of(damage_dealt, frac)
+}
+
+/// Compares two actions, given as (priority, Speed) pairs, to determine which goes first. Higher
+/// priority always goes first, and within the same priority the faster Pokemon goes first. The result
+/// is `Less` if `a` goes first and `Greater` if `b` goes first, so sorting a list of actions with this
+/// puts them in turn order. Speed ties give `Equal`: in-game they're decided randomly. This doesn't
+/// account for Trick Room: use `resolve_order_trick_room` or `TurnOrderMode` for that.
+pub fn resolve_order(a: (i8, u16), b: (i8, u16)) -> Ordering {
+    b.cmp(&a)
+}
+
+/// Compares two actions like `resolve_order`, but under Trick Room: higher priority still always goes
+/// first, but within the same priority the slower Pokemon goes first.
+pub fn resolve_order_trick_room(a: (i8, u16), b: (i8, u16)) -> Ordering {
+    b.0.cmp(&a.0).then(a.1.cmp(&b.1))
+}
+
+/// Whether turn order is being decided normally or under Trick Room, which reverses Speed for the
+/// five turns it lasts.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TurnOrderMode {
+    Normal,
+    TrickRoom,
+}
+
+impl TurnOrderMode {
+    /// Compares two actions, given as (priority, Speed) pairs, using `resolve_order` or
+    /// `resolve_order_trick_room` depending on the mode.
+    pub fn resolve_order(self, a: (i8, u16), b: (i8, u16)) -> Ordering {
+        match self {
+            TurnOrderMode::Normal => resolve_order(a, b),
+            TurnOrderMode::TrickRoom => resolve_order_trick_room(a, b),
+        }
+    }
+}
+
+/// Returns the chance a move with the given accuracy hits, from 0 to 1. Moves that never miss, with
+/// an accuracy of None, always give 1. Otherwise, the user's accuracy stage and the target's evasion
+/// stage are combined into one stage, capped at -6 and +6, and its accuracy multiplier is applied to
+/// the move's accuracy. A 100% accurate move used at +1 accuracy can't go over 1, of course.
+pub fn hit_chance(move_accuracy: Option<u8>, accuracy_stage: StatStage, evasion_stage: StatStage) -> f64 {
+    match move_accuracy {
+        None => 1.0,
+        Some(accuracy) => {
+            let stage = accuracy_stage + -evasion_stage;
+            let chance = f64::from(accuracy) / 100.0 * stage.accuracy_multiplier();
+            chance.clamp(0.0, 1.0)
+        },
+    }
+}
+
+/// A move whose only effect is dealing damage, like Earthquake or Thunderbolt. (Thunderbolt's 10%
+/// paralysis chance is not modeled here.)
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DamageMove {
+    pub name: String,
+    pub base_power: u8,
+    pub move_type: Typing,
+    pub category: MoveCategory,
+    pub accuracy: Option<u8>,
+    pub pp: u8,
+    pub priority: i8,
+    pub flags: MoveFlags,
+    /// The fraction of the damage dealt taken as recoil, if any.
+    pub recoil: Option<(u8, u8)>,
+    /// The fraction of the damage dealt drained back as HP, if any.
+    pub drain: Option<(u8, u8)>,
+}
+
+impl DamageMove {
+    /// Creates a new damaging move with the given properties, normal priority, no flags, and no recoil
+    /// or draining. Priority moves like Quick Attack can set the `priority` field afterwards, punching
+    /// moves and the like can set `flags`, and moves like Flare Blitz can set `recoil` or `drain`.
+    pub fn new(name: &str, base_power: u8, move_type: Typing, category: MoveCategory,
+               accuracy: Option<u8>, pp: u8) -> DamageMove {
+        DamageMove {
+            name: name.to_string(),
+            base_power,
+            move_type,
+            category,
+            accuracy,
+            pp,
+            priority: 0,
+            flags: MoveFlags::default(),
+            recoil: None,
+            drain: None,
+        }
+    }
+}
+
+impl Move for DamageMove {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    fn base_power(&self) -> u8 {
+        self.base_power
+    }
+    fn move_type(&self) -> Typing {
+        self.move_type
+    }
+    fn category(&self) -> MoveCategory {
+        self.category
+    }
+    fn accuracy(&self) -> Option<u8> {
+        self.accuracy
+    }
+    fn pp(&self) -> u8 {
+        self.pp
+    }
+    fn priority(&self) -> i8 {
+        self.priority
+    }
+    fn flags(&self) -> MoveFlags {
+        self.flags
+    }
+    fn recoil_fraction(&self) -> Option<(u8, u8)> {
+        self.recoil
+    }
+    fn drain_fraction(&self) -> Option<(u8, u8)> {
+        self.drain
+    }
+}
+
+/// Returns the accuracy of a one-hit KO move like Fissure or Sheer Cold: 30%, plus 1% for each level
+/// the user has over the target, up to 100%. If the target is a higher level than the user, the move
+/// always fails, which gives None. Since Gen VII, Sheer Cold only starts at 20% if the user isn't Ice
+/// type (and Ice-type targets are immune to it). Accuracy and evasion stages don't affect these moves.
+pub fn ohko_accuracy(user_level: u8, target_level: u8, move_type: Typing,
+                     user_types: (Typing, Option<Typing>)) -> Option<u8> {
+    if target_level > user_level {
+        return None;
+    }
+    let ice_user = user_types.0 == Typing::Ice || user_types.1 == Some(Typing::Ice);
+    let base = if move_type == Typing::Ice && !ice_user { 20 } else { 30 };
+    Some((base + u16::from(user_level - target_level)).min(100) as u8)
+}
+
+/// Returns the multiplier Gravity applies to the accuracy of every move while it's in effect: 5/3,
+/// so a 60% accurate move like Hypnosis hits every time. This is the raw multiplier, so multiplying
+/// it into a chance can go over 1: use `hit_chance_under_gravity` to get a capped chance.
+pub fn gravity_accuracy_multiplier() -> f64 {
+    5.0 / 3.0
+}
+
+/// Returns the chance a move hits while Gravity is in effect: the same as `hit_chance`, but with
+/// Gravity's accuracy boost applied and the result capped at 1.
+pub fn hit_chance_under_gravity(move_accuracy: Option<u8>, accuracy_stage: StatStage,
+                                evasion_stage: StatStage) -> f64 {
+    let chance = hit_chance(move_accuracy, accuracy_stage, evasion_stage) * gravity_accuracy_multiplier();
+    chance.min(1.0)
+}
+
+/// An error for trying to use a move that has no PP left.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NoPpError {
+}
+
+impl fmt::Display for NoPpError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "no PP left")
+    }
+}
+
+impl error::Error for NoPpError {
+    fn description(&self) -> &str {
+        "the move has no PP left, so it can't be used"
+    }
+
+    fn cause(&self) -> Option<&error::Error> {
+        // Generic error, underlying cause isn't tracked.
+        None
+    }
+}
+
+/// One of a Pokemon's four move slots in battle: the move itself, and how many PP it has left out of
+/// its maximum.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MoveSlot {
+    pub move_: DamageMove,
+    pub current_pp: u8,
+    pub max_pp: u8,
+}
+
+impl MoveSlot {
+    /// Creates a move slot with full PP, without any PP Ups: the maximum is the move's base PP.
+    pub fn new(move_: DamageMove) -> MoveSlot {
+        let pp = move_.pp;
+        MoveSlot{move_, current_pp: pp, max_pp: pp}
+    }
+
+    /// Uses the move once, deducting 1 PP, or 2 PP if an opponent has Pressure. A move with only 1 PP
+    /// left can still be used against Pressure, and just goes down to 0. Using a move with no PP left
+    /// is an error, and leaves the slot as it was.
+    pub fn use_move(&mut self, pressure: bool) -> Result<(), NoPpError> {
+        if self.current_pp == 0 {
+            return Err(NoPpError{});
+        }
+        let cost = if pressure { 2 } else { 1 };
+        self.current_pp = self.current_pp.saturating_sub(cost);
+        Ok(())
+    }
+
+    /// Restores the given amount of PP, as with a Leppa Berry or Ether, without going over the
+    /// maximum.
+    pub fn restore(&mut self, amount: u8) {
+        self.current_pp = self.current_pp.saturating_add(amount).min(self.max_pp);
+    }
+}
+
+/// The number of times a multi-hit move strikes. Most multi-hit moves hit between 2 and 5 times, but
+/// some always hit a fixed number of times, like Double Kick (2) or Water Shuriken used by
+/// Greninja-Ash (3).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum HitCount {
+    Fixed(u8),
+    /// An inclusive range of hit counts, like 2 to 5.
+    Range(u8, u8),
+}
+
+impl HitCount {
+    /// Returns each possible number of hits along with its probability. The standard 2-5 range uses
+    /// the distribution the games use since Gen V: 2 and 3 hits have a 37.5% chance each, and 4 and 5
+    /// hits have a 12.5% chance each. Any other range is treated as uniform, and a range whose minimum
+    /// is above its maximum has no possible hit counts, so it gives an empty `Vec`.
+    pub fn distribution(self) -> Vec<(u8, f64)> {
+        match self {
+            HitCount::Fixed(hits) => vec![(hits, 1.0)],
+            HitCount::Range(2, 5) => vec![(2, 0.375), (3, 0.375), (4, 0.125), (5, 0.125)],
+            HitCount::Range(min, max) if min > max => vec![],
+            HitCount::Range(min, max) => {
+                let chance = 1.0 / (f64::from(max) - f64::from(min) + 1.0);
+                (min..=max).map(|hits| (hits, chance)).collect()
+            },
+        }
+    }
+}
+
+/// A move that deals damage by hitting multiple times in a row, like Bullet Seed or Water
+/// Shuriken. The base power is per hit.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MultiHitMove {
+    pub name: String,
+    pub base_power: u8,
+    pub move_type: Typing,
+    pub category: MoveCategory,
+    pub accuracy: Option<u8>,
+    pub pp: u8,
+    pub priority: i8,
+    pub flags: MoveFlags,
+    pub hits: HitCount,
+}
+
+impl MultiHitMove {
+    /// Creates a new multi-hit move with the given properties, normal priority, and no flags.
+    pub fn new(name: &str, base_power: u8, move_type: Typing, category: MoveCategory,
+               accuracy: Option<u8>, pp: u8, hits: HitCount) -> MultiHitMove {
+        MultiHitMove {
+            name: name.to_string(),
+            base_power,
+            move_type,
+            category,
+            accuracy,
+            pp,
+            priority: 0,
+            flags: MoveFlags::default(),
+            hits,
+        }
+    }
+    /// Returns how many times the move hits.
+    pub fn hit_count(&self) -> HitCount {
+        self.hits
+    }
+}
+
+impl Move for MultiHitMove {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    fn base_power(&self) -> u8 {
+        self.base_power
+    }
+    fn move_type(&self) -> Typing {
+        self.move_type
+    }
+    fn category(&self) -> MoveCategory {
+        self.category
+    }
+    fn accuracy(&self) -> Option<u8> {
+        self.accuracy
+    }
+    fn pp(&self) -> u8 {
+        self.pp
+    }
+    fn priority(&self) -> i8 {
+        self.priority
+    }
+    fn flags(&self) -> MoveFlags {
+        self.flags
+    }
+}
+
+/// How much damage a fixed-damage move does.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FixedDamage {
+    /// Damage equal to the user's level, like Seismic Toss and Night Shade.
+    LevelBased,
+    /// The same damage every time, like Dragon Rage (40) and Sonic Boom (20).
+    Constant(u16),
+}
+
+/// A move that always does the same damage, regardless of stats or type effectiveness, like Seismic
+/// Toss or Dragon Rage. Type immunities still apply: Night Shade does nothing to Normal types. These
+/// moves have no base power.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FixedDamageMove {
+    pub name: String,
+    pub move_type: Typing,
+    pub category: MoveCategory,
+    pub accuracy: Option<u8>,
+    pub pp: u8,
+    pub priority: i8,
+    pub flags: MoveFlags,
+    pub damage: FixedDamage,
+}
+
+impl FixedDamageMove {
+    /// Creates a new fixed-damage move with the given properties, normal priority, and no flags.
+    pub fn new(name: &str, move_type: Typing, category: MoveCategory, accuracy: Option<u8>, pp: u8,
+               damage: FixedDamage) -> FixedDamageMove {
+        FixedDamageMove {
+            name: name.to_string(),
+            move_type,
+            category,
+            accuracy,
+            pp,
+            priority: 0,
+            flags: MoveFlags::default(),
+            damage,
+        }
+    }
+    /// Returns the damage the move does when used by a Pokemon of the given level, ignoring the
+    /// target.
+    pub fn fixed_damage(&self, user_level: u8) -> u16 {
+        match self.damage {
+            FixedDamage::LevelBased => u16::from(user_level),
+            FixedDamage::Constant(damage) => damage,
+        }
+    }
+    /// Returns the damage the move does to a target with the given typing: the fixed damage, or 0 if
+    /// the target is immune to the move's type.
+    pub fn damage_against(&self, user_level: u8, defending: (Typing, Option<Typing>)) -> u16 {
+        if defense_multiplier_for(self.move_type, defending) == Multiplier::Immunity {
+            0
+        } else {
+            self.fixed_damage(user_level)
+        }
+    }
+}
+
+impl Move for FixedDamageMove {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    fn base_power(&self) -> u8 {
+        0
+    }
+    fn move_type(&self) -> Typing {
+        self.move_type
+    }
+    fn category(&self) -> MoveCategory {
+        self.category
+    }
+    fn accuracy(&self) -> Option<u8> {
+        self.accuracy
+    }
+    fn pp(&self) -> u8 {
+        self.pp
+    }
+    fn priority(&self) -> i8 {
+        self.priority
+    }
+    fn flags(&self) -> MoveFlags {
+        self.flags
+    }
+}
+
+/// Who a stat-changing move affects.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum StatChangeTarget {
+    /// The user of the move, as with Swords Dance.
+    User,
+    /// The opponent, as with Growl.
+    Opponent,
+}
+
+/// A Status move whose only effect is changing stat stages, like Swords Dance (+2 Atk) or Shell Smash
+/// (+2 Atk, SpA, and Spe, -1 Def and SpD).
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct StatChangeMove {
+    pub name: String,
+    pub move_type: Typing,
+    pub accuracy: Option<u8>,
+    pub pp: u8,
+    pub priority: i8,
+    pub flags: MoveFlags,
+    /// Each stat changed, with the number of stages it changes by.
+    pub changes: Vec<(Stat, StatStage)>,
+    pub target: StatChangeTarget,
+}
+
+impl StatChangeMove {
+    /// Creates a new stat-changing move with the given properties, normal priority, and no flags.
+    pub fn new(name: &str, move_type: Typing, accuracy: Option<u8>, pp: u8,
+               changes: Vec<(Stat, StatStage)>, target: StatChangeTarget) -> StatChangeMove {
+        StatChangeMove {
+            name: name.to_string(),
+            move_type,
+            accuracy,
+            pp,
+            priority: 0,
+            flags: MoveFlags::default(),
+            changes,
+            target,
+        }
+    }
+    /// Applies this move's stat changes to the given stat stages, which are indexed in the same order
+    /// as `Stat`: HP, Atk, Def, SpA, SpD, Spe. As with any stat stage addition, the results are capped
+    /// at -6 and +6.
+    pub fn apply_to(&self, current: &mut [StatStage; 6]) {
+        for &(stat, change) in &self.changes {
+            let i = stat as usize;
+            current[i] = current[i] + change;
+        }
+    }
+}
+
+impl Move for StatChangeMove {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    fn base_power(&self) -> u8 {
+        0
+    }
+    fn move_type(&self) -> Typing {
+        self.move_type
+    }
+    fn category(&self) -> MoveCategory {
+        MoveCategory::Status
+    }
+    fn accuracy(&self) -> Option<u8> {
+        self.accuracy
+    }
+    fn pp(&self) -> u8 {
+        self.pp
+    }
+    fn priority(&self) -> i8 {
+        self.priority
+    }
+    fn flags(&self) -> MoveFlags {
+        self.flags
+    }
+}
+
+/// A one-hit KO move, like Fissure or Sheer Cold, which either faints the target or misses. These
+/// moves have no base power, and their accuracy depends on the levels involved: see `ohko_accuracy`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OhkoMove {
+    pub name: String,
+    pub move_type: Typing,
+    pub category: MoveCategory,
+    pub pp: u8,
+    pub priority: i8,
+    pub flags: MoveFlags,
+}
+
+impl OhkoMove {
+    /// Creates a new one-hit KO move with the given properties, normal priority, and no flags.
+    pub fn new(name: &str, move_type: Typing, category: MoveCategory, pp: u8) -> OhkoMove {
+        OhkoMove {
+            name: name.to_string(),
+            move_type,
+            category,
+            pp,
+            priority: 0,
+            flags: MoveFlags::default(),
+        }
+    }
+    /// Returns the accuracy of the move when used by a Pokemon with the given level and typing against
+    /// a Pokemon of the given level, or None if it fails outright.
+    pub fn accuracy_against(&self, user_level: u8, target_level: u8,
+                            user_types: (Typing, Option<Typing>)) -> Option<u8> {
+        ohko_accuracy(user_level, target_level, self.move_type, user_types)
+    }
+}
+
+impl Move for OhkoMove {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    fn base_power(&self) -> u8 {
+        0
+    }
+    fn move_type(&self) -> Typing {
+        self.move_type
+    }
+    fn category(&self) -> MoveCategory {
+        self.category
+    }
+    /// The accuracy between two Pokemon of the same level. Use `accuracy_against` for the real value.
+    fn accuracy(&self) -> Option<u8> {
+        Some(30)
+    }
+    fn pp(&self) -> u8 {
+        self.pp
+    }
+    fn priority(&self) -> i8 {
+        self.priority
+    }
+    fn flags(&self) -> MoveFlags {
+        self.flags
+    }
+}
+
+/// Any of the moves defined in this file. Unlike a `Box<Move>`, this can be serialized, and it
+/// implements `Move` by calling the same method on the move inside.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MoveKind {
+    Damage(DamageMove),
+    StatChange(StatChangeMove),
+    MultiHit(MultiHitMove),
+    FixedDamage(FixedDamageMove),
+    OHKO(OhkoMove),
+}
+
+impl MoveKind {
+    /// Returns the move inside as a `Move` trait object.
+    fn as_move(&self) -> &dyn Move {
+        match self {
+            MoveKind::Damage(m) => m,
+            MoveKind::StatChange(m) => m,
+            MoveKind::MultiHit(m) => m,
+            MoveKind::FixedDamage(m) => m,
+            MoveKind::OHKO(m) => m,
+        }
+    }
+}
+
+impl Move for MoveKind {
+    fn name(&self) -> &str {
+        self.as_move().name()
+    }
+    fn base_power(&self) -> u8 {
+        self.as_move().base_power()
+    }
+    fn move_type(&self) -> Typing {
+        self.as_move().move_type()
+    }
+    fn category(&self) -> MoveCategory {
+        self.as_move().category()
+    }
+    fn accuracy(&self) -> Option<u8> {
+        self.as_move().accuracy()
+    }
+    fn pp(&self) -> u8 {
+        self.as_move().pp()
+    }
+    fn priority(&self) -> i8 {
+        self.as_move().priority()
+    }
+    fn flags(&self) -> MoveFlags {
+        self.as_move().flags()
+    }
+    fn recoil_fraction(&self) -> Option<(u8, u8)> {
+        self.as_move().recoil_fraction()
+    }
+    fn drain_fraction(&self) -> Option<(u8, u8)> {
+        self.as_move().drain_fraction()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn test_category_stats() {
+        assert_eq!(MoveCategory::Physical.attacking_stat(), Some(Stat::Atk));
+        assert_eq!(MoveCategory::Physical.defending_stat(), Some(Stat::Def));
+        assert_eq!(MoveCategory::Special.attacking_stat(), Some(Stat::SpA));
+        assert_eq!(MoveCategory::Special.defending_stat(), Some(Stat::SpD));
+        assert_eq!(MoveCategory::Status.attacking_stat(), None);
+        assert_eq!(MoveCategory::Status.defending_stat(), None);
+    }
+    #[test]
+    fn test_damage_moves() {
+        let earthquake = DamageMove::new("Earthquake", 100, Typing::Ground, MoveCategory::Physical,
+                                         Some(100), 10);
+        assert_eq!(earthquake.name(), "Earthquake");
+        assert_eq!(earthquake.base_power(), 100);
+        assert_eq!(earthquake.move_type(), Typing::Ground);
+        assert_eq!(earthquake.category(), MoveCategory::Physical);
+        assert_eq!(earthquake.accuracy(), Some(100));
+        assert_eq!(earthquake.pp(), 10);
+
+        let thunderbolt = DamageMove::new("Thunderbolt", 90, Typing::Electric, MoveCategory::Special,
+                                          Some(100), 15);
+        assert_eq!(thunderbolt.name(), "Thunderbolt");
+        assert_eq!(thunderbolt.base_power(), 90);
+        assert_eq!(thunderbolt.move_type(), Typing::Electric);
+        assert_eq!(thunderbolt.category(), MoveCategory::Special);
+        assert_eq!(thunderbolt.pp(), 15);
+    }
+    #[test]
+    fn test_never_misses() {
+        let aerial_ace = DamageMove::new("Aerial Ace", 60, Typing::Flying, MoveCategory::Physical,
+                                         None, 20);
+        assert_eq!(aerial_ace.accuracy(), None);
+    }
+    #[test]
+    fn test_priority() {
+        let earthquake = DamageMove::new("Earthquake", 100, Typing::Ground, MoveCategory::Physical,
+                                         Some(100), 10);
+        assert_eq!(earthquake.priority(), 0);
+        let mut quick_attack = DamageMove::new("Quick Attack", 40, Typing::Normal, MoveCategory::Physical,
+                                               Some(100), 30);
+        quick_attack.priority = 1;
+        assert_eq!(quick_attack.priority(), 1);
+    }
+    #[test]
+    fn test_resolve_order() {
+        // +1 priority beats higher speed
+        assert_eq!(resolve_order((1, 50), (0, 400)), Ordering::Less);
+        assert_eq!(resolve_order((0, 400), (1, 50)), Ordering::Greater);
+        // equal priority falls back to speed
+        assert_eq!(resolve_order((0, 300), (0, 200)), Ordering::Less);
+        assert_eq!(resolve_order((0, 200), (0, 300)), Ordering::Greater);
+        assert_eq!(resolve_order((0, 200), (0, 200)), Ordering::Equal);
+
+        let mut actions = vec![(0, 100), (-7, 400), (1, 80), (0, 250)];
+        actions.sort_by(|&a, &b| resolve_order(a, b));
+        assert_eq!(actions, vec![(1, 80), (0, 250), (0, 100), (-7, 400)]);
+    }
+    #[test]
+    fn test_trick_room_order() {
+        // the slower Pokemon goes first at equal priority
+        assert_eq!(resolve_order_trick_room((0, 300), (0, 200)), Ordering::Greater);
+        assert_eq!(resolve_order_trick_room((0, 200), (0, 300)), Ordering::Less);
+        assert_eq!(resolve_order_trick_room((0, 200), (0, 200)), Ordering::Equal);
+        // priority still wins
+        assert_eq!(resolve_order_trick_room((1, 400), (0, 50)), Ordering::Less);
+
+        let mut actions = vec![(0, 100), (-7, 400), (1, 80), (0, 250)];
+        actions.sort_by(|&a, &b| TurnOrderMode::TrickRoom.resolve_order(a, b));
+        assert_eq!(actions, vec![(1, 80), (0, 100), (0, 250), (-7, 400)]);
+        assert_eq!(TurnOrderMode::Normal.resolve_order((0, 300), (0, 200)), Ordering::Less);
+    }
+    #[test]
+    fn test_hit_chance() {
+        assert_eq!(hit_chance(Some(100), StatStage::Z0, StatStage::Z0), 1.0);
+        assert!((hit_chance(Some(70), StatStage::Z0, StatStage::Z0) - 0.7).abs() < 1e-9);
+        assert_eq!(hit_chance(None, StatStage::Z0, StatStage::P6), 1.0);
+        // +2 evasion is a 3/5 multiplier
+        assert!((hit_chance(Some(100), StatStage::Z0, StatStage::P2) - 0.6).abs() < 1e-9);
+        // +1 accuracy cancels out +1 evasion
+        assert!((hit_chance(Some(70), StatStage::P1, StatStage::P1) - 0.7).abs() < 1e-9);
+        assert_eq!(hit_chance(Some(90), StatStage::P2, StatStage::Z0), 1.0);
+    }
+    #[test]
+    fn test_ohko_accuracy() {
+        let lapras = (Typing::Water, Some(Typing::Ice));
+        assert_eq!(ohko_accuracy(100, 100, Typing::Ground, (Typing::Ground, None)), Some(30));
+        assert_eq!(ohko_accuracy(100, 100, Typing::Ice, lapras), Some(30));
+        assert_eq!(ohko_accuracy(100, 100, Typing::Ice, (Typing::Water, None)), Some(20));
+        assert_eq!(ohko_accuracy(100, 50, Typing::Ground, (Typing::Ground, None)), Some(80));
+        assert_eq!(ohko_accuracy(100, 1, Typing::Ground, (Typing::Ground, None)), Some(100));
+        assert_eq!(ohko_accuracy(50, 51, Typing::Ground, (Typing::Ground, None)), None);
+    }
+    #[test]
+    fn test_gravity_accuracy() {
+        let hypnosis = hit_chance(Some(60), StatStage::Z0, StatStage::Z0) * gravity_accuracy_multiplier();
+        assert!((hypnosis - 1.0).abs() < 1e-9);
+        let focus_blast = hit_chance_under_gravity(Some(70), StatStage::Z0, StatStage::Z0);
+        assert_eq!(focus_blast, 1.0);
+        let stone_edge = hit_chance_under_gravity(Some(80), StatStage::Z0, StatStage::P2);
+        assert!((stone_edge - 0.8).abs() < 1e-9);
+        assert_eq!(hit_chance_under_gravity(None, StatStage::Z0, StatStage::P6), 1.0);
+    }
+    #[test]
+    fn test_move_slot_pp() {
+        let stone_edge = DamageMove::new("Stone Edge", 100, Typing::Rock, MoveCategory::Physical,
+                                         Some(80), 5);
+        let mut slot = MoveSlot::new(stone_edge);
+        assert_eq!((slot.current_pp, slot.max_pp), (5, 5));
+        assert_eq!(slot.use_move(false), Ok(()));
+        assert_eq!(slot.current_pp, 4);
+        assert_eq!(slot.use_move(true), Ok(()));
+        assert_eq!(slot.current_pp, 2);
+        slot.use_move(true).unwrap();
+        assert_eq!(slot.current_pp, 0);
+        assert_eq!(slot.use_move(false), Err(NoPpError{}));
+        assert_eq!(slot.current_pp, 0);
+        slot.restore(10);
+        assert_eq!(slot.current_pp, 5);
+        slot.current_pp = 1;
+        assert_eq!(slot.use_move(true), Ok(()));
+        assert_eq!(slot.current_pp, 0);
+    }
+    #[test]
+    fn test_iron_fist() {
+        let mut mach_punch = DamageMove::new("Mach Punch", 40, Typing::Fighting, MoveCategory::Physical,
+                                             Some(100), 30);
+        mach_punch.flags.punching = true;
+        let close_combat = DamageMove::new("Close Combat", 120, Typing::Fighting, MoveCategory::Physical,
+                                           Some(100), 5);
+        assert_eq!(ability_flag_multiplier(Ability::IronFist, mach_punch.flags()), 1.2);
+        assert_eq!(ability_flag_multiplier(Ability::IronFist, close_combat.flags()), 1.0);
+        assert_eq!(ability_flag_multiplier(Ability::StrongJaw, mach_punch.flags()), 1.0);
+        let crunch = MoveFlags{biting: true, ..Default::default()};
+        assert_eq!(ability_flag_multiplier(Ability::StrongJaw, crunch), 1.5);
+    }
+    #[test]
+    fn test_flag_immunity() {
+        let mut boomburst = DamageMove::new("Boomburst", 140, Typing::Normal, MoveCategory::Special,
+                                            Some(100), 10);
+        boomburst.flags.sound = true;
+        let hyper_voice = MoveFlags{sound: true, ..Default::default()};
+        let shadow_ball = MoveFlags{bullet: true, ..Default::default()};
+        assert!(ability_flag_immunity(Ability::Soundproof, boomburst.flags()));
+        assert!(ability_flag_immunity(Ability::Soundproof, hyper_voice));
+        assert!(!ability_flag_immunity(Ability::Soundproof, shadow_ball));
+        assert!(!ability_flag_immunity(Ability::Soundproof, MoveFlags::default()));
+        assert!(ability_flag_immunity(Ability::Bulletproof, shadow_ball));
+        assert!(!ability_flag_immunity(Ability::Intimidate, hyper_voice));
+    }
+    #[test]
+    fn test_contact_recoil() {
+        let mut u_turn = DamageMove::new("U-turn", 70, Typing::Bug, MoveCategory::Physical, Some(100), 20);
+        u_turn.flags.contact = true;
+        let volt_switch = DamageMove::new("Volt Switch", 70, Typing::Electric, MoveCategory::Special,
+                                          Some(100), 20);
+        let recoil = |flags: MoveFlags, ability, item| {
+            if flags.contact { contact_recoil(300, ability, item) } else { 0 }
+        };
+        // Ferrothorn
+        assert_eq!(recoil(u_turn.flags(), Ability::IronBarbs, Some(Item::RockyHelmet)), 87);
+        assert_eq!(recoil(u_turn.flags(), Ability::Anticipation, Some(Item::RockyHelmet)), 50);
+        assert_eq!(recoil(volt_switch.flags(), Ability::IronBarbs, Some(Item::RockyHelmet)), 0);
+        assert_eq!(contact_recoil(300, Ability::RoughSkin, None), 37);
+        assert_eq!(contact_recoil(300, Ability::Intimidate, Some(Item::Leftovers)), 0);
+    }
+    #[test]
+    fn test_recoil_and_drain() {
+        let mut flare_blitz = DamageMove::new("Flare Blitz", 120, Typing::Fire, MoveCategory::Physical,
+                                              Some(100), 15);
+        flare_blitz.recoil = Some((1, 3));
+        let mut giga_drain = DamageMove::new("Giga Drain", 75, Typing::Grass, MoveCategory::Special,
+                                             Some(100), 10);
+        giga_drain.drain = Some((1, 2));
+        assert_eq!(flare_blitz.recoil_fraction(), Some((1, 3)));
+        assert_eq!(flare_blitz.drain_fraction(), None);
+        assert_eq!(giga_drain.drain_fraction(), Some((1, 2)));
+        assert_eq!(recoil_damage(300, flare_blitz.recoil_fraction().unwrap()), 100);
+        assert_eq!(recoil_damage(200, (1, 3)), 67);
+        assert_eq!(recoil_damage(1, (1, 3)), 1);
+        assert_eq!(drain_heal(151, giga_drain.drain_fraction().unwrap()), 76);
+        assert_eq!(drain_heal(0, (1, 2)), 0);
+    }
+    #[test]
+    fn test_hit_counts() {
+        let mut shuriken = MultiHitMove::new("Water Shuriken", 20, Typing::Water, MoveCategory::Special,
+                                             Some(100), 20, HitCount::Fixed(3));
+        shuriken.priority = 1;
+        assert_eq!(shuriken.hit_count(), HitCount::Fixed(3));
+        assert_eq!(shuriken.hit_count().distribution(), vec![(3, 1.0)]);
+        assert_eq!(shuriken.priority(), 1);
+
+        let bullet_seed = MultiHitMove::new("Bullet Seed", 25, Typing::Grass, MoveCategory::Physical,
+                                            Some(100), 30, HitCount::Range(2, 5));
+        let distribution = bullet_seed.hit_count().distribution();
+        assert_eq!(distribution.iter().map(|&(hits, _)| hits).collect::<Vec<u8>>(), vec![2, 3, 4, 5]);
+        let total: f64 = distribution.iter().map(|&(_, chance)| chance).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+        assert_eq!(distribution[0], (2, 0.375));
+        assert_eq!(distribution[3], (5, 0.125));
+    }
+    #[test]
+    fn test_extreme_hit_ranges() {
+        let all = HitCount::Range(0, 255).distribution();
+        assert_eq!(all.len(), 256);
+        assert_eq!(all[255], (255, 1.0 / 256.0));
+        assert_eq!(HitCount::Range(5, 2).distribution(), vec![]);
+        assert_eq!(HitCount::Range(4, 4).distribution(), vec![(4, 1.0)]);
+    }
+    #[test]
+    fn test_fixed_damage() {
+        let seismic_toss = FixedDamageMove::new("Seismic Toss", Typing::Fighting, MoveCategory::Physical,
+                                                Some(100), 20, FixedDamage::LevelBased);
+        assert_eq!(seismic_toss.fixed_damage(50), 50);
+        assert_eq!(seismic_toss.base_power(), 0);
+        // Seismic Toss ignores resistances, but not Ghost's immunity
+        assert_eq!(seismic_toss.damage_against(100, (Typing::Poison, Some(Typing::Flying))), 100);
+        assert_eq!(seismic_toss.damage_against(100, (Typing::Ghost, None)), 0);
+        let night_shade = FixedDamageMove::new("Night Shade", Typing::Ghost, MoveCategory::Special,
+                                               Some(100), 15, FixedDamage::LevelBased);
+        assert_eq!(night_shade.damage_against(100, (Typing::Normal, None)), 0);
+        assert_eq!(night_shade.damage_against(100, (Typing::Psychic, None)), 100);
+        let dragon_rage = FixedDamageMove::new("Dragon Rage", Typing::Dragon, MoveCategory::Special,
+                                               Some(100), 10, FixedDamage::Constant(40));
+        assert_eq!(dragon_rage.fixed_damage(5), 40);
+        assert_eq!(dragon_rage.damage_against(5, (Typing::Fairy, None)), 0);
+    }
+    #[test]
+    fn test_swords_dance() {
+        let swords_dance = StatChangeMove::new("Swords Dance", Typing::Normal, None, 20,
+                                               vec![(Stat::Atk, StatStage::P2)], StatChangeTarget::User);
+        assert_eq!(swords_dance.category(), MoveCategory::Status);
+        assert_eq!(swords_dance.base_power(), 0);
+        let mut stages = [StatStage::Z0; 6];
+        swords_dance.apply_to(&mut stages);
+        assert_eq!(stages[Stat::Atk as usize], StatStage::P2);
+        swords_dance.apply_to(&mut stages);
+        swords_dance.apply_to(&mut stages);
+        assert_eq!(stages[Stat::Atk as usize], StatStage::P6);
+        swords_dance.apply_to(&mut stages);
+        assert_eq!(stages[Stat::Atk as usize], StatStage::P6);
+        assert_eq!(stages[Stat::Def as usize], StatStage::Z0);
+    }
+    #[test]
+    fn test_shell_smash() {
+        let shell_smash = StatChangeMove::new("Shell Smash", Typing::Normal, None, 15,
+                                              vec![(Stat::Def, StatStage::N1), (Stat::SpD, StatStage::N1),
+                                                   (Stat::Atk, StatStage::P2), (Stat::SpA, StatStage::P2),
+                                                   (Stat::Spe, StatStage::P2)],
+                                              StatChangeTarget::User);
+        let mut stages = [StatStage::Z0; 6];
+        stages[Stat::Def as usize] = StatStage::N6;
+        shell_smash.apply_to(&mut stages);
+        assert_eq!(stages, [StatStage::Z0, StatStage::P2, StatStage::N6, StatStage::P2, StatStage::N1,
+                            StatStage::P2]);
+    }
+    #[test]
+    fn test_is_spread() {
+        let mut earthquake = DamageMove::new("Earthquake", 100, Typing::Ground, MoveCategory::Physical,
+                                             Some(100), 10);
+        assert!(!earthquake.is_spread());
+        earthquake.flags.spread = true;
+        assert!(earthquake.is_spread());
+    }
+    #[test]
+    fn test_move_kind_serde() {
+        let mut bullet_seed = MultiHitMove::new("Bullet Seed", 25, Typing::Grass, MoveCategory::Physical,
+                                                Some(100), 30, HitCount::Range(2, 5));
+        bullet_seed.flags.bullet = true;
+        let kind = MoveKind::MultiHit(bullet_seed);
+        let json = serde_json::to_string(&kind).unwrap();
+        let parsed: MoveKind = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, kind);
+        assert_eq!(parsed.name(), "Bullet Seed");
+        assert_eq!(parsed.base_power(), 25);
+        assert!(parsed.flags().bullet);
+
+        let fissure = MoveKind::OHKO(OhkoMove::new("Fissure", Typing::Ground, MoveCategory::Physical, 5));
+        assert_eq!(fissure.accuracy(), Some(30));
+        assert_eq!(fissure.base_power(), 0);
+        let json = serde_json::to_string(&fissure).unwrap();
+        assert_eq!(serde_json::from_str::<MoveKind>(&json).unwrap(), fissure);
+    }
+}
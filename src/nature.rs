@@ -1,13 +1,15 @@
 //! This file enumerates the 25 natures that Pokemon can have, along with the stats they
 //! affect. [Bulbapedia](https://bulbapedia.bulbagarden.net/wiki/Nature) is used as a source.
 
+use std::fmt;
+
 use crate::stat::Stat;
 
 /// One of the 25 natures a Pokemon can have. There are 5 × 5 = 25 possible natures (natures cannot
 /// affect HP), 5 of which are the same because they have no effect. They are ordered left-right
 /// top-down from the Bulbapedia table: Hardy is Attack+ and Attack- (so no effect), Lonely is Attack+
 /// and Defense-, etc.
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, EnumString)]
 pub enum Nature {
     Hardy,
     Lonely,
@@ -36,7 +38,44 @@ pub enum Nature {
     Serious
 }
 
+impl fmt::Display for Nature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
 impl Nature {
+    /// Returns the name of the nature, like "Adamant". This is what `Display` writes, but it doesn't
+    /// allocate a `String`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Nature::Hardy => "Hardy",
+            Nature::Lonely => "Lonely",
+            Nature::Adamant => "Adamant",
+            Nature::Naughty => "Naughty",
+            Nature::Brave => "Brave",
+            Nature::Bold => "Bold",
+            Nature::Docile => "Docile",
+            Nature::Impish => "Impish",
+            Nature::Lax => "Lax",
+            Nature::Relaxed => "Relaxed",
+            Nature::Modest => "Modest",
+            Nature::Mild => "Mild",
+            Nature::Bashful => "Bashful",
+            Nature::Rash => "Rash",
+            Nature::Quiet => "Quiet",
+            Nature::Calm => "Calm",
+            Nature::Gentle => "Gentle",
+            Nature::Careful => "Careful",
+            Nature::Quirky => "Quirky",
+            Nature::Sassy => "Sassy",
+            Nature::Timid => "Timid",
+            Nature::Hasty => "Hasty",
+            Nature::Jolly => "Jolly",
+            Nature::Naive => "Naive",
+            Nature::Serious => "Serious",
+        }
+    }
     /// Returns a Vector of every Nature, ordered as in the declaration. Doesn't ever change its output.
     pub fn all_natures() -> Vec<Nature> {        
         return vec![Nature::Hardy, Nature::Lonely, Nature::Adamant, Nature::Naughty, Nature::Brave,
@@ -78,6 +117,19 @@ impl Nature {
     }
 }
 
+/// Returns the nature with the given index in the order the games use internally, which is what
+/// shows up in save files: Hardy is 0, Lonely is 1, Brave is 2, and so on up to Quirky at 24. This is
+/// the game's table read row by row with the stats in the order Atk, Def, Spe, SpA, SpD, so it isn't
+/// the same as the order `Nature` is declared in. Returns None for anything past 24.
+pub fn nature_from_index(i: u8) -> Option<Nature> {
+    let natures = [Nature::Hardy, Nature::Lonely, Nature::Brave, Nature::Adamant, Nature::Naughty,
+                   Nature::Bold, Nature::Docile, Nature::Relaxed, Nature::Impish, Nature::Lax,
+                   Nature::Timid, Nature::Hasty, Nature::Serious, Nature::Jolly, Nature::Naive,
+                   Nature::Modest, Nature::Mild, Nature::Quiet, Nature::Bashful, Nature::Rash,
+                   Nature::Calm, Nature::Gentle, Nature::Sassy, Nature::Careful, Nature::Quirky];
+    natures.get(usize::from(i)).cloned()
+}
+
 #[cfg(test)]
 mod tests {
     #[allow(unused_imports)]
@@ -90,4 +142,27 @@ mod tests {
             }
         }
     }
+    #[test]
+    fn test_nature_from_index() {
+        assert_eq!(nature_from_index(0), Some(Nature::Hardy));
+        assert_eq!(nature_from_index(3), Some(Nature::Adamant));
+        assert_eq!(nature_from_index(13), Some(Nature::Jolly));
+        assert_eq!(nature_from_index(24), Some(Nature::Quirky));
+        assert_eq!(nature_from_index(25), None);
+        let all: Vec<Nature> = (0..25).map(|i| nature_from_index(i).unwrap()).collect();
+        let unique: std::collections::HashSet<Nature> = all.iter().cloned().collect();
+        assert_eq!(unique.len(), 25);
+        for (i, &nature) in all.iter().enumerate() {
+            // in the internal order, the boosted stat only changes every 5 natures
+            assert_eq!(nature.increased_stat(), all[i / 5 * 5].increased_stat());
+        }
+    }
+    #[test]
+    fn test_nature_names() {
+        for nature in Nature::all_natures() {
+            assert_eq!(nature.name(), nature.to_string());
+            assert_eq!(nature.name().parse::<Nature>(), Ok(nature));
+        }
+        assert_eq!(Nature::Jolly.name(), "Jolly");
+    }
 }
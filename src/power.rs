@@ -0,0 +1,276 @@
+//! This file implements the formulas for moves whose base power isn't fixed, but depends on the
+//! situation they're used in: the target's weight, the user's remaining HP, and so on. Each function
+//! returns the base power to use in the damage formula. The formulas are current to Gen VII, with
+//! [Bulbapedia](https://bulbapedia.bulbagarden.net/wiki/Main_Page) as a source.
+
+use crate::ability::Ability;
+use crate::item::Item;
+use crate::status::Status;
+
+/// Returns the base power of Low Kick or Grass Knot against a target with the given weight, in
+/// hectograms (see `weight::weight_hg`). Power goes from 20 for targets under 10 kg to 120 for targets
+/// of 200 kg or more.
+pub fn weight_based_power(target_weight_hg: u32) -> u8 {
+    match target_weight_hg {
+        0..=99 => 20,
+        100..=249 => 40,
+        250..=499 => 60,
+        500..=999 => 80,
+        1000..=1999 => 100,
+        _ => 120,
+    }
+}
+
+/// Returns the base power of Electro Ball, which is stronger the more times faster the user is than
+/// the target: 150 at 4 times as fast, down to 40 if the user is slower. A target with 0 Speed is
+/// treated as infinitely slower.
+pub fn electro_ball_power(user_speed: u16, target_speed: u16) -> u8 {
+    if target_speed == 0 {
+        return 150;
+    }
+    match user_speed / target_speed {
+        0 => 40,
+        1 => 60,
+        2 => 80,
+        3 => 120,
+        _ => 150,
+    }
+}
+
+/// Returns the base power of Gyro Ball, which is stronger the slower the user is than the target: 25
+/// times the target's Speed over the user's, plus 1, up to a maximum of 150. A user with 0 Speed is
+/// treated as having 1.
+pub fn gyro_ball_power(user_speed: u16, target_speed: u16) -> u8 {
+    let power = 25 * u32::from(target_speed) / u32::from(user_speed.max(1)) + 1;
+    power.min(150) as u8
+}
+
+/// Returns the base power of Flail or Reversal, which are stronger the less HP the user has left:
+/// from 20 at more than 2/3 of max HP up to 200 below about 4%. The brackets are based on
+/// 48 times the fraction of HP remaining, floored. A max HP of 0 is treated as full HP.
+pub fn flail_power(current_hp: u16, max_hp: u16) -> u8 {
+    if max_hp == 0 {
+        return 20;
+    }
+    match 48 * u32::from(current_hp) / u32::from(max_hp) {
+        0..=1 => 200,
+        2..=4 => 150,
+        5..=9 => 100,
+        10..=16 => 80,
+        17..=32 => 40,
+        _ => 20,
+    }
+}
+
+/// Returns the base power of Eruption or Water Spout, which are stronger the more HP the user has
+/// left: 150 times the fraction of HP remaining, floored, but at least 1. A max HP of 0 is treated as
+/// full HP.
+pub fn eruption_power(current_hp: u16, max_hp: u16) -> u8 {
+    if max_hp == 0 {
+        return 150;
+    }
+    let power = 150 * u32::from(current_hp.min(max_hp)) / u32::from(max_hp);
+    power.max(1) as u8
+}
+
+/// Returns the base power of Knock Off, which is 1.5 times stronger, rounded down, if the target is
+/// holding an item it can lose. Items that can't be removed, like Z-Crystals and a Mega Stone on its
+/// own species, don't count: see `Item::is_removable` for the full list. The result is capped at 255.
+pub fn knock_off_power(base: u8, target_item: Option<Item>) -> u8 {
+    match target_item {
+        Some(item) if item.is_removable() => (u16::from(base) * 3 / 2).min(255) as u8,
+        _ => base,
+    }
+}
+
+/// Returns the base power of Facade, which doubles if the user is burned, paralyzed, or poisoned
+/// (badly or not). Sleep and freeze don't count. Facade also ignores the Attack drop from being
+/// burned, so a caller using this for a burned user must not apply the burn penalty as well.
+pub fn facade_power(base: u8, user_status: Option<Status>) -> u8 {
+    match user_status {
+        Some(Status::Burn) | Some(Status::Paralysis) | Some(Status::Poison) |
+        Some(Status::BadlyPoisoned(_)) => base.saturating_mul(2),
+        _ => base,
+    }
+}
+
+/// Returns the base power of Hex, which doubles if the target has any non-volatile status condition,
+/// including sleep and freeze.
+pub fn hex_power(base: u8, target_status: Option<Status>) -> u8 {
+    match target_status {
+        Some(_) => base.saturating_mul(2),
+        None => base,
+    }
+}
+
+/// Returns the base power of Acrobatics, which doubles if the user isn't holding an item. An item
+/// that's been used up, like a Flying Gem or a berry, counts as not holding one.
+pub fn acrobatics_power(base: u8, user_item: Option<Item>) -> u8 {
+    if user_item.is_none() {
+        base.saturating_mul(2)
+    } else {
+        base
+    }
+}
+
+/// Returns the base power of Rollout or Ice Ball on the given turn of using it in a row, counting the
+/// first turn as 0: the base power doubles each turn, up to 16 times on the fifth turn, after which
+/// the move stops and the count starts again from 0. Having used Defense Curl at any point before
+/// doubles the power again. Turn counts past 4 are treated as 4. Unlike the other functions here,
+/// this returns a `u16`, because later turns go well past 255: Rollout does 480 on its fifth turn,
+/// and 960 after Defense Curl.
+pub fn rollout_power(base: u8, consecutive_turns: u8, defense_curl_used: bool) -> u16 {
+    let power = u16::from(base) << consecutive_turns.min(4);
+    if defense_curl_used {
+        power * 2
+    } else {
+        power
+    }
+}
+
+/// Returns the base power of a move used by a Pokemon with the given ability, after Technician: moves
+/// with 60 base power or less get 1.5 times stronger, rounded down. For moves with variable power,
+/// like Flail, this should be given the power from the matching function here.
+pub fn technician_power(base: u8, ability: Ability) -> u8 {
+    if ability == Ability::Technician && base <= 60 {
+        (u16::from(base) * 3 / 2) as u8
+    } else {
+        base
+    }
+}
+
+/// Returns the base power of Return used by a Pokemon with the given friendship: friendship / 2.5,
+/// rounded down, so 102 at the maximum friendship of 255. The power is always at least 1.
+pub fn return_power(friendship: u8) -> u8 {
+    (u16::from(friendship) * 10 / 25).max(1) as u8
+}
+
+/// Returns the base power of Frustration used by a Pokemon with the given friendship. This is the
+/// mirror image of Return: (255 - friendship) / 2.5, rounded down, so 102 at a friendship of 0.
+pub fn frustration_power(friendship: u8) -> u8 {
+    return_power(255 - friendship)
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn test_weight_based_power() {
+        assert_eq!(weight_based_power(99), 20);
+        assert_eq!(weight_based_power(100), 40);
+        assert_eq!(weight_based_power(249), 40);
+        assert_eq!(weight_based_power(250), 60);
+        assert_eq!(weight_based_power(500), 80);
+        assert_eq!(weight_based_power(1999), 100);
+        assert_eq!(weight_based_power(2000), 120);
+    }
+    #[test]
+    fn test_heavy_targets() {
+        use crate::species::{MegaEvolution, Species};
+        use crate::weight::weight_hg;
+        assert_eq!(weight_based_power(weight_hg(Species::Snorlax)), 120);
+        assert_eq!(weight_based_power(weight_hg(Species::Cosmoem)), 120);
+        assert_eq!(weight_based_power(u32::max_value()), 120);
+        // Mega Evolution can change the bracket
+        assert_eq!(weight_based_power(weight_hg(Species::Sableye(MegaEvolution::Normal))), 40);
+        assert_eq!(weight_based_power(weight_hg(Species::Sableye(MegaEvolution::Mega))), 100);
+    }
+    #[test]
+    fn test_electro_ball_power() {
+        assert_eq!(electro_ball_power(400, 100), 150);
+        assert_eq!(electro_ball_power(399, 100), 120);
+        assert_eq!(electro_ball_power(200, 100), 80);
+        assert_eq!(electro_ball_power(100, 100), 60);
+        assert_eq!(electro_ball_power(99, 100), 40);
+        assert_eq!(electro_ball_power(5, 0), 150);
+    }
+    #[test]
+    fn test_gyro_ball_power() {
+        // Ferrothorn (76 Spe) against a Choice Scarf Garchomp (499 Spe)
+        assert_eq!(gyro_ball_power(76, 499), 150);
+        assert_eq!(gyro_ball_power(76, 200), 66);
+        assert_eq!(gyro_ball_power(300, 100), 9);
+        assert_eq!(gyro_ball_power(0, 100), 150);
+        assert_eq!(gyro_ball_power(100, 0), 1);
+    }
+    #[test]
+    fn test_flail_power() {
+        // 48 * current / max crosses each bracket at these HP values out of 480
+        assert_eq!(flail_power(19, 480), 200);
+        assert_eq!(flail_power(20, 480), 150);
+        assert_eq!(flail_power(49, 480), 150);
+        assert_eq!(flail_power(50, 480), 100);
+        assert_eq!(flail_power(99, 480), 100);
+        assert_eq!(flail_power(100, 480), 80);
+        assert_eq!(flail_power(169, 480), 80);
+        assert_eq!(flail_power(170, 480), 40);
+        assert_eq!(flail_power(329, 480), 40);
+        assert_eq!(flail_power(330, 480), 20);
+        assert_eq!(flail_power(480, 480), 20);
+        assert_eq!(flail_power(0, 0), 20);
+    }
+    #[test]
+    fn test_eruption_power() {
+        assert_eq!(eruption_power(341, 341), 150);
+        assert_eq!(eruption_power(170, 340), 75);
+        assert_eq!(eruption_power(10, 341), 4);
+        assert_eq!(eruption_power(1, 341), 1);
+        assert_eq!(eruption_power(0, 0), 150);
+    }
+    #[test]
+    fn test_knock_off_power() {
+        assert_eq!(knock_off_power(65, Some(Item::Leftovers)), 97);
+        assert_eq!(knock_off_power(65, None), 65);
+        assert_eq!(knock_off_power(65, Some(Item::Garchompite)), 65);
+        assert_eq!(knock_off_power(65, Some(Item::FiriumZ)), 65);
+        assert_eq!(knock_off_power(65, Some(Item::FlamePlate)), 65);
+        assert_eq!(knock_off_power(65, Some(Item::SitrusBerry)), 97);
+        assert_eq!(knock_off_power(170, Some(Item::Leftovers)), 255);
+        assert_eq!(knock_off_power(200, Some(Item::Leftovers)), 255);
+    }
+    #[test]
+    fn test_facade_power() {
+        assert_eq!(facade_power(70, Some(Status::Burn)), 140);
+        assert_eq!(facade_power(70, Some(Status::BadlyPoisoned(3))), 140);
+        assert_eq!(facade_power(70, Some(Status::Sleep(2))), 70);
+        assert_eq!(facade_power(70, None), 70);
+    }
+    #[test]
+    fn test_hex_power() {
+        assert_eq!(hex_power(65, Some(Status::Poison)), 130);
+        assert_eq!(hex_power(65, Some(Status::Freeze)), 130);
+        assert_eq!(hex_power(65, None), 65);
+    }
+    #[test]
+    fn test_acrobatics_power() {
+        assert_eq!(acrobatics_power(55, None), 110);
+        assert_eq!(acrobatics_power(55, Some(Item::Leftovers)), 55);
+    }
+    #[test]
+    fn test_rollout_power() {
+        let powers: Vec<u16> = (0..5).map(|turn| rollout_power(30, turn, false)).collect();
+        assert_eq!(powers, vec![30, 60, 120, 240, 480]);
+        assert_eq!(rollout_power(30, 9, false), 480);
+        let curled: Vec<u16> = (0..5).map(|turn| rollout_power(30, turn, true)).collect();
+        assert_eq!(curled, vec![60, 120, 240, 480, 960]);
+    }
+    #[test]
+    fn test_technician_power() {
+        assert_eq!(technician_power(60, Ability::Technician), 90);
+        assert_eq!(technician_power(61, Ability::Technician), 61);
+        assert_eq!(technician_power(25, Ability::Technician), 37);
+        assert_eq!(technician_power(60, Ability::Intimidate), 60);
+        assert_eq!(technician_power(flail_power(480, 480), Ability::Technician), 30);
+    }
+    #[test]
+    fn test_friendship_power() {
+        assert_eq!(return_power(255), 102);
+        assert_eq!(return_power(128), 51);
+        assert_eq!(return_power(0), 1);
+        assert_eq!(frustration_power(0), 102);
+        assert_eq!(frustration_power(127), 51);
+        assert_eq!(frustration_power(255), 1);
+    }
+}
@@ -0,0 +1,326 @@
+//! This file defines a Pokemon set: everything that describes a competitive Pokemon before it enters
+//! battle, including its species, ability, item, nature, EVs, IVs, level, and moves. Sets are what
+//! players share and build teams out of.
+
+use std::error;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::ability::Ability;
+use crate::base_stats::{base_stats, BaseStats};
+use crate::item::Item;
+use crate::nature::Nature;
+use crate::r#move::DamageMove;
+use crate::species::Species;
+use crate::stat::{calc_stat, Stat};
+
+/// The six stats, in the order used to index EV, IV, and stat arrays.
+const STATS: [Stat; 6] = [Stat::HP, Stat::Atk, Stat::Def, Stat::SpA, Stat::SpD, Stat::Spe];
+
+/// A Pokemon's IVs, in the order HP, Atk, Def, SpA, SpD, Spe. They can be indexed by `Stat`, as in
+/// `ivs[Stat::Spe]`. The default is `IVs::perfect()`, which is what almost every competitive set uses.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IVs(pub [u8; 6]);
+
+/// An error for an IV above the maximum of 31, recording the stat and the IV it was given.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IvError {
+    pub stat: Stat,
+    pub value: u8,
+}
+
+impl fmt::Display for IvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} IV of {} is more than 31", self.stat, self.value)
+    }
+}
+
+impl error::Error for IvError {
+    fn description(&self) -> &str {
+        "IV was not in range 0-31"
+    }
+
+    fn cause(&self) -> Option<&error::Error> {
+        // Generic error, underlying cause isn't tracked.
+        None
+    }
+}
+
+impl IVs {
+    /// Returns 31 IVs in every stat.
+    pub fn perfect() -> IVs {
+        IVs([31; 6])
+    }
+    /// Checks that every IV is at most 31, returning an error for the first one that isn't.
+    pub fn validate(&self) -> Result<(), IvError> {
+        for &stat in STATS.iter() {
+            if self[stat] > 31 {
+                return Err(IvError{stat, value: self[stat]});
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for IVs {
+    fn default() -> IVs {
+        IVs::perfect()
+    }
+}
+
+impl Index<Stat> for IVs {
+    type Output = u8;
+
+    fn index(&self, stat: Stat) -> &u8 {
+        &self.0[stat as usize]
+    }
+}
+
+impl IndexMut<Stat> for IVs {
+    fn index_mut(&mut self, stat: Stat) -> &mut u8 {
+        &mut self.0[stat as usize]
+    }
+}
+
+/// A Pokemon's EVs, in the order HP, Atk, Def, SpA, SpD, Spe. Like `IVs`, they can be indexed by
+/// `Stat`. The default is no EVs at all.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EVs(pub [u8; 6]);
+
+impl EVs {
+    /// Returns the total number of EVs across all six stats.
+    pub fn total(&self) -> u16 {
+        self.0.iter().map(|&ev| u16::from(ev)).sum()
+    }
+    /// Checks that the EVs are legal: no more than 252 in any one stat, and no more than 510 in total.
+    /// The first problem found is returned.
+    pub fn validate(&self) -> Result<(), SetError> {
+        for &stat in STATS.iter() {
+            if self[stat] > 252 {
+                return Err(SetError::TooManyEVs(stat));
+            }
+        }
+        if self.total() > 510 {
+            return Err(SetError::TooManyTotalEVs(self.total()));
+        }
+        Ok(())
+    }
+}
+
+impl Index<Stat> for EVs {
+    type Output = u8;
+
+    fn index(&self, stat: Stat) -> &u8 {
+        &self.0[stat as usize]
+    }
+}
+
+impl IndexMut<Stat> for EVs {
+    fn index_mut(&mut self, stat: Stat) -> &mut u8 {
+        &mut self.0[stat as usize]
+    }
+}
+
+/// A competitive Pokemon set. EVs, IVs, and computed stats are all indexed in the same order as
+/// `Stat`: HP, Atk, Def, SpA, SpD, Spe.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PokemonSet {
+    pub species: Species,
+    pub ability: Ability,
+    pub item: Option<Item>,
+    pub nature: Nature,
+    pub evs: EVs,
+    pub ivs: IVs,
+    pub level: u8,
+    pub moves: Vec<DamageMove>,
+}
+
+/// The ways a set can be invalid.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SetError {
+    /// More than 252 EVs were put into the given stat.
+    TooManyEVs(Stat),
+    /// More than 510 EVs were used in total. The total is included.
+    TooManyTotalEVs(u16),
+    /// The IV for the given stat was more than 31.
+    InvalidIV(Stat),
+}
+
+impl fmt::Display for SetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SetError::TooManyEVs(stat) => write!(f, "more than 252 EVs in {}", stat),
+            SetError::TooManyTotalEVs(total) => write!(f, "{} total EVs is more than 510", total),
+            SetError::InvalidIV(stat) => write!(f, "{} IV is more than 31", stat),
+        }
+    }
+}
+
+impl error::Error for SetError {
+    fn description(&self) -> &str {
+        "set is not legal"
+    }
+
+    fn cause(&self) -> Option<&error::Error> {
+        // Generic error, underlying cause isn't tracked.
+        None
+    }
+}
+
+impl PokemonSet {
+    /// Returns the actual stats of this set, in the order HP, Atk, Def, SpA, SpD, Spe. Shedinja always
+    /// has 1 HP, regardless of anything else.
+    pub fn computed_stats(&self) -> [u16; 6] {
+        let base = base_stats(self.species);
+        let mut stats = [0; 6];
+        for (i, &stat) in STATS.iter().enumerate() {
+            stats[i] = calc_stat(stat, base.get(stat), self.level, self.nature, self.evs[stat], self.ivs[stat]);
+        }
+        if self.species == Species::Shedinja {
+            stats[0] = 1;
+        }
+        stats
+    }
+    /// Checks that the set's EVs and IVs are legal: no more than 252 EVs in any one stat, no more than
+    /// 510 in total, and no IV above 31. The first problem found is returned.
+    pub fn validate(&self) -> Result<(), SetError> {
+        self.evs.validate()?;
+        self.ivs.validate().map_err(|err| SetError::InvalidIV(err.stat))
+    }
+}
+
+/// Finds the least investment in HP and a defensive stat (`Def` or `SpD`) that lets a Pokemon survive
+/// an attack, with 31 IVs everywhere. `incoming_max` is the attack's maximum damage against this
+/// Pokemon with the given nature but no EVs in the defensive stat. Because damage is very nearly
+/// inversely proportional to the defending stat, the damage at higher investment is estimated by
+/// scaling: this can be off by a point or so, so check very close calls with `calc_damage`.
+///
+/// EVs are searched in steps of 4, and the pair with the smallest total is returned as (HP EVs,
+/// defensive EVs), preferring less HP investment between pairs with the same total. Returns None if
+/// even 252/252 isn't enough, or if `stat` isn't `Def` or `SpD`.
+pub fn minimize_bulk_evs(base: &BaseStats, level: u8, nature: Nature, incoming_max: u16,
+                         stat: Stat) -> Option<(u8, u8)> {
+    if stat != Stat::Def && stat != Stat::SpD {
+        return None;
+    }
+    let uninvested = u32::from(calc_stat(stat, base.get(stat), level, nature, 0, 31));
+    for total in (0..=504).step_by(4) {
+        let min_hp = if total > 252 { total - 252 } else { 0 };
+        for hp_evs in (min_hp..=total.min(252)).step_by(4) {
+            let def_evs = total - hp_evs;
+            let hp = calc_stat(Stat::HP, base.hp, level, nature, hp_evs as u8, 31);
+            let defense = u32::from(calc_stat(stat, base.get(stat), level, nature, def_evs as u8, 31));
+            let damage = u32::from(incoming_max) * uninvested / defense;
+            if u32::from(hp) > damage {
+                return Some((hp_evs as u8, def_evs as u8));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+    use crate::r#move::MoveCategory;
+    use crate::species::{GenieForme, MegaEvolution};
+    use crate::typing::Typing;
+
+    fn garchomp() -> PokemonSet {
+        PokemonSet {
+            species: Species::Garchomp(MegaEvolution::Normal),
+            ability: Ability::RoughSkin,
+            item: None,
+            nature: Nature::Jolly,
+            evs: EVs([4, 252, 0, 0, 0, 252]),
+            ivs: IVs::perfect(),
+            level: 100,
+            moves: vec![DamageMove::new("Earthquake", 100, Typing::Ground, MoveCategory::Physical,
+                                        Some(100), 10)],
+        }
+    }
+
+    #[test]
+    fn test_valid_set() {
+        let set = garchomp();
+        assert_eq!(set.validate(), Ok(()));
+        assert_eq!(set.computed_stats(), [358, 359, 226, 176, 206, 333]);
+    }
+    #[test]
+    fn test_over_evd_set() {
+        let mut set = garchomp();
+        set.evs = EVs([252, 252, 0, 0, 0, 252]);
+        assert_eq!(set.validate(), Err(SetError::TooManyTotalEVs(756)));
+        set.evs = EVs([0, 255, 0, 0, 0, 0]);
+        assert_eq!(set.validate(), Err(SetError::TooManyEVs(Stat::Atk)));
+    }
+    #[test]
+    fn test_shedinja() {
+        let mut set = garchomp();
+        set.species = Species::Shedinja;
+        set.evs = EVs([252, 252, 0, 0, 0, 4]);
+        assert_eq!(set.computed_stats()[0], 1);
+    }
+    #[test]
+    fn test_minimize_bulk_evs() {
+        use crate::damage::{calc_damage, DamageModifiers};
+        // +2 252+ Atk Huge Power Mega Mawile (1356 Atk) Play Rough vs. Relaxed Tangrowth
+        let tangrowth = base_stats(Species::Tangrowth);
+        let stab = DamageModifiers{stab: 1.5, ..Default::default()};
+        let uninvested = calc_stat(Stat::Def, tangrowth.def, 100, Nature::Relaxed, 0, 31);
+        let incoming = calc_damage(100, 1356, uninvested, 90, stab, false).1;
+        assert_eq!(minimize_bulk_evs(&tangrowth, 100, Nature::Relaxed, incoming, Stat::Def),
+                   Some((252, 252)));
+        // the real calc agrees it's a survive
+        let defense = calc_stat(Stat::Def, tangrowth.def, 100, Nature::Relaxed, 252, 31);
+        let hp = calc_stat(Stat::HP, tangrowth.hp, 100, Nature::Relaxed, 252, 31);
+        assert!(calc_damage(100, 1356, defense, 90, stab, false).1 < hp);
+
+        // unboosted, it doesn't need any investment at all
+        let incoming = calc_damage(100, 678, uninvested, 90, stab, false).1;
+        assert_eq!(minimize_bulk_evs(&tangrowth, 100, Nature::Relaxed, incoming, Stat::Def), Some((0, 0)));
+
+        // Landorus-Therian can't survive it no matter what
+        let landorus = base_stats(Species::Landorus(GenieForme::Therian));
+        let uninvested = calc_stat(Stat::Def, landorus.def, 100, Nature::Impish, 0, 31);
+        let incoming = calc_damage(100, 1356, uninvested, 90, stab, false).1;
+        assert_eq!(minimize_bulk_evs(&landorus, 100, Nature::Impish, incoming, Stat::Def), None);
+        assert_eq!(minimize_bulk_evs(&landorus, 100, Nature::Impish, 100, Stat::Atk), None);
+    }
+    #[test]
+    fn test_minimize_bulk_evs_partial() {
+        let tangrowth = base_stats(Species::Tangrowth);
+        let (hp_evs, def_evs) = minimize_bulk_evs(&tangrowth, 100, Nature::Relaxed, 400, Stat::Def).unwrap();
+        assert!(u16::from(hp_evs) + u16::from(def_evs) < 504);
+        assert_eq!(hp_evs % 4, 0);
+        assert_eq!(def_evs % 4, 0);
+    }
+    #[test]
+    fn test_perfect_ivs() {
+        let ivs = IVs::perfect();
+        assert_eq!(ivs, IVs::default());
+        assert_eq!(ivs[Stat::Spe], 31);
+        assert_eq!(ivs.validate(), Ok(()));
+    }
+    #[test]
+    fn test_invalid_iv() {
+        let mut ivs = IVs::perfect();
+        ivs[Stat::Atk] = 0;
+        assert_eq!(ivs.validate(), Ok(()));
+        ivs[Stat::SpD] = 32;
+        assert_eq!(ivs.validate(), Err(IvError{stat: Stat::SpD, value: 32}));
+        let mut set = garchomp();
+        set.ivs = ivs;
+        assert_eq!(set.validate(), Err(SetError::InvalidIV(Stat::SpD)));
+    }
+    #[test]
+    fn test_ev_budget() {
+        let evs = EVs([252, 4, 0, 0, 0, 252]);
+        assert_eq!(evs.total(), 508);
+        assert_eq!(evs[Stat::Atk], 4);
+        assert_eq!(evs.validate(), Ok(()));
+        assert_eq!(EVs([252, 252, 8, 0, 0, 0]).validate(), Err(SetError::TooManyTotalEVs(512)));
+        assert_eq!(EVs::default().total(), 0);
+    }
+}
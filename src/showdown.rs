@@ -0,0 +1,645 @@
+//! This file implements reading and writing sets in the text format used by [Pokemon
+//! Showdown](https://pokemonshowdown.com), which is how competitive players share sets. A set in this
+//! format looks like this:
+//!
+//! ```text
+//! Landorus-Therian (M) @ Rocky Helmet
+//! Ability: Intimidate
+//! EVs: 252 HP / 216 Def / 40 Spe
+//! Impish Nature
+//! - Stealth Rock
+//! - Earthquake
+//! - U-turn
+//! - Toxic
+//! ```
+
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+use strum::IntoEnumIterator;
+
+use crate::ability::{abilities, Ability};
+use crate::item::Item;
+use crate::nature::Nature;
+use crate::r#move::{DamageMove, MoveCategory};
+use crate::set::{EVs, IVs, PokemonSet};
+use crate::species::*;
+use crate::stat::Stat;
+use crate::typing::Typing;
+
+/// The ways reading a Showdown set can fail. Each variant other than `MissingSpecies` includes the
+/// text that couldn't be understood.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ParseError {
+    /// The text was empty, so there was no species line.
+    MissingSpecies,
+    UnknownSpecies(String),
+    UnknownItem(String),
+    UnknownAbility(String),
+    UnknownNature(String),
+    InvalidEVs(String),
+    InvalidIVs(String),
+    InvalidLevel(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::MissingSpecies => write!(f, "no species given"),
+            ParseError::UnknownSpecies(s) => write!(f, "unknown species {}", s),
+            ParseError::UnknownItem(s) => write!(f, "unknown item {}", s),
+            ParseError::UnknownAbility(s) => write!(f, "unknown ability {}", s),
+            ParseError::UnknownNature(s) => write!(f, "unknown nature {}", s),
+            ParseError::InvalidEVs(s) => write!(f, "invalid EVs {}", s),
+            ParseError::InvalidIVs(s) => write!(f, "invalid IVs {}", s),
+            ParseError::InvalidLevel(s) => write!(f, "invalid level {}", s),
+        }
+    }
+}
+
+impl error::Error for ParseError {
+    fn description(&self) -> &str {
+        "text could not be read as a Showdown set"
+    }
+
+    fn cause(&self) -> Option<&error::Error> {
+        // Generic error, underlying cause isn't tracked.
+        None
+    }
+}
+
+/// Reads a single set in Showdown's text format. The first line gives the species, optionally with a
+/// nickname (as in "Lando (Landorus-Therian)"), a gender, and an item after an @. Every other line is
+/// optional: a missing ability defaults to the species' first ability, a missing nature to Serious,
+/// missing EVs to 0, missing IVs to 31, and a missing level to 100. Lines this library has no use for,
+/// like "Shiny: Yes", are ignored.
+///
+/// There's no move database yet, so moves only keep their names: every other field is left as 0 or
+/// None, with the move's type as Normal and category as Status.
+pub fn from_showdown(text: &str) -> Result<PokemonSet, ParseError> {
+    let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+    let first = lines.next().ok_or(ParseError::MissingSpecies)?;
+    let (name, item) = match first.find(" @ ") {
+        Some(i) => (&first[..i], Some(first[i + 3..].trim())),
+        None => (first, None),
+    };
+    let name = species_name(name);
+    let species = parse_species(name).ok_or_else(|| ParseError::UnknownSpecies(name.to_string()))?;
+    let item = match item {
+        Some(item) => Some(Item::from_str(item).map_err(|_| ParseError::UnknownItem(item.to_string()))?),
+        None => None,
+    };
+    let mut set = PokemonSet {
+        species,
+        ability: abilities(species)[0],
+        item,
+        nature: Nature::Serious,
+        evs: EVs::default(),
+        ivs: IVs::perfect(),
+        level: 100,
+        moves: vec![],
+    };
+    for line in lines {
+        if let Some(name) = line.strip_prefix('-') {
+            let name = name.trim();
+            set.moves.push(DamageMove::new(name, 0, Typing::Normal, MoveCategory::Status, None, 0));
+        } else if let Some(ability) = line.strip_prefix("Ability:") {
+            let ability = ability.trim();
+            set.ability = Ability::from_str(ability)
+                .map_err(|_| ParseError::UnknownAbility(ability.to_string()))?;
+        } else if let Some(evs) = line.strip_prefix("EVs:") {
+            set.evs = parse_ev_string(evs.trim())?;
+        } else if let Some(ivs) = line.strip_prefix("IVs:") {
+            set.ivs = parse_iv_string(ivs.trim())?;
+        } else if let Some(level) = line.strip_prefix("Level:") {
+            let level = level.trim();
+            set.level = level.parse().map_err(|_| ParseError::InvalidLevel(level.to_string()))?;
+        } else if let Some(nature) = line.strip_suffix(" Nature") {
+            let nature = nature.trim();
+            set.nature = Nature::from_str(nature)
+                .map_err(|_| ParseError::UnknownNature(nature.to_string()))?;
+        }
+    }
+    Ok(set)
+}
+
+/// Gets the species from the part of the first line before the item, removing the gender and the
+/// nickname if there is one.
+fn species_name(name: &str) -> &str {
+    let mut name = name.trim();
+    if name.ends_with(" (M)") || name.ends_with(" (F)") {
+        name = name[..name.len() - 4].trim();
+    }
+    match name.rfind(" (") {
+        Some(i) if name.ends_with(')') => &name[i + 2..name.len() - 1],
+        _ => name,
+    }
+}
+
+/// Reduces a name to lowercase ASCII letters and digits, so "Kommo-o", "Kommo-O", and "KommoO" are all
+/// the same. The gender symbols become letters, so "Nidoran♀" matches "Nidoran-F".
+fn simplify(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            'é' => 'e',
+            '♀' => 'f',
+            '♂' => 'm',
+            c => c,
+        })
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Parses a species name without a forme, being lenient about capitalization and punctuation.
+fn parse_discriminant(name: &str) -> Option<SpeciesDiscriminant> {
+    SpeciesDiscriminant::from_str(name).ok().or_else(|| {
+        let name = simplify(name);
+        SpeciesDiscriminant::iter().find(|species| simplify(&format!("{:?}", species)) == name)
+    })
+}
+
+/// Parses a species name as Showdown writes it, with the forme after a hyphen: "Landorus-Therian",
+/// "Charizard-Mega-X", or "Necrozma-Dusk-Mane". A species name alone gives the default forme.
+fn parse_species(name: &str) -> Option<Species> {
+    if let Some(species) = parse_discriminant(name) {
+        return Some(Species::from(species));
+    }
+    for (i, _) in name.match_indices('-') {
+        if let Some(species) = parse_discriminant(&name[..i]) {
+            return with_forme(species, &name[i + 1..]);
+        }
+    }
+    None
+}
+
+/// Gives the species in the forme with the given Showdown name. Species that don't have formes in this
+/// library ignore the forme name, since those formes are purely cosmetic (like Gastrodon-East).
+fn with_forme(species: SpeciesDiscriminant, forme: &str) -> Option<Species> {
+    if !Species::from(species).has_forme() {
+        return Some(Species::from(species));
+    }
+    let forme: String = forme.chars().filter(|&c| c.is_alphanumeric() || c == '%').collect();
+    let forme = match forme.as_str() {
+        "10%" => "TenPercent",
+        "50%" => "FiftyPercent",
+        "Zen" => "ZenMode",
+        "Blade" => "Sword",
+        "Bond" => "BattleBond",
+        "DawnWings" => "DuskWings",
+        other => other,
+    };
+    match species {
+        SpeciesDiscriminant::Venusaur => MegaEvolution::from_str(forme).ok().map(Species::Venusaur),
+        SpeciesDiscriminant::Charizard => XYMegaEvolution::from_str(forme).ok().map(Species::Charizard),
+        SpeciesDiscriminant::Blastoise => MegaEvolution::from_str(forme).ok().map(Species::Blastoise),
+        SpeciesDiscriminant::Beedrill => MegaEvolution::from_str(forme).ok().map(Species::Beedrill),
+        SpeciesDiscriminant::Pidgeot => MegaEvolution::from_str(forme).ok().map(Species::Pidgeot),
+        SpeciesDiscriminant::Rattata => AlolaForme::from_str(forme).ok().map(Species::Rattata),
+        SpeciesDiscriminant::Raticate => AlolaForme::from_str(forme).ok().map(Species::Raticate),
+        SpeciesDiscriminant::Raichu => AlolaForme::from_str(forme).ok().map(Species::Raichu),
+        SpeciesDiscriminant::Sandshrew => AlolaForme::from_str(forme).ok().map(Species::Sandshrew),
+        SpeciesDiscriminant::Sandslash => AlolaForme::from_str(forme).ok().map(Species::Sandslash),
+        SpeciesDiscriminant::Vulpix => AlolaForme::from_str(forme).ok().map(Species::Vulpix),
+        SpeciesDiscriminant::Ninetales => AlolaForme::from_str(forme).ok().map(Species::Ninetales),
+        SpeciesDiscriminant::Diglett => AlolaForme::from_str(forme).ok().map(Species::Diglett),
+        SpeciesDiscriminant::Dugtrio => AlolaForme::from_str(forme).ok().map(Species::Dugtrio),
+        SpeciesDiscriminant::Persian => AlolaForme::from_str(forme).ok().map(Species::Persian),
+        SpeciesDiscriminant::Alakazam => MegaEvolution::from_str(forme).ok().map(Species::Alakazam),
+        SpeciesDiscriminant::Geodude => AlolaForme::from_str(forme).ok().map(Species::Geodude),
+        SpeciesDiscriminant::Graveler => AlolaForme::from_str(forme).ok().map(Species::Graveler),
+        SpeciesDiscriminant::Golem => AlolaForme::from_str(forme).ok().map(Species::Golem),
+        SpeciesDiscriminant::Grimer => AlolaForme::from_str(forme).ok().map(Species::Grimer),
+        SpeciesDiscriminant::Muk => AlolaForme::from_str(forme).ok().map(Species::Muk),
+        SpeciesDiscriminant::Gengar => MegaEvolution::from_str(forme).ok().map(Species::Gengar),
+        SpeciesDiscriminant::Exeggutor => AlolaForme::from_str(forme).ok().map(Species::Exeggutor),
+        SpeciesDiscriminant::Marowak => AlolaForme::from_str(forme).ok().map(Species::Marowak),
+        SpeciesDiscriminant::Kangaskhan => MegaEvolution::from_str(forme).ok().map(Species::Kangaskhan),
+        SpeciesDiscriminant::Pinsir => MegaEvolution::from_str(forme).ok().map(Species::Pinsir),
+        SpeciesDiscriminant::Gyarados => MegaEvolution::from_str(forme).ok().map(Species::Gyarados),
+        SpeciesDiscriminant::Aerodactyl => MegaEvolution::from_str(forme).ok().map(Species::Aerodactyl),
+        SpeciesDiscriminant::Mewtwo => XYMegaEvolution::from_str(forme).ok().map(Species::Mewtwo),
+        SpeciesDiscriminant::Steelix => MegaEvolution::from_str(forme).ok().map(Species::Steelix),
+        SpeciesDiscriminant::Scizor => MegaEvolution::from_str(forme).ok().map(Species::Scizor),
+        SpeciesDiscriminant::Heracross => MegaEvolution::from_str(forme).ok().map(Species::Heracross),
+        SpeciesDiscriminant::Houndoom => MegaEvolution::from_str(forme).ok().map(Species::Houndoom),
+        SpeciesDiscriminant::Tyranitar => MegaEvolution::from_str(forme).ok().map(Species::Tyranitar),
+        SpeciesDiscriminant::Sceptile => MegaEvolution::from_str(forme).ok().map(Species::Sceptile),
+        SpeciesDiscriminant::Blaziken => MegaEvolution::from_str(forme).ok().map(Species::Blaziken),
+        SpeciesDiscriminant::Swampert => MegaEvolution::from_str(forme).ok().map(Species::Swampert),
+        SpeciesDiscriminant::Gardevoir => MegaEvolution::from_str(forme).ok().map(Species::Gardevoir),
+        SpeciesDiscriminant::Sableye => MegaEvolution::from_str(forme).ok().map(Species::Sableye),
+        SpeciesDiscriminant::Mawile => MegaEvolution::from_str(forme).ok().map(Species::Mawile),
+        SpeciesDiscriminant::Aggron => MegaEvolution::from_str(forme).ok().map(Species::Aggron),
+        SpeciesDiscriminant::Medicham => MegaEvolution::from_str(forme).ok().map(Species::Medicham),
+        SpeciesDiscriminant::Manectric => MegaEvolution::from_str(forme).ok().map(Species::Manectric),
+        SpeciesDiscriminant::Sharpedo => MegaEvolution::from_str(forme).ok().map(Species::Sharpedo),
+        SpeciesDiscriminant::Camerupt => MegaEvolution::from_str(forme).ok().map(Species::Camerupt),
+        SpeciesDiscriminant::Altaria => MegaEvolution::from_str(forme).ok().map(Species::Altaria),
+        SpeciesDiscriminant::Castform => CastformForme::from_str(forme).ok().map(Species::Castform),
+        SpeciesDiscriminant::Salamence => MegaEvolution::from_str(forme).ok().map(Species::Salamence),
+        SpeciesDiscriminant::Metagross => MegaEvolution::from_str(forme).ok().map(Species::Metagross),
+        SpeciesDiscriminant::Kyogre => PrimalReversion::from_str(forme).ok().map(Species::Kyogre),
+        SpeciesDiscriminant::Groudon => PrimalReversion::from_str(forme).ok().map(Species::Groudon),
+        SpeciesDiscriminant::Rayquaza => MegaEvolution::from_str(forme).ok().map(Species::Rayquaza),
+        SpeciesDiscriminant::Deoxys => DeoxysForme::from_str(forme).ok().map(Species::Deoxys),
+        SpeciesDiscriminant::Wormadam => WormadamForme::from_str(forme).ok().map(Species::Wormadam),
+        SpeciesDiscriminant::Lopunny => MegaEvolution::from_str(forme).ok().map(Species::Lopunny),
+        SpeciesDiscriminant::Garchomp => MegaEvolution::from_str(forme).ok().map(Species::Garchomp),
+        SpeciesDiscriminant::Lucario => MegaEvolution::from_str(forme).ok().map(Species::Lucario),
+        SpeciesDiscriminant::Abomasnow => MegaEvolution::from_str(forme).ok().map(Species::Abomasnow),
+        SpeciesDiscriminant::Gallade => MegaEvolution::from_str(forme).ok().map(Species::Gallade),
+        SpeciesDiscriminant::Rotom => RotomForme::from_str(forme).ok().map(Species::Rotom),
+        SpeciesDiscriminant::Giratina => GiratinaForme::from_str(forme).ok().map(Species::Giratina),
+        SpeciesDiscriminant::Shaymin => ShayminForme::from_str(forme).ok().map(Species::Shaymin),
+        SpeciesDiscriminant::Arceus => ArceusForme::from_str(forme).ok().map(Species::Arceus),
+        SpeciesDiscriminant::Darmanitan => DarmanitanForme::from_str(forme).ok().map(Species::Darmanitan),
+        SpeciesDiscriminant::Tornadus => GenieForme::from_str(forme).ok().map(Species::Tornadus),
+        SpeciesDiscriminant::Thundurus => GenieForme::from_str(forme).ok().map(Species::Thundurus),
+        SpeciesDiscriminant::Landorus => GenieForme::from_str(forme).ok().map(Species::Landorus),
+        SpeciesDiscriminant::Kyurem => KyuremForme::from_str(forme).ok().map(Species::Kyurem),
+        SpeciesDiscriminant::Meloetta => MeloettaForme::from_str(forme).ok().map(Species::Meloetta),
+        SpeciesDiscriminant::Greninja => GreninjaForme::from_str(forme).ok().map(Species::Greninja),
+        SpeciesDiscriminant::Aegislash => AegislashForme::from_str(forme).ok().map(Species::Aegislash),
+        SpeciesDiscriminant::Pumpkaboo => GourgeistForme::from_str(forme).ok().map(Species::Pumpkaboo),
+        SpeciesDiscriminant::Gourgeist => GourgeistForme::from_str(forme).ok().map(Species::Gourgeist),
+        SpeciesDiscriminant::Zygarde => ZygardeForme::from_str(forme).ok().map(Species::Zygarde),
+        SpeciesDiscriminant::Diancie => MegaEvolution::from_str(forme).ok().map(Species::Diancie),
+        SpeciesDiscriminant::Hoopa => HoopaForme::from_str(forme).ok().map(Species::Hoopa),
+        SpeciesDiscriminant::Oricorio => OricorioForme::from_str(forme).ok().map(Species::Oricorio),
+        SpeciesDiscriminant::Lycanroc => LycanrocForme::from_str(forme).ok().map(Species::Lycanroc),
+        SpeciesDiscriminant::Wishiwashi => WishiwashiForme::from_str(forme).ok().map(Species::Wishiwashi),
+        SpeciesDiscriminant::TypeNull => SilvallyForme::from_str(forme).ok().map(Species::TypeNull),
+        SpeciesDiscriminant::Silvally => SilvallyForme::from_str(forme).ok().map(Species::Silvally),
+        SpeciesDiscriminant::Minior => MiniorForme::from_str(forme).ok().map(Species::Minior),
+        SpeciesDiscriminant::Mimikyu => MimikyuForme::from_str(forme).ok().map(Species::Mimikyu),
+        SpeciesDiscriminant::Necrozma => NecrozmaForme::from_str(forme).ok().map(Species::Necrozma),
+        _ => None,
+    }
+}
+
+/// Parses an EV or IV spread like "252 HP / 4 Def / 252 Spe", with unmentioned stats set to the
+/// default. A stat that's mentioned more than once makes the whole spread invalid.
+fn parse_spread(spread: &str, default: u8) -> Option<[u8; 6]> {
+    let mut values = [default; 6];
+    let mut seen = [false; 6];
+    for part in spread.split('/') {
+        let mut words = part.split_whitespace();
+        let value = words.next()?.parse().ok()?;
+        let stat = Stat::from_str(words.next()?).ok()?;
+        if words.next().is_some() || seen[stat as usize] {
+            return None;
+        }
+        values[stat as usize] = value;
+        seen[stat as usize] = true;
+    }
+    Some(values)
+}
+
+/// Parses the EVs from a Showdown EV line without the "EVs:" at the start, like
+/// "252 Atk / 4 Def / 252 Spe". Stats that aren't mentioned get 0 EVs. This only checks that the text
+/// makes sense, not that the EVs are legal, so use `EVs::validate` for that.
+pub fn parse_ev_string(s: &str) -> Result<EVs, ParseError> {
+    parse_spread(s, 0).map(EVs).ok_or_else(|| ParseError::InvalidEVs(s.to_string()))
+}
+
+/// Parses the IVs from a Showdown IV line, without the "IVs:" at the start: for example, "0 Atk".
+/// Stats that aren't mentioned get 31 IVs. As with `parse_ev_string`, use `IVs::validate` to check
+/// that the IVs are legal.
+pub fn parse_iv_string(s: &str) -> Result<IVs, ParseError> {
+    parse_spread(s, 31).map(IVs).ok_or_else(|| ParseError::InvalidIVs(s.to_string()))
+}
+
+/// Writes EVs the way a Showdown EV line does, leaving out stats with no EVs, so that
+/// `parse_ev_string` reads it back as the same EVs. No EVs at all gives an empty string.
+pub fn to_ev_string(evs: EVs) -> String {
+    spread_string(evs.0, 0).unwrap_or_default()
+}
+
+/// Writes IVs the way a Showdown IV line does, leaving out stats with 31 IVs. Perfect IVs give an
+/// empty string.
+pub fn to_iv_string(ivs: IVs) -> String {
+    spread_string(ivs.0, 31).unwrap_or_default()
+}
+
+impl PokemonSet {
+    /// Writes this set in Showdown's text format, so that `from_showdown` reads it back as the same
+    /// set. Only the EVs that aren't 0 and the IVs that aren't 31 are written, and the EV, IV, and
+    /// level lines are left out entirely if they'd say nothing new.
+    pub fn to_showdown(&self) -> String {
+        let mut text = showdown_name(self.species);
+        if let Some(item) = self.item {
+            text += &format!(" @ {}", item);
+        }
+        text += &format!("\nAbility: {}", self.ability);
+        if self.level != 100 {
+            text += &format!("\nLevel: {}", self.level);
+        }
+        if let Some(evs) = spread_string(self.evs.0, 0) {
+            text += &format!("\nEVs: {}", evs);
+        }
+        text += &format!("\n{} Nature", self.nature);
+        if let Some(ivs) = spread_string(self.ivs.0, 31) {
+            text += &format!("\nIVs: {}", ivs);
+        }
+        for m in &self.moves {
+            text += &format!("\n- {}", m.name);
+        }
+        text + "\n"
+    }
+}
+
+/// Gives the name Showdown uses for a species, without any forme. That's the name of the enum variant,
+/// except for the species whose names have punctuation or spaces, which are spelled out here.
+fn showdown_species_name(species: SpeciesDiscriminant) -> String {
+    match species {
+        SpeciesDiscriminant::NidoranF => "Nidoran-F",
+        SpeciesDiscriminant::NidoranM => "Nidoran-M",
+        SpeciesDiscriminant::Farfetchd => "Farfetch’d",
+        SpeciesDiscriminant::MrMime => "Mr. Mime",
+        SpeciesDiscriminant::HoOh => "Ho-Oh",
+        SpeciesDiscriminant::MimeJr => "Mime Jr.",
+        SpeciesDiscriminant::PorygonZ => "Porygon-Z",
+        SpeciesDiscriminant::Flabebe => "Flabébé",
+        SpeciesDiscriminant::TypeNull => "Type: Null",
+        SpeciesDiscriminant::JangmoO => "Jangmo-o",
+        SpeciesDiscriminant::HakamoO => "Hakamo-o",
+        SpeciesDiscriminant::KommoO => "Kommo-o",
+        SpeciesDiscriminant::TapuKoko => "Tapu Koko",
+        SpeciesDiscriminant::TapuLele => "Tapu Lele",
+        SpeciesDiscriminant::TapuBulu => "Tapu Bulu",
+        SpeciesDiscriminant::TapuFini => "Tapu Fini",
+        _ => return format!("{:?}", species),
+    }.to_string()
+}
+
+/// Gives the name Showdown uses for a species and forme, like "Landorus-Therian". Default formes are
+/// just the species name.
+fn showdown_name(species: Species) -> String {
+    let name = showdown_species_name(SpeciesDiscriminant::from(species));
+    if !species.has_forme() || Species::from(SpeciesDiscriminant::from(species)) == species {
+        return name;
+    }
+    let forme = match forme_name(species).as_str() {
+        "TenPercent" => "10%".to_string(),
+        "FiftyPercent" => "50%".to_string(),
+        "ZenMode" => "Zen".to_string(),
+        "Sword" => "Blade".to_string(),
+        "BattleBond" => "Bond".to_string(),
+        "MegaX" => "Mega-X".to_string(),
+        "MegaY" => "Mega-Y".to_string(),
+        "PomPom" => "Pom-Pom".to_string(),
+        "Pau" => "Pa'u".to_string(),
+        "DuskMane" => "Dusk-Mane".to_string(),
+        "DuskWings" => "Dawn-Wings".to_string(),
+        other => other.to_string(),
+    };
+    format!("{}-{}", name, forme)
+}
+
+/// Gives the name of a species' forme as the forme enum displays it, or an empty string if the species
+/// doesn't have formes.
+fn forme_name(species: Species) -> String {
+    match species {
+        Species::Venusaur(forme) => forme.to_string(),
+        Species::Charizard(forme) => forme.to_string(),
+        Species::Blastoise(forme) => forme.to_string(),
+        Species::Beedrill(forme) => forme.to_string(),
+        Species::Pidgeot(forme) => forme.to_string(),
+        Species::Rattata(forme) => forme.to_string(),
+        Species::Raticate(forme) => forme.to_string(),
+        Species::Raichu(forme) => forme.to_string(),
+        Species::Sandshrew(forme) => forme.to_string(),
+        Species::Sandslash(forme) => forme.to_string(),
+        Species::Vulpix(forme) => forme.to_string(),
+        Species::Ninetales(forme) => forme.to_string(),
+        Species::Diglett(forme) => forme.to_string(),
+        Species::Dugtrio(forme) => forme.to_string(),
+        Species::Persian(forme) => forme.to_string(),
+        Species::Alakazam(forme) => forme.to_string(),
+        Species::Geodude(forme) => forme.to_string(),
+        Species::Graveler(forme) => forme.to_string(),
+        Species::Golem(forme) => forme.to_string(),
+        Species::Grimer(forme) => forme.to_string(),
+        Species::Muk(forme) => forme.to_string(),
+        Species::Gengar(forme) => forme.to_string(),
+        Species::Exeggutor(forme) => forme.to_string(),
+        Species::Marowak(forme) => forme.to_string(),
+        Species::Kangaskhan(forme) => forme.to_string(),
+        Species::Pinsir(forme) => forme.to_string(),
+        Species::Gyarados(forme) => forme.to_string(),
+        Species::Aerodactyl(forme) => forme.to_string(),
+        Species::Mewtwo(forme) => forme.to_string(),
+        Species::Steelix(forme) => forme.to_string(),
+        Species::Scizor(forme) => forme.to_string(),
+        Species::Heracross(forme) => forme.to_string(),
+        Species::Houndoom(forme) => forme.to_string(),
+        Species::Tyranitar(forme) => forme.to_string(),
+        Species::Sceptile(forme) => forme.to_string(),
+        Species::Blaziken(forme) => forme.to_string(),
+        Species::Swampert(forme) => forme.to_string(),
+        Species::Gardevoir(forme) => forme.to_string(),
+        Species::Sableye(forme) => forme.to_string(),
+        Species::Mawile(forme) => forme.to_string(),
+        Species::Aggron(forme) => forme.to_string(),
+        Species::Medicham(forme) => forme.to_string(),
+        Species::Manectric(forme) => forme.to_string(),
+        Species::Sharpedo(forme) => forme.to_string(),
+        Species::Camerupt(forme) => forme.to_string(),
+        Species::Altaria(forme) => forme.to_string(),
+        Species::Castform(forme) => forme.to_string(),
+        Species::Salamence(forme) => forme.to_string(),
+        Species::Metagross(forme) => forme.to_string(),
+        Species::Kyogre(forme) => forme.to_string(),
+        Species::Groudon(forme) => forme.to_string(),
+        Species::Rayquaza(forme) => forme.to_string(),
+        Species::Deoxys(forme) => forme.to_string(),
+        Species::Wormadam(forme) => forme.to_string(),
+        Species::Lopunny(forme) => forme.to_string(),
+        Species::Garchomp(forme) => forme.to_string(),
+        Species::Lucario(forme) => forme.to_string(),
+        Species::Abomasnow(forme) => forme.to_string(),
+        Species::Gallade(forme) => forme.to_string(),
+        Species::Rotom(forme) => forme.to_string(),
+        Species::Giratina(forme) => forme.to_string(),
+        Species::Shaymin(forme) => forme.to_string(),
+        Species::Arceus(forme) => forme.to_string(),
+        Species::Darmanitan(forme) => forme.to_string(),
+        Species::Tornadus(forme) => forme.to_string(),
+        Species::Thundurus(forme) => forme.to_string(),
+        Species::Landorus(forme) => forme.to_string(),
+        Species::Kyurem(forme) => forme.to_string(),
+        Species::Meloetta(forme) => forme.to_string(),
+        Species::Greninja(forme) => forme.to_string(),
+        Species::Aegislash(forme) => forme.to_string(),
+        Species::Pumpkaboo(forme) => forme.to_string(),
+        Species::Gourgeist(forme) => forme.to_string(),
+        Species::Zygarde(forme) => forme.to_string(),
+        Species::Diancie(forme) => forme.to_string(),
+        Species::Hoopa(forme) => forme.to_string(),
+        Species::Oricorio(forme) => forme.to_string(),
+        Species::Lycanroc(forme) => forme.to_string(),
+        Species::Wishiwashi(forme) => forme.to_string(),
+        Species::TypeNull(forme) => forme.to_string(),
+        Species::Silvally(forme) => forme.to_string(),
+        Species::Minior(forme) => forme.to_string(),
+        Species::Mimikyu(forme) => forme.to_string(),
+        Species::Necrozma(forme) => forme.to_string(),
+        _ => String::new(),
+    }
+}
+
+/// Writes an EV or IV spread like "252 HP / 4 Def / 252 Spe", leaving out stats with the default
+/// value. Returns None if every stat has the default value.
+fn spread_string(values: [u8; 6], default: u8) -> Option<String> {
+    let stats = [Stat::HP, Stat::Atk, Stat::Def, Stat::SpA, Stat::SpD, Stat::Spe];
+    let parts: Vec<String> = stats.iter()
+        .zip(values.iter())
+        .filter(|&(_, &value)| value != default)
+        .map(|(stat, value)| format!("{} {}", value, stat.abbreviation()))
+        .collect();
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join(" / "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+    use crate::r#move::Move;
+
+    #[test]
+    fn test_landorus_paste() {
+        let set = from_showdown("Landorus-Therian (M) @ Rocky Helmet
+Ability: Intimidate
+EVs: 252 HP / 216 Def / 40 Spe
+Impish Nature
+- Stealth Rock
+- Earthquake
+- U-turn
+- Toxic
+").unwrap();
+        assert_eq!(set.species, Species::Landorus(GenieForme::Therian));
+        assert_eq!(set.item, Some(Item::RockyHelmet));
+        assert_eq!(set.ability, Ability::Intimidate);
+        assert_eq!(set.nature, Nature::Impish);
+        assert_eq!(set.evs, EVs([252, 0, 216, 0, 0, 40]));
+        assert_eq!(set.ivs, IVs::perfect());
+        assert_eq!(set.level, 100);
+        let moves: Vec<&str> = set.moves.iter().map(|m| m.name()).collect();
+        assert_eq!(moves, vec!["Stealth Rock", "Earthquake", "U-turn", "Toxic"]);
+    }
+    #[test]
+    fn test_nickname_and_defaults() {
+        let set = from_showdown("Lando (Landorus-Therian) @ Choice Scarf
+IVs: 0 Atk
+Level: 50").unwrap();
+        assert_eq!(set.species, Species::Landorus(GenieForme::Therian));
+        assert_eq!(set.item, Some(Item::ChoiceScarf));
+        assert_eq!(set.ability, Ability::Intimidate);
+        assert_eq!(set.nature, Nature::Serious);
+        assert_eq!(set.evs, EVs::default());
+        assert_eq!(set.ivs, IVs([31, 0, 31, 31, 31, 31]));
+        assert_eq!(set.level, 50);
+
+        let set = from_showdown("Garchomp").unwrap();
+        assert_eq!(set.species, Species::Garchomp(MegaEvolution::Normal));
+        assert_eq!(set.item, None);
+        assert_eq!(set.ability, Ability::SandVeil);
+        assert!(set.moves.is_empty());
+    }
+    #[test]
+    fn test_species_names() {
+        assert_eq!(parse_species("Charizard-Mega-X"), Some(Species::Charizard(XYMegaEvolution::MegaX)));
+        assert_eq!(parse_species("Kommo-o"), Some(Species::KommoO));
+        assert_eq!(parse_species("Ho-Oh"), Some(Species::HoOh));
+        assert_eq!(parse_species("Porygon-Z"), Some(Species::PorygonZ));
+        assert_eq!(parse_species("Aegislash-Blade"), Some(Species::Aegislash(AegislashForme::Sword)));
+        assert_eq!(parse_species("Zygarde-10%"), Some(Species::Zygarde(ZygardeForme::TenPercent)));
+        assert_eq!(parse_species("Oricorio-Pa'u"), Some(Species::Oricorio(OricorioForme::Pau)));
+        assert_eq!(parse_species("Gastrodon-East"), Some(Species::Gastrodon));
+        assert_eq!(parse_species("Landorus-Foo"), None);
+        let names = [(Species::NidoranF, "Nidoran-F"), (Species::NidoranM, "Nidoran-M"),
+                     (Species::Farfetchd, "Farfetch’d"), (Species::MrMime, "Mr. Mime"),
+                     (Species::HoOh, "Ho-Oh"), (Species::MimeJr, "Mime Jr."),
+                     (Species::PorygonZ, "Porygon-Z"), (Species::Flabebe, "Flabébé"),
+                     (Species::TypeNull(SilvallyForme::Normal), "Type: Null"),
+                     (Species::JangmoO, "Jangmo-o"), (Species::HakamoO, "Hakamo-o"),
+                     (Species::KommoO, "Kommo-o"), (Species::TapuKoko, "Tapu Koko"),
+                     (Species::TapuLele, "Tapu Lele"), (Species::TapuBulu, "Tapu Bulu"),
+                     (Species::TapuFini, "Tapu Fini")];
+        for &(species, name) in names.iter() {
+            assert_eq!(showdown_name(species), name);
+            assert_eq!(parse_species(name), Some(species));
+        }
+        assert_eq!(parse_species("Nidoran♀"), Some(Species::NidoranF));
+        assert_eq!(parse_species("Nidoran♂"), Some(Species::NidoranM));
+        assert_eq!(showdown_name(Species::Garchomp(MegaEvolution::Normal)), "Garchomp");
+    }
+    #[test]
+    fn test_errors() {
+        assert_eq!(from_showdown(""), Err(ParseError::MissingSpecies));
+        assert_eq!(from_showdown("Missingno"), Err(ParseError::UnknownSpecies("Missingno".to_string())));
+        assert_eq!(from_showdown("Garchomp @ Rare Candy"),
+                   Err(ParseError::UnknownItem("Rare Candy".to_string())));
+        assert_eq!(from_showdown("Garchomp\nEVs: 252 Attack Stat"),
+                   Err(ParseError::InvalidEVs("252 Attack Stat".to_string())));
+    }
+    #[test]
+    fn test_ev_strings() {
+        let evs = parse_ev_string("252 Atk / 4 Def / 252 Spe").unwrap();
+        assert_eq!(evs, EVs([0, 252, 4, 0, 0, 252]));
+        assert_eq!(to_ev_string(evs), "252 Atk / 4 Def / 252 Spe");
+        assert_eq!(to_ev_string(EVs::default()), "");
+        assert_eq!(parse_iv_string("0 Atk").unwrap(), IVs([31, 0, 31, 31, 31, 31]));
+        assert_eq!(to_iv_string(IVs::perfect()), "");
+    }
+    #[test]
+    fn test_repeated_stat() {
+        assert_eq!(parse_ev_string("252 Atk / 4 Atk"),
+                   Err(ParseError::InvalidEVs("252 Atk / 4 Atk".to_string())));
+        assert_eq!(parse_iv_string("0 Spe / 0 Spe"), Err(ParseError::InvalidIVs("0 Spe / 0 Spe".to_string())));
+    }
+    #[test]
+    fn test_round_trip() {
+        let paste = "Landorus-Therian @ Rocky Helmet
+Ability: Intimidate
+Level: 50
+EVs: 252 HP / 216 Def / 40 Spe
+Impish Nature
+IVs: 30 SpA / 30 Spe
+- Stealth Rock
+- Earthquake
+- U-turn
+- Toxic
+";
+        let set = from_showdown(paste).unwrap();
+        assert_eq!(set.to_showdown(), paste);
+        assert_eq!(from_showdown(&set.to_showdown()), Ok(set));
+
+        for name in &["Charizard-Mega-X", "Necrozma-Dawn-Wings", "Zygarde-10%", "Oricorio-Pa'u",
+                      "Darmanitan-Zen", "Aegislash-Blade", "Nidoran-F", "Type: Null", "Tapu Koko"] {
+            let set = from_showdown(name).unwrap();
+            assert_eq!(from_showdown(&set.to_showdown()), Ok(set.clone()));
+            assert!(set.to_showdown().starts_with(name));
+        }
+    }
+    #[test]
+    fn test_default_ivs_omitted() {
+        let set = from_showdown("Garchomp @ Choice Scarf
+Ability: Rough Skin
+EVs: 252 Atk / 4 SpD / 252 Spe
+Jolly Nature
+- Earthquake
+- Outrage").unwrap();
+        let text = set.to_showdown();
+        assert!(!text.contains("IVs:"));
+        assert!(!text.contains("Level:"));
+        assert!(text.contains("EVs: 252 Atk / 4 SpD / 252 Spe"));
+        assert!(text.starts_with("Garchomp @ Choice Scarf\nAbility: Rough Skin\n"));
+    }
+}
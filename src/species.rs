@@ -27,15 +27,21 @@
 //! it's coded as a property of the move Techno Blast, which is the only effect besides
 //! appearance. Thus, Genesect does not have specific formes in this library.
 
+use std::cmp::Ordering;
 use std::fmt;
 
+use strum::IntoEnumIterator;
+
+use crate::typing::Typing;
+
 /// The Castform formes: Normal, Sunny, Rainy, and Snowy. These don't change stats, but they do
 /// change typing to the one resembling the weather: Normal, Fire, Water, and Ice. This would be a
 /// super cool game mechanic if Castform's stats were at all usable in competitive play: as it stands
 /// this is not going to ever be used, and it mainly tests how comfortable you are with camel case
 /// (bet you're glad I have the extra e in forme!)
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum CastformForme {
+    #[default]
     Normal,
     Sunny,
     Rainy,
@@ -46,8 +52,9 @@ pub enum CastformForme {
 /// mechanic. These *are* competitively relevant, unlike Castform: Deoxys-Speed has the highest Speed
 /// stat in the game, Deoxys-Attack has a bonkers 180 Atk and SpA, and Deoxys-Defense has 160 Def and
 /// SpD.
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum DeoxysForme {
+    #[default]
     Normal,
     Attack,
     Defense,
@@ -57,8 +64,9 @@ pub enum DeoxysForme {
 /// The Wormadam formes. The Burmy formes that determine a Wormadam's forme upon evolution are purely
 /// cosmetic: these, however, impact typing and move compatiblity. Compared to Deoxys, Trash is
 /// probably a pretty good descriptor; nonetheless, here they are.
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum WormadamForme {
+    #[default]
     Plant,
     Sandy,
     Trash
@@ -67,9 +75,10 @@ pub enum WormadamForme {
 /// The Rotom formes. These change typing, and stats between the "ghost" normal forme and the ones
 /// after Rotom possesses an appliance, but the choice of appliance doesn't affect base stats. It
 /// does, however, affect typing and move compatibility.
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum RotomForme {
     /// The unevolved form of Rotom. It's just called "Rotom", so Ghost is used as a name.
+    #[default]
     Ghost,
     Heat,
     Wash,
@@ -81,8 +90,9 @@ pub enum RotomForme {
 /// The Giratina formes. There are two: the Altered forme has 100 attacking stats and 120 defensive
 /// stats, and the Origin forme switches them. Their abilities also differ (Pressure/Telepathy and
 /// Levitate respectively).
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum GiratinaForme {
+    #[default]
     Altered,
     Origin
 }
@@ -92,8 +102,9 @@ pub enum GiratinaForme {
 /// itself. As such, there's one forme for each typing. An interesting small but important detail is
 /// that Arceus-Dragon can learn Draco Meteor, which no other Arceus forme can. This doesn't matter
 /// competitively, as you could just switch the plates after, but it's good to know.
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum ArceusForme {
+    #[default]
     Normal,
     Fire,
     Water,
@@ -116,8 +127,9 @@ pub enum ArceusForme {
 
 /// The Darminitan formes. In a mechanic that will become very common, Darmanitan switches to Zen Mode
 /// if its HP is below half at the end of a turn. It changes typing and stats.
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum DarmanitanForme {
+    #[default]
     Standard,
     /// Represented as "Zen Mode" in-game.
     ZenMode
@@ -126,9 +138,10 @@ pub enum DarmanitanForme {
 /// The Kyurem formes. These are important: they change some learned moves and base
 /// stats. Kyurem-White is a specially-speced upgrade from normal Kyurem, and Kyurem-Black is a
 /// physically-speced version of normal Kyurem.
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum KyuremForme {
     /// In the game this is just "Kyurem", so Normal is arbitrary.
+    #[default]
     Normal,
     Black,
     White
@@ -137,8 +150,9 @@ pub enum KyuremForme {
 /// The Meloetta formes. These are unique in that Meloetta switches between them by using Relic Song
 /// in battle: out of battle and at the start of battles, it is in Meloetta-Aria forme. These change
 /// typing and stats.
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum MeloettaForme {
+    #[default]
     Aria,
     Pirouette
 }
@@ -151,9 +165,10 @@ pub enum MeloettaForme {
 /// there are three formes of Greninja: Protean/Torrent, pre-bond, and Greninja-Ash. The nomenclature
 /// for this isn't well-defined in the main games, so in this library Greninja-Normal is Torrent or
 /// Protean, BattleBond is pre-bond Greninja with Battle Bond, and Ash is Greninja-Ash.
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum GreninjaForme {
     /// A Greninja without Battle Bond as an ability.
+    #[default]
     Normal,
     /// A Greninja with the ability Battle Bond, but not actually transformed into Greninja-Ash. These
     /// are always male and cannot breed, but otherwise have the same typing, movepool, and stats as
@@ -167,9 +182,10 @@ pub enum GreninjaForme {
 /// technically true that Gourgeist-Super and Gourgeist-Small can't learn Insomnia natively,
 /// Gourgeist-Small can by breeding and an event Gourgeist-Super has Insomnia, so in competitive play
 /// that difference doesn't matter.
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum GourgeistForme {
     Small,
+    #[default]
     Average,
     Large,
     Super
@@ -181,9 +197,10 @@ pub enum GourgeistForme {
 /// HP of Zygarde-Complete, means that the HP will increase considerably). The different formes have
 /// different base stats but not different typing or movepool. Because 10% and 50% are not valid
 /// identifiers, `TenPercent` and `FiftyPercent` are used instead.
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum ZygardeForme {
     TenPercent,
+    #[default]
     FiftyPercent,
     Complete
 }
@@ -191,8 +208,9 @@ pub enum ZygardeForme {
 
 /// The Hoopa formes. These are completely different Pokemon, with different stats, movepool, typing,
 /// and appearance.
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum HoopaForme {
+    #[default]
     Confined,
     Unbound
 }
@@ -201,9 +219,10 @@ pub enum HoopaForme {
 /// The Oricorio formes. These change Oricorio's typing, which is also important because Revelation
 /// Dance uses the user's primary type. Because `Pom-Pom` and `Pa'u` are not valid identifiers,
 /// `PomPom` and `Pau` are used instead.
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum OricorioForme {
     /// The Fire-type Oricorio.
+    #[default]
     Baile,
     /// The Electric-type Oricorio, which is displayed, "Pom-Pom" in-game.
     PomPom,
@@ -214,8 +233,9 @@ pub enum OricorioForme {
 }
 
 /// The Lycanroc formes. These change movepool, ability, and base stats.
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum LycanrocForme {
+    #[default]
     Midday,
     Midnight,
     Dusk
@@ -224,16 +244,18 @@ pub enum LycanrocForme {
 
 /// The Wishiwashi formes. Wishiwashi starts out in School forme, changing to Solo forme when its HP
 /// reaches 25% of its maximum HP at the end of a turn. Base stats are the only difference.
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum WishiwashiForme {
+    #[default]
     School,
     Solo
 }
 
 /// The Shaymin formes. These have different typings, stats, movepools, and abilities: it's easier to
 /// think of them as different Pokemon.
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum ShayminForme {    
+    #[default]
     Land,
     Sky
 }
@@ -242,8 +264,9 @@ pub enum ShayminForme {
 /// The Silvally (and Type: Null) formes. These mirror Arceus formes in depending on held item and
 /// affecting a single attack, Multi-Attack instead of Judgment. As such, it has the exact same values
 /// as `ArceusForme`.
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum SilvallyForme {
+    #[default]
     Normal,
     Fire,
     Water,
@@ -268,8 +291,9 @@ pub enum SilvallyForme {
 /// The Minior formes. These behave like Wishiwashi's formes, only with the cutoff at 50% of max
 /// HP. There are also different Core colors, but those aren't competitively relevant. These formes
 /// have different base stats, and Meteor Form Minior has status immunity due to Shields Down.
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum MiniorForme {
+    #[default]
     Meteor,
     Core
 }
@@ -278,8 +302,9 @@ pub enum MiniorForme {
 /// The Mimikyu formes. Mimikyu has its disguise at the start of battle, and upon being hit by any
 /// attack loses it and turns into Mimikyu-Busted, not taking any damage. There are no other changes:
 /// it is just a flag for whether Disguise is still active.
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum MimikyuForme {
+    #[default]
     Disguised,
     Busted
 }
@@ -289,9 +314,10 @@ pub enum MimikyuForme {
 /// completely, while Ultra Necrozma is an evolution in-battle using Ultranecrozmium Z. These each
 /// differ in stats, typing, and ability. Due to hyphens not being allowed in identifiers, they have
 /// been elided.
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum NecrozmaForme {
     /// The standard Necrozma found in Sun and Moon.
+    #[default]
     Normal,
     /// The Solgaleo form found in Ultra Sun, written "Dusk Mane" in game.
     DuskMane,
@@ -303,8 +329,9 @@ pub enum NecrozmaForme {
 
 /// The Alola formes. These change typing, ability, base stats, and movepool, and are basically
 /// completely different Pokemon.
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum AlolaForme {
+    #[default]
     Normal,
     Alola
 }
@@ -314,8 +341,9 @@ pub enum AlolaForme {
 /// some special Pokemon have both an X and Y Mega Evolution, and those have a separate enumerated
 /// type. These don't change HP, but they add 100 total points in base stats and can change typing
 /// and ability.
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum MegaEvolution {
+    #[default]
     Normal,
     Mega
 }
@@ -323,8 +351,9 @@ pub enum MegaEvolution {
 
 /// The rarer kind of Mega Evolution, one where there is both an X and Y evolution. The only Pokemon
 /// with this are Charizard and Mewtwo.
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum XYMegaEvolution {
+    #[default]
     Normal,
     /// This is represented as "Mega-X" in-game.
     MegaX,
@@ -336,8 +365,9 @@ pub enum XYMegaEvolution {
 /// The Primal Reversions, Groudon and Kyogre formes that have different abilities, typings, and
 /// stats. A held item causes the switch on switch-in, and it can happen multiple times unlike Mega
 /// Evolutions.
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum PrimalReversion {
+    #[default]
     Normal,
     Primal
 }
@@ -345,8 +375,9 @@ pub enum PrimalReversion {
 /// The Genie formes: Incarnate and Therian. These are closer to different Pokemon than different
 /// formes, with differing typing, ability, stats, and movepool. These affect Thundurus, Tornadus, and
 /// Landorus.
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum GenieForme {
+    #[default]
     Incarnate,
     Therian
 }
@@ -354,15 +385,13 @@ pub enum GenieForme {
 
 /// The Aegislash formes. Aegislash uses the Sword form to attack, and the Shield forme to defend:
 /// using different types of moves toggles the two.
-#[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
+#[derive(Copy, Clone, Debug, Default, Display, PartialEq, Eq, Hash, EnumString, EnumIter)]
 pub enum AegislashForme {
     Sword,
+    #[default]
     Shield
 }
 
-
-
-
 /// A Pokemon species. Note that, because Rust doesn't allow it, what would be `Nidoran♀` is
 /// `NidoranF` and what would be `Nidoran♂` is `NidoranM`. All other punctuation has also been
 /// removed, and the result is camel-cased, with hyphens denoting word boundaries. For example,
@@ -1040,7 +1069,7 @@ pub enum Species {
     Landorus(GenieForme),
     Kyurem(KyuremForme),
     Keldeo,
-    Meloetta(KyuremForme),
+    Meloetta(MeloettaForme),
     Genesect,
     // gen 6 starts here
     Chespin,
@@ -1311,6 +1340,1048 @@ impl Species {
     // TODO: implement generic "get string of underlying forme" using Box?
 }
 
+impl From<SpeciesDiscriminant> for Species {
+    /// Gives the default forme of a species: the one it's in outside of battle, or the one it's listed
+    /// as without qualification. This is almost always the first forme, but Aegislash defaults to
+    /// Shield forme, Zygarde to 50% forme, and Pumpkaboo and Gourgeist to Average size.
+    fn from(species: SpeciesDiscriminant) -> Species {
+        match species {
+            // in National Pokedex order
+            SpeciesDiscriminant::Bulbasaur => Species::Bulbasaur,
+            SpeciesDiscriminant::Ivysaur => Species::Ivysaur,
+            SpeciesDiscriminant::Venusaur => Species::Venusaur(MegaEvolution::Normal),
+            SpeciesDiscriminant::Charmander => Species::Charmander,
+            SpeciesDiscriminant::Charmeleon => Species::Charmeleon,
+            SpeciesDiscriminant::Charizard => Species::Charizard(XYMegaEvolution::Normal),
+            SpeciesDiscriminant::Squirtle => Species::Squirtle,
+            SpeciesDiscriminant::Wartortle => Species::Wartortle,
+            SpeciesDiscriminant::Blastoise => Species::Blastoise(MegaEvolution::Normal),
+            SpeciesDiscriminant::Caterpie => Species::Caterpie,
+            SpeciesDiscriminant::Metapod => Species::Metapod,
+            SpeciesDiscriminant::Butterfree => Species::Butterfree,
+            SpeciesDiscriminant::Weedle => Species::Weedle,
+            SpeciesDiscriminant::Kakuna => Species::Kakuna,
+            SpeciesDiscriminant::Beedrill => Species::Beedrill(MegaEvolution::Normal),
+            SpeciesDiscriminant::Pidgey => Species::Pidgey,
+            SpeciesDiscriminant::Pidgeotto => Species::Pidgeotto,
+            SpeciesDiscriminant::Pidgeot => Species::Pidgeot(MegaEvolution::Normal),
+            SpeciesDiscriminant::Rattata => Species::Rattata(AlolaForme::Normal),
+            SpeciesDiscriminant::Raticate => Species::Raticate(AlolaForme::Normal),
+            SpeciesDiscriminant::Spearow => Species::Spearow,
+            SpeciesDiscriminant::Fearow => Species::Fearow,
+            SpeciesDiscriminant::Ekans => Species::Ekans,
+            SpeciesDiscriminant::Arbok => Species::Arbok,
+            SpeciesDiscriminant::Pikachu => Species::Pikachu,
+            SpeciesDiscriminant::Raichu => Species::Raichu(AlolaForme::Normal),
+            SpeciesDiscriminant::Sandshrew => Species::Sandshrew(AlolaForme::Normal),
+            SpeciesDiscriminant::Sandslash => Species::Sandslash(AlolaForme::Normal),
+            SpeciesDiscriminant::NidoranF => Species::NidoranF,
+            SpeciesDiscriminant::Nidorina => Species::Nidorina,
+            SpeciesDiscriminant::Nidoqueen => Species::Nidoqueen,
+            SpeciesDiscriminant::NidoranM => Species::NidoranM,
+            SpeciesDiscriminant::Nidorino => Species::Nidorino,
+            SpeciesDiscriminant::Nidoking => Species::Nidoking,
+            SpeciesDiscriminant::Clefairy => Species::Clefairy,
+            SpeciesDiscriminant::Clefable => Species::Clefable,
+            SpeciesDiscriminant::Vulpix => Species::Vulpix(AlolaForme::Normal),
+            SpeciesDiscriminant::Ninetales => Species::Ninetales(AlolaForme::Normal),
+            SpeciesDiscriminant::Jigglypuff => Species::Jigglypuff,
+            SpeciesDiscriminant::Wigglytuff => Species::Wigglytuff,
+            SpeciesDiscriminant::Zubat => Species::Zubat,
+            SpeciesDiscriminant::Golbat => Species::Golbat,
+            SpeciesDiscriminant::Oddish => Species::Oddish,
+            SpeciesDiscriminant::Gloom => Species::Gloom,
+            SpeciesDiscriminant::Vileplume => Species::Vileplume,
+            SpeciesDiscriminant::Paras => Species::Paras,
+            SpeciesDiscriminant::Parasect => Species::Parasect,
+            SpeciesDiscriminant::Venonat => Species::Venonat,
+            SpeciesDiscriminant::Venomoth => Species::Venomoth,
+            SpeciesDiscriminant::Diglett => Species::Diglett(AlolaForme::Normal),
+            SpeciesDiscriminant::Dugtrio => Species::Dugtrio(AlolaForme::Normal),
+            SpeciesDiscriminant::Meowth => Species::Meowth,
+            SpeciesDiscriminant::Persian => Species::Persian(AlolaForme::Normal),
+            SpeciesDiscriminant::Psyduck => Species::Psyduck,
+            SpeciesDiscriminant::Golduck => Species::Golduck,
+            SpeciesDiscriminant::Mankey => Species::Mankey,
+            SpeciesDiscriminant::Primeape => Species::Primeape,
+            SpeciesDiscriminant::Growlithe => Species::Growlithe,
+            SpeciesDiscriminant::Arcanine => Species::Arcanine,
+            SpeciesDiscriminant::Poliwag => Species::Poliwag,
+            SpeciesDiscriminant::Poliwhirl => Species::Poliwhirl,
+            SpeciesDiscriminant::Poliwrath => Species::Poliwrath,
+            SpeciesDiscriminant::Abra => Species::Abra,
+            SpeciesDiscriminant::Kadabra => Species::Kadabra,
+            SpeciesDiscriminant::Alakazam => Species::Alakazam(MegaEvolution::Normal),
+            SpeciesDiscriminant::Machop => Species::Machop,
+            SpeciesDiscriminant::Machoke => Species::Machoke,
+            SpeciesDiscriminant::Machamp => Species::Machamp,
+            SpeciesDiscriminant::Bellsprout => Species::Bellsprout,
+            SpeciesDiscriminant::Weepinbell => Species::Weepinbell,
+            SpeciesDiscriminant::Victreebel => Species::Victreebel,
+            SpeciesDiscriminant::Tentacool => Species::Tentacool,
+            SpeciesDiscriminant::Tentacruel => Species::Tentacruel,
+            SpeciesDiscriminant::Geodude => Species::Geodude(AlolaForme::Normal),
+            SpeciesDiscriminant::Graveler => Species::Graveler(AlolaForme::Normal),
+            SpeciesDiscriminant::Golem => Species::Golem(AlolaForme::Normal),
+            SpeciesDiscriminant::Ponyta => Species::Ponyta,
+            SpeciesDiscriminant::Rapidash => Species::Rapidash,
+            SpeciesDiscriminant::Slowpoke => Species::Slowpoke,
+            SpeciesDiscriminant::Slowbro => Species::Slowbro,
+            SpeciesDiscriminant::Magnemite => Species::Magnemite,
+            SpeciesDiscriminant::Magneton => Species::Magneton,
+            SpeciesDiscriminant::Farfetchd => Species::Farfetchd,
+            SpeciesDiscriminant::Doduo => Species::Doduo,
+            SpeciesDiscriminant::Dodrio => Species::Dodrio,
+            SpeciesDiscriminant::Seel => Species::Seel,
+            SpeciesDiscriminant::Dewgong => Species::Dewgong,
+            SpeciesDiscriminant::Grimer => Species::Grimer(AlolaForme::Normal),
+            SpeciesDiscriminant::Muk => Species::Muk(AlolaForme::Normal),
+            SpeciesDiscriminant::Shellder => Species::Shellder,
+            SpeciesDiscriminant::Cloyster => Species::Cloyster,
+            SpeciesDiscriminant::Gastly => Species::Gastly,
+            SpeciesDiscriminant::Haunter => Species::Haunter,
+            SpeciesDiscriminant::Gengar => Species::Gengar(MegaEvolution::Normal),
+            SpeciesDiscriminant::Onix => Species::Onix,
+            SpeciesDiscriminant::Drowzee => Species::Drowzee,
+            SpeciesDiscriminant::Hypno => Species::Hypno,
+            SpeciesDiscriminant::Krabby => Species::Krabby,
+            SpeciesDiscriminant::Kingler => Species::Kingler,
+            SpeciesDiscriminant::Voltorb => Species::Voltorb,
+            SpeciesDiscriminant::Electrode => Species::Electrode,
+            SpeciesDiscriminant::Exeggcute => Species::Exeggcute,
+            SpeciesDiscriminant::Exeggutor => Species::Exeggutor(AlolaForme::Normal),
+            SpeciesDiscriminant::Cubone => Species::Cubone,
+            SpeciesDiscriminant::Marowak => Species::Marowak(AlolaForme::Normal),
+            SpeciesDiscriminant::Hitmonlee => Species::Hitmonlee,
+            SpeciesDiscriminant::Hitmonchan => Species::Hitmonchan,
+            SpeciesDiscriminant::Lickitung => Species::Lickitung,
+            SpeciesDiscriminant::Koffing => Species::Koffing,
+            SpeciesDiscriminant::Weezing => Species::Weezing,
+            SpeciesDiscriminant::Rhyhorn => Species::Rhyhorn,
+            SpeciesDiscriminant::Rhydon => Species::Rhydon,
+            SpeciesDiscriminant::Chansey => Species::Chansey,
+            SpeciesDiscriminant::Tangela => Species::Tangela,
+            SpeciesDiscriminant::Kangaskhan => Species::Kangaskhan(MegaEvolution::Normal),
+            SpeciesDiscriminant::Horsea => Species::Horsea,
+            SpeciesDiscriminant::Seadra => Species::Seadra,
+            SpeciesDiscriminant::Goldeen => Species::Goldeen,
+            SpeciesDiscriminant::Seaking => Species::Seaking,
+            SpeciesDiscriminant::Staryu => Species::Staryu,
+            SpeciesDiscriminant::Starmie => Species::Starmie,
+            SpeciesDiscriminant::MrMime => Species::MrMime,
+            SpeciesDiscriminant::Scyther => Species::Scyther,
+            SpeciesDiscriminant::Jynx => Species::Jynx,
+            SpeciesDiscriminant::Electabuzz => Species::Electabuzz,
+            SpeciesDiscriminant::Magmar => Species::Magmar,
+            SpeciesDiscriminant::Pinsir => Species::Pinsir(MegaEvolution::Normal),
+            SpeciesDiscriminant::Tauros => Species::Tauros,
+            SpeciesDiscriminant::Magikarp => Species::Magikarp,
+            SpeciesDiscriminant::Gyarados => Species::Gyarados(MegaEvolution::Normal),
+            SpeciesDiscriminant::Lapras => Species::Lapras,
+            SpeciesDiscriminant::Ditto => Species::Ditto,
+            SpeciesDiscriminant::Eevee => Species::Eevee,
+            SpeciesDiscriminant::Vaporeon => Species::Vaporeon,
+            SpeciesDiscriminant::Jolteon => Species::Jolteon,
+            SpeciesDiscriminant::Flareon => Species::Flareon,
+            SpeciesDiscriminant::Porygon => Species::Porygon,
+            SpeciesDiscriminant::Omanyte => Species::Omanyte,
+            SpeciesDiscriminant::Omastar => Species::Omastar,
+            SpeciesDiscriminant::Kabuto => Species::Kabuto,
+            SpeciesDiscriminant::Kabutops => Species::Kabutops,
+            SpeciesDiscriminant::Aerodactyl => Species::Aerodactyl(MegaEvolution::Normal),
+            SpeciesDiscriminant::Snorlax => Species::Snorlax,
+            SpeciesDiscriminant::Articuno => Species::Articuno,
+            SpeciesDiscriminant::Zapdos => Species::Zapdos,
+            SpeciesDiscriminant::Moltres => Species::Moltres,
+            SpeciesDiscriminant::Dratini => Species::Dratini,
+            SpeciesDiscriminant::Dragonair => Species::Dragonair,
+            SpeciesDiscriminant::Dragonite => Species::Dragonite,
+            SpeciesDiscriminant::Mewtwo => Species::Mewtwo(XYMegaEvolution::Normal),
+            SpeciesDiscriminant::Mew => Species::Mew,
+            // gen 2 starts here
+            SpeciesDiscriminant::Chikorita => Species::Chikorita,
+            SpeciesDiscriminant::Bayleef => Species::Bayleef,
+            SpeciesDiscriminant::Meganium => Species::Meganium,
+            SpeciesDiscriminant::Cyndaquil => Species::Cyndaquil,
+            SpeciesDiscriminant::Quilava => Species::Quilava,
+            SpeciesDiscriminant::Typhlosion => Species::Typhlosion,
+            SpeciesDiscriminant::Totodile => Species::Totodile,
+            SpeciesDiscriminant::Croconaw => Species::Croconaw,
+            SpeciesDiscriminant::Feraligatr => Species::Feraligatr,
+            SpeciesDiscriminant::Sentret => Species::Sentret,
+            SpeciesDiscriminant::Furret => Species::Furret,
+            SpeciesDiscriminant::Hoothoot => Species::Hoothoot,
+            SpeciesDiscriminant::Noctowl => Species::Noctowl,
+            SpeciesDiscriminant::Ledyba => Species::Ledyba,
+            SpeciesDiscriminant::Ledian => Species::Ledian,
+            SpeciesDiscriminant::Spinarak => Species::Spinarak,
+            SpeciesDiscriminant::Ariados => Species::Ariados,
+            SpeciesDiscriminant::Crobat => Species::Crobat,
+            SpeciesDiscriminant::Chinchou => Species::Chinchou,
+            SpeciesDiscriminant::Lanturn => Species::Lanturn,
+            SpeciesDiscriminant::Pichu => Species::Pichu,
+            SpeciesDiscriminant::Cleffa => Species::Cleffa,
+            SpeciesDiscriminant::Igglybuff => Species::Igglybuff,
+            SpeciesDiscriminant::Togepi => Species::Togepi,
+            SpeciesDiscriminant::Togetic => Species::Togetic,
+            SpeciesDiscriminant::Natu => Species::Natu,
+            SpeciesDiscriminant::Xatu => Species::Xatu,
+            SpeciesDiscriminant::Mareep => Species::Mareep,
+            SpeciesDiscriminant::Flaaffy => Species::Flaaffy,
+            SpeciesDiscriminant::Ampharos => Species::Ampharos,
+            SpeciesDiscriminant::Bellossom => Species::Bellossom,
+            SpeciesDiscriminant::Marill => Species::Marill,
+            SpeciesDiscriminant::Azumarill => Species::Azumarill,
+            SpeciesDiscriminant::Sudowoodo => Species::Sudowoodo,
+            SpeciesDiscriminant::Politoed => Species::Politoed,
+            SpeciesDiscriminant::Hoppip => Species::Hoppip,
+            SpeciesDiscriminant::Skiploom => Species::Skiploom,
+            SpeciesDiscriminant::Jumpluff => Species::Jumpluff,
+            SpeciesDiscriminant::Aipom => Species::Aipom,
+            SpeciesDiscriminant::Sunkern => Species::Sunkern,
+            SpeciesDiscriminant::Sunflora => Species::Sunflora,
+            SpeciesDiscriminant::Yanma => Species::Yanma,
+            SpeciesDiscriminant::Wooper => Species::Wooper,
+            SpeciesDiscriminant::Quagsire => Species::Quagsire,
+            SpeciesDiscriminant::Espeon => Species::Espeon,
+            SpeciesDiscriminant::Umbreon => Species::Umbreon,
+            SpeciesDiscriminant::Murkrow => Species::Murkrow,
+            SpeciesDiscriminant::Slowking => Species::Slowking,
+            SpeciesDiscriminant::Misdreavus => Species::Misdreavus,
+            SpeciesDiscriminant::Unown => Species::Unown,
+            SpeciesDiscriminant::Wobbuffet => Species::Wobbuffet,
+            SpeciesDiscriminant::Girafarig => Species::Girafarig,
+            SpeciesDiscriminant::Pineco => Species::Pineco,
+            SpeciesDiscriminant::Forretress => Species::Forretress,
+            SpeciesDiscriminant::Dunsparce => Species::Dunsparce,
+            SpeciesDiscriminant::Gligar => Species::Gligar,
+            SpeciesDiscriminant::Steelix => Species::Steelix(MegaEvolution::Normal),
+            SpeciesDiscriminant::Snubbull => Species::Snubbull,
+            SpeciesDiscriminant::Granbull => Species::Granbull,
+            SpeciesDiscriminant::Qwilfish => Species::Qwilfish,
+            SpeciesDiscriminant::Scizor => Species::Scizor(MegaEvolution::Normal),
+            SpeciesDiscriminant::Shuckle => Species::Shuckle,
+            SpeciesDiscriminant::Heracross => Species::Heracross(MegaEvolution::Normal),
+            SpeciesDiscriminant::Sneasel => Species::Sneasel,
+            SpeciesDiscriminant::Teddiursa => Species::Teddiursa,
+            SpeciesDiscriminant::Ursaring => Species::Ursaring,
+            SpeciesDiscriminant::Slugma => Species::Slugma,
+            SpeciesDiscriminant::Magcargo => Species::Magcargo,
+            SpeciesDiscriminant::Swinub => Species::Swinub,
+            SpeciesDiscriminant::Piloswine => Species::Piloswine,
+            SpeciesDiscriminant::Corsola => Species::Corsola,
+            SpeciesDiscriminant::Remoraid => Species::Remoraid,
+            SpeciesDiscriminant::Octillery => Species::Octillery,
+            SpeciesDiscriminant::Delibird => Species::Delibird,
+            SpeciesDiscriminant::Mantine => Species::Mantine,
+            SpeciesDiscriminant::Skarmory => Species::Skarmory,
+            SpeciesDiscriminant::Houndour => Species::Houndour,
+            SpeciesDiscriminant::Houndoom => Species::Houndoom(MegaEvolution::Normal),
+            SpeciesDiscriminant::Kingdra => Species::Kingdra,
+            SpeciesDiscriminant::Phanpy => Species::Phanpy,
+            SpeciesDiscriminant::Donphan => Species::Donphan,
+            SpeciesDiscriminant::Porygon2 => Species::Porygon2,
+            SpeciesDiscriminant::Stantler => Species::Stantler,
+            SpeciesDiscriminant::Smeargle => Species::Smeargle,
+            SpeciesDiscriminant::Tyrogue => Species::Tyrogue,
+            SpeciesDiscriminant::Hitmontop => Species::Hitmontop,
+            SpeciesDiscriminant::Smoochum => Species::Smoochum,
+            SpeciesDiscriminant::Elekid => Species::Elekid,
+            SpeciesDiscriminant::Magby => Species::Magby,
+            SpeciesDiscriminant::Miltank => Species::Miltank,
+            SpeciesDiscriminant::Blissey => Species::Blissey,
+            SpeciesDiscriminant::Raikou => Species::Raikou,
+            SpeciesDiscriminant::Entei => Species::Entei,
+            SpeciesDiscriminant::Suicune => Species::Suicune,
+            SpeciesDiscriminant::Larvitar => Species::Larvitar,
+            SpeciesDiscriminant::Pupitar => Species::Pupitar,
+            SpeciesDiscriminant::Tyranitar => Species::Tyranitar(MegaEvolution::Normal),
+            SpeciesDiscriminant::Lugia => Species::Lugia,
+            SpeciesDiscriminant::HoOh => Species::HoOh,
+            SpeciesDiscriminant::Celebi => Species::Celebi,
+            // gen 3 starts here
+            SpeciesDiscriminant::Treecko => Species::Treecko,
+            SpeciesDiscriminant::Grovyle => Species::Grovyle,
+            SpeciesDiscriminant::Sceptile => Species::Sceptile(MegaEvolution::Normal),
+            SpeciesDiscriminant::Torchic => Species::Torchic,
+            SpeciesDiscriminant::Combusken => Species::Combusken,
+            SpeciesDiscriminant::Blaziken => Species::Blaziken(MegaEvolution::Normal),
+            SpeciesDiscriminant::Mudkip => Species::Mudkip,
+            SpeciesDiscriminant::Marshtomp => Species::Marshtomp,
+            SpeciesDiscriminant::Swampert => Species::Swampert(MegaEvolution::Normal),
+            SpeciesDiscriminant::Poochyena => Species::Poochyena,
+            SpeciesDiscriminant::Mightyena => Species::Mightyena,
+            SpeciesDiscriminant::Zigzagoon => Species::Zigzagoon,
+            SpeciesDiscriminant::Linoone => Species::Linoone,
+            SpeciesDiscriminant::Wurmple => Species::Wurmple,
+            SpeciesDiscriminant::Silcoon => Species::Silcoon,
+            SpeciesDiscriminant::Beautifly => Species::Beautifly,
+            SpeciesDiscriminant::Cascoon => Species::Cascoon,
+            SpeciesDiscriminant::Dustox => Species::Dustox,
+            SpeciesDiscriminant::Lotad => Species::Lotad,
+            SpeciesDiscriminant::Lombre => Species::Lombre,
+            SpeciesDiscriminant::Ludicolo => Species::Ludicolo,
+            SpeciesDiscriminant::Seedot => Species::Seedot,
+            SpeciesDiscriminant::Nuzleaf => Species::Nuzleaf,
+            SpeciesDiscriminant::Shiftry => Species::Shiftry,
+            SpeciesDiscriminant::Taillow => Species::Taillow,
+            SpeciesDiscriminant::Swellow => Species::Swellow,
+            SpeciesDiscriminant::Wingull => Species::Wingull,
+            SpeciesDiscriminant::Pelipper => Species::Pelipper,
+            SpeciesDiscriminant::Ralts => Species::Ralts,
+            SpeciesDiscriminant::Kirlia => Species::Kirlia,
+            SpeciesDiscriminant::Gardevoir => Species::Gardevoir(MegaEvolution::Normal),
+            SpeciesDiscriminant::Surskit => Species::Surskit,
+            SpeciesDiscriminant::Masquerain => Species::Masquerain,
+            SpeciesDiscriminant::Shroomish => Species::Shroomish,
+            SpeciesDiscriminant::Breloom => Species::Breloom,
+            SpeciesDiscriminant::Slakoth => Species::Slakoth,
+            SpeciesDiscriminant::Vigoroth => Species::Vigoroth,
+            SpeciesDiscriminant::Slaking => Species::Slaking,
+            SpeciesDiscriminant::Nincada => Species::Nincada,
+            SpeciesDiscriminant::Ninjask => Species::Ninjask,
+            SpeciesDiscriminant::Shedinja => Species::Shedinja,
+            SpeciesDiscriminant::Whismur => Species::Whismur,
+            SpeciesDiscriminant::Loudred => Species::Loudred,
+            SpeciesDiscriminant::Exploud => Species::Exploud,
+            SpeciesDiscriminant::Makuhita => Species::Makuhita,
+            SpeciesDiscriminant::Hariyama => Species::Hariyama,
+            SpeciesDiscriminant::Azurill => Species::Azurill,
+            SpeciesDiscriminant::Nosepass => Species::Nosepass,
+            SpeciesDiscriminant::Skitty => Species::Skitty,
+            SpeciesDiscriminant::Delcatty => Species::Delcatty,
+            SpeciesDiscriminant::Sableye => Species::Sableye(MegaEvolution::Normal),
+            SpeciesDiscriminant::Mawile => Species::Mawile(MegaEvolution::Normal),
+            SpeciesDiscriminant::Aron => Species::Aron,
+            SpeciesDiscriminant::Lairon => Species::Lairon,
+            SpeciesDiscriminant::Aggron => Species::Aggron(MegaEvolution::Normal),
+            SpeciesDiscriminant::Meditite => Species::Meditite,
+            SpeciesDiscriminant::Medicham => Species::Medicham(MegaEvolution::Normal),
+            SpeciesDiscriminant::Electrike => Species::Electrike,
+            SpeciesDiscriminant::Manectric => Species::Manectric(MegaEvolution::Normal),
+            SpeciesDiscriminant::Plusle => Species::Plusle,
+            SpeciesDiscriminant::Minun => Species::Minun,
+            SpeciesDiscriminant::Volbeat => Species::Volbeat,
+            SpeciesDiscriminant::Illumise => Species::Illumise,
+            SpeciesDiscriminant::Roselia => Species::Roselia,
+            SpeciesDiscriminant::Gulpin => Species::Gulpin,
+            SpeciesDiscriminant::Swalot => Species::Swalot,
+            SpeciesDiscriminant::Carvanha => Species::Carvanha,
+            SpeciesDiscriminant::Sharpedo => Species::Sharpedo(MegaEvolution::Normal),
+            SpeciesDiscriminant::Wailmer => Species::Wailmer,
+            SpeciesDiscriminant::Wailord => Species::Wailord,
+            SpeciesDiscriminant::Numel => Species::Numel,
+            SpeciesDiscriminant::Camerupt => Species::Camerupt(MegaEvolution::Normal),
+            SpeciesDiscriminant::Torkoal => Species::Torkoal,
+            SpeciesDiscriminant::Spoink => Species::Spoink,
+            SpeciesDiscriminant::Grumpig => Species::Grumpig,
+            SpeciesDiscriminant::Spinda => Species::Spinda,
+            SpeciesDiscriminant::Trapinch => Species::Trapinch,
+            SpeciesDiscriminant::Vibrava => Species::Vibrava,
+            SpeciesDiscriminant::Flygon => Species::Flygon,
+            SpeciesDiscriminant::Cacnea => Species::Cacnea,
+            SpeciesDiscriminant::Cacturne => Species::Cacturne,
+            SpeciesDiscriminant::Swablu => Species::Swablu,
+            SpeciesDiscriminant::Altaria => Species::Altaria(MegaEvolution::Normal),
+            SpeciesDiscriminant::Zangoose => Species::Zangoose,
+            SpeciesDiscriminant::Seviper => Species::Seviper,
+            SpeciesDiscriminant::Lunatone => Species::Lunatone,
+            SpeciesDiscriminant::Solrock => Species::Solrock,
+            SpeciesDiscriminant::Barboach => Species::Barboach,
+            SpeciesDiscriminant::Whiscash => Species::Whiscash,
+            SpeciesDiscriminant::Corphish => Species::Corphish,
+            SpeciesDiscriminant::Crawdaunt => Species::Crawdaunt,
+            SpeciesDiscriminant::Baltoy => Species::Baltoy,
+            SpeciesDiscriminant::Claydol => Species::Claydol,
+            SpeciesDiscriminant::Lileep => Species::Lileep,
+            SpeciesDiscriminant::Cradily => Species::Cradily,
+            SpeciesDiscriminant::Anorith => Species::Anorith,
+            SpeciesDiscriminant::Armaldo => Species::Armaldo,
+            SpeciesDiscriminant::Feebas => Species::Feebas,
+            SpeciesDiscriminant::Milotic => Species::Milotic,
+            SpeciesDiscriminant::Castform => Species::Castform(CastformForme::Normal),
+            SpeciesDiscriminant::Kecleon => Species::Kecleon,
+            SpeciesDiscriminant::Shuppet => Species::Shuppet,
+            SpeciesDiscriminant::Banette => Species::Banette,
+            SpeciesDiscriminant::Duskull => Species::Duskull,
+            SpeciesDiscriminant::Dusclops => Species::Dusclops,
+            SpeciesDiscriminant::Tropius => Species::Tropius,
+            SpeciesDiscriminant::Chimecho => Species::Chimecho,
+            SpeciesDiscriminant::Absol => Species::Absol,
+            SpeciesDiscriminant::Wynaut => Species::Wynaut,
+            SpeciesDiscriminant::Snorunt => Species::Snorunt,
+            SpeciesDiscriminant::Glalie => Species::Glalie,
+            SpeciesDiscriminant::Spheal => Species::Spheal,
+            SpeciesDiscriminant::Sealeo => Species::Sealeo,
+            SpeciesDiscriminant::Walrein => Species::Walrein,
+            SpeciesDiscriminant::Clamperl => Species::Clamperl,
+            SpeciesDiscriminant::Huntail => Species::Huntail,
+            SpeciesDiscriminant::Gorebyss => Species::Gorebyss,
+            SpeciesDiscriminant::Relicanth => Species::Relicanth,
+            SpeciesDiscriminant::Luvdisc => Species::Luvdisc,
+            SpeciesDiscriminant::Bagon => Species::Bagon,
+            SpeciesDiscriminant::Shelgon => Species::Shelgon,
+            SpeciesDiscriminant::Salamence => Species::Salamence(MegaEvolution::Normal),
+            SpeciesDiscriminant::Beldum => Species::Beldum,
+            SpeciesDiscriminant::Metang => Species::Metang,
+            SpeciesDiscriminant::Metagross => Species::Metagross(MegaEvolution::Normal),
+            SpeciesDiscriminant::Regirock => Species::Regirock,
+            SpeciesDiscriminant::Regice => Species::Regice,
+            SpeciesDiscriminant::Registeel => Species::Registeel,
+            SpeciesDiscriminant::Latias => Species::Latias,
+            SpeciesDiscriminant::Latios => Species::Latios,
+            SpeciesDiscriminant::Kyogre => Species::Kyogre(PrimalReversion::Normal),
+            SpeciesDiscriminant::Groudon => Species::Groudon(PrimalReversion::Normal),
+            SpeciesDiscriminant::Rayquaza => Species::Rayquaza(MegaEvolution::Normal),
+            SpeciesDiscriminant::Jirachi => Species::Jirachi,
+            SpeciesDiscriminant::Deoxys => Species::Deoxys(DeoxysForme::Normal),
+            // gen 4 starts here
+            SpeciesDiscriminant::Turtwig => Species::Turtwig,
+            SpeciesDiscriminant::Grotle => Species::Grotle,
+            SpeciesDiscriminant::Torterra => Species::Torterra,
+            SpeciesDiscriminant::Chimchar => Species::Chimchar,
+            SpeciesDiscriminant::Monferno => Species::Monferno,
+            SpeciesDiscriminant::Infernape => Species::Infernape,
+            SpeciesDiscriminant::Piplup => Species::Piplup,
+            SpeciesDiscriminant::Prinplup => Species::Prinplup,
+            SpeciesDiscriminant::Empoleon => Species::Empoleon,
+            SpeciesDiscriminant::Starly => Species::Starly,
+            SpeciesDiscriminant::Staravia => Species::Staravia,
+            SpeciesDiscriminant::Staraptor => Species::Staraptor,
+            SpeciesDiscriminant::Bidoof => Species::Bidoof,
+            SpeciesDiscriminant::Bibarel => Species::Bibarel,
+            SpeciesDiscriminant::Kricketot => Species::Kricketot,
+            SpeciesDiscriminant::Kricketune => Species::Kricketune,
+            SpeciesDiscriminant::Shinx => Species::Shinx,
+            SpeciesDiscriminant::Luxio => Species::Luxio,
+            SpeciesDiscriminant::Luxray => Species::Luxray,
+            SpeciesDiscriminant::Budew => Species::Budew,
+            SpeciesDiscriminant::Roserade => Species::Roserade,
+            SpeciesDiscriminant::Cranidos => Species::Cranidos,
+            SpeciesDiscriminant::Rampardos => Species::Rampardos,
+            SpeciesDiscriminant::Shieldon => Species::Shieldon,
+            SpeciesDiscriminant::Bastiodon => Species::Bastiodon,
+            SpeciesDiscriminant::Burmy => Species::Burmy,
+            SpeciesDiscriminant::Wormadam => Species::Wormadam(WormadamForme::Plant),
+            SpeciesDiscriminant::Mothim => Species::Mothim,
+            SpeciesDiscriminant::Combee => Species::Combee,
+            SpeciesDiscriminant::Vespiquen => Species::Vespiquen,
+            SpeciesDiscriminant::Pachirisu => Species::Pachirisu,
+            SpeciesDiscriminant::Buizel => Species::Buizel,
+            SpeciesDiscriminant::Floatzel => Species::Floatzel,
+            SpeciesDiscriminant::Cherubi => Species::Cherubi,
+            SpeciesDiscriminant::Cherrim => Species::Cherrim,
+            SpeciesDiscriminant::Shellos => Species::Shellos,
+            SpeciesDiscriminant::Gastrodon => Species::Gastrodon,
+            SpeciesDiscriminant::Ambipom => Species::Ambipom,
+            SpeciesDiscriminant::Drifloon => Species::Drifloon,
+            SpeciesDiscriminant::Drifblim => Species::Drifblim,
+            SpeciesDiscriminant::Buneary => Species::Buneary,
+            SpeciesDiscriminant::Lopunny => Species::Lopunny(MegaEvolution::Normal),
+            SpeciesDiscriminant::Mismagius => Species::Mismagius,
+            SpeciesDiscriminant::Honchkrow => Species::Honchkrow,
+            SpeciesDiscriminant::Glameow => Species::Glameow,
+            SpeciesDiscriminant::Purugly => Species::Purugly,
+            SpeciesDiscriminant::Chingling => Species::Chingling,
+            SpeciesDiscriminant::Stunky => Species::Stunky,
+            SpeciesDiscriminant::Skuntank => Species::Skuntank,
+            SpeciesDiscriminant::Bronzor => Species::Bronzor,
+            SpeciesDiscriminant::Bronzong => Species::Bronzong,
+            SpeciesDiscriminant::Bonsly => Species::Bonsly,
+            SpeciesDiscriminant::MimeJr => Species::MimeJr,
+            SpeciesDiscriminant::Happiny => Species::Happiny,
+            SpeciesDiscriminant::Chatot => Species::Chatot,
+            SpeciesDiscriminant::Spiritomb => Species::Spiritomb,
+            SpeciesDiscriminant::Gible => Species::Gible,
+            SpeciesDiscriminant::Gabite => Species::Gabite,
+            SpeciesDiscriminant::Garchomp => Species::Garchomp(MegaEvolution::Normal),
+            SpeciesDiscriminant::Munchlax => Species::Munchlax,
+            SpeciesDiscriminant::Riolu => Species::Riolu,
+            SpeciesDiscriminant::Lucario => Species::Lucario(MegaEvolution::Normal),
+            SpeciesDiscriminant::Hippopotas => Species::Hippopotas,
+            SpeciesDiscriminant::Hippowdon => Species::Hippowdon,
+            SpeciesDiscriminant::Skorupi => Species::Skorupi,
+            SpeciesDiscriminant::Drapion => Species::Drapion,
+            SpeciesDiscriminant::Croagunk => Species::Croagunk,
+            SpeciesDiscriminant::Toxicroak => Species::Toxicroak,
+            SpeciesDiscriminant::Carnivine => Species::Carnivine,
+            SpeciesDiscriminant::Finneon => Species::Finneon,
+            SpeciesDiscriminant::Lumineon => Species::Lumineon,
+            SpeciesDiscriminant::Mantyke => Species::Mantyke,
+            SpeciesDiscriminant::Snover => Species::Snover,
+            SpeciesDiscriminant::Abomasnow => Species::Abomasnow(MegaEvolution::Normal),
+            SpeciesDiscriminant::Weavile => Species::Weavile,
+            SpeciesDiscriminant::Magnezone => Species::Magnezone,
+            SpeciesDiscriminant::Lickilicky => Species::Lickilicky,
+            SpeciesDiscriminant::Rhyperior => Species::Rhyperior,
+            SpeciesDiscriminant::Tangrowth => Species::Tangrowth,
+            SpeciesDiscriminant::Electivire => Species::Electivire,
+            SpeciesDiscriminant::Magmortar => Species::Magmortar,
+            SpeciesDiscriminant::Togekiss => Species::Togekiss,
+            SpeciesDiscriminant::Yanmega => Species::Yanmega,
+            SpeciesDiscriminant::Leafeon => Species::Leafeon,
+            SpeciesDiscriminant::Glaceon => Species::Glaceon,
+            SpeciesDiscriminant::Gliscor => Species::Gliscor,
+            SpeciesDiscriminant::Mamoswine => Species::Mamoswine,
+            SpeciesDiscriminant::PorygonZ => Species::PorygonZ,
+            SpeciesDiscriminant::Gallade => Species::Gallade(MegaEvolution::Normal),
+            SpeciesDiscriminant::Probopass => Species::Probopass,
+            SpeciesDiscriminant::Dusknoir => Species::Dusknoir,
+            SpeciesDiscriminant::Froslass => Species::Froslass,
+            SpeciesDiscriminant::Rotom => Species::Rotom(RotomForme::Ghost),
+            SpeciesDiscriminant::Uxie => Species::Uxie,
+            SpeciesDiscriminant::Mesprit => Species::Mesprit,
+            SpeciesDiscriminant::Azelf => Species::Azelf,
+            SpeciesDiscriminant::Dialga => Species::Dialga,
+            SpeciesDiscriminant::Palkia => Species::Palkia,
+            SpeciesDiscriminant::Heatran => Species::Heatran,
+            SpeciesDiscriminant::Regigigas => Species::Regigigas,
+            SpeciesDiscriminant::Giratina => Species::Giratina(GiratinaForme::Altered),
+            SpeciesDiscriminant::Cresselia => Species::Cresselia,
+            SpeciesDiscriminant::Phione => Species::Phione,
+            SpeciesDiscriminant::Manaphy => Species::Manaphy,
+            SpeciesDiscriminant::Darkrai => Species::Darkrai,
+            SpeciesDiscriminant::Shaymin => Species::Shaymin(ShayminForme::Land),
+            SpeciesDiscriminant::Arceus => Species::Arceus(ArceusForme::Normal),
+            // gen 5 starts here
+            SpeciesDiscriminant::Victini => Species::Victini,
+            SpeciesDiscriminant::Snivy => Species::Snivy,
+            SpeciesDiscriminant::Servine => Species::Servine,
+            SpeciesDiscriminant::Serperior => Species::Serperior,
+            SpeciesDiscriminant::Tepig => Species::Tepig,
+            SpeciesDiscriminant::Pignite => Species::Pignite,
+            SpeciesDiscriminant::Emboar => Species::Emboar,
+            SpeciesDiscriminant::Oshawott => Species::Oshawott,
+            SpeciesDiscriminant::Dewott => Species::Dewott,
+            SpeciesDiscriminant::Samurott => Species::Samurott,
+            SpeciesDiscriminant::Patrat => Species::Patrat,
+            SpeciesDiscriminant::Watchog => Species::Watchog,
+            SpeciesDiscriminant::Lillipup => Species::Lillipup,
+            SpeciesDiscriminant::Herdier => Species::Herdier,
+            SpeciesDiscriminant::Stoutland => Species::Stoutland,
+            SpeciesDiscriminant::Purrloin => Species::Purrloin,
+            SpeciesDiscriminant::Liepard => Species::Liepard,
+            SpeciesDiscriminant::Pansage => Species::Pansage,
+            SpeciesDiscriminant::Simisage => Species::Simisage,
+            SpeciesDiscriminant::Pansear => Species::Pansear,
+            SpeciesDiscriminant::Simisear => Species::Simisear,
+            SpeciesDiscriminant::Panpour => Species::Panpour,
+            SpeciesDiscriminant::Simipour => Species::Simipour,
+            SpeciesDiscriminant::Munna => Species::Munna,
+            SpeciesDiscriminant::Musharna => Species::Musharna,
+            SpeciesDiscriminant::Pidove => Species::Pidove,
+            SpeciesDiscriminant::Tranquill => Species::Tranquill,
+            SpeciesDiscriminant::Unfezant => Species::Unfezant,
+            SpeciesDiscriminant::Blitzle => Species::Blitzle,
+            SpeciesDiscriminant::Zebstrika => Species::Zebstrika,
+            SpeciesDiscriminant::Roggenrola => Species::Roggenrola,
+            SpeciesDiscriminant::Boldore => Species::Boldore,
+            SpeciesDiscriminant::Gigalith => Species::Gigalith,
+            SpeciesDiscriminant::Woobat => Species::Woobat,
+            SpeciesDiscriminant::Swoobat => Species::Swoobat,
+            SpeciesDiscriminant::Drilbur => Species::Drilbur,
+            SpeciesDiscriminant::Excadrill => Species::Excadrill,
+            SpeciesDiscriminant::Audino => Species::Audino,
+            SpeciesDiscriminant::Timburr => Species::Timburr,
+            SpeciesDiscriminant::Gurdurr => Species::Gurdurr,
+            SpeciesDiscriminant::Conkeldurr => Species::Conkeldurr,
+            SpeciesDiscriminant::Tympole => Species::Tympole,
+            SpeciesDiscriminant::Palpitoad => Species::Palpitoad,
+            SpeciesDiscriminant::Seismitoad => Species::Seismitoad,
+            SpeciesDiscriminant::Throh => Species::Throh,
+            SpeciesDiscriminant::Sawk => Species::Sawk,
+            SpeciesDiscriminant::Sewaddle => Species::Sewaddle,
+            SpeciesDiscriminant::Swadloon => Species::Swadloon,
+            SpeciesDiscriminant::Leavanny => Species::Leavanny,
+            SpeciesDiscriminant::Venipede => Species::Venipede,
+            SpeciesDiscriminant::Whirlipede => Species::Whirlipede,
+            SpeciesDiscriminant::Scolipede => Species::Scolipede,
+            SpeciesDiscriminant::Cottonee => Species::Cottonee,
+            SpeciesDiscriminant::Whimsicott => Species::Whimsicott,
+            SpeciesDiscriminant::Petilil => Species::Petilil,
+            SpeciesDiscriminant::Lilligant => Species::Lilligant,
+            SpeciesDiscriminant::Basculin => Species::Basculin,
+            SpeciesDiscriminant::Sandile => Species::Sandile,
+            SpeciesDiscriminant::Krokorok => Species::Krokorok,
+            SpeciesDiscriminant::Krookodile => Species::Krookodile,
+            SpeciesDiscriminant::Darumaka => Species::Darumaka,
+            SpeciesDiscriminant::Darmanitan => Species::Darmanitan(DarmanitanForme::Standard),
+            SpeciesDiscriminant::Maractus => Species::Maractus,
+            SpeciesDiscriminant::Dwebble => Species::Dwebble,
+            SpeciesDiscriminant::Crustle => Species::Crustle,
+            SpeciesDiscriminant::Scraggy => Species::Scraggy,
+            SpeciesDiscriminant::Scrafty => Species::Scrafty,
+            SpeciesDiscriminant::Sigilyph => Species::Sigilyph,
+            SpeciesDiscriminant::Yamask => Species::Yamask,
+            SpeciesDiscriminant::Cofagrigus => Species::Cofagrigus,
+            SpeciesDiscriminant::Tirtouga => Species::Tirtouga,
+            SpeciesDiscriminant::Carracosta => Species::Carracosta,
+            SpeciesDiscriminant::Archen => Species::Archen,
+            SpeciesDiscriminant::Archeops => Species::Archeops,
+            SpeciesDiscriminant::Trubbish => Species::Trubbish,
+            SpeciesDiscriminant::Garbodor => Species::Garbodor,
+            SpeciesDiscriminant::Zorua => Species::Zorua,
+            SpeciesDiscriminant::Zoroark => Species::Zoroark,
+            SpeciesDiscriminant::Minccino => Species::Minccino,
+            SpeciesDiscriminant::Cinccino => Species::Cinccino,
+            SpeciesDiscriminant::Gothita => Species::Gothita,
+            SpeciesDiscriminant::Gothorita => Species::Gothorita,
+            SpeciesDiscriminant::Gothitelle => Species::Gothitelle,
+            SpeciesDiscriminant::Solosis => Species::Solosis,
+            SpeciesDiscriminant::Duosion => Species::Duosion,
+            SpeciesDiscriminant::Reuniclus => Species::Reuniclus,
+            SpeciesDiscriminant::Ducklett => Species::Ducklett,
+            SpeciesDiscriminant::Swanna => Species::Swanna,
+            SpeciesDiscriminant::Vanillite => Species::Vanillite,
+            SpeciesDiscriminant::Vanillish => Species::Vanillish,
+            SpeciesDiscriminant::Vanilluxe => Species::Vanilluxe,
+            SpeciesDiscriminant::Deerling => Species::Deerling,
+            SpeciesDiscriminant::Sawsbuck => Species::Sawsbuck,
+            SpeciesDiscriminant::Emolga => Species::Emolga,
+            SpeciesDiscriminant::Karrablast => Species::Karrablast,
+            SpeciesDiscriminant::Escavalier => Species::Escavalier,
+            SpeciesDiscriminant::Foongus => Species::Foongus,
+            SpeciesDiscriminant::Amoonguss => Species::Amoonguss,
+            SpeciesDiscriminant::Frillish => Species::Frillish,
+            SpeciesDiscriminant::Jellicent => Species::Jellicent,
+            SpeciesDiscriminant::Alomomola => Species::Alomomola,
+            SpeciesDiscriminant::Joltik => Species::Joltik,
+            SpeciesDiscriminant::Galvantula => Species::Galvantula,
+            SpeciesDiscriminant::Ferroseed => Species::Ferroseed,
+            SpeciesDiscriminant::Ferrothorn => Species::Ferrothorn,
+            SpeciesDiscriminant::Klink => Species::Klink,
+            SpeciesDiscriminant::Klang => Species::Klang,
+            SpeciesDiscriminant::Klinklang => Species::Klinklang,
+            SpeciesDiscriminant::Tynamo => Species::Tynamo,
+            SpeciesDiscriminant::Eelektrik => Species::Eelektrik,
+            SpeciesDiscriminant::Eelektross => Species::Eelektross,
+            SpeciesDiscriminant::Elgyem => Species::Elgyem,
+            SpeciesDiscriminant::Beheeyem => Species::Beheeyem,
+            SpeciesDiscriminant::Litwick => Species::Litwick,
+            SpeciesDiscriminant::Lampent => Species::Lampent,
+            SpeciesDiscriminant::Chandelure => Species::Chandelure,
+            SpeciesDiscriminant::Axew => Species::Axew,
+            SpeciesDiscriminant::Fraxure => Species::Fraxure,
+            SpeciesDiscriminant::Haxorus => Species::Haxorus,
+            SpeciesDiscriminant::Cubchoo => Species::Cubchoo,
+            SpeciesDiscriminant::Beartic => Species::Beartic,
+            SpeciesDiscriminant::Cryogonal => Species::Cryogonal,
+            SpeciesDiscriminant::Shelmet => Species::Shelmet,
+            SpeciesDiscriminant::Accelgor => Species::Accelgor,
+            SpeciesDiscriminant::Stunfisk => Species::Stunfisk,
+            SpeciesDiscriminant::Mienfoo => Species::Mienfoo,
+            SpeciesDiscriminant::Mienshao => Species::Mienshao,
+            SpeciesDiscriminant::Druddigon => Species::Druddigon,
+            SpeciesDiscriminant::Golett => Species::Golett,
+            SpeciesDiscriminant::Golurk => Species::Golurk,
+            SpeciesDiscriminant::Pawniard => Species::Pawniard,
+            SpeciesDiscriminant::Bisharp => Species::Bisharp,
+            SpeciesDiscriminant::Bouffalant => Species::Bouffalant,
+            SpeciesDiscriminant::Rufflet => Species::Rufflet,
+            SpeciesDiscriminant::Braviary => Species::Braviary,
+            SpeciesDiscriminant::Vullaby => Species::Vullaby,
+            SpeciesDiscriminant::Mandibuzz => Species::Mandibuzz,
+            SpeciesDiscriminant::Heatmor => Species::Heatmor,
+            SpeciesDiscriminant::Durant => Species::Durant,
+            SpeciesDiscriminant::Deino => Species::Deino,
+            SpeciesDiscriminant::Zweilous => Species::Zweilous,
+            SpeciesDiscriminant::Hydreigon => Species::Hydreigon,
+            SpeciesDiscriminant::Larvesta => Species::Larvesta,
+            SpeciesDiscriminant::Volcarona => Species::Volcarona,
+            SpeciesDiscriminant::Cobalion => Species::Cobalion,
+            SpeciesDiscriminant::Terrakion => Species::Terrakion,
+            SpeciesDiscriminant::Virizion => Species::Virizion,
+            SpeciesDiscriminant::Tornadus => Species::Tornadus(GenieForme::Incarnate),
+            SpeciesDiscriminant::Thundurus => Species::Thundurus(GenieForme::Incarnate),
+            SpeciesDiscriminant::Reshiram => Species::Reshiram,
+            SpeciesDiscriminant::Zekrom => Species::Zekrom,
+            SpeciesDiscriminant::Landorus => Species::Landorus(GenieForme::Incarnate),
+            SpeciesDiscriminant::Kyurem => Species::Kyurem(KyuremForme::Normal),
+            SpeciesDiscriminant::Keldeo => Species::Keldeo,
+            SpeciesDiscriminant::Meloetta => Species::Meloetta(MeloettaForme::Aria),
+            SpeciesDiscriminant::Genesect => Species::Genesect,
+            // gen 6 starts here
+            SpeciesDiscriminant::Chespin => Species::Chespin,
+            SpeciesDiscriminant::Quilladin => Species::Quilladin,
+            SpeciesDiscriminant::Chesnaught => Species::Chesnaught,
+            SpeciesDiscriminant::Fennekin => Species::Fennekin,
+            SpeciesDiscriminant::Braixen => Species::Braixen,
+            SpeciesDiscriminant::Delphox => Species::Delphox,
+            SpeciesDiscriminant::Froakie => Species::Froakie,
+            SpeciesDiscriminant::Frogadier => Species::Frogadier,
+            SpeciesDiscriminant::Greninja => Species::Greninja(GreninjaForme::Normal),
+            SpeciesDiscriminant::Bunnelby => Species::Bunnelby,
+            SpeciesDiscriminant::Diggersby => Species::Diggersby,
+            SpeciesDiscriminant::Fletchling => Species::Fletchling,
+            SpeciesDiscriminant::Fletchinder => Species::Fletchinder,
+            SpeciesDiscriminant::Talonflame => Species::Talonflame,
+            SpeciesDiscriminant::Scatterbug => Species::Scatterbug,
+            SpeciesDiscriminant::Spewpa => Species::Spewpa,
+            SpeciesDiscriminant::Vivillon => Species::Vivillon,
+            SpeciesDiscriminant::Litleo => Species::Litleo,
+            SpeciesDiscriminant::Pyroar => Species::Pyroar,
+            SpeciesDiscriminant::Flabebe => Species::Flabebe,
+            SpeciesDiscriminant::Floette => Species::Floette,
+            SpeciesDiscriminant::Florges => Species::Florges,
+            SpeciesDiscriminant::Skiddo => Species::Skiddo,
+            SpeciesDiscriminant::Gogoat => Species::Gogoat,
+            SpeciesDiscriminant::Pancham => Species::Pancham,
+            SpeciesDiscriminant::Pangoro => Species::Pangoro,
+            SpeciesDiscriminant::Furfrou => Species::Furfrou,
+            SpeciesDiscriminant::Espurr => Species::Espurr,
+            SpeciesDiscriminant::Meowstic => Species::Meowstic,
+            SpeciesDiscriminant::Honedge => Species::Honedge,
+            SpeciesDiscriminant::Doublade => Species::Doublade,
+            SpeciesDiscriminant::Aegislash => Species::Aegislash(AegislashForme::Shield),
+            SpeciesDiscriminant::Spritzee => Species::Spritzee,
+            SpeciesDiscriminant::Aromatisse => Species::Aromatisse,
+            SpeciesDiscriminant::Swirlix => Species::Swirlix,
+            SpeciesDiscriminant::Slurpuff => Species::Slurpuff,
+            SpeciesDiscriminant::Inkay => Species::Inkay,
+            SpeciesDiscriminant::Malamar => Species::Malamar,
+            SpeciesDiscriminant::Binacle => Species::Binacle,
+            SpeciesDiscriminant::Barbaracle => Species::Barbaracle,
+            SpeciesDiscriminant::Skrelp => Species::Skrelp,
+            SpeciesDiscriminant::Dragalge => Species::Dragalge,
+            SpeciesDiscriminant::Clauncher => Species::Clauncher,
+            SpeciesDiscriminant::Clawitzer => Species::Clawitzer,
+            SpeciesDiscriminant::Helioptile => Species::Helioptile,
+            SpeciesDiscriminant::Heliolisk => Species::Heliolisk,
+            SpeciesDiscriminant::Tyrunt => Species::Tyrunt,
+            SpeciesDiscriminant::Tyrantrum => Species::Tyrantrum,
+            SpeciesDiscriminant::Amaura => Species::Amaura,
+            SpeciesDiscriminant::Aurorus => Species::Aurorus,
+            SpeciesDiscriminant::Sylveon => Species::Sylveon,
+            SpeciesDiscriminant::Hawlucha => Species::Hawlucha,
+            SpeciesDiscriminant::Dedenne => Species::Dedenne,
+            SpeciesDiscriminant::Carbink => Species::Carbink,
+            SpeciesDiscriminant::Goomy => Species::Goomy,
+            SpeciesDiscriminant::Sliggoo => Species::Sliggoo,
+            SpeciesDiscriminant::Goodra => Species::Goodra,
+            SpeciesDiscriminant::Klefki => Species::Klefki,
+            SpeciesDiscriminant::Phantump => Species::Phantump,
+            SpeciesDiscriminant::Trevenant => Species::Trevenant,
+            SpeciesDiscriminant::Pumpkaboo => Species::Pumpkaboo(GourgeistForme::Average),
+            SpeciesDiscriminant::Gourgeist => Species::Gourgeist(GourgeistForme::Average),
+            SpeciesDiscriminant::Bergmite => Species::Bergmite,
+            SpeciesDiscriminant::Avalugg => Species::Avalugg,
+            SpeciesDiscriminant::Noibat => Species::Noibat,
+            SpeciesDiscriminant::Noivern => Species::Noivern,
+            SpeciesDiscriminant::Xerneas => Species::Xerneas,
+            SpeciesDiscriminant::Yveltal => Species::Yveltal,
+            SpeciesDiscriminant::Zygarde => Species::Zygarde(ZygardeForme::FiftyPercent),
+            SpeciesDiscriminant::Diancie => Species::Diancie(MegaEvolution::Normal),
+            SpeciesDiscriminant::Hoopa => Species::Hoopa(HoopaForme::Confined),
+            SpeciesDiscriminant::Volcanion => Species::Volcanion,
+            // gen 7 starts here
+            SpeciesDiscriminant::Rowlet => Species::Rowlet,
+            SpeciesDiscriminant::Dartrix => Species::Dartrix,
+            SpeciesDiscriminant::Decidueye => Species::Decidueye,
+            SpeciesDiscriminant::Litten => Species::Litten,
+            SpeciesDiscriminant::Torracat => Species::Torracat,
+            SpeciesDiscriminant::Incineroar => Species::Incineroar,
+            SpeciesDiscriminant::Popplio => Species::Popplio,
+            SpeciesDiscriminant::Brionne => Species::Brionne,
+            SpeciesDiscriminant::Primarina => Species::Primarina,
+            SpeciesDiscriminant::Pikipek => Species::Pikipek,
+            SpeciesDiscriminant::Trumbeak => Species::Trumbeak,
+            SpeciesDiscriminant::Toucannon => Species::Toucannon,
+            SpeciesDiscriminant::Yungoos => Species::Yungoos,
+            SpeciesDiscriminant::Gumshoos => Species::Gumshoos,
+            SpeciesDiscriminant::Grubbin => Species::Grubbin,
+            SpeciesDiscriminant::Charjabug => Species::Charjabug,
+            SpeciesDiscriminant::Vikavolt => Species::Vikavolt,
+            SpeciesDiscriminant::Crabrawler => Species::Crabrawler,
+            SpeciesDiscriminant::Crabominable => Species::Crabominable,
+            SpeciesDiscriminant::Oricorio => Species::Oricorio(OricorioForme::Baile),
+            SpeciesDiscriminant::Cutiefly => Species::Cutiefly,
+            SpeciesDiscriminant::Ribombee => Species::Ribombee,
+            SpeciesDiscriminant::Rockruff => Species::Rockruff,
+            SpeciesDiscriminant::Lycanroc => Species::Lycanroc(LycanrocForme::Midday),
+            SpeciesDiscriminant::Wishiwashi => Species::Wishiwashi(WishiwashiForme::School),
+            SpeciesDiscriminant::Mareanie => Species::Mareanie,
+            SpeciesDiscriminant::Toxapex => Species::Toxapex,
+            SpeciesDiscriminant::Mudbray => Species::Mudbray,
+            SpeciesDiscriminant::Mudsdale => Species::Mudsdale,
+            SpeciesDiscriminant::Dewpider => Species::Dewpider,
+            SpeciesDiscriminant::Araquanid => Species::Araquanid,
+            SpeciesDiscriminant::Fomantis => Species::Fomantis,
+            SpeciesDiscriminant::Lurantis => Species::Lurantis,
+            SpeciesDiscriminant::Morelull => Species::Morelull,
+            SpeciesDiscriminant::Shiinotic => Species::Shiinotic,
+            SpeciesDiscriminant::Salandit => Species::Salandit,
+            SpeciesDiscriminant::Salazzle => Species::Salazzle,
+            SpeciesDiscriminant::Stufful => Species::Stufful,
+            SpeciesDiscriminant::Bewear => Species::Bewear,
+            SpeciesDiscriminant::Bounsweet => Species::Bounsweet,
+            SpeciesDiscriminant::Steenee => Species::Steenee,
+            SpeciesDiscriminant::Tsareena => Species::Tsareena,
+            SpeciesDiscriminant::Comfey => Species::Comfey,
+            SpeciesDiscriminant::Oranguru => Species::Oranguru,
+            SpeciesDiscriminant::Passimian => Species::Passimian,
+            SpeciesDiscriminant::Wimpod => Species::Wimpod,
+            SpeciesDiscriminant::Golisopod => Species::Golisopod,
+            SpeciesDiscriminant::Sandygast => Species::Sandygast,
+            SpeciesDiscriminant::Palossand => Species::Palossand,
+            SpeciesDiscriminant::Pyukumuku => Species::Pyukumuku,
+            SpeciesDiscriminant::TypeNull => Species::TypeNull(SilvallyForme::Normal),
+            SpeciesDiscriminant::Silvally => Species::Silvally(SilvallyForme::Normal),
+            SpeciesDiscriminant::Minior => Species::Minior(MiniorForme::Meteor),
+            SpeciesDiscriminant::Komala => Species::Komala,
+            SpeciesDiscriminant::Turtonator => Species::Turtonator,
+            SpeciesDiscriminant::Togedemaru => Species::Togedemaru,
+            SpeciesDiscriminant::Mimikyu => Species::Mimikyu(MimikyuForme::Disguised),
+            SpeciesDiscriminant::Bruxish => Species::Bruxish,
+            SpeciesDiscriminant::Drampa => Species::Drampa,
+            SpeciesDiscriminant::Dhelmise => Species::Dhelmise,
+            SpeciesDiscriminant::JangmoO => Species::JangmoO,
+            SpeciesDiscriminant::HakamoO => Species::HakamoO,
+            SpeciesDiscriminant::KommoO => Species::KommoO,
+            SpeciesDiscriminant::TapuKoko => Species::TapuKoko,
+            SpeciesDiscriminant::TapuLele => Species::TapuLele,
+            SpeciesDiscriminant::TapuBulu => Species::TapuBulu,
+            SpeciesDiscriminant::TapuFini => Species::TapuFini,
+            SpeciesDiscriminant::Cosmog => Species::Cosmog,
+            SpeciesDiscriminant::Cosmoem => Species::Cosmoem,
+            SpeciesDiscriminant::Solgaleo => Species::Solgaleo,
+            SpeciesDiscriminant::Lunala => Species::Lunala,
+            SpeciesDiscriminant::Nihilego => Species::Nihilego,
+            SpeciesDiscriminant::Buzzwole => Species::Buzzwole,
+            SpeciesDiscriminant::Pheromosa => Species::Pheromosa,
+            SpeciesDiscriminant::Xurkitree => Species::Xurkitree,
+            SpeciesDiscriminant::Celesteela => Species::Celesteela,
+            SpeciesDiscriminant::Kartana => Species::Kartana,
+            SpeciesDiscriminant::Guzzlord => Species::Guzzlord,
+            SpeciesDiscriminant::Necrozma => Species::Necrozma(NecrozmaForme::Normal),
+            SpeciesDiscriminant::Magearna => Species::Magearna,
+            SpeciesDiscriminant::Marshadow => Species::Marshadow,
+            SpeciesDiscriminant::Poipole => Species::Poipole,
+            SpeciesDiscriminant::Naganadel => Species::Naganadel,
+            SpeciesDiscriminant::Stakataka => Species::Stakataka,
+            SpeciesDiscriminant::Blacephalon => Species::Blacephalon,
+            SpeciesDiscriminant::Zeraora => Species::Zeraora,
+        }
+    }
+}
+
+impl From<Typing> for ArceusForme {
+    /// Gives the Arceus forme with the given type.
+    fn from(typing: Typing) -> ArceusForme {
+        match typing {
+            Typing::Normal => ArceusForme::Normal,
+            Typing::Fighting => ArceusForme::Fighting,
+            Typing::Flying => ArceusForme::Flying,
+            Typing::Poison => ArceusForme::Poison,
+            Typing::Ground => ArceusForme::Ground,
+            Typing::Rock => ArceusForme::Rock,
+            Typing::Bug => ArceusForme::Bug,
+            Typing::Ghost => ArceusForme::Ghost,
+            Typing::Steel => ArceusForme::Steel,
+            Typing::Fire => ArceusForme::Fire,
+            Typing::Water => ArceusForme::Water,
+            Typing::Grass => ArceusForme::Grass,
+            Typing::Electric => ArceusForme::Electric,
+            Typing::Psychic => ArceusForme::Psychic,
+            Typing::Ice => ArceusForme::Ice,
+            Typing::Dragon => ArceusForme::Dragon,
+            Typing::Dark => ArceusForme::Dark,
+            Typing::Fairy => ArceusForme::Fairy,
+        }
+    }
+}
+
+impl From<Typing> for SilvallyForme {
+    /// Gives the Silvally forme with the given type.
+    fn from(typing: Typing) -> SilvallyForme {
+        match typing {
+            Typing::Normal => SilvallyForme::Normal,
+            Typing::Fighting => SilvallyForme::Fighting,
+            Typing::Flying => SilvallyForme::Flying,
+            Typing::Poison => SilvallyForme::Poison,
+            Typing::Ground => SilvallyForme::Ground,
+            Typing::Rock => SilvallyForme::Rock,
+            Typing::Bug => SilvallyForme::Bug,
+            Typing::Ghost => SilvallyForme::Ghost,
+            Typing::Steel => SilvallyForme::Steel,
+            Typing::Fire => SilvallyForme::Fire,
+            Typing::Water => SilvallyForme::Water,
+            Typing::Grass => SilvallyForme::Grass,
+            Typing::Electric => SilvallyForme::Electric,
+            Typing::Psychic => SilvallyForme::Psychic,
+            Typing::Ice => SilvallyForme::Ice,
+            Typing::Dragon => SilvallyForme::Dragon,
+            Typing::Dark => SilvallyForme::Dark,
+            Typing::Fairy => SilvallyForme::Fairy,
+        }
+    }
+}
+
+impl Species {
+    /// Returns every forme of the given species, in the order the forme enum lists them. Species
+    /// without formes give just the one `Species` value.
+    pub fn formes(species: SpeciesDiscriminant) -> Vec<Species> {
+        match species {
+            // in National Pokedex order
+            SpeciesDiscriminant::Venusaur => MegaEvolution::iter().map(Species::Venusaur).collect(),
+            SpeciesDiscriminant::Charizard => XYMegaEvolution::iter().map(Species::Charizard).collect(),
+            SpeciesDiscriminant::Blastoise => MegaEvolution::iter().map(Species::Blastoise).collect(),
+            SpeciesDiscriminant::Beedrill => MegaEvolution::iter().map(Species::Beedrill).collect(),
+            SpeciesDiscriminant::Pidgeot => MegaEvolution::iter().map(Species::Pidgeot).collect(),
+            SpeciesDiscriminant::Rattata => AlolaForme::iter().map(Species::Rattata).collect(),
+            SpeciesDiscriminant::Raticate => AlolaForme::iter().map(Species::Raticate).collect(),
+            SpeciesDiscriminant::Raichu => AlolaForme::iter().map(Species::Raichu).collect(),
+            SpeciesDiscriminant::Sandshrew => AlolaForme::iter().map(Species::Sandshrew).collect(),
+            SpeciesDiscriminant::Sandslash => AlolaForme::iter().map(Species::Sandslash).collect(),
+            SpeciesDiscriminant::Vulpix => AlolaForme::iter().map(Species::Vulpix).collect(),
+            SpeciesDiscriminant::Ninetales => AlolaForme::iter().map(Species::Ninetales).collect(),
+            SpeciesDiscriminant::Diglett => AlolaForme::iter().map(Species::Diglett).collect(),
+            SpeciesDiscriminant::Dugtrio => AlolaForme::iter().map(Species::Dugtrio).collect(),
+            SpeciesDiscriminant::Persian => AlolaForme::iter().map(Species::Persian).collect(),
+            SpeciesDiscriminant::Alakazam => MegaEvolution::iter().map(Species::Alakazam).collect(),
+            SpeciesDiscriminant::Geodude => AlolaForme::iter().map(Species::Geodude).collect(),
+            SpeciesDiscriminant::Graveler => AlolaForme::iter().map(Species::Graveler).collect(),
+            SpeciesDiscriminant::Golem => AlolaForme::iter().map(Species::Golem).collect(),
+            SpeciesDiscriminant::Grimer => AlolaForme::iter().map(Species::Grimer).collect(),
+            SpeciesDiscriminant::Muk => AlolaForme::iter().map(Species::Muk).collect(),
+            SpeciesDiscriminant::Gengar => MegaEvolution::iter().map(Species::Gengar).collect(),
+            SpeciesDiscriminant::Exeggutor => AlolaForme::iter().map(Species::Exeggutor).collect(),
+            SpeciesDiscriminant::Marowak => AlolaForme::iter().map(Species::Marowak).collect(),
+            SpeciesDiscriminant::Kangaskhan => MegaEvolution::iter().map(Species::Kangaskhan).collect(),
+            SpeciesDiscriminant::Pinsir => MegaEvolution::iter().map(Species::Pinsir).collect(),
+            SpeciesDiscriminant::Gyarados => MegaEvolution::iter().map(Species::Gyarados).collect(),
+            SpeciesDiscriminant::Aerodactyl => MegaEvolution::iter().map(Species::Aerodactyl).collect(),
+            SpeciesDiscriminant::Mewtwo => XYMegaEvolution::iter().map(Species::Mewtwo).collect(),
+            SpeciesDiscriminant::Steelix => MegaEvolution::iter().map(Species::Steelix).collect(),
+            SpeciesDiscriminant::Scizor => MegaEvolution::iter().map(Species::Scizor).collect(),
+            SpeciesDiscriminant::Heracross => MegaEvolution::iter().map(Species::Heracross).collect(),
+            SpeciesDiscriminant::Houndoom => MegaEvolution::iter().map(Species::Houndoom).collect(),
+            SpeciesDiscriminant::Tyranitar => MegaEvolution::iter().map(Species::Tyranitar).collect(),
+            SpeciesDiscriminant::Sceptile => MegaEvolution::iter().map(Species::Sceptile).collect(),
+            SpeciesDiscriminant::Blaziken => MegaEvolution::iter().map(Species::Blaziken).collect(),
+            SpeciesDiscriminant::Swampert => MegaEvolution::iter().map(Species::Swampert).collect(),
+            SpeciesDiscriminant::Gardevoir => MegaEvolution::iter().map(Species::Gardevoir).collect(),
+            SpeciesDiscriminant::Sableye => MegaEvolution::iter().map(Species::Sableye).collect(),
+            SpeciesDiscriminant::Mawile => MegaEvolution::iter().map(Species::Mawile).collect(),
+            SpeciesDiscriminant::Aggron => MegaEvolution::iter().map(Species::Aggron).collect(),
+            SpeciesDiscriminant::Medicham => MegaEvolution::iter().map(Species::Medicham).collect(),
+            SpeciesDiscriminant::Manectric => MegaEvolution::iter().map(Species::Manectric).collect(),
+            SpeciesDiscriminant::Sharpedo => MegaEvolution::iter().map(Species::Sharpedo).collect(),
+            SpeciesDiscriminant::Camerupt => MegaEvolution::iter().map(Species::Camerupt).collect(),
+            SpeciesDiscriminant::Altaria => MegaEvolution::iter().map(Species::Altaria).collect(),
+            SpeciesDiscriminant::Castform => CastformForme::iter().map(Species::Castform).collect(),
+            SpeciesDiscriminant::Salamence => MegaEvolution::iter().map(Species::Salamence).collect(),
+            SpeciesDiscriminant::Metagross => MegaEvolution::iter().map(Species::Metagross).collect(),
+            SpeciesDiscriminant::Kyogre => PrimalReversion::iter().map(Species::Kyogre).collect(),
+            SpeciesDiscriminant::Groudon => PrimalReversion::iter().map(Species::Groudon).collect(),
+            SpeciesDiscriminant::Rayquaza => MegaEvolution::iter().map(Species::Rayquaza).collect(),
+            SpeciesDiscriminant::Deoxys => DeoxysForme::iter().map(Species::Deoxys).collect(),
+            SpeciesDiscriminant::Wormadam => WormadamForme::iter().map(Species::Wormadam).collect(),
+            SpeciesDiscriminant::Lopunny => MegaEvolution::iter().map(Species::Lopunny).collect(),
+            SpeciesDiscriminant::Garchomp => MegaEvolution::iter().map(Species::Garchomp).collect(),
+            SpeciesDiscriminant::Lucario => MegaEvolution::iter().map(Species::Lucario).collect(),
+            SpeciesDiscriminant::Abomasnow => MegaEvolution::iter().map(Species::Abomasnow).collect(),
+            SpeciesDiscriminant::Gallade => MegaEvolution::iter().map(Species::Gallade).collect(),
+            SpeciesDiscriminant::Rotom => RotomForme::iter().map(Species::Rotom).collect(),
+            SpeciesDiscriminant::Giratina => GiratinaForme::iter().map(Species::Giratina).collect(),
+            SpeciesDiscriminant::Shaymin => ShayminForme::iter().map(Species::Shaymin).collect(),
+            SpeciesDiscriminant::Arceus => ArceusForme::iter().map(Species::Arceus).collect(),
+            SpeciesDiscriminant::Darmanitan => DarmanitanForme::iter().map(Species::Darmanitan).collect(),
+            SpeciesDiscriminant::Tornadus => GenieForme::iter().map(Species::Tornadus).collect(),
+            SpeciesDiscriminant::Thundurus => GenieForme::iter().map(Species::Thundurus).collect(),
+            SpeciesDiscriminant::Landorus => GenieForme::iter().map(Species::Landorus).collect(),
+            SpeciesDiscriminant::Kyurem => KyuremForme::iter().map(Species::Kyurem).collect(),
+            SpeciesDiscriminant::Meloetta => MeloettaForme::iter().map(Species::Meloetta).collect(),
+            SpeciesDiscriminant::Greninja => GreninjaForme::iter().map(Species::Greninja).collect(),
+            SpeciesDiscriminant::Aegislash => AegislashForme::iter().map(Species::Aegislash).collect(),
+            SpeciesDiscriminant::Pumpkaboo => GourgeistForme::iter().map(Species::Pumpkaboo).collect(),
+            SpeciesDiscriminant::Gourgeist => GourgeistForme::iter().map(Species::Gourgeist).collect(),
+            SpeciesDiscriminant::Zygarde => ZygardeForme::iter().map(Species::Zygarde).collect(),
+            SpeciesDiscriminant::Diancie => MegaEvolution::iter().map(Species::Diancie).collect(),
+            SpeciesDiscriminant::Hoopa => HoopaForme::iter().map(Species::Hoopa).collect(),
+            SpeciesDiscriminant::Oricorio => OricorioForme::iter().map(Species::Oricorio).collect(),
+            SpeciesDiscriminant::Lycanroc => LycanrocForme::iter().map(Species::Lycanroc).collect(),
+            SpeciesDiscriminant::Wishiwashi => WishiwashiForme::iter().map(Species::Wishiwashi).collect(),
+            SpeciesDiscriminant::TypeNull => SilvallyForme::iter().map(Species::TypeNull).collect(),
+            SpeciesDiscriminant::Silvally => SilvallyForme::iter().map(Species::Silvally).collect(),
+            SpeciesDiscriminant::Minior => MiniorForme::iter().map(Species::Minior).collect(),
+            SpeciesDiscriminant::Mimikyu => MimikyuForme::iter().map(Species::Mimikyu).collect(),
+            SpeciesDiscriminant::Necrozma => NecrozmaForme::iter().map(Species::Necrozma).collect(),
+            _ => vec![Species::from(species)],
+        }
+    }
+
+    /// Returns a key that orders species the way the Pokedex does: the National Pokedex number, and
+    /// then the index of the forme in the order `formes` gives. Bulbasaur is (1, 0), and
+    /// Charizard-Mega-Y is (6, 2).
+    pub fn sort_key(self) -> (u16, u8) {
+        let species = SpeciesDiscriminant::from(self);
+        let forme = Species::formes(species).iter().position(|&forme| forme == self).unwrap_or(0);
+        (species as u16 + 1, forme as u8)
+    }
+}
+
+/// Looks up a species and forme by number, the inverse of `Species::sort_key`: `dex` is the National
+/// Pokedex number, starting from 1 for Bulbasaur, and `forme` is the index of the forme in the order
+/// it's declared in its forme enum. Species without formes only have forme 0. Returns None if either
+/// number is out of range. Note that the forme indices are this library's, which don't always match
+/// the ones the games use internally.
+pub fn species_from_ids(dex: u16, forme: u8) -> Option<Species> {
+    let species = SpeciesDiscriminant::iter().nth(usize::from(dex).checked_sub(1)?)?;
+    Species::formes(species).get(usize::from(forme)).cloned()
+}
+
+/// A `Species` that can be sorted, or used as a key in a `BTreeMap`, ordered by `Species::sort_key`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OrderedSpecies(pub Species);
+
+impl Ord for OrderedSpecies {
+    fn cmp(&self, other: &OrderedSpecies) -> Ordering {
+        self.0.sort_key().cmp(&other.0.sort_key())
+    }
+}
+
+impl PartialOrd for OrderedSpecies {
+    fn partial_cmp(&self, other: &OrderedSpecies) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Returns every `Species` value: each species in National Pokedex order, with all of its formes.
+/// This includes cosmetic-but-distinct formes like the Arceus types and in-battle formes like
+/// Aegislash-Blade, because `Species` does.
+pub fn all_species_with_formes() -> impl Iterator<Item = Species> {
+    SpeciesDiscriminant::iter().flat_map(Species::formes)
+}
+
+/// Returns true if the given species is a Mega Evolution, like Charizard-Mega-X. Primal Reversions
+/// and Ultra Necrozma are similar, but aren't Mega Evolutions, so they give false.
+pub fn is_mega(species: Species) -> bool {
+    match species {
+        // in National Pokedex order
+        Species::Venusaur(MegaEvolution::Mega) | Species::Charizard(XYMegaEvolution::MegaX)
+            | Species::Charizard(XYMegaEvolution::MegaY) | Species::Blastoise(MegaEvolution::Mega)
+            | Species::Beedrill(MegaEvolution::Mega) | Species::Pidgeot(MegaEvolution::Mega)
+            | Species::Alakazam(MegaEvolution::Mega) | Species::Gengar(MegaEvolution::Mega)
+            | Species::Kangaskhan(MegaEvolution::Mega) | Species::Pinsir(MegaEvolution::Mega)
+            | Species::Gyarados(MegaEvolution::Mega) | Species::Aerodactyl(MegaEvolution::Mega)
+            | Species::Mewtwo(XYMegaEvolution::MegaX) | Species::Mewtwo(XYMegaEvolution::MegaY)
+            | Species::Steelix(MegaEvolution::Mega) | Species::Scizor(MegaEvolution::Mega)
+            | Species::Heracross(MegaEvolution::Mega) | Species::Houndoom(MegaEvolution::Mega)
+            | Species::Tyranitar(MegaEvolution::Mega) | Species::Sceptile(MegaEvolution::Mega)
+            | Species::Blaziken(MegaEvolution::Mega) | Species::Swampert(MegaEvolution::Mega)
+            | Species::Gardevoir(MegaEvolution::Mega) | Species::Sableye(MegaEvolution::Mega)
+            | Species::Mawile(MegaEvolution::Mega) | Species::Aggron(MegaEvolution::Mega)
+            | Species::Medicham(MegaEvolution::Mega) | Species::Manectric(MegaEvolution::Mega)
+            | Species::Sharpedo(MegaEvolution::Mega) | Species::Camerupt(MegaEvolution::Mega)
+            | Species::Altaria(MegaEvolution::Mega) | Species::Salamence(MegaEvolution::Mega)
+            | Species::Metagross(MegaEvolution::Mega) | Species::Rayquaza(MegaEvolution::Mega)
+            | Species::Lopunny(MegaEvolution::Mega) | Species::Garchomp(MegaEvolution::Mega)
+            | Species::Lucario(MegaEvolution::Mega) | Species::Abomasnow(MegaEvolution::Mega)
+            | Species::Gallade(MegaEvolution::Mega) | Species::Diancie(MegaEvolution::Mega) => true,
+        _ => false,
+    }
+}
+
 // impl fmt::Display for Species {
 //     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 //         let species: Species = self.into();        
@@ -1394,4 +2465,90 @@ mod tests {
         assert_eq!(&SpeciesDiscriminant::TapuFini.to_string(), "Tapu Fini");
         assert_eq!(&SpeciesDiscriminant::TypeNull.to_string(), "Type: Null");
     }
+
+    #[test]
+    fn test_default_formes() {
+        assert_eq!(Species::from(SpeciesDiscriminant::Bulbasaur), Species::Bulbasaur);
+        assert_eq!(Species::from(SpeciesDiscriminant::Giratina), Species::Giratina(GiratinaForme::Altered));
+        assert_eq!(Species::from(SpeciesDiscriminant::Aegislash), Species::Aegislash(AegislashForme::Shield));
+        assert_eq!(Species::from(SpeciesDiscriminant::Zygarde), Species::Zygarde(ZygardeForme::FiftyPercent));
+        for species in SpeciesDiscriminant::iter() {
+            assert_eq!(SpeciesDiscriminant::from(Species::from(species)), species);
+        }
+    }
+
+    #[test]
+    fn test_forme_defaults() {
+        assert_eq!(DeoxysForme::default(), DeoxysForme::Normal);
+        assert_eq!(DarmanitanForme::default(), DarmanitanForme::Standard);
+        assert_eq!(MeloettaForme::default(), MeloettaForme::Aria);
+        assert_eq!(GiratinaForme::default(), GiratinaForme::Altered);
+        assert_eq!(GourgeistForme::default(), GourgeistForme::Average);
+        assert_eq!(AegislashForme::default(), AegislashForme::Shield);
+        assert_eq!(ZygardeForme::default(), ZygardeForme::FiftyPercent);
+        assert_eq!(Species::Deoxys(Default::default()), Species::from(SpeciesDiscriminant::Deoxys));
+        assert_eq!(Species::Rotom(Default::default()), Species::from(SpeciesDiscriminant::Rotom));
+        assert_eq!(Species::Landorus(Default::default()), Species::from(SpeciesDiscriminant::Landorus));
+        assert_eq!(Species::Charizard(Default::default()), Species::from(SpeciesDiscriminant::Charizard));
+        assert_eq!(Species::Kyogre(Default::default()), Species::from(SpeciesDiscriminant::Kyogre));
+        assert_eq!(Species::Persian(Default::default()), Species::from(SpeciesDiscriminant::Persian));
+        assert_eq!(Species::Necrozma(Default::default()), Species::from(SpeciesDiscriminant::Necrozma));
+    }
+
+    #[test]
+    fn test_species_from_ids() {
+        assert_eq!(species_from_ids(1, 0), Some(Species::Bulbasaur));
+        assert_eq!(species_from_ids(386, 2), Some(Species::Deoxys(DeoxysForme::Defense)));
+        assert_eq!(species_from_ids(386, 4), None);
+        assert_eq!(species_from_ids(25, 1), None);
+        assert_eq!(species_from_ids(0, 0), None);
+        assert_eq!(species_from_ids(808, 0), None);
+        for species in all_species_with_formes() {
+            let (dex, forme) = species.sort_key();
+            assert_eq!(species_from_ids(dex, forme), Some(species));
+        }
+    }
+
+    #[test]
+    fn test_all_species_with_formes() {
+        let all: Vec<Species> = all_species_with_formes().collect();
+        // 807 species, and 155 extra formes among the 84 species that have them
+        assert_eq!(SpeciesDiscriminant::iter().count(), 807);
+        assert_eq!(all.len(), 807 + 155);
+        assert_eq!(all[0], Species::Bulbasaur);
+        for typing in Typing::all_typings() {
+            assert!(all.contains(&Species::TypeNull(SilvallyForme::from(typing))));
+            assert!(all.contains(&Species::Silvally(SilvallyForme::from(typing))));
+        }
+        assert_eq!(Species::formes(SpeciesDiscriminant::Charizard).len(), 3);
+        assert_eq!(Species::formes(SpeciesDiscriminant::Pikachu), vec![Species::Pikachu]);
+    }
+    #[test]
+    fn test_is_mega() {
+        assert!(is_mega(Species::Charizard(XYMegaEvolution::MegaY)));
+        assert!(is_mega(Species::Diancie(MegaEvolution::Mega)));
+        assert!(!is_mega(Species::Charizard(XYMegaEvolution::Normal)));
+        assert!(!is_mega(Species::Groudon(PrimalReversion::Primal)));
+        assert!(!is_mega(Species::Pikachu));
+        assert_eq!(all_species_with_formes().filter(|&s| is_mega(s)).count(), 40);
+    }
+
+    #[test]
+    fn test_sort_key() {
+        assert_eq!(Species::Bulbasaur.sort_key(), (1, 0));
+        assert_eq!(Species::Charizard(XYMegaEvolution::MegaY).sort_key(), (6, 2));
+        assert_eq!(Species::Zeraora.sort_key(), (807, 0));
+        let mut team = vec![OrderedSpecies(Species::Rotom(RotomForme::Wash)),
+                            OrderedSpecies(Species::Charizard(XYMegaEvolution::MegaY)),
+                            OrderedSpecies(Species::Rotom(RotomForme::Ghost)),
+                            OrderedSpecies(Species::Pikachu),
+                            OrderedSpecies(Species::Charizard(XYMegaEvolution::Normal))];
+        team.sort();
+        let sorted: Vec<Species> = team.into_iter().map(|s| s.0).collect();
+        assert_eq!(sorted, vec![Species::Charizard(XYMegaEvolution::Normal),
+                                Species::Charizard(XYMegaEvolution::MegaY), Species::Pikachu,
+                                Species::Rotom(RotomForme::Ghost), Species::Rotom(RotomForme::Wash)]);
+        let ordered: Vec<OrderedSpecies> = all_species_with_formes().map(OrderedSpecies).collect();
+        assert!(ordered.windows(2).all(|pair| pair[0] < pair[1]));
+    }
 }
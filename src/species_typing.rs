@@ -0,0 +1,970 @@
+//! This file defines the typing of every Pokemon species and forme: a primary `Typing` and an optional
+//! secondary one. The values are current to Gen VII, and use
+//! [Bulbapedia](https://bulbapedia.bulbagarden.net/wiki/List_of_Pokémon_by_National_Pokédex_number)
+//! as a source. As with base stats, every `Species` value fully determines its forme, and so its
+//! typing: Arceus and Silvally formes have the type of their plate or memory, for instance.
+
+use crate::item::Item;
+use crate::species::*;
+use crate::typing::Typing;
+
+/// Returns the typing of the given species, as a primary `Typing` and an optional secondary one in
+/// the order the games list them. This is the same form that functions like `defensive_profile` take.
+pub fn species_typing(species: Species) -> (Typing, Option<Typing>) {
+    match species {
+        // in National Pokedex order
+        Species::Bulbasaur => (Typing::Grass, Some(Typing::Poison)),
+        Species::Ivysaur => (Typing::Grass, Some(Typing::Poison)),
+        Species::Venusaur(_) => (Typing::Grass, Some(Typing::Poison)),
+        Species::Charmander => (Typing::Fire, None),
+        Species::Charmeleon => (Typing::Fire, None),
+        Species::Charizard(XYMegaEvolution::Normal) => (Typing::Fire, Some(Typing::Flying)),
+        Species::Charizard(XYMegaEvolution::MegaX) => (Typing::Fire, Some(Typing::Dragon)),
+        Species::Charizard(XYMegaEvolution::MegaY) => (Typing::Fire, Some(Typing::Flying)),
+        Species::Squirtle => (Typing::Water, None),
+        Species::Wartortle => (Typing::Water, None),
+        Species::Blastoise(_) => (Typing::Water, None),
+        Species::Caterpie => (Typing::Bug, None),
+        Species::Metapod => (Typing::Bug, None),
+        Species::Butterfree => (Typing::Bug, Some(Typing::Flying)),
+        Species::Weedle => (Typing::Bug, Some(Typing::Poison)),
+        Species::Kakuna => (Typing::Bug, Some(Typing::Poison)),
+        Species::Beedrill(_) => (Typing::Bug, Some(Typing::Poison)),
+        Species::Pidgey => (Typing::Normal, Some(Typing::Flying)),
+        Species::Pidgeotto => (Typing::Normal, Some(Typing::Flying)),
+        Species::Pidgeot(_) => (Typing::Normal, Some(Typing::Flying)),
+        Species::Rattata(AlolaForme::Normal) => (Typing::Normal, None),
+        Species::Rattata(AlolaForme::Alola) => (Typing::Dark, Some(Typing::Normal)),
+        Species::Raticate(AlolaForme::Normal) => (Typing::Normal, None),
+        Species::Raticate(AlolaForme::Alola) => (Typing::Dark, Some(Typing::Normal)),
+        Species::Spearow => (Typing::Normal, Some(Typing::Flying)),
+        Species::Fearow => (Typing::Normal, Some(Typing::Flying)),
+        Species::Ekans => (Typing::Poison, None),
+        Species::Arbok => (Typing::Poison, None),
+        Species::Pikachu => (Typing::Electric, None),
+        Species::Raichu(AlolaForme::Normal) => (Typing::Electric, None),
+        Species::Raichu(AlolaForme::Alola) => (Typing::Electric, Some(Typing::Psychic)),
+        Species::Sandshrew(AlolaForme::Normal) => (Typing::Ground, None),
+        Species::Sandshrew(AlolaForme::Alola) => (Typing::Ice, Some(Typing::Steel)),
+        Species::Sandslash(AlolaForme::Normal) => (Typing::Ground, None),
+        Species::Sandslash(AlolaForme::Alola) => (Typing::Ice, Some(Typing::Steel)),
+        Species::NidoranF => (Typing::Poison, None),
+        Species::Nidorina => (Typing::Poison, None),
+        Species::Nidoqueen => (Typing::Poison, Some(Typing::Ground)),
+        Species::NidoranM => (Typing::Poison, None),
+        Species::Nidorino => (Typing::Poison, None),
+        Species::Nidoking => (Typing::Poison, Some(Typing::Ground)),
+        Species::Clefairy => (Typing::Fairy, None),
+        Species::Clefable => (Typing::Fairy, None),
+        Species::Vulpix(AlolaForme::Normal) => (Typing::Fire, None),
+        Species::Vulpix(AlolaForme::Alola) => (Typing::Ice, None),
+        Species::Ninetales(AlolaForme::Normal) => (Typing::Fire, None),
+        Species::Ninetales(AlolaForme::Alola) => (Typing::Ice, Some(Typing::Fairy)),
+        Species::Jigglypuff => (Typing::Normal, Some(Typing::Fairy)),
+        Species::Wigglytuff => (Typing::Normal, Some(Typing::Fairy)),
+        Species::Zubat => (Typing::Poison, Some(Typing::Flying)),
+        Species::Golbat => (Typing::Poison, Some(Typing::Flying)),
+        Species::Oddish => (Typing::Grass, Some(Typing::Poison)),
+        Species::Gloom => (Typing::Grass, Some(Typing::Poison)),
+        Species::Vileplume => (Typing::Grass, Some(Typing::Poison)),
+        Species::Paras => (Typing::Bug, Some(Typing::Grass)),
+        Species::Parasect => (Typing::Bug, Some(Typing::Grass)),
+        Species::Venonat => (Typing::Bug, Some(Typing::Poison)),
+        Species::Venomoth => (Typing::Bug, Some(Typing::Poison)),
+        Species::Diglett(AlolaForme::Normal) => (Typing::Ground, None),
+        Species::Diglett(AlolaForme::Alola) => (Typing::Ground, Some(Typing::Steel)),
+        Species::Dugtrio(AlolaForme::Normal) => (Typing::Ground, None),
+        Species::Dugtrio(AlolaForme::Alola) => (Typing::Ground, Some(Typing::Steel)),
+        Species::Meowth => (Typing::Normal, None),
+        Species::Persian(AlolaForme::Normal) => (Typing::Normal, None),
+        Species::Persian(AlolaForme::Alola) => (Typing::Dark, None),
+        Species::Psyduck => (Typing::Water, None),
+        Species::Golduck => (Typing::Water, None),
+        Species::Mankey => (Typing::Fighting, None),
+        Species::Primeape => (Typing::Fighting, None),
+        Species::Growlithe => (Typing::Fire, None),
+        Species::Arcanine => (Typing::Fire, None),
+        Species::Poliwag => (Typing::Water, None),
+        Species::Poliwhirl => (Typing::Water, None),
+        Species::Poliwrath => (Typing::Water, Some(Typing::Fighting)),
+        Species::Abra => (Typing::Psychic, None),
+        Species::Kadabra => (Typing::Psychic, None),
+        Species::Alakazam(_) => (Typing::Psychic, None),
+        Species::Machop => (Typing::Fighting, None),
+        Species::Machoke => (Typing::Fighting, None),
+        Species::Machamp => (Typing::Fighting, None),
+        Species::Bellsprout => (Typing::Grass, Some(Typing::Poison)),
+        Species::Weepinbell => (Typing::Grass, Some(Typing::Poison)),
+        Species::Victreebel => (Typing::Grass, Some(Typing::Poison)),
+        Species::Tentacool => (Typing::Water, Some(Typing::Poison)),
+        Species::Tentacruel => (Typing::Water, Some(Typing::Poison)),
+        Species::Geodude(AlolaForme::Normal) => (Typing::Rock, Some(Typing::Ground)),
+        Species::Geodude(AlolaForme::Alola) => (Typing::Rock, Some(Typing::Electric)),
+        Species::Graveler(AlolaForme::Normal) => (Typing::Rock, Some(Typing::Ground)),
+        Species::Graveler(AlolaForme::Alola) => (Typing::Rock, Some(Typing::Electric)),
+        Species::Golem(AlolaForme::Normal) => (Typing::Rock, Some(Typing::Ground)),
+        Species::Golem(AlolaForme::Alola) => (Typing::Rock, Some(Typing::Electric)),
+        Species::Ponyta => (Typing::Fire, None),
+        Species::Rapidash => (Typing::Fire, None),
+        Species::Slowpoke => (Typing::Water, Some(Typing::Psychic)),
+        Species::Slowbro => (Typing::Water, Some(Typing::Psychic)),
+        Species::Magnemite => (Typing::Electric, Some(Typing::Steel)),
+        Species::Magneton => (Typing::Electric, Some(Typing::Steel)),
+        Species::Farfetchd => (Typing::Normal, Some(Typing::Flying)),
+        Species::Doduo => (Typing::Normal, Some(Typing::Flying)),
+        Species::Dodrio => (Typing::Normal, Some(Typing::Flying)),
+        Species::Seel => (Typing::Water, None),
+        Species::Dewgong => (Typing::Water, Some(Typing::Ice)),
+        Species::Grimer(AlolaForme::Normal) => (Typing::Poison, None),
+        Species::Grimer(AlolaForme::Alola) => (Typing::Poison, Some(Typing::Dark)),
+        Species::Muk(AlolaForme::Normal) => (Typing::Poison, None),
+        Species::Muk(AlolaForme::Alola) => (Typing::Poison, Some(Typing::Dark)),
+        Species::Shellder => (Typing::Water, None),
+        Species::Cloyster => (Typing::Water, Some(Typing::Ice)),
+        Species::Gastly => (Typing::Ghost, Some(Typing::Poison)),
+        Species::Haunter => (Typing::Ghost, Some(Typing::Poison)),
+        Species::Gengar(_) => (Typing::Ghost, Some(Typing::Poison)),
+        Species::Onix => (Typing::Rock, Some(Typing::Ground)),
+        Species::Drowzee => (Typing::Psychic, None),
+        Species::Hypno => (Typing::Psychic, None),
+        Species::Krabby => (Typing::Water, None),
+        Species::Kingler => (Typing::Water, None),
+        Species::Voltorb => (Typing::Electric, None),
+        Species::Electrode => (Typing::Electric, None),
+        Species::Exeggcute => (Typing::Grass, Some(Typing::Psychic)),
+        Species::Exeggutor(AlolaForme::Normal) => (Typing::Grass, Some(Typing::Psychic)),
+        Species::Exeggutor(AlolaForme::Alola) => (Typing::Grass, Some(Typing::Dragon)),
+        Species::Cubone => (Typing::Ground, None),
+        Species::Marowak(AlolaForme::Normal) => (Typing::Ground, None),
+        Species::Marowak(AlolaForme::Alola) => (Typing::Fire, Some(Typing::Ghost)),
+        Species::Hitmonlee => (Typing::Fighting, None),
+        Species::Hitmonchan => (Typing::Fighting, None),
+        Species::Lickitung => (Typing::Normal, None),
+        Species::Koffing => (Typing::Poison, None),
+        Species::Weezing => (Typing::Poison, None),
+        Species::Rhyhorn => (Typing::Ground, Some(Typing::Rock)),
+        Species::Rhydon => (Typing::Ground, Some(Typing::Rock)),
+        Species::Chansey => (Typing::Normal, None),
+        Species::Tangela => (Typing::Grass, None),
+        Species::Kangaskhan(_) => (Typing::Normal, None),
+        Species::Horsea => (Typing::Water, None),
+        Species::Seadra => (Typing::Water, None),
+        Species::Goldeen => (Typing::Water, None),
+        Species::Seaking => (Typing::Water, None),
+        Species::Staryu => (Typing::Water, None),
+        Species::Starmie => (Typing::Water, Some(Typing::Psychic)),
+        Species::MrMime => (Typing::Psychic, Some(Typing::Fairy)),
+        Species::Scyther => (Typing::Bug, Some(Typing::Flying)),
+        Species::Jynx => (Typing::Ice, Some(Typing::Psychic)),
+        Species::Electabuzz => (Typing::Electric, None),
+        Species::Magmar => (Typing::Fire, None),
+        Species::Pinsir(MegaEvolution::Normal) => (Typing::Bug, None),
+        Species::Pinsir(MegaEvolution::Mega) => (Typing::Bug, Some(Typing::Flying)),
+        Species::Tauros => (Typing::Normal, None),
+        Species::Magikarp => (Typing::Water, None),
+        Species::Gyarados(MegaEvolution::Normal) => (Typing::Water, Some(Typing::Flying)),
+        Species::Gyarados(MegaEvolution::Mega) => (Typing::Water, Some(Typing::Dark)),
+        Species::Lapras => (Typing::Water, Some(Typing::Ice)),
+        Species::Ditto => (Typing::Normal, None),
+        Species::Eevee => (Typing::Normal, None),
+        Species::Vaporeon => (Typing::Water, None),
+        Species::Jolteon => (Typing::Electric, None),
+        Species::Flareon => (Typing::Fire, None),
+        Species::Porygon => (Typing::Normal, None),
+        Species::Omanyte => (Typing::Rock, Some(Typing::Water)),
+        Species::Omastar => (Typing::Rock, Some(Typing::Water)),
+        Species::Kabuto => (Typing::Rock, Some(Typing::Water)),
+        Species::Kabutops => (Typing::Rock, Some(Typing::Water)),
+        Species::Aerodactyl(_) => (Typing::Rock, Some(Typing::Flying)),
+        Species::Snorlax => (Typing::Normal, None),
+        Species::Articuno => (Typing::Ice, Some(Typing::Flying)),
+        Species::Zapdos => (Typing::Electric, Some(Typing::Flying)),
+        Species::Moltres => (Typing::Fire, Some(Typing::Flying)),
+        Species::Dratini => (Typing::Dragon, None),
+        Species::Dragonair => (Typing::Dragon, None),
+        Species::Dragonite => (Typing::Dragon, Some(Typing::Flying)),
+        Species::Mewtwo(XYMegaEvolution::Normal) => (Typing::Psychic, None),
+        Species::Mewtwo(XYMegaEvolution::MegaX) => (Typing::Psychic, Some(Typing::Fighting)),
+        Species::Mewtwo(XYMegaEvolution::MegaY) => (Typing::Psychic, None),
+        Species::Mew => (Typing::Psychic, None),
+        // gen 2 starts here
+        Species::Chikorita => (Typing::Grass, None),
+        Species::Bayleef => (Typing::Grass, None),
+        Species::Meganium => (Typing::Grass, None),
+        Species::Cyndaquil => (Typing::Fire, None),
+        Species::Quilava => (Typing::Fire, None),
+        Species::Typhlosion => (Typing::Fire, None),
+        Species::Totodile => (Typing::Water, None),
+        Species::Croconaw => (Typing::Water, None),
+        Species::Feraligatr => (Typing::Water, None),
+        Species::Sentret => (Typing::Normal, None),
+        Species::Furret => (Typing::Normal, None),
+        Species::Hoothoot => (Typing::Normal, Some(Typing::Flying)),
+        Species::Noctowl => (Typing::Normal, Some(Typing::Flying)),
+        Species::Ledyba => (Typing::Bug, Some(Typing::Flying)),
+        Species::Ledian => (Typing::Bug, Some(Typing::Flying)),
+        Species::Spinarak => (Typing::Bug, Some(Typing::Poison)),
+        Species::Ariados => (Typing::Bug, Some(Typing::Poison)),
+        Species::Crobat => (Typing::Poison, Some(Typing::Flying)),
+        Species::Chinchou => (Typing::Water, Some(Typing::Electric)),
+        Species::Lanturn => (Typing::Water, Some(Typing::Electric)),
+        Species::Pichu => (Typing::Electric, None),
+        Species::Cleffa => (Typing::Fairy, None),
+        Species::Igglybuff => (Typing::Normal, Some(Typing::Fairy)),
+        Species::Togepi => (Typing::Fairy, None),
+        Species::Togetic => (Typing::Fairy, Some(Typing::Flying)),
+        Species::Natu => (Typing::Psychic, Some(Typing::Flying)),
+        Species::Xatu => (Typing::Psychic, Some(Typing::Flying)),
+        Species::Mareep => (Typing::Electric, None),
+        Species::Flaaffy => (Typing::Electric, None),
+        Species::Ampharos => (Typing::Electric, None),
+        Species::Bellossom => (Typing::Grass, None),
+        Species::Marill => (Typing::Water, Some(Typing::Fairy)),
+        Species::Azumarill => (Typing::Water, Some(Typing::Fairy)),
+        Species::Sudowoodo => (Typing::Rock, None),
+        Species::Politoed => (Typing::Water, None),
+        Species::Hoppip => (Typing::Grass, Some(Typing::Flying)),
+        Species::Skiploom => (Typing::Grass, Some(Typing::Flying)),
+        Species::Jumpluff => (Typing::Grass, Some(Typing::Flying)),
+        Species::Aipom => (Typing::Normal, None),
+        Species::Sunkern => (Typing::Grass, None),
+        Species::Sunflora => (Typing::Grass, None),
+        Species::Yanma => (Typing::Bug, Some(Typing::Flying)),
+        Species::Wooper => (Typing::Water, Some(Typing::Ground)),
+        Species::Quagsire => (Typing::Water, Some(Typing::Ground)),
+        Species::Espeon => (Typing::Psychic, None),
+        Species::Umbreon => (Typing::Dark, None),
+        Species::Murkrow => (Typing::Dark, Some(Typing::Flying)),
+        Species::Slowking => (Typing::Water, Some(Typing::Psychic)),
+        Species::Misdreavus => (Typing::Ghost, None),
+        Species::Unown => (Typing::Psychic, None),
+        Species::Wobbuffet => (Typing::Psychic, None),
+        Species::Girafarig => (Typing::Normal, Some(Typing::Psychic)),
+        Species::Pineco => (Typing::Bug, None),
+        Species::Forretress => (Typing::Bug, Some(Typing::Steel)),
+        Species::Dunsparce => (Typing::Normal, None),
+        Species::Gligar => (Typing::Ground, Some(Typing::Flying)),
+        Species::Steelix(_) => (Typing::Steel, Some(Typing::Ground)),
+        Species::Snubbull => (Typing::Fairy, None),
+        Species::Granbull => (Typing::Fairy, None),
+        Species::Qwilfish => (Typing::Water, Some(Typing::Poison)),
+        Species::Scizor(_) => (Typing::Bug, Some(Typing::Steel)),
+        Species::Shuckle => (Typing::Bug, Some(Typing::Rock)),
+        Species::Heracross(_) => (Typing::Bug, Some(Typing::Fighting)),
+        Species::Sneasel => (Typing::Dark, Some(Typing::Ice)),
+        Species::Teddiursa => (Typing::Normal, None),
+        Species::Ursaring => (Typing::Normal, None),
+        Species::Slugma => (Typing::Fire, None),
+        Species::Magcargo => (Typing::Fire, Some(Typing::Rock)),
+        Species::Swinub => (Typing::Ice, Some(Typing::Ground)),
+        Species::Piloswine => (Typing::Ice, Some(Typing::Ground)),
+        Species::Corsola => (Typing::Water, Some(Typing::Rock)),
+        Species::Remoraid => (Typing::Water, None),
+        Species::Octillery => (Typing::Water, None),
+        Species::Delibird => (Typing::Ice, Some(Typing::Flying)),
+        Species::Mantine => (Typing::Water, Some(Typing::Flying)),
+        Species::Skarmory => (Typing::Steel, Some(Typing::Flying)),
+        Species::Houndour => (Typing::Dark, Some(Typing::Fire)),
+        Species::Houndoom(_) => (Typing::Dark, Some(Typing::Fire)),
+        Species::Kingdra => (Typing::Water, Some(Typing::Dragon)),
+        Species::Phanpy => (Typing::Ground, None),
+        Species::Donphan => (Typing::Ground, None),
+        Species::Porygon2 => (Typing::Normal, None),
+        Species::Stantler => (Typing::Normal, None),
+        Species::Smeargle => (Typing::Normal, None),
+        Species::Tyrogue => (Typing::Fighting, None),
+        Species::Hitmontop => (Typing::Fighting, None),
+        Species::Smoochum => (Typing::Ice, Some(Typing::Psychic)),
+        Species::Elekid => (Typing::Electric, None),
+        Species::Magby => (Typing::Fire, None),
+        Species::Miltank => (Typing::Normal, None),
+        Species::Blissey => (Typing::Normal, None),
+        Species::Raikou => (Typing::Electric, None),
+        Species::Entei => (Typing::Fire, None),
+        Species::Suicune => (Typing::Water, None),
+        Species::Larvitar => (Typing::Rock, Some(Typing::Ground)),
+        Species::Pupitar => (Typing::Rock, Some(Typing::Ground)),
+        Species::Tyranitar(_) => (Typing::Rock, Some(Typing::Dark)),
+        Species::Lugia => (Typing::Psychic, Some(Typing::Flying)),
+        Species::HoOh => (Typing::Fire, Some(Typing::Flying)),
+        Species::Celebi => (Typing::Psychic, Some(Typing::Grass)),
+        // gen 3 starts here
+        Species::Treecko => (Typing::Grass, None),
+        Species::Grovyle => (Typing::Grass, None),
+        Species::Sceptile(MegaEvolution::Normal) => (Typing::Grass, None),
+        Species::Sceptile(MegaEvolution::Mega) => (Typing::Grass, Some(Typing::Dragon)),
+        Species::Torchic => (Typing::Fire, None),
+        Species::Combusken => (Typing::Fire, Some(Typing::Fighting)),
+        Species::Blaziken(_) => (Typing::Fire, Some(Typing::Fighting)),
+        Species::Mudkip => (Typing::Water, None),
+        Species::Marshtomp => (Typing::Water, Some(Typing::Ground)),
+        Species::Swampert(_) => (Typing::Water, Some(Typing::Ground)),
+        Species::Poochyena => (Typing::Dark, None),
+        Species::Mightyena => (Typing::Dark, None),
+        Species::Zigzagoon => (Typing::Normal, None),
+        Species::Linoone => (Typing::Normal, None),
+        Species::Wurmple => (Typing::Bug, None),
+        Species::Silcoon => (Typing::Bug, None),
+        Species::Beautifly => (Typing::Bug, Some(Typing::Flying)),
+        Species::Cascoon => (Typing::Bug, None),
+        Species::Dustox => (Typing::Bug, Some(Typing::Poison)),
+        Species::Lotad => (Typing::Water, Some(Typing::Grass)),
+        Species::Lombre => (Typing::Water, Some(Typing::Grass)),
+        Species::Ludicolo => (Typing::Water, Some(Typing::Grass)),
+        Species::Seedot => (Typing::Grass, None),
+        Species::Nuzleaf => (Typing::Grass, Some(Typing::Dark)),
+        Species::Shiftry => (Typing::Grass, Some(Typing::Dark)),
+        Species::Taillow => (Typing::Normal, Some(Typing::Flying)),
+        Species::Swellow => (Typing::Normal, Some(Typing::Flying)),
+        Species::Wingull => (Typing::Water, Some(Typing::Flying)),
+        Species::Pelipper => (Typing::Water, Some(Typing::Flying)),
+        Species::Ralts => (Typing::Psychic, Some(Typing::Fairy)),
+        Species::Kirlia => (Typing::Psychic, Some(Typing::Fairy)),
+        Species::Gardevoir(_) => (Typing::Psychic, Some(Typing::Fairy)),
+        Species::Surskit => (Typing::Bug, Some(Typing::Water)),
+        Species::Masquerain => (Typing::Bug, Some(Typing::Flying)),
+        Species::Shroomish => (Typing::Grass, None),
+        Species::Breloom => (Typing::Grass, Some(Typing::Fighting)),
+        Species::Slakoth => (Typing::Normal, None),
+        Species::Vigoroth => (Typing::Normal, None),
+        Species::Slaking => (Typing::Normal, None),
+        Species::Nincada => (Typing::Bug, Some(Typing::Ground)),
+        Species::Ninjask => (Typing::Bug, Some(Typing::Flying)),
+        Species::Shedinja => (Typing::Bug, Some(Typing::Ghost)),
+        Species::Whismur => (Typing::Normal, None),
+        Species::Loudred => (Typing::Normal, None),
+        Species::Exploud => (Typing::Normal, None),
+        Species::Makuhita => (Typing::Fighting, None),
+        Species::Hariyama => (Typing::Fighting, None),
+        Species::Azurill => (Typing::Normal, Some(Typing::Fairy)),
+        Species::Nosepass => (Typing::Rock, None),
+        Species::Skitty => (Typing::Normal, None),
+        Species::Delcatty => (Typing::Normal, None),
+        Species::Sableye(_) => (Typing::Dark, Some(Typing::Ghost)),
+        Species::Mawile(_) => (Typing::Steel, Some(Typing::Fairy)),
+        Species::Aron => (Typing::Steel, Some(Typing::Rock)),
+        Species::Lairon => (Typing::Steel, Some(Typing::Rock)),
+        Species::Aggron(MegaEvolution::Normal) => (Typing::Steel, Some(Typing::Rock)),
+        Species::Aggron(MegaEvolution::Mega) => (Typing::Steel, None),
+        Species::Meditite => (Typing::Fighting, Some(Typing::Psychic)),
+        Species::Medicham(_) => (Typing::Fighting, Some(Typing::Psychic)),
+        Species::Electrike => (Typing::Electric, None),
+        Species::Manectric(_) => (Typing::Electric, None),
+        Species::Plusle => (Typing::Electric, None),
+        Species::Minun => (Typing::Electric, None),
+        Species::Volbeat => (Typing::Bug, None),
+        Species::Illumise => (Typing::Bug, None),
+        Species::Roselia => (Typing::Grass, Some(Typing::Poison)),
+        Species::Gulpin => (Typing::Poison, None),
+        Species::Swalot => (Typing::Poison, None),
+        Species::Carvanha => (Typing::Water, Some(Typing::Dark)),
+        Species::Sharpedo(_) => (Typing::Water, Some(Typing::Dark)),
+        Species::Wailmer => (Typing::Water, None),
+        Species::Wailord => (Typing::Water, None),
+        Species::Numel => (Typing::Fire, Some(Typing::Ground)),
+        Species::Camerupt(_) => (Typing::Fire, Some(Typing::Ground)),
+        Species::Torkoal => (Typing::Fire, None),
+        Species::Spoink => (Typing::Psychic, None),
+        Species::Grumpig => (Typing::Psychic, None),
+        Species::Spinda => (Typing::Normal, None),
+        Species::Trapinch => (Typing::Ground, None),
+        Species::Vibrava => (Typing::Ground, Some(Typing::Dragon)),
+        Species::Flygon => (Typing::Ground, Some(Typing::Dragon)),
+        Species::Cacnea => (Typing::Grass, None),
+        Species::Cacturne => (Typing::Grass, Some(Typing::Dark)),
+        Species::Swablu => (Typing::Normal, Some(Typing::Flying)),
+        Species::Altaria(MegaEvolution::Normal) => (Typing::Dragon, Some(Typing::Flying)),
+        Species::Altaria(MegaEvolution::Mega) => (Typing::Dragon, Some(Typing::Fairy)),
+        Species::Zangoose => (Typing::Normal, None),
+        Species::Seviper => (Typing::Poison, None),
+        Species::Lunatone => (Typing::Rock, Some(Typing::Psychic)),
+        Species::Solrock => (Typing::Rock, Some(Typing::Psychic)),
+        Species::Barboach => (Typing::Water, Some(Typing::Ground)),
+        Species::Whiscash => (Typing::Water, Some(Typing::Ground)),
+        Species::Corphish => (Typing::Water, None),
+        Species::Crawdaunt => (Typing::Water, Some(Typing::Dark)),
+        Species::Baltoy => (Typing::Ground, Some(Typing::Psychic)),
+        Species::Claydol => (Typing::Ground, Some(Typing::Psychic)),
+        Species::Lileep => (Typing::Rock, Some(Typing::Grass)),
+        Species::Cradily => (Typing::Rock, Some(Typing::Grass)),
+        Species::Anorith => (Typing::Rock, Some(Typing::Bug)),
+        Species::Armaldo => (Typing::Rock, Some(Typing::Bug)),
+        Species::Feebas => (Typing::Water, None),
+        Species::Milotic => (Typing::Water, None),
+        Species::Castform(CastformForme::Normal) => (Typing::Normal, None),
+        Species::Castform(CastformForme::Sunny) => (Typing::Fire, None),
+        Species::Castform(CastformForme::Rainy) => (Typing::Water, None),
+        Species::Castform(CastformForme::Snowy) => (Typing::Ice, None),
+        Species::Kecleon => (Typing::Normal, None),
+        Species::Shuppet => (Typing::Ghost, None),
+        Species::Banette => (Typing::Ghost, None),
+        Species::Duskull => (Typing::Ghost, None),
+        Species::Dusclops => (Typing::Ghost, None),
+        Species::Tropius => (Typing::Grass, Some(Typing::Flying)),
+        Species::Chimecho => (Typing::Psychic, None),
+        Species::Absol => (Typing::Dark, None),
+        Species::Wynaut => (Typing::Psychic, None),
+        Species::Snorunt => (Typing::Ice, None),
+        Species::Glalie => (Typing::Ice, None),
+        Species::Spheal => (Typing::Ice, Some(Typing::Water)),
+        Species::Sealeo => (Typing::Ice, Some(Typing::Water)),
+        Species::Walrein => (Typing::Ice, Some(Typing::Water)),
+        Species::Clamperl => (Typing::Water, None),
+        Species::Huntail => (Typing::Water, None),
+        Species::Gorebyss => (Typing::Water, None),
+        Species::Relicanth => (Typing::Water, Some(Typing::Rock)),
+        Species::Luvdisc => (Typing::Water, None),
+        Species::Bagon => (Typing::Dragon, None),
+        Species::Shelgon => (Typing::Dragon, None),
+        Species::Salamence(_) => (Typing::Dragon, Some(Typing::Flying)),
+        Species::Beldum => (Typing::Steel, Some(Typing::Psychic)),
+        Species::Metang => (Typing::Steel, Some(Typing::Psychic)),
+        Species::Metagross(_) => (Typing::Steel, Some(Typing::Psychic)),
+        Species::Regirock => (Typing::Rock, None),
+        Species::Regice => (Typing::Ice, None),
+        Species::Registeel => (Typing::Steel, None),
+        Species::Latias => (Typing::Dragon, Some(Typing::Psychic)),
+        Species::Latios => (Typing::Dragon, Some(Typing::Psychic)),
+        Species::Kyogre(_) => (Typing::Water, None),
+        Species::Groudon(PrimalReversion::Normal) => (Typing::Ground, None),
+        Species::Groudon(PrimalReversion::Primal) => (Typing::Ground, Some(Typing::Fire)),
+        Species::Rayquaza(_) => (Typing::Dragon, Some(Typing::Flying)),
+        Species::Jirachi => (Typing::Steel, Some(Typing::Psychic)),
+        Species::Deoxys(_) => (Typing::Psychic, None),
+        // gen 4 starts here
+        Species::Turtwig => (Typing::Grass, None),
+        Species::Grotle => (Typing::Grass, None),
+        Species::Torterra => (Typing::Grass, Some(Typing::Ground)),
+        Species::Chimchar => (Typing::Fire, None),
+        Species::Monferno => (Typing::Fire, Some(Typing::Fighting)),
+        Species::Infernape => (Typing::Fire, Some(Typing::Fighting)),
+        Species::Piplup => (Typing::Water, None),
+        Species::Prinplup => (Typing::Water, None),
+        Species::Empoleon => (Typing::Water, Some(Typing::Steel)),
+        Species::Starly => (Typing::Normal, Some(Typing::Flying)),
+        Species::Staravia => (Typing::Normal, Some(Typing::Flying)),
+        Species::Staraptor => (Typing::Normal, Some(Typing::Flying)),
+        Species::Bidoof => (Typing::Normal, None),
+        Species::Bibarel => (Typing::Normal, Some(Typing::Water)),
+        Species::Kricketot => (Typing::Bug, None),
+        Species::Kricketune => (Typing::Bug, None),
+        Species::Shinx => (Typing::Electric, None),
+        Species::Luxio => (Typing::Electric, None),
+        Species::Luxray => (Typing::Electric, None),
+        Species::Budew => (Typing::Grass, Some(Typing::Poison)),
+        Species::Roserade => (Typing::Grass, Some(Typing::Poison)),
+        Species::Cranidos => (Typing::Rock, None),
+        Species::Rampardos => (Typing::Rock, None),
+        Species::Shieldon => (Typing::Rock, Some(Typing::Steel)),
+        Species::Bastiodon => (Typing::Rock, Some(Typing::Steel)),
+        Species::Burmy => (Typing::Bug, None),
+        Species::Wormadam(WormadamForme::Plant) => (Typing::Bug, Some(Typing::Grass)),
+        Species::Wormadam(WormadamForme::Sandy) => (Typing::Bug, Some(Typing::Ground)),
+        Species::Wormadam(WormadamForme::Trash) => (Typing::Bug, Some(Typing::Steel)),
+        Species::Mothim => (Typing::Bug, Some(Typing::Flying)),
+        Species::Combee => (Typing::Bug, Some(Typing::Flying)),
+        Species::Vespiquen => (Typing::Bug, Some(Typing::Flying)),
+        Species::Pachirisu => (Typing::Electric, None),
+        Species::Buizel => (Typing::Water, None),
+        Species::Floatzel => (Typing::Water, None),
+        Species::Cherubi => (Typing::Grass, None),
+        Species::Cherrim => (Typing::Grass, None),
+        Species::Shellos => (Typing::Water, None),
+        Species::Gastrodon => (Typing::Water, Some(Typing::Ground)),
+        Species::Ambipom => (Typing::Normal, None),
+        Species::Drifloon => (Typing::Ghost, Some(Typing::Flying)),
+        Species::Drifblim => (Typing::Ghost, Some(Typing::Flying)),
+        Species::Buneary => (Typing::Normal, None),
+        Species::Lopunny(MegaEvolution::Normal) => (Typing::Normal, None),
+        Species::Lopunny(MegaEvolution::Mega) => (Typing::Normal, Some(Typing::Fighting)),
+        Species::Mismagius => (Typing::Ghost, None),
+        Species::Honchkrow => (Typing::Dark, Some(Typing::Flying)),
+        Species::Glameow => (Typing::Normal, None),
+        Species::Purugly => (Typing::Normal, None),
+        Species::Chingling => (Typing::Psychic, None),
+        Species::Stunky => (Typing::Poison, Some(Typing::Dark)),
+        Species::Skuntank => (Typing::Poison, Some(Typing::Dark)),
+        Species::Bronzor => (Typing::Steel, Some(Typing::Psychic)),
+        Species::Bronzong => (Typing::Steel, Some(Typing::Psychic)),
+        Species::Bonsly => (Typing::Rock, None),
+        Species::MimeJr => (Typing::Psychic, Some(Typing::Fairy)),
+        Species::Happiny => (Typing::Normal, None),
+        Species::Chatot => (Typing::Normal, Some(Typing::Flying)),
+        Species::Spiritomb => (Typing::Ghost, Some(Typing::Dark)),
+        Species::Gible => (Typing::Dragon, Some(Typing::Ground)),
+        Species::Gabite => (Typing::Dragon, Some(Typing::Ground)),
+        Species::Garchomp(_) => (Typing::Dragon, Some(Typing::Ground)),
+        Species::Munchlax => (Typing::Normal, None),
+        Species::Riolu => (Typing::Fighting, None),
+        Species::Lucario(_) => (Typing::Fighting, Some(Typing::Steel)),
+        Species::Hippopotas => (Typing::Ground, None),
+        Species::Hippowdon => (Typing::Ground, None),
+        Species::Skorupi => (Typing::Poison, Some(Typing::Bug)),
+        Species::Drapion => (Typing::Poison, Some(Typing::Dark)),
+        Species::Croagunk => (Typing::Poison, Some(Typing::Fighting)),
+        Species::Toxicroak => (Typing::Poison, Some(Typing::Fighting)),
+        Species::Carnivine => (Typing::Grass, None),
+        Species::Finneon => (Typing::Water, None),
+        Species::Lumineon => (Typing::Water, None),
+        Species::Mantyke => (Typing::Water, Some(Typing::Flying)),
+        Species::Snover => (Typing::Grass, Some(Typing::Ice)),
+        Species::Abomasnow(_) => (Typing::Grass, Some(Typing::Ice)),
+        Species::Weavile => (Typing::Dark, Some(Typing::Ice)),
+        Species::Magnezone => (Typing::Electric, Some(Typing::Steel)),
+        Species::Lickilicky => (Typing::Normal, None),
+        Species::Rhyperior => (Typing::Ground, Some(Typing::Rock)),
+        Species::Tangrowth => (Typing::Grass, None),
+        Species::Electivire => (Typing::Electric, None),
+        Species::Magmortar => (Typing::Fire, None),
+        Species::Togekiss => (Typing::Fairy, Some(Typing::Flying)),
+        Species::Yanmega => (Typing::Bug, Some(Typing::Flying)),
+        Species::Leafeon => (Typing::Grass, None),
+        Species::Glaceon => (Typing::Ice, None),
+        Species::Gliscor => (Typing::Ground, Some(Typing::Flying)),
+        Species::Mamoswine => (Typing::Ice, Some(Typing::Ground)),
+        Species::PorygonZ => (Typing::Normal, None),
+        Species::Gallade(_) => (Typing::Psychic, Some(Typing::Fighting)),
+        Species::Probopass => (Typing::Rock, Some(Typing::Steel)),
+        Species::Dusknoir => (Typing::Ghost, None),
+        Species::Froslass => (Typing::Ice, Some(Typing::Ghost)),
+        Species::Rotom(RotomForme::Ghost) => (Typing::Electric, Some(Typing::Ghost)),
+        Species::Rotom(RotomForme::Heat) => (Typing::Electric, Some(Typing::Fire)),
+        Species::Rotom(RotomForme::Wash) => (Typing::Electric, Some(Typing::Water)),
+        Species::Rotom(RotomForme::Frost) => (Typing::Electric, Some(Typing::Ice)),
+        Species::Rotom(RotomForme::Fan) => (Typing::Electric, Some(Typing::Flying)),
+        Species::Rotom(RotomForme::Mow) => (Typing::Electric, Some(Typing::Grass)),
+        Species::Uxie => (Typing::Psychic, None),
+        Species::Mesprit => (Typing::Psychic, None),
+        Species::Azelf => (Typing::Psychic, None),
+        Species::Dialga => (Typing::Steel, Some(Typing::Dragon)),
+        Species::Palkia => (Typing::Water, Some(Typing::Dragon)),
+        Species::Heatran => (Typing::Fire, Some(Typing::Steel)),
+        Species::Regigigas => (Typing::Normal, None),
+        Species::Giratina(_) => (Typing::Ghost, Some(Typing::Dragon)),
+        Species::Cresselia => (Typing::Psychic, None),
+        Species::Phione => (Typing::Water, None),
+        Species::Manaphy => (Typing::Water, None),
+        Species::Darkrai => (Typing::Dark, None),
+        Species::Shaymin(ShayminForme::Land) => (Typing::Grass, None),
+        Species::Shaymin(ShayminForme::Sky) => (Typing::Grass, Some(Typing::Flying)),
+        Species::Arceus(ArceusForme::Normal) => (Typing::Normal, None),
+        Species::Arceus(ArceusForme::Fire) => (Typing::Fire, None),
+        Species::Arceus(ArceusForme::Water) => (Typing::Water, None),
+        Species::Arceus(ArceusForme::Electric) => (Typing::Electric, None),
+        Species::Arceus(ArceusForme::Grass) => (Typing::Grass, None),
+        Species::Arceus(ArceusForme::Ice) => (Typing::Ice, None),
+        Species::Arceus(ArceusForme::Fighting) => (Typing::Fighting, None),
+        Species::Arceus(ArceusForme::Poison) => (Typing::Poison, None),
+        Species::Arceus(ArceusForme::Ground) => (Typing::Ground, None),
+        Species::Arceus(ArceusForme::Flying) => (Typing::Flying, None),
+        Species::Arceus(ArceusForme::Psychic) => (Typing::Psychic, None),
+        Species::Arceus(ArceusForme::Bug) => (Typing::Bug, None),
+        Species::Arceus(ArceusForme::Rock) => (Typing::Rock, None),
+        Species::Arceus(ArceusForme::Ghost) => (Typing::Ghost, None),
+        Species::Arceus(ArceusForme::Dragon) => (Typing::Dragon, None),
+        Species::Arceus(ArceusForme::Dark) => (Typing::Dark, None),
+        Species::Arceus(ArceusForme::Steel) => (Typing::Steel, None),
+        Species::Arceus(ArceusForme::Fairy) => (Typing::Fairy, None),
+        // gen 5 starts here
+        Species::Victini => (Typing::Psychic, Some(Typing::Fire)),
+        Species::Snivy => (Typing::Grass, None),
+        Species::Servine => (Typing::Grass, None),
+        Species::Serperior => (Typing::Grass, None),
+        Species::Tepig => (Typing::Fire, None),
+        Species::Pignite => (Typing::Fire, Some(Typing::Fighting)),
+        Species::Emboar => (Typing::Fire, Some(Typing::Fighting)),
+        Species::Oshawott => (Typing::Water, None),
+        Species::Dewott => (Typing::Water, None),
+        Species::Samurott => (Typing::Water, None),
+        Species::Patrat => (Typing::Normal, None),
+        Species::Watchog => (Typing::Normal, None),
+        Species::Lillipup => (Typing::Normal, None),
+        Species::Herdier => (Typing::Normal, None),
+        Species::Stoutland => (Typing::Normal, None),
+        Species::Purrloin => (Typing::Dark, None),
+        Species::Liepard => (Typing::Dark, None),
+        Species::Pansage => (Typing::Grass, None),
+        Species::Simisage => (Typing::Grass, None),
+        Species::Pansear => (Typing::Fire, None),
+        Species::Simisear => (Typing::Fire, None),
+        Species::Panpour => (Typing::Water, None),
+        Species::Simipour => (Typing::Water, None),
+        Species::Munna => (Typing::Psychic, None),
+        Species::Musharna => (Typing::Psychic, None),
+        Species::Pidove => (Typing::Normal, Some(Typing::Flying)),
+        Species::Tranquill => (Typing::Normal, Some(Typing::Flying)),
+        Species::Unfezant => (Typing::Normal, Some(Typing::Flying)),
+        Species::Blitzle => (Typing::Electric, None),
+        Species::Zebstrika => (Typing::Electric, None),
+        Species::Roggenrola => (Typing::Rock, None),
+        Species::Boldore => (Typing::Rock, None),
+        Species::Gigalith => (Typing::Rock, None),
+        Species::Woobat => (Typing::Psychic, Some(Typing::Flying)),
+        Species::Swoobat => (Typing::Psychic, Some(Typing::Flying)),
+        Species::Drilbur => (Typing::Ground, None),
+        Species::Excadrill => (Typing::Ground, Some(Typing::Steel)),
+        Species::Audino => (Typing::Normal, None),
+        Species::Timburr => (Typing::Fighting, None),
+        Species::Gurdurr => (Typing::Fighting, None),
+        Species::Conkeldurr => (Typing::Fighting, None),
+        Species::Tympole => (Typing::Water, None),
+        Species::Palpitoad => (Typing::Water, Some(Typing::Ground)),
+        Species::Seismitoad => (Typing::Water, Some(Typing::Ground)),
+        Species::Throh => (Typing::Fighting, None),
+        Species::Sawk => (Typing::Fighting, None),
+        Species::Sewaddle => (Typing::Bug, Some(Typing::Grass)),
+        Species::Swadloon => (Typing::Bug, Some(Typing::Grass)),
+        Species::Leavanny => (Typing::Bug, Some(Typing::Grass)),
+        Species::Venipede => (Typing::Bug, Some(Typing::Poison)),
+        Species::Whirlipede => (Typing::Bug, Some(Typing::Poison)),
+        Species::Scolipede => (Typing::Bug, Some(Typing::Poison)),
+        Species::Cottonee => (Typing::Grass, Some(Typing::Fairy)),
+        Species::Whimsicott => (Typing::Grass, Some(Typing::Fairy)),
+        Species::Petilil => (Typing::Grass, None),
+        Species::Lilligant => (Typing::Grass, None),
+        Species::Basculin => (Typing::Water, None),
+        Species::Sandile => (Typing::Ground, Some(Typing::Dark)),
+        Species::Krokorok => (Typing::Ground, Some(Typing::Dark)),
+        Species::Krookodile => (Typing::Ground, Some(Typing::Dark)),
+        Species::Darumaka => (Typing::Fire, None),
+        Species::Darmanitan(DarmanitanForme::Standard) => (Typing::Fire, None),
+        Species::Darmanitan(DarmanitanForme::ZenMode) => (Typing::Fire, Some(Typing::Psychic)),
+        Species::Maractus => (Typing::Grass, None),
+        Species::Dwebble => (Typing::Bug, Some(Typing::Rock)),
+        Species::Crustle => (Typing::Bug, Some(Typing::Rock)),
+        Species::Scraggy => (Typing::Dark, Some(Typing::Fighting)),
+        Species::Scrafty => (Typing::Dark, Some(Typing::Fighting)),
+        Species::Sigilyph => (Typing::Psychic, Some(Typing::Flying)),
+        Species::Yamask => (Typing::Ghost, None),
+        Species::Cofagrigus => (Typing::Ghost, None),
+        Species::Tirtouga => (Typing::Water, Some(Typing::Rock)),
+        Species::Carracosta => (Typing::Water, Some(Typing::Rock)),
+        Species::Archen => (Typing::Rock, Some(Typing::Flying)),
+        Species::Archeops => (Typing::Rock, Some(Typing::Flying)),
+        Species::Trubbish => (Typing::Poison, None),
+        Species::Garbodor => (Typing::Poison, None),
+        Species::Zorua => (Typing::Dark, None),
+        Species::Zoroark => (Typing::Dark, None),
+        Species::Minccino => (Typing::Normal, None),
+        Species::Cinccino => (Typing::Normal, None),
+        Species::Gothita => (Typing::Psychic, None),
+        Species::Gothorita => (Typing::Psychic, None),
+        Species::Gothitelle => (Typing::Psychic, None),
+        Species::Solosis => (Typing::Psychic, None),
+        Species::Duosion => (Typing::Psychic, None),
+        Species::Reuniclus => (Typing::Psychic, None),
+        Species::Ducklett => (Typing::Water, Some(Typing::Flying)),
+        Species::Swanna => (Typing::Water, Some(Typing::Flying)),
+        Species::Vanillite => (Typing::Ice, None),
+        Species::Vanillish => (Typing::Ice, None),
+        Species::Vanilluxe => (Typing::Ice, None),
+        Species::Deerling => (Typing::Normal, Some(Typing::Grass)),
+        Species::Sawsbuck => (Typing::Normal, Some(Typing::Grass)),
+        Species::Emolga => (Typing::Electric, Some(Typing::Flying)),
+        Species::Karrablast => (Typing::Bug, None),
+        Species::Escavalier => (Typing::Bug, Some(Typing::Steel)),
+        Species::Foongus => (Typing::Grass, Some(Typing::Poison)),
+        Species::Amoonguss => (Typing::Grass, Some(Typing::Poison)),
+        Species::Frillish => (Typing::Water, Some(Typing::Ghost)),
+        Species::Jellicent => (Typing::Water, Some(Typing::Ghost)),
+        Species::Alomomola => (Typing::Water, None),
+        Species::Joltik => (Typing::Bug, Some(Typing::Electric)),
+        Species::Galvantula => (Typing::Bug, Some(Typing::Electric)),
+        Species::Ferroseed => (Typing::Grass, Some(Typing::Steel)),
+        Species::Ferrothorn => (Typing::Grass, Some(Typing::Steel)),
+        Species::Klink => (Typing::Steel, None),
+        Species::Klang => (Typing::Steel, None),
+        Species::Klinklang => (Typing::Steel, None),
+        Species::Tynamo => (Typing::Electric, None),
+        Species::Eelektrik => (Typing::Electric, None),
+        Species::Eelektross => (Typing::Electric, None),
+        Species::Elgyem => (Typing::Psychic, None),
+        Species::Beheeyem => (Typing::Psychic, None),
+        Species::Litwick => (Typing::Ghost, Some(Typing::Fire)),
+        Species::Lampent => (Typing::Ghost, Some(Typing::Fire)),
+        Species::Chandelure => (Typing::Ghost, Some(Typing::Fire)),
+        Species::Axew => (Typing::Dragon, None),
+        Species::Fraxure => (Typing::Dragon, None),
+        Species::Haxorus => (Typing::Dragon, None),
+        Species::Cubchoo => (Typing::Ice, None),
+        Species::Beartic => (Typing::Ice, None),
+        Species::Cryogonal => (Typing::Ice, None),
+        Species::Shelmet => (Typing::Bug, None),
+        Species::Accelgor => (Typing::Bug, None),
+        Species::Stunfisk => (Typing::Ground, Some(Typing::Electric)),
+        Species::Mienfoo => (Typing::Fighting, None),
+        Species::Mienshao => (Typing::Fighting, None),
+        Species::Druddigon => (Typing::Dragon, None),
+        Species::Golett => (Typing::Ground, Some(Typing::Ghost)),
+        Species::Golurk => (Typing::Ground, Some(Typing::Ghost)),
+        Species::Pawniard => (Typing::Dark, Some(Typing::Steel)),
+        Species::Bisharp => (Typing::Dark, Some(Typing::Steel)),
+        Species::Bouffalant => (Typing::Normal, None),
+        Species::Rufflet => (Typing::Normal, Some(Typing::Flying)),
+        Species::Braviary => (Typing::Normal, Some(Typing::Flying)),
+        Species::Vullaby => (Typing::Dark, Some(Typing::Flying)),
+        Species::Mandibuzz => (Typing::Dark, Some(Typing::Flying)),
+        Species::Heatmor => (Typing::Fire, None),
+        Species::Durant => (Typing::Bug, Some(Typing::Steel)),
+        Species::Deino => (Typing::Dark, Some(Typing::Dragon)),
+        Species::Zweilous => (Typing::Dark, Some(Typing::Dragon)),
+        Species::Hydreigon => (Typing::Dark, Some(Typing::Dragon)),
+        Species::Larvesta => (Typing::Bug, Some(Typing::Fire)),
+        Species::Volcarona => (Typing::Bug, Some(Typing::Fire)),
+        Species::Cobalion => (Typing::Steel, Some(Typing::Fighting)),
+        Species::Terrakion => (Typing::Rock, Some(Typing::Fighting)),
+        Species::Virizion => (Typing::Grass, Some(Typing::Fighting)),
+        Species::Tornadus(_) => (Typing::Flying, None),
+        Species::Thundurus(_) => (Typing::Electric, Some(Typing::Flying)),
+        Species::Reshiram => (Typing::Dragon, Some(Typing::Fire)),
+        Species::Zekrom => (Typing::Dragon, Some(Typing::Electric)),
+        Species::Landorus(_) => (Typing::Ground, Some(Typing::Flying)),
+        Species::Kyurem(_) => (Typing::Dragon, Some(Typing::Ice)),
+        Species::Keldeo => (Typing::Water, Some(Typing::Fighting)),
+        Species::Meloetta(MeloettaForme::Aria) => (Typing::Normal, Some(Typing::Psychic)),
+        Species::Meloetta(MeloettaForme::Pirouette) => (Typing::Normal, Some(Typing::Fighting)),
+        Species::Genesect => (Typing::Bug, Some(Typing::Steel)),
+        // gen 6 starts here
+        Species::Chespin => (Typing::Grass, None),
+        Species::Quilladin => (Typing::Grass, None),
+        Species::Chesnaught => (Typing::Grass, Some(Typing::Fighting)),
+        Species::Fennekin => (Typing::Fire, None),
+        Species::Braixen => (Typing::Fire, None),
+        Species::Delphox => (Typing::Fire, Some(Typing::Psychic)),
+        Species::Froakie => (Typing::Water, None),
+        Species::Frogadier => (Typing::Water, None),
+        Species::Greninja(_) => (Typing::Water, Some(Typing::Dark)),
+        Species::Bunnelby => (Typing::Normal, None),
+        Species::Diggersby => (Typing::Normal, Some(Typing::Ground)),
+        Species::Fletchling => (Typing::Normal, Some(Typing::Flying)),
+        Species::Fletchinder => (Typing::Fire, Some(Typing::Flying)),
+        Species::Talonflame => (Typing::Fire, Some(Typing::Flying)),
+        Species::Scatterbug => (Typing::Bug, None),
+        Species::Spewpa => (Typing::Bug, None),
+        Species::Vivillon => (Typing::Bug, Some(Typing::Flying)),
+        Species::Litleo => (Typing::Fire, Some(Typing::Normal)),
+        Species::Pyroar => (Typing::Fire, Some(Typing::Normal)),
+        Species::Flabebe => (Typing::Fairy, None),
+        Species::Floette => (Typing::Fairy, None),
+        Species::Florges => (Typing::Fairy, None),
+        Species::Skiddo => (Typing::Grass, None),
+        Species::Gogoat => (Typing::Grass, None),
+        Species::Pancham => (Typing::Fighting, None),
+        Species::Pangoro => (Typing::Fighting, Some(Typing::Dark)),
+        Species::Furfrou => (Typing::Normal, None),
+        Species::Espurr => (Typing::Psychic, None),
+        Species::Meowstic => (Typing::Psychic, None),
+        Species::Honedge => (Typing::Steel, Some(Typing::Ghost)),
+        Species::Doublade => (Typing::Steel, Some(Typing::Ghost)),
+        Species::Aegislash(_) => (Typing::Steel, Some(Typing::Ghost)),
+        Species::Spritzee => (Typing::Fairy, None),
+        Species::Aromatisse => (Typing::Fairy, None),
+        Species::Swirlix => (Typing::Fairy, None),
+        Species::Slurpuff => (Typing::Fairy, None),
+        Species::Inkay => (Typing::Dark, Some(Typing::Psychic)),
+        Species::Malamar => (Typing::Dark, Some(Typing::Psychic)),
+        Species::Binacle => (Typing::Rock, Some(Typing::Water)),
+        Species::Barbaracle => (Typing::Rock, Some(Typing::Water)),
+        Species::Skrelp => (Typing::Poison, Some(Typing::Water)),
+        Species::Dragalge => (Typing::Poison, Some(Typing::Dragon)),
+        Species::Clauncher => (Typing::Water, None),
+        Species::Clawitzer => (Typing::Water, None),
+        Species::Helioptile => (Typing::Electric, Some(Typing::Normal)),
+        Species::Heliolisk => (Typing::Electric, Some(Typing::Normal)),
+        Species::Tyrunt => (Typing::Rock, Some(Typing::Dragon)),
+        Species::Tyrantrum => (Typing::Rock, Some(Typing::Dragon)),
+        Species::Amaura => (Typing::Rock, Some(Typing::Ice)),
+        Species::Aurorus => (Typing::Rock, Some(Typing::Ice)),
+        Species::Sylveon => (Typing::Fairy, None),
+        Species::Hawlucha => (Typing::Fighting, Some(Typing::Flying)),
+        Species::Dedenne => (Typing::Electric, Some(Typing::Fairy)),
+        Species::Carbink => (Typing::Rock, Some(Typing::Fairy)),
+        Species::Goomy => (Typing::Dragon, None),
+        Species::Sliggoo => (Typing::Dragon, None),
+        Species::Goodra => (Typing::Dragon, None),
+        Species::Klefki => (Typing::Steel, Some(Typing::Fairy)),
+        Species::Phantump => (Typing::Ghost, Some(Typing::Grass)),
+        Species::Trevenant => (Typing::Ghost, Some(Typing::Grass)),
+        Species::Pumpkaboo(_) => (Typing::Ghost, Some(Typing::Grass)),
+        Species::Gourgeist(_) => (Typing::Ghost, Some(Typing::Grass)),
+        Species::Bergmite => (Typing::Ice, None),
+        Species::Avalugg => (Typing::Ice, None),
+        Species::Noibat => (Typing::Flying, Some(Typing::Dragon)),
+        Species::Noivern => (Typing::Flying, Some(Typing::Dragon)),
+        Species::Xerneas => (Typing::Fairy, None),
+        Species::Yveltal => (Typing::Dark, Some(Typing::Flying)),
+        Species::Zygarde(_) => (Typing::Dragon, Some(Typing::Ground)),
+        Species::Diancie(_) => (Typing::Rock, Some(Typing::Fairy)),
+        Species::Hoopa(HoopaForme::Confined) => (Typing::Psychic, Some(Typing::Ghost)),
+        Species::Hoopa(HoopaForme::Unbound) => (Typing::Psychic, Some(Typing::Dark)),
+        Species::Volcanion => (Typing::Fire, Some(Typing::Water)),
+        // gen 7 starts here
+        Species::Rowlet => (Typing::Grass, Some(Typing::Flying)),
+        Species::Dartrix => (Typing::Grass, Some(Typing::Flying)),
+        Species::Decidueye => (Typing::Grass, Some(Typing::Ghost)),
+        Species::Litten => (Typing::Fire, None),
+        Species::Torracat => (Typing::Fire, None),
+        Species::Incineroar => (Typing::Fire, Some(Typing::Dark)),
+        Species::Popplio => (Typing::Water, None),
+        Species::Brionne => (Typing::Water, None),
+        Species::Primarina => (Typing::Water, Some(Typing::Fairy)),
+        Species::Pikipek => (Typing::Normal, Some(Typing::Flying)),
+        Species::Trumbeak => (Typing::Normal, Some(Typing::Flying)),
+        Species::Toucannon => (Typing::Normal, Some(Typing::Flying)),
+        Species::Yungoos => (Typing::Normal, None),
+        Species::Gumshoos => (Typing::Normal, None),
+        Species::Grubbin => (Typing::Bug, None),
+        Species::Charjabug => (Typing::Bug, Some(Typing::Electric)),
+        Species::Vikavolt => (Typing::Bug, Some(Typing::Electric)),
+        Species::Crabrawler => (Typing::Fighting, None),
+        Species::Crabominable => (Typing::Fighting, Some(Typing::Ice)),
+        Species::Oricorio(OricorioForme::Baile) => (Typing::Fire, Some(Typing::Flying)),
+        Species::Oricorio(OricorioForme::PomPom) => (Typing::Electric, Some(Typing::Flying)),
+        Species::Oricorio(OricorioForme::Pau) => (Typing::Psychic, Some(Typing::Flying)),
+        Species::Oricorio(OricorioForme::Sensu) => (Typing::Ghost, Some(Typing::Flying)),
+        Species::Cutiefly => (Typing::Bug, Some(Typing::Fairy)),
+        Species::Ribombee => (Typing::Bug, Some(Typing::Fairy)),
+        Species::Rockruff => (Typing::Rock, None),
+        Species::Lycanroc(_) => (Typing::Rock, None),
+        Species::Wishiwashi(_) => (Typing::Water, None),
+        Species::Mareanie => (Typing::Poison, Some(Typing::Water)),
+        Species::Toxapex => (Typing::Poison, Some(Typing::Water)),
+        Species::Mudbray => (Typing::Ground, None),
+        Species::Mudsdale => (Typing::Ground, None),
+        Species::Dewpider => (Typing::Water, Some(Typing::Bug)),
+        Species::Araquanid => (Typing::Water, Some(Typing::Bug)),
+        Species::Fomantis => (Typing::Grass, None),
+        Species::Lurantis => (Typing::Grass, None),
+        Species::Morelull => (Typing::Grass, Some(Typing::Fairy)),
+        Species::Shiinotic => (Typing::Grass, Some(Typing::Fairy)),
+        Species::Salandit => (Typing::Poison, Some(Typing::Fire)),
+        Species::Salazzle => (Typing::Poison, Some(Typing::Fire)),
+        Species::Stufful => (Typing::Normal, Some(Typing::Fighting)),
+        Species::Bewear => (Typing::Normal, Some(Typing::Fighting)),
+        Species::Bounsweet => (Typing::Grass, None),
+        Species::Steenee => (Typing::Grass, None),
+        Species::Tsareena => (Typing::Grass, None),
+        Species::Comfey => (Typing::Fairy, None),
+        Species::Oranguru => (Typing::Normal, Some(Typing::Psychic)),
+        Species::Passimian => (Typing::Fighting, None),
+        Species::Wimpod => (Typing::Bug, Some(Typing::Water)),
+        Species::Golisopod => (Typing::Bug, Some(Typing::Water)),
+        Species::Sandygast => (Typing::Ghost, Some(Typing::Ground)),
+        Species::Palossand => (Typing::Ghost, Some(Typing::Ground)),
+        Species::Pyukumuku => (Typing::Water, None),
+        Species::TypeNull(_) => (Typing::Normal, None),
+        Species::Silvally(SilvallyForme::Normal) => (Typing::Normal, None),
+        Species::Silvally(SilvallyForme::Fire) => (Typing::Fire, None),
+        Species::Silvally(SilvallyForme::Water) => (Typing::Water, None),
+        Species::Silvally(SilvallyForme::Electric) => (Typing::Electric, None),
+        Species::Silvally(SilvallyForme::Grass) => (Typing::Grass, None),
+        Species::Silvally(SilvallyForme::Ice) => (Typing::Ice, None),
+        Species::Silvally(SilvallyForme::Fighting) => (Typing::Fighting, None),
+        Species::Silvally(SilvallyForme::Poison) => (Typing::Poison, None),
+        Species::Silvally(SilvallyForme::Ground) => (Typing::Ground, None),
+        Species::Silvally(SilvallyForme::Flying) => (Typing::Flying, None),
+        Species::Silvally(SilvallyForme::Psychic) => (Typing::Psychic, None),
+        Species::Silvally(SilvallyForme::Bug) => (Typing::Bug, None),
+        Species::Silvally(SilvallyForme::Rock) => (Typing::Rock, None),
+        Species::Silvally(SilvallyForme::Ghost) => (Typing::Ghost, None),
+        Species::Silvally(SilvallyForme::Dragon) => (Typing::Dragon, None),
+        Species::Silvally(SilvallyForme::Dark) => (Typing::Dark, None),
+        Species::Silvally(SilvallyForme::Steel) => (Typing::Steel, None),
+        Species::Silvally(SilvallyForme::Fairy) => (Typing::Fairy, None),
+        Species::Minior(_) => (Typing::Rock, Some(Typing::Flying)),
+        Species::Komala => (Typing::Normal, None),
+        Species::Turtonator => (Typing::Fire, Some(Typing::Dragon)),
+        Species::Togedemaru => (Typing::Electric, Some(Typing::Steel)),
+        Species::Mimikyu(_) => (Typing::Ghost, Some(Typing::Fairy)),
+        Species::Bruxish => (Typing::Water, Some(Typing::Psychic)),
+        Species::Drampa => (Typing::Normal, Some(Typing::Dragon)),
+        Species::Dhelmise => (Typing::Ghost, Some(Typing::Grass)),
+        Species::JangmoO => (Typing::Dragon, None),
+        Species::HakamoO => (Typing::Dragon, Some(Typing::Fighting)),
+        Species::KommoO => (Typing::Dragon, Some(Typing::Fighting)),
+        Species::TapuKoko => (Typing::Electric, Some(Typing::Fairy)),
+        Species::TapuLele => (Typing::Psychic, Some(Typing::Fairy)),
+        Species::TapuBulu => (Typing::Grass, Some(Typing::Fairy)),
+        Species::TapuFini => (Typing::Water, Some(Typing::Fairy)),
+        Species::Cosmog => (Typing::Psychic, None),
+        Species::Cosmoem => (Typing::Psychic, None),
+        Species::Solgaleo => (Typing::Psychic, Some(Typing::Steel)),
+        Species::Lunala => (Typing::Psychic, Some(Typing::Ghost)),
+        Species::Nihilego => (Typing::Rock, Some(Typing::Poison)),
+        Species::Buzzwole => (Typing::Bug, Some(Typing::Fighting)),
+        Species::Pheromosa => (Typing::Bug, Some(Typing::Fighting)),
+        Species::Xurkitree => (Typing::Electric, None),
+        Species::Celesteela => (Typing::Steel, Some(Typing::Flying)),
+        Species::Kartana => (Typing::Grass, Some(Typing::Steel)),
+        Species::Guzzlord => (Typing::Dark, Some(Typing::Dragon)),
+        Species::Necrozma(NecrozmaForme::Normal) => (Typing::Psychic, None),
+        Species::Necrozma(NecrozmaForme::DuskMane) => (Typing::Psychic, Some(Typing::Steel)),
+        Species::Necrozma(NecrozmaForme::DuskWings) => (Typing::Psychic, Some(Typing::Ghost)),
+        Species::Necrozma(NecrozmaForme::Ultra) => (Typing::Psychic, Some(Typing::Dragon)),
+        Species::Magearna => (Typing::Steel, Some(Typing::Fairy)),
+        Species::Marshadow => (Typing::Fighting, Some(Typing::Ghost)),
+        Species::Poipole => (Typing::Poison, None),
+        Species::Naganadel => (Typing::Poison, Some(Typing::Dragon)),
+        Species::Stakataka => (Typing::Rock, Some(Typing::Steel)),
+        Species::Blacephalon => (Typing::Fire, Some(Typing::Ghost)),
+        Species::Zeraora => (Typing::Electric, None),
+    }
+}
+
+/// Returns the type of Revelation Dance when used by the given species, which is the user's primary
+/// type. This is most relevant for Oricorio, the only Pokemon that can learn it normally, whose
+/// formes each give it a different type.
+pub fn revelation_dance_type(species: Species) -> Typing {
+    species_typing(species).0
+}
+
+/// Returns the type of Judgment when used by Arceus in the given forme, which is Arceus's type.
+pub fn judgment_type(forme: ArceusForme) -> Typing {
+    species_typing(Species::Arceus(forme)).0
+}
+
+/// Returns the type of Multi-Attack when used by Silvally in the given forme, which is Silvally's
+/// type.
+pub fn multi_attack_type(forme: SilvallyForme) -> Typing {
+    species_typing(Species::Silvally(forme)).0
+}
+
+/// Returns the type of Techno Blast when the user holds the given item: the type of the drive if it's
+/// holding one, and Normal otherwise.
+pub fn techno_blast_type(item: Option<Item>) -> Typing {
+    item.and_then(Item::drive_type).unwrap_or(Typing::Normal)
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn test_species_typing() {
+        assert_eq!(species_typing(Species::Garchomp(MegaEvolution::Mega)), (Typing::Dragon, Some(Typing::Ground)));
+        assert_eq!(species_typing(Species::Charizard(XYMegaEvolution::MegaX)), (Typing::Fire, Some(Typing::Dragon)));
+        assert_eq!(species_typing(Species::Marowak(AlolaForme::Alola)), (Typing::Fire, Some(Typing::Ghost)));
+        assert_eq!(species_typing(Species::Rotom(RotomForme::Wash)), (Typing::Electric, Some(Typing::Water)));
+        assert_eq!(species_typing(Species::Arceus(ArceusForme::Ground)), (Typing::Ground, None));
+        assert_eq!(species_typing(Species::Pikachu), (Typing::Electric, None));
+    }
+    #[test]
+    fn test_revelation_dance() {
+        assert_eq!(revelation_dance_type(Species::Oricorio(OricorioForme::Baile)), Typing::Fire);
+        assert_eq!(revelation_dance_type(Species::Oricorio(OricorioForme::PomPom)), Typing::Electric);
+        assert_eq!(revelation_dance_type(Species::Oricorio(OricorioForme::Pau)), Typing::Psychic);
+        assert_eq!(revelation_dance_type(Species::Oricorio(OricorioForme::Sensu)), Typing::Ghost);
+        assert_eq!(revelation_dance_type(Species::Lycanroc(LycanrocForme::Dusk)), Typing::Rock);
+    }
+    #[test]
+    fn test_item_move_types() {
+        assert_eq!(judgment_type(ArceusForme::Water), Typing::Water);
+        assert_eq!(judgment_type(ArceusForme::Normal), Typing::Normal);
+        assert_eq!(multi_attack_type(SilvallyForme::Steel), Typing::Steel);
+        assert_eq!(techno_blast_type(Some(Item::DouseDrive)), Typing::Water);
+        assert_eq!(techno_blast_type(Some(Item::ChillDrive)), Typing::Ice);
+        assert_eq!(techno_blast_type(Some(Item::SplashPlate)), Typing::Normal);
+        assert_eq!(techno_blast_type(None), Typing::Normal);
+    }
+}
@@ -0,0 +1,55 @@
+//! This file computes speed tiers: the actual Speed stats of Pokemon at a given investment, which
+//! competitive players use to figure out who outspeeds whom. Effects that multiply Speed outside of
+//! stat stages, like Choice Scarf or paralysis, aren't handled here: apply them to the result.
+
+use std::cmp::Reverse;
+
+use crate::base_stats::base_stats;
+use crate::nature::Nature;
+use crate::species::Species;
+use crate::stat::{calc_stat, Stat};
+use crate::stat_stage::StatStage;
+
+/// Calculates a Pokemon's actual Speed from its base Speed and investment, applying the stat stage
+/// multiplier on top of the computed stat and flooring the result, as the games do.
+pub fn speed_tier(base_speed: u16, level: u8, nature: Nature, ev: u8, iv: u8, stage: StatStage) -> u16 {
+    let speed = calc_stat(Stat::Spe, base_speed, level, nature, ev, iv);
+    (f64::from(speed) * stage.normal_multiplier()).floor() as u16
+}
+
+/// Gives the Speed of each of the given species at the given level with maximum investment: a
+/// Speed-boosting nature, 252 EVs, and 31 IVs, with no stat stages. The result is sorted from fastest
+/// to slowest, and species that tie keep the order they were given in.
+pub fn speed_tiers(species: &[Species], level: u8) -> Vec<(Species, u16)> {
+    let mut tiers: Vec<(Species, u16)> = species.iter().map(|&s| {
+        (s, speed_tier(base_stats(s).spe, level, Nature::Jolly, 252, 31, StatStage::Z0))
+    }).collect();
+    tiers.sort_by_key(|&(_, speed)| Reverse(speed));
+    tiers
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn test_speed_tier() {
+        // base 100, like Salamence or Volcarona
+        assert_eq!(speed_tier(100, 100, Nature::Timid, 252, 31, StatStage::Z0), 328);
+        assert_eq!(speed_tier(100, 100, Nature::Timid, 252, 31, StatStage::P1), 492);
+        assert_eq!(speed_tier(100, 100, Nature::Modest, 0, 31, StatStage::N1), 157);
+        // a Choice Scarf is applied to the result
+        let scarfed = (f64::from(speed_tier(100, 100, Nature::Jolly, 252, 31, StatStage::Z0)) * 1.5) as u16;
+        assert_eq!(scarfed, 492);
+    }
+    #[test]
+    fn test_speed_tiers() {
+        use crate::species::MegaEvolution;
+        let tiers = speed_tiers(&[Species::Ferrothorn, Species::Garchomp(MegaEvolution::Normal),
+                                  Species::Weavile], 100);
+        assert_eq!(tiers, vec![(Species::Weavile, 383),
+                               (Species::Garchomp(MegaEvolution::Normal), 333),
+                               (Species::Ferrothorn, 152)]);
+    }
+}
@@ -1,11 +1,15 @@
 //! This file provides a simple way of dealing with Pokemon stats, of which there are 6: HP, Attack,
 //! Defense, Special Attack, Special Defense, and Speed.
 
+use std::error;
 use std::fmt;
+use std::str::FromStr;
+
+use crate::nature::Nature;
 
 /// One of the six Pokemon stats. The abbreviated names are used to reduce ambiguity in
 /// nomenclature. The long forms are used for string conversion.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Stat {
     HP,
     Atk,
@@ -17,14 +21,152 @@ pub enum Stat {
 
 impl fmt::Display for Stat {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", match *self {
+        f.write_str(self.name())
+    }
+}
+
+impl Stat {
+    /// Returns the full name of the stat, like "Special Attack". This is what `Display` writes, but it
+    /// doesn't allocate a `String`.
+    pub fn name(self) -> &'static str {
+        match self {
             Stat::HP => "HP",
             Stat::Atk => "Attack",
             Stat::Def => "Defense",
             Stat::SpA => "Special Attack",
             Stat::SpD => "Special Defense",
             Stat::Spe => "Speed",
-        })
+        }
+    }
+    /// Returns the abbreviated name of the stat, as used in Showdown EV spreads: HP, Atk, Def, SpA,
+    /// SpD, or Spe.
+    pub fn abbreviation(self) -> &'static str {
+        match self {
+            Stat::HP => "HP",
+            Stat::Atk => "Atk",
+            Stat::Def => "Def",
+            Stat::SpA => "SpA",
+            Stat::SpD => "SpD",
+            Stat::Spe => "Spe",
+        }
+    }
+}
+
+/// An error for parsing a string that isn't the name of a stat.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InvalidStatError {
+}
+
+impl fmt::Display for InvalidStatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid stat name")
+    }
+}
+
+impl error::Error for InvalidStatError {
+    fn description(&self) -> &str {
+        "given string was not a stat's abbreviation (like SpA) or full name (like Special Attack)"
+    }
+
+    fn cause(&self) -> Option<&error::Error> {
+        // Generic error, underlying cause isn't tracked.
+        None
+    }
+}
+
+impl FromStr for Stat {
+    type Err = InvalidStatError;
+
+    /// Parses either the abbreviated name of a stat, as used in Showdown EV spreads ("HP", "Atk",
+    /// "Def", "SpA", "SpD", "Spe"), or the full name that `Display` gives.
+    fn from_str(s: &str) -> Result<Stat, InvalidStatError> {
+        match s {
+            "HP" => Ok(Stat::HP),
+            "Atk" | "Attack" => Ok(Stat::Atk),
+            "Def" | "Defense" => Ok(Stat::Def),
+            "SpA" | "Special Attack" => Ok(Stat::SpA),
+            "SpD" | "Special Defense" => Ok(Stat::SpD),
+            "Spe" | "Speed" => Ok(Stat::Spe),
+            _ => Err(InvalidStatError{}),
+        }
     }
 }
 
+/// Calculates the actual value of a stat from its base stat, the Pokemon's level, its nature, and
+/// the EVs and IVs invested in it, using the formula from Gen III onward. Every division is floored,
+/// as in the games. HP uses a different formula from the other stats, and is never affected by
+/// nature. (Shedinja, whose HP is always 1, is the one exception this doesn't handle.)
+pub fn calc_stat(stat: Stat, base: u16, level: u8, nature: Nature, ev: u8, iv: u8) -> u16 {
+    let level = u32::from(level);
+    let raw = (2 * u32::from(base) + u32::from(iv) + u32::from(ev) / 4) * level / 100;
+    if stat == Stat::HP {
+        return (raw + level + 10) as u16;
+    }
+    let nature_percent = if !nature.has_stat_effect() {
+        100
+    } else if nature.increased_stat() == stat {
+        110
+    } else if nature.decreased_stat() == stat {
+        90
+    } else {
+        100
+    };
+    ((raw + 5) * nature_percent / 100) as u16
+}
+
+/// Calculates the highest value a stat can have with the given nature: 31 IVs and 252 EVs. For the
+/// highest value possible at all, pass a nature that boosts the stat.
+pub fn max_stat(stat: Stat, base: u16, level: u8, nature: Nature) -> u16 {
+    calc_stat(stat, base, level, nature, 252, 31)
+}
+
+/// Calculates the lowest value a stat can have with the given nature: 0 IVs and 0 EVs. For the lowest
+/// value possible at all, pass a nature that lowers the stat.
+pub fn min_stat(stat: Stat, base: u16, level: u8, nature: Nature) -> u16 {
+    calc_stat(stat, base, level, nature, 0, 0)
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(Stat::from_str("SpA"), Ok(Stat::SpA));
+        assert_eq!(Stat::from_str("Special Defense"), Ok(Stat::SpD));
+        assert_eq!(Stat::from_str(&Stat::Spe.to_string()), Ok(Stat::Spe));
+        assert!(Stat::from_str("Sp. Atk").is_err());
+        assert_eq!(Stat::from_str(Stat::SpD.abbreviation()), Ok(Stat::SpD));
+    }
+    #[test]
+    fn test_names() {
+        let stats = [Stat::HP, Stat::Atk, Stat::Def, Stat::SpA, Stat::SpD, Stat::Spe];
+        for &stat in stats.iter() {
+            assert_eq!(stat.name(), stat.to_string());
+            assert_eq!(Stat::from_str(stat.name()), Ok(stat));
+        }
+        assert_eq!(Stat::SpA.name(), "Special Attack");
+    }
+    #[test]
+    fn test_calc_stat() {
+        // Garchomp: 108 HP, 130 Atk, 102 Spe
+        assert_eq!(calc_stat(Stat::HP, 108, 100, Nature::Jolly, 4, 31), 358);
+        assert_eq!(calc_stat(Stat::Atk, 130, 100, Nature::Jolly, 252, 31), 359);
+        assert_eq!(calc_stat(Stat::Atk, 130, 100, Nature::Adamant, 252, 31), 394);
+        assert_eq!(calc_stat(Stat::Spe, 102, 100, Nature::Jolly, 252, 31), 333);
+        assert_eq!(calc_stat(Stat::SpA, 80, 100, Nature::Jolly, 0, 31), 176);
+        // level 50
+        assert_eq!(calc_stat(Stat::HP, 108, 50, Nature::Hardy, 252, 31), 215);
+        assert_eq!(calc_stat(Stat::Spe, 102, 50, Nature::Hardy, 252, 31), 154);
+    }
+    #[test]
+    fn test_stat_bounds() {
+        assert_eq!(max_stat(Stat::Atk, 100, 100, Nature::Adamant), 328);
+        assert_eq!(max_stat(Stat::Atk, 100, 100, Nature::Hardy), 299);
+        assert_eq!(min_stat(Stat::Atk, 100, 100, Nature::Modest), 184);
+        assert_eq!(min_stat(Stat::Atk, 100, 100, Nature::Hardy), 205);
+        assert_eq!(max_stat(Stat::HP, 100, 100, Nature::Adamant), 404);
+        assert_eq!(min_stat(Stat::HP, 100, 100, Nature::Modest), 310);
+    }
+}
@@ -8,11 +8,11 @@
 //! brokenness of moves like Minimize. That means a stat stage of -3 is still just a 50% reduction.
 
 use std::convert::From;
-use std::ops::Add;
+use std::ops::{Add, Neg};
 
 /// A stat stage, from -6 to 6 inclusive. Nomenclature follows the `bounded_integer` crate's rules: N
 /// is replacing a minus sign, and P is replacing a plus sign. Z0 is 0.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[repr(i8)]
 pub enum StatStage {
     N6 = -6,
@@ -92,6 +92,29 @@ impl Add for StatStage {
     }
 }
 
+impl Neg for StatStage {
+    type Output = StatStage;
+
+    /// Flips the direction of a stat stage, so -2 becomes +2. This never goes out of bounds.
+    fn neg(self) -> StatStage {
+        match self {
+            StatStage::N6 => StatStage::P6,
+            StatStage::N5 => StatStage::P5,
+            StatStage::N4 => StatStage::P4,
+            StatStage::N3 => StatStage::P3,
+            StatStage::N2 => StatStage::P2,
+            StatStage::N1 => StatStage::P1,
+            StatStage::Z0 => StatStage::Z0,
+            StatStage::P1 => StatStage::N1,
+            StatStage::P2 => StatStage::N2,
+            StatStage::P3 => StatStage::N3,
+            StatStage::P4 => StatStage::N4,
+            StatStage::P5 => StatStage::N5,
+            StatStage::P6 => StatStage::N6,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[allow(unused_imports)]
@@ -137,4 +160,12 @@ mod tests {
         assert_eq!(StatStage::P1 + StatStage::P1, StatStage::P2);
         assert_eq!(StatStage::P4 + StatStage::P5, StatStage::P6);
     }
+
+    #[test]
+    fn test_negation() {
+        assert_eq!(-StatStage::N3, StatStage::P3);
+        assert_eq!(-StatStage::P6, StatStage::N6);
+        assert_eq!(-StatStage::Z0, StatStage::Z0);
+        assert_eq!(StatStage::P2 + -StatStage::P1, StatStage::P1);
+    }
 }
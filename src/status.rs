@@ -0,0 +1,236 @@
+//! This file defines the major status conditions: burn, paralysis, poison, bad poison, sleep, and
+//! freeze. A Pokemon can only have one of these at a time, and they persist after switching out. The
+//! effects described are current to Gen VII, with
+//! [Bulbapedia](https://bulbapedia.bulbagarden.net/wiki/Status_condition) as a source.
+//!
+//! Confusion, which is a volatile status that ends on switching out, is handled here as well.
+//!
+//! The last piece is figuring out whether a Pokemon can be given a status at all: several types,
+//! abilities, and terrains grant immunities.
+
+use crate::ability::Ability;
+use crate::damage::{calc_damage, DamageModifiers};
+use crate::terrain::Terrain;
+use crate::typing::Typing;
+
+/// A major status condition. Bad poison and sleep carry counters: `BadlyPoisoned` holds how many
+/// turns the Pokemon has been badly poisoned for, starting at 1, and `Sleep` holds how many more turns
+/// the Pokemon will stay asleep.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// Halves the damage of physical moves and deals 1/16 max HP each turn.
+    Burn,
+    /// Halves Speed and makes the Pokemon fully paralyzed a quarter of the time.
+    Paralysis,
+    /// Deals 1/8 max HP each turn.
+    Poison,
+    /// Deals an increasing fraction of max HP each turn: 1/16, then 2/16, and so on.
+    BadlyPoisoned(u8),
+    /// Prevents the Pokemon from moving until it wakes up.
+    Sleep(u8),
+    /// Prevents the Pokemon from moving until it thaws.
+    Freeze,
+}
+
+impl Status {
+    /// Returns the multiplier this status applies to the damage of the Pokemon's physical moves: 0.5
+    /// for burn (which technically halves the damage rather than the Attack stat), and 1 otherwise.
+    pub fn attack_multiplier(self) -> f64 {
+        match self {
+            Status::Burn => 0.5,
+            _ => 1.0,
+        }
+    }
+    /// Returns the multiplier this status applies to Speed: 0.5 for paralysis (it was 0.25 before Gen
+    /// VII), and 1 otherwise.
+    pub fn speed_multiplier(self) -> f64 {
+        match self {
+            Status::Paralysis => 0.5,
+            _ => 1.0,
+        }
+    }
+    /// Returns the damage this status deals at the end of each turn to a Pokemon with the given max
+    /// HP. Burn deals 1/16 (1/8 before Gen VII), poison deals 1/8, and bad poison deals the counter
+    /// times 1/16, with the counter capped at 15. Damaging statuses always deal at least 1 HP.
+    pub fn end_of_turn_damage(self, max_hp: u16) -> u16 {
+        let sixteenths = match self {
+            Status::Burn => 1,
+            Status::Poison => 2,
+            Status::BadlyPoisoned(counter) => return toxic_damage(counter, max_hp),
+            _ => return 0,
+        };
+        (u32::from(max_hp) * sixteenths / 16).max(1) as u16
+    }
+    /// Applies this status at the end of a turn, returning the damage it deals (as in
+    /// `end_of_turn_damage`) and the status the Pokemon has going into the next turn. The only status
+    /// that changes is bad poison, whose counter goes up by one each turn, up to 15.
+    pub fn end_of_turn(self, max_hp: u16) -> (u16, Status) {
+        let next = match self {
+            Status::BadlyPoisoned(counter) => Status::BadlyPoisoned(counter.clamp(1, 14) + 1),
+            _ => self,
+        };
+        (self.end_of_turn_damage(max_hp), next)
+    }
+    /// Advances this status at the start of the Pokemon's turn, before it tries to move, and returns
+    /// the status it has afterwards along with whether it can act this turn. A sleeping Pokemon with
+    /// no turns left wakes up and acts right away; otherwise its counter goes down by one and it
+    /// stays asleep. A frozen Pokemon has a 20% chance of thawing each turn: `thaw` is the result of
+    /// that roll, which the caller makes so that this stays deterministic. (Being hit by a Fire-type
+    /// move also thaws a Pokemon, but that happens on the hit, not here.) Other statuses don't change
+    /// and don't stop the Pokemon from acting, although paralysis might still fully paralyze it.
+    pub fn tick_status(self, thaw: bool) -> (Option<Status>, bool) {
+        match self {
+            Status::Sleep(0) => (None, true),
+            Status::Sleep(turns) => (Some(Status::Sleep(turns - 1)), false),
+            Status::Freeze if thaw => (None, true),
+            Status::Freeze => (Some(Status::Freeze), false),
+            _ => (Some(self), true),
+        }
+    }
+}
+
+/// Returns the damage bad poison deals on the turn with the given counter to a Pokemon with the given
+/// max HP: counter/16 of max HP, so 1/16 on the first turn, 2/16 on the second, and so on, but at
+/// least 1. The counter is treated as at least 1 and at most 15.
+pub fn toxic_damage(counter: u8, max_hp: u16) -> u16 {
+    let sixteenths = u32::from(counter.clamp(1, 15));
+    (u32::from(max_hp) * sixteenths / 16).max(1) as u16
+}
+
+/// Calculates the minimum and maximum damage a confused Pokemon does when it hits itself: a typeless
+/// physical attack with 40 base power, using its own Attack and Defense, which can't get STAB, be
+/// super effective, or crit.
+pub fn confusion_self_damage(attack: u16, defense: u16, level: u8) -> (u16, u16) {
+    calc_damage(level, attack, defense, 40, DamageModifiers::default(), false)
+}
+
+/// Returns true if a Pokemon with the given typing and ability can be given the given status, or
+/// false if something makes it immune. Fire types can't be burned, Electric types can't be paralyzed
+/// (from Gen VI on), Poison and Steel types can't be poisoned, and Ice types can't be frozen. On top
+/// of that, abilities like Limber, Water Veil, Insomnia, and Comatose block statuses, Misty Terrain
+/// protects grounded Pokemon from every status, and Electric Terrain keeps grounded Pokemon awake.
+/// Pass None for the terrain if no terrain is active. This doesn't consider abilities that only work
+/// in certain weather, like Leaf Guard, or whether the Pokemon already has a status.
+pub fn can_be_statused(status: Status, typing: (Typing, Option<Typing>), ability: Ability,
+                       terrain: Option<Terrain>, grounded: bool) -> bool {
+    let has_type = |t: Typing| typing.0 == t || typing.1 == Some(t);
+    if ability == Ability::Comatose || (grounded && terrain == Some(Terrain::Misty)) {
+        return false;
+    }
+    match status {
+        Status::Burn => {
+            !has_type(Typing::Fire) && ability != Ability::WaterVeil && ability != Ability::WaterBubble
+        },
+        Status::Paralysis => !has_type(Typing::Electric) && ability != Ability::Limber,
+        Status::Poison | Status::BadlyPoisoned(_) => {
+            !has_type(Typing::Poison) && !has_type(Typing::Steel) && ability != Ability::Immunity
+        },
+        Status::Sleep(_) => {
+            let sleepless = matches!(ability,
+                                     Ability::Insomnia | Ability::VitalSpirit | Ability::SweetVeil);
+            !sleepless && !(grounded && terrain == Some(Terrain::Electric))
+        },
+        Status::Freeze => !has_type(Typing::Ice) && ability != Ability::MagmaArmor,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn test_multipliers() {
+        assert_eq!(Status::Burn.attack_multiplier(), 0.5);
+        assert_eq!(Status::Paralysis.attack_multiplier(), 1.0);
+        assert_eq!(Status::Paralysis.speed_multiplier(), 0.5);
+        assert_eq!(Status::Sleep(2).speed_multiplier(), 1.0);
+    }
+    #[test]
+    fn test_burn_damage() {
+        assert_eq!(Status::Burn.end_of_turn_damage(352), 22);
+        assert_eq!(Status::Burn.end_of_turn_damage(1), 1);
+        assert_eq!(Status::Poison.end_of_turn_damage(352), 44);
+        assert_eq!(Status::Paralysis.end_of_turn_damage(352), 0);
+        assert_eq!(Status::Freeze.end_of_turn_damage(352), 0);
+    }
+    #[test]
+    fn test_bad_poison_ramping() {
+        assert_eq!(Status::BadlyPoisoned(1).end_of_turn_damage(320), 20);
+        assert_eq!(Status::BadlyPoisoned(2).end_of_turn_damage(320), 40);
+        assert_eq!(Status::BadlyPoisoned(3).end_of_turn_damage(320), 60);
+        assert_eq!(Status::BadlyPoisoned(15).end_of_turn_damage(320), 300);
+        assert_eq!(Status::BadlyPoisoned(20).end_of_turn_damage(320), 300);
+    }
+    #[test]
+    fn test_sleep_ticks() {
+        let mut status = Some(Status::Sleep(2));
+        let mut turns_asleep = 0;
+        while let Some(Status::Sleep(_)) = status {
+            let (next, can_act) = status.unwrap().tick_status(false);
+            if !can_act {
+                turns_asleep += 1;
+            }
+            status = next;
+        }
+        assert_eq!(turns_asleep, 2);
+        assert_eq!(status, None);
+        assert_eq!(Status::Sleep(0).tick_status(false), (None, true));
+    }
+    #[test]
+    fn test_freeze_ticks() {
+        assert_eq!(Status::Freeze.tick_status(false), (Some(Status::Freeze), false));
+        assert_eq!(Status::Freeze.tick_status(true), (None, true));
+        assert_eq!(Status::Burn.tick_status(true), (Some(Status::Burn), true));
+    }
+    #[test]
+    fn test_toxic_escalation() {
+        assert_eq!(toxic_damage(1, 400), 25);
+        assert_eq!(toxic_damage(2, 400), 50);
+        assert_eq!(toxic_damage(3, 400), 75);
+        let mut status = Status::BadlyPoisoned(1);
+        let mut damage = vec![];
+        for _ in 0..3 {
+            let (dealt, next) = status.end_of_turn(400);
+            damage.push(dealt);
+            status = next;
+        }
+        assert_eq!(damage, vec![25, 50, 75]);
+        assert_eq!(status, Status::BadlyPoisoned(4));
+        assert_eq!(Status::BadlyPoisoned(15).end_of_turn(400).1, Status::BadlyPoisoned(15));
+        assert_eq!(Status::Burn.end_of_turn(400), (25, Status::Burn));
+    }
+    #[test]
+    fn test_confusion_self_damage() {
+        // level 50 Jolly 252 Atk Garchomp: 182 Atk, 115 Def
+        assert_eq!(confusion_self_damage(182, 115, 50), (24, 29));
+        assert_eq!(confusion_self_damage(1, 999, 1), (1, 2));
+    }
+    #[test]
+    fn test_can_be_statused() {
+        let fire = (Typing::Fire, None);
+        let electric = (Typing::Electric, Some(Typing::Flying));
+        let water = (Typing::Water, None);
+        assert!(!can_be_statused(Status::Burn, fire, Ability::Blaze, None, true));
+        assert!(can_be_statused(Status::Burn, water, Ability::Torrent, None, true));
+        assert!(!can_be_statused(Status::Burn, water, Ability::WaterVeil, None, true));
+        assert!(!can_be_statused(Status::Paralysis, electric, Ability::Static, None, false));
+        assert!(can_be_statused(Status::Paralysis, fire, Ability::Blaze, None, true));
+        assert!(!can_be_statused(Status::Paralysis, water, Ability::Limber, None, true));
+        assert!(!can_be_statused(Status::BadlyPoisoned(1), (Typing::Steel, None), Ability::Sturdy,
+                                 None, true));
+        assert!(!can_be_statused(Status::Sleep(2), water, Ability::Insomnia, None, true));
+    }
+    #[test]
+    fn test_terrain_status_immunity() {
+        let water = (Typing::Water, None);
+        let misty = Some(Terrain::Misty);
+        assert!(!can_be_statused(Status::Burn, water, Ability::Torrent, misty, true));
+        assert!(!can_be_statused(Status::Sleep(3), water, Ability::Torrent, misty, true));
+        assert!(can_be_statused(Status::Burn, water, Ability::Torrent, misty, false));
+        let electric = Some(Terrain::Electric);
+        assert!(!can_be_statused(Status::Sleep(3), water, Ability::Torrent, electric, true));
+        assert!(can_be_statused(Status::Burn, water, Ability::Torrent, electric, true));
+        assert!(can_be_statused(Status::Sleep(3), water, Ability::Torrent, electric, false));
+    }
+}
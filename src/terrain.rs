@@ -1,5 +1,8 @@
 //! This file defines the various types of terrains, field conditions that have become very relevant
-//! in the USUM OU meta due to the Tapus setting them on switch in.
+//! in the USUM OU meta due to the Tapus setting them on switch in. The abilities that set each
+//! terrain are covered here too.
+
+use crate::ability::Ability;
 
 #[derive(Copy, Clone, Debug, Display, PartialEq, Eq, Hash, EnumString)]
 pub enum Terrain {
@@ -17,3 +20,31 @@ pub enum Terrain {
     /// as well as increasing the power of Psychic-type moves used by grounded Pokemon.
     Psychic,
 }
+
+/// Returns the terrain a Pokemon with the given ability sets when it switches in, or None if its
+/// ability isn't one of the four Surges. This is the terrain counterpart of
+/// `weather::weather_on_switch_in`.
+pub fn terrain_on_switch_in(ability: Ability) -> Option<Terrain> {
+    match ability {
+        Ability::ElectricSurge => Some(Terrain::Electric),
+        Ability::GrassySurge => Some(Terrain::Grassy),
+        Ability::MistySurge => Some(Terrain::Misty),
+        Ability::PsychicSurge => Some(Terrain::Psychic),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn test_terrain_on_switch_in() {
+        assert_eq!(terrain_on_switch_in(Ability::ElectricSurge), Some(Terrain::Electric));
+        assert_eq!(terrain_on_switch_in(Ability::GrassySurge), Some(Terrain::Grassy));
+        assert_eq!(terrain_on_switch_in(Ability::MistySurge), Some(Terrain::Misty));
+        assert_eq!(terrain_on_switch_in(Ability::PsychicSurge), Some(Terrain::Psychic));
+        assert_eq!(terrain_on_switch_in(Ability::SurgeSurfer), None);
+    }
+}
@@ -6,7 +6,8 @@
 use std::convert::{Into, TryFrom};
 use std::error;
 use std::fmt;
-use std::ops::{Mul};
+use std::ops::{Div, Mul};
+use std::str::FromStr;
 use std::f32::EPSILON;
 
 
@@ -70,6 +71,45 @@ impl Multiplier {
             Err(InvalidNumericMultiplierError{})
         }
     }
+
+    /// Converts a numeric multiplier that came from multiplying or dividing two valid multipliers
+    /// back into an enum value, clamping anything past 4x weakness or resistance.
+    fn from_clamped_num_multiplier(num: f32) -> Multiplier {
+        if (num - 0.125).abs() <= EPSILON || (num - 0.0625).abs() <= EPSILON {
+            Multiplier::DoubleResistance
+        } else if (num - 8.0).abs() <= EPSILON || (num - 16.0).abs() <= EPSILON {
+            Multiplier::DoubleWeakness
+        } else {
+            Multiplier::from_num_multiplier(num).unwrap()
+        }
+    }
+
+    /// Returns the multiplier as a label for displaying to users, with fraction characters for the
+    /// resistances: "0x", "¼x", "½x", "1x", "2x", or "4x".
+    pub fn as_fraction_str(self) -> &'static str {
+        match self {
+            Multiplier::Immunity => "0x",
+            Multiplier::DoubleResistance => "¼x",
+            Multiplier::Resistance => "½x",
+            Multiplier::Regular => "1x",
+            Multiplier::Weakness => "2x",
+            Multiplier::DoubleWeakness => "4x",
+        }
+    }
+
+    /// Parses one of the labels `as_fraction_str` gives back into a multiplier. Anything else is an
+    /// error: use `from_str` for plain numbers.
+    pub fn from_fraction_str(s: &str) -> Result<Multiplier, InvalidNumericMultiplierError> {
+        match s {
+            "0x" => Ok(Multiplier::Immunity),
+            "¼x" => Ok(Multiplier::DoubleResistance),
+            "½x" => Ok(Multiplier::Resistance),
+            "1x" => Ok(Multiplier::Regular),
+            "2x" => Ok(Multiplier::Weakness),
+            "4x" => Ok(Multiplier::DoubleWeakness),
+            _ => Err(InvalidNumericMultiplierError{})
+        }
+    }
 }
 
 impl Into<f32> for Multiplier {
@@ -85,6 +125,35 @@ impl Into<f32> for Multiplier {
     }
 }
 
+impl fmt::Display for Multiplier {
+    /// Writes the multiplier as it's usually written in damage calculators: "x0", "x0.25", "x0.5",
+    /// "x1", "x2", or "x4".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "x{}", match *self {
+            Multiplier::Immunity => "0",
+            Multiplier::DoubleResistance => "0.25",
+            Multiplier::Resistance => "0.5",
+            Multiplier::Regular => "1",
+            Multiplier::Weakness => "2",
+            Multiplier::DoubleWeakness => "4",
+        })
+    }
+}
+
+impl FromStr for Multiplier {
+    type Err = InvalidNumericMultiplierError;
+
+    /// Parses a multiplier in the format `Display` uses, like "x0.5", or just the number, like
+    /// "0.5". Any string that isn't one of the six valid multipliers is an error.
+    fn from_str(s: &str) -> Result<Multiplier, InvalidNumericMultiplierError> {
+        let num = s.strip_prefix('x').unwrap_or(s);
+        match num.parse::<f32>() {
+            Ok(multiplier) => Multiplier::from_num_multiplier(multiplier),
+            Err(_) => Err(InvalidNumericMultiplierError{})
+        }
+    }
+}
+
 
 impl Mul<Multiplier> for Multiplier {
     type Output = Multiplier;
@@ -94,13 +163,28 @@ impl Mul<Multiplier> for Multiplier {
     fn mul(self, _rhs: Multiplier) -> Multiplier {
         let mul1: f32 = self.into();
         let mul2: f32 = _rhs.into();
-        let num = mul1 * mul2;
-        if (num - 0.125).abs() <= EPSILON || (num - 0.0625).abs() <= EPSILON {
-            Multiplier::DoubleResistance
-        } else if (num - 8.0).abs() <= EPSILON || (num - 16.0).abs() <= EPSILON {
+        Multiplier::from_clamped_num_multiplier(mul1 * mul2)
+    }
+}
+
+impl Div<Multiplier> for Multiplier {
+    type Output = Multiplier;
+
+    /// Undoes a multiplication, with the same clamping as multiplication: DoubleWeakness divided by
+    /// Weakness is Weakness, and DoubleResistance divided by Weakness is DoubleResistance.
+    ///
+    /// Immunity is the troublesome case, because multiplying by it throws away all information. The
+    /// choice made here is that Immunity divided by anything stays Immunity, and anything else
+    /// divided by Immunity saturates to DoubleWeakness, as if dividing by zero gave infinity.
+    fn div(self, _rhs: Multiplier) -> Multiplier {
+        if self == Multiplier::Immunity {
+            Multiplier::Immunity
+        } else if _rhs == Multiplier::Immunity {
             Multiplier::DoubleWeakness
         } else {
-            Multiplier::from_num_multiplier(num).unwrap()
+            let mul1: f32 = self.into();
+            let mul2: f32 = _rhs.into();
+            Multiplier::from_clamped_num_multiplier(mul1 / mul2)
         }
     }
 }
@@ -111,8 +195,9 @@ impl Mul<Multiplier> for Multiplier {
 /// The type weakness chart, copied from Bulbapedia and current to Gen VII.
 /// The format is a flattened version of the type matrix, given in the order it appears in Bulbapedia,
 /// also the order that it appears in the `Typing` enum. For example, the sixth element is 0.5,
-/// because Normal deals half damage against Rock.
-const TYPE_MULTIPLIERS: [f32; 324] = [
+/// because Normal deals half damage against Rock. This is a `static` rather than a `const` so that
+/// `Typing::offense_row` can hand out references into it.
+static TYPE_MULTIPLIERS: [f32; 324] = [
     1.0, 1.0, 1.0, 1.0, 1.0, 0.5, 1.0, 0.0, 0.5, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0,  // Normal
     2.0, 1.0, 0.5, 0.5, 1.0, 2.0, 0.5, 0.0, 2.0, 1.0, 1.0, 1.0, 1.0, 0.5, 2.0, 1.0, 2.0, 0.5,  // Fighting
     1.0, 2.0, 1.0, 1.0, 1.0, 0.5, 2.0, 1.0, 0.5, 1.0, 1.0, 2.0, 0.5, 1.0, 1.0, 1.0, 1.0, 1.0,  // Flying
@@ -155,8 +240,34 @@ impl error::Error for InvalidTypingCodeError {
     }
 }
 
-/// A Pokemon type, although `Typing` is used to prevent any confusion with types in Rust.
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+/// An error for looking up a `Typing` in a generation before it was introduced: Steel and Dark
+/// before Gen II, and Fairy before Gen VI.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TypingNotInGenerationError {
+    pub typing: Typing,
+    pub generation: u8,
+}
+
+impl fmt::Display for TypingNotInGenerationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?} type does not exist in generation {}", self.typing, self.generation)
+    }
+}
+
+impl error::Error for TypingNotInGenerationError {
+    fn description(&self) -> &str {
+        "typing was introduced after the given generation"
+    }
+
+    fn cause(&self) -> Option<&error::Error> {
+        // Generic error, underlying cause isn't tracked.
+        None
+    }
+}
+
+/// A Pokemon type, although `Typing` is used to prevent any confusion with types in Rust. Typings are
+/// ordered by `num_code`, so sorting a list of them puts them in type chart order.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize, Deserialize)]
 #[repr(u8)]
 pub enum Typing {
     Normal,
@@ -179,6 +290,13 @@ pub enum Typing {
     Fairy,
 }
 
+impl fmt::Display for Typing {
+    /// Writes the name of the type, like "Fire".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
 impl TryFrom<u8> for Typing {
     type Error = InvalidTypingCodeError;
 
@@ -237,12 +355,92 @@ impl Typing {
             Typing::Fairy => 17,
         }
     }
+    /// Returns the name of the type, like "Fire". This is what `Display` writes, but it doesn't
+    /// allocate a `String`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Typing::Normal => "Normal",
+            Typing::Fighting => "Fighting",
+            Typing::Flying => "Flying",
+            Typing::Poison => "Poison",
+            Typing::Ground => "Ground",
+            Typing::Rock => "Rock",
+            Typing::Bug => "Bug",
+            Typing::Ghost => "Ghost",
+            Typing::Steel => "Steel",
+            Typing::Fire => "Fire",
+            Typing::Water => "Water",
+            Typing::Grass => "Grass",
+            Typing::Electric => "Electric",
+            Typing::Psychic => "Psychic",
+            Typing::Ice => "Ice",
+            Typing::Dragon => "Dragon",
+            Typing::Dark => "Dark",
+            Typing::Fairy => "Fairy",
+        }
+    }
     /// Returns the multiplier a move this `Typing` has when attacking a Pokemon with the given other `Typing`.
     pub fn offense_multiplier(self, other: Typing) -> Multiplier {
         // get index in flattened matrix
         let index: usize = (self.num_code() as usize * 18) + (other.num_code() as usize);
         Multiplier::from_num_multiplier(TYPE_MULTIPLIERS[index]).unwrap()
     }
+    /// Returns the row of the type chart for this `Typing` as raw numbers: the multiplier a move of
+    /// this `Typing` has against each other `Typing`, indexed by `num_code`. Unlike
+    /// `offense_multipliers`, this doesn't allocate, so it's handy for passing the chart along to
+    /// other code as-is.
+    pub fn offense_row(self) -> &'static [f32; 18] {
+        let start = self.num_code() as usize * 18;
+        <&[f32; 18]>::try_from(&TYPE_MULTIPLIERS[start..start + 18]).unwrap()
+    }
+    /// Returns the multiplier a move of this `Typing` has when attacking a Pokemon with the given
+    /// other `Typing` in an Inverse Battle. Inverse Battles flip every relationship: weaknesses
+    /// become resistances and vice versa, and immunities become weaknesses.
+    pub fn inverse_offense_multiplier(self, other: Typing) -> Multiplier {
+        match self.offense_multiplier(other) {
+            Multiplier::Immunity => Multiplier::Weakness,
+            Multiplier::DoubleResistance => Multiplier::DoubleWeakness,
+            Multiplier::Resistance => Multiplier::Weakness,
+            Multiplier::Regular => Multiplier::Regular,
+            Multiplier::Weakness => Multiplier::Resistance,
+            Multiplier::DoubleWeakness => Multiplier::DoubleResistance,
+        }
+    }
+    /// Returns the first generation this `Typing` appeared in: Gen II for Steel and Dark, Gen VI for
+    /// Fairy, and Gen I for everything else.
+    pub fn introduced_in(self) -> u8 {
+        match self {
+            Typing::Steel | Typing::Dark => 2,
+            Typing::Fairy => 6,
+            _ => 1,
+        }
+    }
+    /// Returns the multiplier a move of this `Typing` has when attacking a Pokemon with the given
+    /// other `Typing` in the given generation. The type chart has barely changed since Gen II: before
+    /// Gen VI, Steel also resisted Ghost and Dark, and Gen I had a few quirks of its own (Bug and
+    /// Poison were super effective on each other, Ghost couldn't touch Psychic, and Ice was neutral on
+    /// Fire). Anything from Gen VI on uses the current chart. If either `Typing` didn't exist yet in
+    /// that generation, like Fairy before Gen VI, this returns an error instead of guessing.
+    pub fn offense_multiplier_gen(self, other: Typing, generation: u8)
+                                  -> Result<Multiplier, TypingNotInGenerationError> {
+        for &typing in &[self, other] {
+            if typing.introduced_in() > generation {
+                return Err(TypingNotInGenerationError{typing, generation});
+            }
+        }
+        let multiplier = match (self, other) {
+            (Typing::Ghost, Typing::Steel) | (Typing::Dark, Typing::Steel) if generation < 6 => {
+                Multiplier::Resistance
+            },
+            (Typing::Bug, Typing::Poison) | (Typing::Poison, Typing::Bug) if generation == 1 => {
+                Multiplier::Weakness
+            },
+            (Typing::Ghost, Typing::Psychic) if generation == 1 => Multiplier::Immunity,
+            (Typing::Ice, Typing::Fire) if generation == 1 => Multiplier::Regular,
+            _ => self.offense_multiplier(other),
+        };
+        Ok(multiplier)
+    }
     /// Returns a `Vec` of 18 `Multiplier`s, indicating the offensive multiplier this `Typing`
     /// receives on each other typing, in numerical order.
     pub fn offense_multipliers(self) -> Vec<Multiplier> {
@@ -297,6 +495,212 @@ impl Typing {
     }
 }
 
+/// Returns the multiplier an attack of the given `Typing` has against a Pokemon with the given
+/// defending typing, which is a primary `Typing` and an optional secondary one. A secondary typing
+/// that duplicates the primary one is ignored, so `(Fire, Some(Fire))` is treated as pure Fire.
+pub fn defense_multiplier_for(attacking: Typing, defending: (Typing, Option<Typing>)) -> Multiplier {
+    match defending {
+        (typing1, Some(typing2)) if typing1 != typing2 => {
+            attacking.combined_effectiveness((typing1, typing2))
+        },
+        (typing1, _) => attacking.offense_multiplier(typing1),
+    }
+}
+
+/// Returns the defensive profile of the given typing: the combined multiplier each of the 18
+/// attacking `Typing`s has against it, indexed by `num_code`. For example, the tenth element (index
+/// 9) is the multiplier Fire-type attacks have against the given typing.
+pub fn defensive_profile(defending: (Typing, Option<Typing>)) -> [Multiplier; 18] {
+    let mut profile = [Multiplier::Regular; 18];
+    for t in Typing::all_typings() {
+        profile[t.num_code() as usize] = defense_multiplier_for(t, defending);
+    }
+    profile
+}
+
+/// Returns a one-line summary of how every attacking type does against the given typing, like
+/// "x4: Fire; x2: Fighting; x0.5: Normal, Water". Attacking types are grouped by their multiplier,
+/// from most to least effective, and listed in numerical order within each group. Types that do
+/// neutral damage are left out, and a typing with no weaknesses, resistances, or immunities gives an
+/// empty string.
+pub fn weakness_report(defending: (Typing, Option<Typing>)) -> String {
+    let profile = defensive_profile(defending);
+    let groups = [Multiplier::DoubleWeakness, Multiplier::Weakness, Multiplier::Resistance,
+                  Multiplier::DoubleResistance, Multiplier::Immunity];
+    groups.iter()
+        .filter_map(|&mult| {
+            let types: Vec<String> = Typing::all_typings().into_iter()
+                .filter(|&t| profile[t.num_code() as usize] == mult)
+                .map(|t| t.to_string())
+                .collect();
+            if types.is_empty() {
+                None
+            } else {
+                Some(format!("{}: {}", mult, types.join(", ")))
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("; ")
+}
+
+/// Returns every distinct typing a Pokemon could have: the 18 single types, followed by the 153
+/// pairs of different types, for 171 in total. Pairs are only listed once, with the lower-numbered
+/// `Typing` first, because `(Fire, Some(Water))` and `(Water, Some(Fire))` are equivalent defensively.
+pub fn all_type_combinations() -> Vec<(Typing, Option<Typing>)> {
+    let typings = Typing::all_typings();
+    let mut combos: Vec<(Typing, Option<Typing>)> = typings.iter().map(|&t| (t, None)).collect();
+    for (i, &typing1) in typings.iter().enumerate() {
+        for &typing2 in typings.iter().skip(i + 1) {
+            combos.push((typing1, Some(typing2)));
+        }
+    }
+    combos
+}
+
+/// Rates how good the given typing is defensively: across the 18 attacking types, each resistance
+/// or immunity counts +1, each weakness counts -1, and 4x resistances and 4x weaknesses count double.
+/// Neutral matchups count for nothing. Pure Normal, for example, has one weakness and one immunity,
+/// so it rates 0.
+pub fn defensive_rating(defending: (Typing, Option<Typing>)) -> i32 {
+    defensive_profile(defending).iter()
+        .map(|&mult| match mult {
+            Multiplier::Immunity | Multiplier::Resistance => 1,
+            Multiplier::DoubleResistance => 2,
+            Multiplier::Regular => 0,
+            Multiplier::Weakness => -1,
+            Multiplier::DoubleWeakness => -2,
+        })
+        .sum()
+}
+
+/// Returns the `n` dual typings with the highest `defensive_rating`, best first, along with their
+/// ratings. Typings with the same rating are listed in the order `all_type_combinations` gives.
+pub fn best_defensive_typings(n: usize) -> Vec<((Typing, Option<Typing>), i32)> {
+    let mut rated: Vec<((Typing, Option<Typing>), i32)> = all_type_combinations().into_iter()
+        .filter(|&(_, typing2)| typing2.is_some())
+        .map(|combo| (combo, defensive_rating(combo)))
+        .collect();
+    rated.sort_by_key(|&(_, rating)| -rating);
+    rated.truncate(n);
+    rated
+}
+
+/// Returns the best multiplier any of the given attacking `Typing`s has against the given
+/// defending typing, or `Immunity` if no attacking types are given.
+fn best_multiplier(attacking: &[Typing], defending: (Typing, Option<Typing>)) -> Multiplier {
+    attacking.iter()
+        .map(|&t| defense_multiplier_for(t, defending))
+        .max()
+        .unwrap_or(Multiplier::Immunity)
+}
+
+/// Returns how many of the 324 type combinations are hit at least neutrally by at least one of the
+/// given attacking `Typing`s. The combinations are every ordered pair of the 18 types, with a type
+/// paired with itself standing for the pure typing, so each dual typing is counted twice (as
+/// Fire/Water and as Water/Fire) and each pure typing once. A move set with perfect coverage scores
+/// 324.
+pub fn coverage_score(attacking: &[Typing]) -> usize {
+    let typings = Typing::all_typings();
+    typings.iter()
+        .flat_map(|&typing1| typings.iter().map(move |&typing2| {
+            (typing1, if typing1 == typing2 { None } else { Some(typing2) })
+        }))
+        .filter(|&combo| best_multiplier(attacking, combo) >= Multiplier::Regular)
+        .count()
+}
+
+/// Returns the typings that every one of the given attacking `Typing`s hits not very effectively
+/// or not at all, in the same order as `all_type_combinations`. Unlike `coverage_score`, each dual
+/// typing is only listed once.
+pub fn uncovered_types(attacking: &[Typing]) -> Vec<(Typing, Option<Typing>)> {
+    all_type_combinations().into_iter()
+        .filter(|&combo| best_multiplier(attacking, combo) < Multiplier::Regular)
+        .collect()
+}
+
+/// Returns the attacking `Typing`s that hit every member of the given team at least neutrally, in
+/// numerical order. If no single type does, this is empty.
+pub fn required_coverage(team: &[(Typing, Option<Typing>)]) -> Vec<Typing> {
+    Typing::all_typings().into_iter()
+        .filter(|&t| team.iter().all(|&member| defense_multiplier_for(t, member) >= Multiplier::Regular))
+        .collect()
+}
+
+/// Returns true if every type that hits `a` super effectively is resisted or nullified by `b`, so `b`
+/// can always switch in on attacks `a` is weak to.
+pub fn covers_weaknesses(a: (Typing, Option<Typing>), b: (Typing, Option<Typing>)) -> bool {
+    let a_profile = defensive_profile(a);
+    let b_profile = defensive_profile(b);
+    a_profile.iter().zip(b_profile.iter())
+        .all(|(&on_a, &on_b)| on_a <= Multiplier::Regular || on_b < Multiplier::Regular)
+}
+
+/// Returns true if each of the two typings covers the other's weaknesses, as described in
+/// `covers_weaknesses`: a perfect defensive core.
+pub fn mutual_coverage(a: (Typing, Option<Typing>), b: (Typing, Option<Typing>)) -> bool {
+    covers_weaknesses(a, b) && covers_weaknesses(b, a)
+}
+
+/// An error for an entry in the type chart that isn't one of the legal multipliers, recording which
+/// matchup it's for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ChartError {
+    pub attacking: Typing,
+    pub defending: Typing,
+}
+
+impl fmt::Display for ChartError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid type chart entry for {:?} attacking {:?}", self.attacking, self.defending)
+    }
+}
+
+impl error::Error for ChartError {
+    fn description(&self) -> &str {
+        "a type chart entry was not 0, 0.25, 0.5, 1, 2, or 4"
+    }
+
+    fn cause(&self) -> Option<&error::Error> {
+        // Generic error, underlying cause isn't tracked.
+        None
+    }
+}
+
+/// Checks that all 324 entries of the type chart are legal multipliers, returning an error for the
+/// first one that isn't. The chart is transcribed by hand, so this guards against a typo like 0.2
+/// sneaking in, which would otherwise only show up as a panic when that matchup is looked up.
+pub fn validate_chart() -> Result<(), ChartError> {
+    let typings = Typing::all_typings();
+    for (i, &multiplier) in TYPE_MULTIPLIERS.iter().enumerate() {
+        if Multiplier::from_num_multiplier(multiplier).is_err() {
+            return Err(ChartError{attacking: typings[i / 18], defending: typings[i % 18]});
+        }
+    }
+    Ok(())
+}
+
+/// Returns the entire type chart as a grid of `Multiplier`s, indexed by
+/// `[attacker.num_code()][defender.num_code()]`. For example, `full_chart()[9][11]` is the multiplier
+/// Fire-type attacks have against Grass types.
+pub fn full_chart() -> [[Multiplier; 18]; 18] {
+    let mut chart = [[Multiplier::Regular; 18]; 18];
+    for (i, row) in TYPE_MULTIPLIERS.chunks(18).enumerate() {
+        for (j, &multiplier) in row.iter().enumerate() {
+            chart[i][j] = Multiplier::from_num_multiplier(multiplier).unwrap();
+        }
+    }
+    chart
+}
+
+/// Returns the entire type chart as a grid of numbers, indexed in the same way as `full_chart`.
+pub fn full_chart_numeric() -> [[f32; 18]; 18] {
+    let mut chart = [[1.0; 18]; 18];
+    for (i, row) in TYPE_MULTIPLIERS.chunks(18).enumerate() {
+        chart[i].copy_from_slice(row);
+    }
+    chart
+}
+
 #[cfg(test)]
 mod tests {
     #[allow(unused_imports)]
@@ -316,6 +720,40 @@ mod tests {
         assert_eq!(Multiplier::DoubleWeakness * Multiplier::Resistance, Multiplier::Weakness);
     }
     #[test]
+    fn test_division() {
+        assert_eq!(Multiplier::DoubleWeakness / Multiplier::Weakness, Multiplier::Weakness);
+        assert_eq!(Multiplier::Regular / Multiplier::Resistance, Multiplier::Weakness);
+        assert_eq!(Multiplier::Resistance / Multiplier::Weakness, Multiplier::DoubleResistance);
+        assert_eq!(Multiplier::Weakness / Multiplier::Weakness, Multiplier::Regular);
+        assert_eq!(Multiplier::DoubleResistance / Multiplier::Weakness, Multiplier::DoubleResistance);
+        assert_eq!(Multiplier::DoubleWeakness / Multiplier::DoubleResistance, Multiplier::DoubleWeakness);
+        assert_eq!((Multiplier::Weakness * Multiplier::Resistance) / Multiplier::Resistance,
+                   Multiplier::Weakness);
+    }
+    #[test]
+    fn test_division_by_immunity() {
+        assert_eq!(Multiplier::Regular / Multiplier::Immunity, Multiplier::DoubleWeakness);
+        assert_eq!(Multiplier::DoubleResistance / Multiplier::Immunity, Multiplier::DoubleWeakness);
+        assert_eq!(Multiplier::Immunity / Multiplier::Immunity, Multiplier::Immunity);
+        assert_eq!(Multiplier::Immunity / Multiplier::Weakness, Multiplier::Immunity);
+    }
+    #[test]
+    fn test_multiplier_strings() {
+        let multipliers = vec![Multiplier::Immunity, Multiplier::DoubleResistance, Multiplier::Resistance,
+                               Multiplier::Regular, Multiplier::Weakness, Multiplier::DoubleWeakness];
+        for mult in multipliers {
+            assert_eq!(Multiplier::from_str(&mult.to_string()).unwrap(), mult);
+        }
+        assert_eq!(&Multiplier::DoubleResistance.to_string(), "x0.25");
+        assert_eq!(&Multiplier::Weakness.to_string(), "x2");
+        assert_eq!(Multiplier::from_str("0.5").unwrap(), Multiplier::Resistance);
+        assert_eq!(Multiplier::from_str("4").unwrap(), Multiplier::DoubleWeakness);
+        assert!(Multiplier::from_str("x3").is_err());
+        assert!(Multiplier::from_str("double").is_err());
+        assert!(Multiplier::from_str("xx2").is_err());
+        assert!(Multiplier::from_str("xxx0.5").is_err());
+    }
+    #[test]
     fn test_offense_multipliers() {
         assert_eq!(Typing::Ground.offense_multiplier(Typing::Flying), Multiplier::Immunity);
         assert_eq!(Typing::Water.offense_multiplier(Typing::Fire), Multiplier::Weakness);
@@ -323,6 +761,14 @@ mod tests {
         assert_eq!(Typing::Fighting.offense_multiplier(Typing::Psychic), Multiplier::Resistance);
     }
     #[test]
+    fn test_inverse_offense_multipliers() {
+        assert_eq!(Typing::Ground.inverse_offense_multiplier(Typing::Flying), Multiplier::Weakness);
+        assert_eq!(Typing::Normal.inverse_offense_multiplier(Typing::Ghost), Multiplier::Weakness);
+        assert_eq!(Typing::Water.inverse_offense_multiplier(Typing::Fire), Multiplier::Resistance);
+        assert_eq!(Typing::Fire.inverse_offense_multiplier(Typing::Water), Multiplier::Weakness);
+        assert_eq!(Typing::Normal.inverse_offense_multiplier(Typing::Grass), Multiplier::Regular);
+    }
+    #[test]
     fn test_defense_multipliers() {
         assert_eq!(Typing::Ghost.defense_multiplier(Typing::Normal), Multiplier::Immunity);
         assert_eq!(Typing::Flying.defense_multiplier(Typing::Electric), Multiplier::Weakness);
@@ -400,4 +846,205 @@ mod tests {
         assert_eq!(Typing::Fighting.combined_effectiveness((Typing::Psychic, Typing::Fairy)),
                    Multiplier::DoubleResistance);
     }
+    #[test]
+    fn test_defense_multiplier_for() {
+        assert_eq!(defense_multiplier_for(Typing::Ground, (Typing::Fire, None)), Multiplier::Weakness);
+        assert_eq!(defense_multiplier_for(Typing::Ground, (Typing::Fire, Some(Typing::Flying))),
+                   Multiplier::Immunity);
+        assert_eq!(defense_multiplier_for(Typing::Water, (Typing::Fire, Some(Typing::Fire))),
+                   Multiplier::Weakness);
+    }
+    #[test]
+    fn test_defensive_profile() {
+        // Ferrothorn, Grass/Steel
+        let profile = defensive_profile((Typing::Grass, Some(Typing::Steel)));
+        assert_eq!(profile[Typing::Fire.num_code() as usize], Multiplier::DoubleWeakness);
+        assert_eq!(profile[Typing::Fighting.num_code() as usize], Multiplier::Weakness);
+        assert_eq!(profile[Typing::Poison.num_code() as usize], Multiplier::Immunity);
+        assert_eq!(profile[Typing::Grass.num_code() as usize], Multiplier::DoubleResistance);
+        assert_eq!(profile[Typing::Dragon.num_code() as usize], Multiplier::Resistance);
+        assert_eq!(profile[Typing::Ground.num_code() as usize], Multiplier::Regular);
+        let resistances: Vec<Typing> = Typing::all_typings().into_iter()
+            .filter(|&t| profile[t.num_code() as usize] == Multiplier::Resistance)
+            .collect();
+        assert_eq!(resistances,
+                   vec![Typing::Normal, Typing::Rock, Typing::Steel, Typing::Water, Typing::Electric,
+                        Typing::Psychic, Typing::Dragon, Typing::Fairy]);
+    }
+    #[test]
+    fn test_all_type_combinations() {
+        let combos = all_type_combinations();
+        assert_eq!(combos.len(), 171);
+        assert_eq!(combos[0], (Typing::Normal, None));
+        assert_eq!(combos[18], (Typing::Normal, Some(Typing::Fighting)));
+        assert!(!combos.contains(&(Typing::Water, Some(Typing::Fire))));
+    }
+    #[test]
+    fn test_coverage() {
+        // Fire and Rock can't touch Swampert, but adding Ground covers everything
+        let uncovered = uncovered_types(&[Typing::Fire, Typing::Rock]);
+        assert!(uncovered.contains(&(Typing::Ground, Some(Typing::Water))));
+        let missed: usize = uncovered.iter()
+            .map(|&(_, typing2)| if typing2.is_some() { 2 } else { 1 })
+            .sum();
+        assert_eq!(coverage_score(&[Typing::Fire, Typing::Rock]), 324 - missed);
+        assert_eq!(uncovered_types(&[Typing::Fire, Typing::Ground, Typing::Rock]), vec![]);
+        assert_eq!(coverage_score(&[Typing::Fire, Typing::Ground, Typing::Rock]), 324);
+
+        // Ice and Electric are famously good together, but still miss some Electric types
+        assert_eq!(uncovered_types(&[Typing::Ice, Typing::Electric]),
+                   vec![(Typing::Steel, Some(Typing::Electric)), (Typing::Fire, Some(Typing::Electric)),
+                        (Typing::Electric, Some(Typing::Ice))]);
+
+        // the only typing Ghost + Fighting doesn't hit is Normal/Ghost, which no Pokemon has in Gen VII
+        assert_eq!(uncovered_types(&[Typing::Ghost, Typing::Fighting]),
+                   vec![(Typing::Normal, Some(Typing::Ghost))]);
+        // Normal/Ghost and Ghost/Normal
+        assert_eq!(coverage_score(&[Typing::Ghost, Typing::Fighting]), 322);
+        assert_eq!(coverage_score(&[]), 0);
+    }
+    #[test]
+    fn test_full_chart() {
+        let chart = full_chart();
+        let fire = Typing::Fire.num_code() as usize;
+        let grass = Typing::Grass.num_code() as usize;
+        assert_eq!(chart[fire][grass], Multiplier::Weakness);
+        assert_eq!(full_chart_numeric()[fire][grass], 2.0);
+        for &(attacker, defender) in &[(Typing::Normal, Typing::Ghost), (Typing::Dragon, Typing::Fairy),
+                                       (Typing::Water, Typing::Water), (Typing::Ground, Typing::Steel),
+                                       (Typing::Bug, Typing::Dark), (Typing::Fairy, Typing::Fighting)] {
+            let cell = chart[attacker.num_code() as usize][defender.num_code() as usize];
+            assert_eq!(cell, attacker.offense_multiplier(defender));
+        }
+    }
+    #[test]
+    fn test_defensive_cores() {
+        // Skarmory and Swampert
+        assert!(mutual_coverage((Typing::Steel, Some(Typing::Flying)), (Typing::Water, Some(Typing::Ground))));
+        // Ferrothorn and Azumarill
+        assert!(mutual_coverage((Typing::Grass, Some(Typing::Steel)), (Typing::Water, Some(Typing::Fairy))));
+        // a pure Fire/Water/Grass triangle resists some of each other's weaknesses, but not all of them
+        assert!(!covers_weaknesses((Typing::Grass, None), (Typing::Fire, None)));
+        assert!(!mutual_coverage((Typing::Water, None), (Typing::Grass, None)));
+    }
+    #[test]
+    fn test_shared_weakness() {
+        // Garchomp and Salamence are both 4x weak to Ice
+        let garchomp = (Typing::Dragon, Some(Typing::Ground));
+        let salamence = (Typing::Dragon, Some(Typing::Flying));
+        assert!(!covers_weaknesses(garchomp, salamence));
+        assert!(!covers_weaknesses(salamence, garchomp));
+        assert!(!mutual_coverage(garchomp, salamence));
+    }
+    #[test]
+    fn test_validate_chart() {
+        assert_eq!(validate_chart(), Ok(()));
+    }
+    #[test]
+    fn test_chart_counts() {
+        // the number of types each type is super effective against, not very effective against, and
+        // has no effect on, in Typing order
+        let counts = [(0, 2, 1), (5, 5, 1), (3, 3, 0), (2, 4, 1), (5, 2, 1), (4, 3, 0),
+                      (3, 7, 0), (2, 1, 1), (3, 4, 0), (4, 4, 0), (3, 3, 0), (3, 7, 0),
+                      (2, 3, 1), (2, 2, 1), (4, 4, 0), (1, 1, 1), (2, 3, 0), (3, 3, 0)];
+        for (t, &(weak, resist, immune)) in Typing::all_typings().into_iter().zip(counts.iter()) {
+            assert_eq!(t.weak_against().len(), weak, "{:?}", t);
+            assert_eq!(t.resistant_against().len(), resist, "{:?}", t);
+            assert_eq!(t.immune_against().len(), immune, "{:?}", t);
+        }
+        // and a couple of well-known defensive profiles
+        assert_eq!(Typing::Normal.weak_to(), vec![Typing::Fighting]);
+        assert_eq!(Typing::Steel.resistant_to().len(), 10);
+    }
+    #[test]
+    fn test_typing_order() {
+        assert!(Typing::Normal < Typing::Fighting);
+        assert!(Typing::Dark < Typing::Fairy);
+        let mut shuffled = Typing::all_typings();
+        shuffled.reverse();
+        shuffled.swap(3, 11);
+        shuffled.swap(0, 7);
+        shuffled.sort();
+        assert_eq!(shuffled, Typing::all_typings());
+        for (a, b) in Typing::all_typings().into_iter().zip(Typing::all_typings().into_iter().skip(1)) {
+            assert_eq!(a.cmp(&b), a.num_code().cmp(&b.num_code()));
+        }
+    }
+    #[test]
+    fn test_weakness_report() {
+        assert_eq!(weakness_report((Typing::Grass, Some(Typing::Steel))),
+                   "x4: Fire; x2: Fighting; x0.5: Normal, Rock, Steel, Water, Electric, Psychic, Dragon, \
+                    Fairy; x0.25: Grass; x0: Poison");
+        assert_eq!(weakness_report((Typing::Normal, None)), "x2: Fighting; x0: Ghost");
+        assert_eq!(Typing::Fairy.to_string(), "Fairy");
+    }
+    #[test]
+    fn test_offense_row() {
+        assert_eq!(Typing::Fire.offense_row()[Typing::Grass.num_code() as usize], 2.0);
+        assert_eq!(Typing::Normal.offense_row()[Typing::Ghost.num_code() as usize], 0.0);
+        for t in Typing::all_typings() {
+            let row: Vec<Multiplier> = t.offense_row().iter()
+                .map(|&m| Multiplier::from_num_multiplier(m).unwrap())
+                .collect();
+            assert_eq!(row, t.offense_multipliers());
+        }
+    }
+    #[test]
+    fn test_typing_names() {
+        for t in Typing::all_typings() {
+            assert_eq!(t.name(), t.to_string());
+        }
+        assert_eq!(Typing::Psychic.name(), "Psychic");
+    }
+    #[test]
+    fn test_defensive_rating() {
+        assert_eq!(defensive_rating((Typing::Normal, None)), 0);
+        assert_eq!(defensive_rating((Typing::Flying, Some(Typing::Steel))), 10);
+        // Abomasnow: 4x weak to Fire and weak to six more types, with only four resistances
+        assert_eq!(defensive_rating((Typing::Grass, Some(Typing::Ice))), -4);
+        let best = best_defensive_typings(10);
+        assert_eq!(best.len(), 10);
+        assert_eq!(best[0], ((Typing::Steel, Some(Typing::Fire)), 11));
+        assert!(best.contains(&((Typing::Flying, Some(Typing::Steel)), 10)));
+        assert!(best.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+        assert!(best.iter().all(|&((_, typing2), _)| typing2.is_some()));
+        let all = best_defensive_typings(1000);
+        assert_eq!(all.len(), 153);
+        assert_eq!(all.last().unwrap().1, -4);
+    }
+    #[test]
+    fn test_fraction_strings() {
+        let multipliers = vec![Multiplier::Immunity, Multiplier::DoubleResistance, Multiplier::Resistance,
+                               Multiplier::Regular, Multiplier::Weakness, Multiplier::DoubleWeakness];
+        for mult in multipliers {
+            assert_eq!(Multiplier::from_fraction_str(mult.as_fraction_str()), Ok(mult));
+        }
+        assert_eq!(Multiplier::DoubleResistance.as_fraction_str(), "¼x");
+        assert_eq!(Multiplier::Resistance.as_fraction_str(), "½x");
+        assert!(Multiplier::from_fraction_str("0.5x").is_err());
+    }
+    #[test]
+    fn test_required_coverage() {
+        // Jirachi, Skarmory, Empoleon, and Magearna
+        let steel_team = [(Typing::Steel, Some(Typing::Psychic)), (Typing::Steel, Some(Typing::Flying)),
+                          (Typing::Water, Some(Typing::Steel)), (Typing::Steel, Some(Typing::Fairy))];
+        assert_eq!(required_coverage(&steel_team),
+                   vec![Typing::Fighting, Typing::Ghost, Typing::Fire, Typing::Electric]);
+        // Gyarados, Sableye, Mega Mawile, Ferrothorn, and Snorlax leave nothing that hits them all
+        let team = [(Typing::Water, Some(Typing::Flying)), (Typing::Dark, Some(Typing::Ghost)),
+                    (Typing::Steel, Some(Typing::Fairy)), (Typing::Grass, Some(Typing::Steel)),
+                    (Typing::Normal, None)];
+        assert_eq!(required_coverage(&team), vec![]);
+    }
+    #[test]
+    fn test_offense_multiplier_gen() {
+        assert_eq!(Typing::Ghost.offense_multiplier_gen(Typing::Steel, 5), Ok(Multiplier::Resistance));
+        assert_eq!(Typing::Ghost.offense_multiplier_gen(Typing::Steel, 7), Ok(Multiplier::Regular));
+        assert_eq!(Typing::Dark.offense_multiplier_gen(Typing::Steel, 4), Ok(Multiplier::Resistance));
+        assert_eq!(Typing::Fire.offense_multiplier_gen(Typing::Grass, 3), Ok(Multiplier::Weakness));
+        assert_eq!(Typing::Ghost.offense_multiplier_gen(Typing::Psychic, 1), Ok(Multiplier::Immunity));
+        assert_eq!(Typing::Fairy.offense_multiplier_gen(Typing::Dragon, 5),
+                   Err(TypingNotInGenerationError{typing: Typing::Fairy, generation: 5}));
+        assert_eq!(Typing::Dragon.offense_multiplier_gen(Typing::Fairy, 6), Ok(Multiplier::Immunity));
+    }
 }
@@ -1,6 +1,13 @@
 //! This file enumerates the possible weather conditions in Pokemon. Harsh sunlight, heavy rain, and
 //! mysterious air currents are all illegal in OU (Groudon and Kyogre's Primal Reversions are both
 //! banned, as is Mega Rayquaza), but for completeness's sake I include them nontheless.
+//!
+//! This file also covers the effects sand and hail have on Pokemon by their type: chip damage at the
+//! end of each turn, and the Special Defense boost Rock types get in sand. It also says which
+//! abilities summon weather when their user switches in.
+
+use crate::ability::Ability;
+use crate::typing::Typing;
 
 /// Each type of weather that can appear in Pokemon. Normal is just the designation for a battle
 /// without any other weather currently in effect.
@@ -25,4 +32,159 @@ impl Weather {
             _ => false,
         }
     }
+
+    /// Returns the number of turns this weather lasts when set, or None if it doesn't run out on its
+    /// own. Normal weather and the special weathers never expire, and the others last 5 turns (8 if
+    /// the user is holding the matching rock, which this doesn't account for).
+    pub fn default_duration(self) -> Option<u8> {
+        if self == Weather::Normal || self.is_special() {
+            None
+        } else {
+            Some(5)
+        }
+    }
+}
+
+/// The weather currently in effect, along with how many more turns it will last. `turns_left` is None
+/// for weather that doesn't expire.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct WeatherState {
+    pub kind: Weather,
+    pub turns_left: Option<u8>,
+}
+
+impl WeatherState {
+    /// Makes a newly-set weather of the given kind. If `extended` is true, the weather was set by a
+    /// Pokemon holding the matching rock (Damp Rock, Heat Rock, etc.) and lasts 8 turns instead of
+    /// 5. Weather that doesn't expire ignores this.
+    pub fn new(kind: Weather, extended: bool) -> WeatherState {
+        let turns_left = match kind.default_duration() {
+            Some(_) if extended => Some(8),
+            duration => duration,
+        };
+        WeatherState{kind, turns_left}
+    }
+
+    /// Counts down a turn at the end of the turn. When the weather runs out, it becomes Normal,
+    /// which never expires.
+    pub fn tick(&mut self) {
+        if let Some(turns) = self.turns_left {
+            if turns <= 1 {
+                *self = WeatherState::new(Weather::Normal, false);
+            } else {
+                self.turns_left = Some(turns - 1);
+            }
+        }
+    }
+}
+
+/// Returns true if the given typing includes the given type.
+fn has_type(typing: (Typing, Option<Typing>), t: Typing) -> bool {
+    typing.0 == t || typing.1 == Some(t)
+}
+
+/// Returns the multiplier sand applies to the Special Defense of a Pokemon with the given typing: 1.5
+/// for Rock types, and 1 for everything else.
+pub fn sandstorm_spd_multiplier(typing: (Typing, Option<Typing>)) -> f64 {
+    if has_type(typing, Typing::Rock) {
+        1.5
+    } else {
+        1.0
+    }
+}
+
+/// Returns the damage sand does at the end of each turn to a Pokemon with the given typing and max
+/// HP: 1/16 of max HP, but at least 1, and nothing at all to Rock, Ground, and Steel types. Abilities
+/// and items that protect from sand, like Overcoat, Sand Veil, Magic Guard, or Safety Goggles, aren't
+/// accounted for here. Unlike terrain, sand hits grounded and airborne Pokemon alike (a Flying type
+/// or Air Balloon holder takes the same chip damage), so there's no need to pass in whether the
+/// Pokemon is grounded.
+pub fn sandstorm_damage(typing: (Typing, Option<Typing>), max_hp: u16) -> u16 {
+    if [Typing::Rock, Typing::Ground, Typing::Steel].iter().any(|&t| has_type(typing, t)) {
+        0
+    } else {
+        (max_hp / 16).max(1)
+    }
+}
+
+/// Returns the damage hail does at the end of each turn to a Pokemon with the given typing and max
+/// HP: 1/16 of max HP, but at least 1, and nothing at all to Ice types. As with `sandstorm_damage`,
+/// protective abilities and items are left to the caller.
+pub fn hail_damage(typing: (Typing, Option<Typing>), max_hp: u16) -> u16 {
+    if has_type(typing, Typing::Ice) {
+        0
+    } else {
+        (max_hp / 16).max(1)
+    }
+}
+
+/// Returns the weather a Pokemon with the given ability sets when it switches in, or None if its
+/// ability doesn't set weather. Drizzle, Drought, Sand Stream, and Snow Warning set their regular
+/// weathers, and Primal Kyogre, Primal Groudon, and Mega Rayquaza's abilities set the special ones.
+/// Whether the weather actually changes is up to the caller: regular weather can't replace a special
+/// weather.
+pub fn weather_on_switch_in(ability: Ability) -> Option<Weather> {
+    match ability {
+        Ability::Drizzle => Some(Weather::Rain),
+        Ability::Drought => Some(Weather::Sun),
+        Ability::SandStream => Some(Weather::Sand),
+        Ability::SnowWarning => Some(Weather::Hail),
+        Ability::PrimordialSea => Some(Weather::HeavyRain),
+        Ability::DesolateLand => Some(Weather::HarshSun),
+        Ability::DeltaStream => Some(Weather::StrongWinds),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn test_sand_expires() {
+        let mut state = WeatherState::new(Weather::Sand, false);
+        for _ in 0..4 {
+            state.tick();
+            assert_eq!(state.kind, Weather::Sand);
+        }
+        state.tick();
+        assert_eq!(state, WeatherState{kind: Weather::Normal, turns_left: None});
+    }
+    #[test]
+    fn test_permanent_weather() {
+        let mut state = WeatherState::new(Weather::HeavyRain, true);
+        assert_eq!(state.turns_left, None);
+        for _ in 0..100 {
+            state.tick();
+        }
+        assert_eq!(state.kind, Weather::HeavyRain);
+        assert_eq!(WeatherState::new(Weather::Rain, true).turns_left, Some(8));
+    }
+    #[test]
+    fn test_sandstorm() {
+        // Tyranitar
+        assert_eq!(sandstorm_spd_multiplier((Typing::Rock, Some(Typing::Dark))), 1.5);
+        assert_eq!(sandstorm_spd_multiplier((Typing::Water, None)), 1.0);
+        assert_eq!(sandstorm_damage((Typing::Rock, Some(Typing::Dark)), 404), 0);
+        assert_eq!(sandstorm_damage((Typing::Ground, Some(Typing::Dragon)), 420), 0);
+        // Vaporeon
+        assert_eq!(sandstorm_damage((Typing::Water, None), 464), 29);
+        assert_eq!(sandstorm_damage((Typing::Bug, Some(Typing::Ghost)), 1), 1);
+    }
+    #[test]
+    fn test_hail() {
+        // Abomasnow
+        assert_eq!(hail_damage((Typing::Grass, Some(Typing::Ice)), 383), 0);
+        // Ferrothorn takes hail damage even though it's immune to sand
+        assert_eq!(hail_damage((Typing::Grass, Some(Typing::Steel)), 352), 22);
+        assert_eq!(sandstorm_damage((Typing::Grass, Some(Typing::Steel)), 352), 0);
+    }
+    #[test]
+    fn test_weather_on_switch_in() {
+        assert_eq!(weather_on_switch_in(Ability::Drought), Some(Weather::Sun));
+        assert_eq!(weather_on_switch_in(Ability::SandStream), Some(Weather::Sand));
+        assert_eq!(weather_on_switch_in(Ability::DeltaStream), Some(Weather::StrongWinds));
+        assert_eq!(weather_on_switch_in(Ability::Intimidate), None);
+    }
 }
@@ -0,0 +1,909 @@
+//! This file defines the weight of every Pokemon species and forme, which determines the power of
+//! moves like Low Kick and Heavy Slam. As in the games, weights are stored in hectograms (tenths of a
+//! kilogram), so they're always whole numbers. The values are current to Gen VII, and use
+//! [Bulbapedia](https://bulbapedia.bulbagarden.net/wiki/List_of_Pokémon_by_weight) as a source.
+//! Formes can have different weights: Mega Evolutions and Alolan formes usually do.
+
+use crate::species::*;
+
+/// Returns the weight of the given species in hectograms: Snorlax weighs 4600, or 460.0 kg.
+pub fn weight_hg(species: Species) -> u32 {
+    match species {
+        // in National Pokedex order
+        Species::Bulbasaur => 69,
+        Species::Ivysaur => 130,
+        Species::Venusaur(MegaEvolution::Normal) => 1000,
+        Species::Venusaur(MegaEvolution::Mega) => 1555,
+        Species::Charmander => 85,
+        Species::Charmeleon => 190,
+        Species::Charizard(XYMegaEvolution::Normal) => 905,
+        Species::Charizard(XYMegaEvolution::MegaX) => 1105,
+        Species::Charizard(XYMegaEvolution::MegaY) => 1005,
+        Species::Squirtle => 90,
+        Species::Wartortle => 225,
+        Species::Blastoise(MegaEvolution::Normal) => 855,
+        Species::Blastoise(MegaEvolution::Mega) => 1011,
+        Species::Caterpie => 29,
+        Species::Metapod => 99,
+        Species::Butterfree => 320,
+        Species::Weedle => 32,
+        Species::Kakuna => 100,
+        Species::Beedrill(MegaEvolution::Normal) => 295,
+        Species::Beedrill(MegaEvolution::Mega) => 405,
+        Species::Pidgey => 18,
+        Species::Pidgeotto => 300,
+        Species::Pidgeot(MegaEvolution::Normal) => 395,
+        Species::Pidgeot(MegaEvolution::Mega) => 505,
+        Species::Rattata(AlolaForme::Normal) => 35,
+        Species::Rattata(AlolaForme::Alola) => 38,
+        Species::Raticate(AlolaForme::Normal) => 185,
+        Species::Raticate(AlolaForme::Alola) => 255,
+        Species::Spearow => 20,
+        Species::Fearow => 380,
+        Species::Ekans => 69,
+        Species::Arbok => 650,
+        Species::Pikachu => 60,
+        Species::Raichu(AlolaForme::Normal) => 300,
+        Species::Raichu(AlolaForme::Alola) => 210,
+        Species::Sandshrew(AlolaForme::Normal) => 120,
+        Species::Sandshrew(AlolaForme::Alola) => 400,
+        Species::Sandslash(AlolaForme::Normal) => 295,
+        Species::Sandslash(AlolaForme::Alola) => 550,
+        Species::NidoranF => 70,
+        Species::Nidorina => 200,
+        Species::Nidoqueen => 600,
+        Species::NidoranM => 90,
+        Species::Nidorino => 195,
+        Species::Nidoking => 620,
+        Species::Clefairy => 75,
+        Species::Clefable => 400,
+        Species::Vulpix(_) => 99,
+        Species::Ninetales(_) => 199,
+        Species::Jigglypuff => 55,
+        Species::Wigglytuff => 120,
+        Species::Zubat => 75,
+        Species::Golbat => 550,
+        Species::Oddish => 54,
+        Species::Gloom => 86,
+        Species::Vileplume => 186,
+        Species::Paras => 54,
+        Species::Parasect => 295,
+        Species::Venonat => 300,
+        Species::Venomoth => 125,
+        Species::Diglett(AlolaForme::Normal) => 8,
+        Species::Diglett(AlolaForme::Alola) => 10,
+        Species::Dugtrio(AlolaForme::Normal) => 333,
+        Species::Dugtrio(AlolaForme::Alola) => 666,
+        Species::Meowth => 42,
+        Species::Persian(AlolaForme::Normal) => 320,
+        Species::Persian(AlolaForme::Alola) => 330,
+        Species::Psyduck => 196,
+        Species::Golduck => 766,
+        Species::Mankey => 280,
+        Species::Primeape => 320,
+        Species::Growlithe => 190,
+        Species::Arcanine => 1550,
+        Species::Poliwag => 124,
+        Species::Poliwhirl => 200,
+        Species::Poliwrath => 540,
+        Species::Abra => 195,
+        Species::Kadabra => 565,
+        Species::Alakazam(_) => 480,
+        Species::Machop => 195,
+        Species::Machoke => 705,
+        Species::Machamp => 1300,
+        Species::Bellsprout => 40,
+        Species::Weepinbell => 64,
+        Species::Victreebel => 155,
+        Species::Tentacool => 455,
+        Species::Tentacruel => 550,
+        Species::Geodude(AlolaForme::Normal) => 200,
+        Species::Geodude(AlolaForme::Alola) => 203,
+        Species::Graveler(AlolaForme::Normal) => 1050,
+        Species::Graveler(AlolaForme::Alola) => 1100,
+        Species::Golem(AlolaForme::Normal) => 3000,
+        Species::Golem(AlolaForme::Alola) => 3160,
+        Species::Ponyta => 300,
+        Species::Rapidash => 950,
+        Species::Slowpoke => 360,
+        Species::Slowbro => 785,
+        Species::Magnemite => 60,
+        Species::Magneton => 600,
+        Species::Farfetchd => 150,
+        Species::Doduo => 392,
+        Species::Dodrio => 852,
+        Species::Seel => 900,
+        Species::Dewgong => 1200,
+        Species::Grimer(AlolaForme::Normal) => 300,
+        Species::Grimer(AlolaForme::Alola) => 420,
+        Species::Muk(AlolaForme::Normal) => 300,
+        Species::Muk(AlolaForme::Alola) => 520,
+        Species::Shellder => 40,
+        Species::Cloyster => 1325,
+        Species::Gastly => 1,
+        Species::Haunter => 1,
+        Species::Gengar(_) => 405,
+        Species::Onix => 2100,
+        Species::Drowzee => 324,
+        Species::Hypno => 756,
+        Species::Krabby => 65,
+        Species::Kingler => 600,
+        Species::Voltorb => 104,
+        Species::Electrode => 666,
+        Species::Exeggcute => 25,
+        Species::Exeggutor(AlolaForme::Normal) => 1200,
+        Species::Exeggutor(AlolaForme::Alola) => 4156,
+        Species::Cubone => 65,
+        Species::Marowak(AlolaForme::Normal) => 450,
+        Species::Marowak(AlolaForme::Alola) => 340,
+        Species::Hitmonlee => 498,
+        Species::Hitmonchan => 502,
+        Species::Lickitung => 655,
+        Species::Koffing => 10,
+        Species::Weezing => 95,
+        Species::Rhyhorn => 1150,
+        Species::Rhydon => 1200,
+        Species::Chansey => 346,
+        Species::Tangela => 350,
+        Species::Kangaskhan(MegaEvolution::Normal) => 800,
+        Species::Kangaskhan(MegaEvolution::Mega) => 1000,
+        Species::Horsea => 80,
+        Species::Seadra => 250,
+        Species::Goldeen => 150,
+        Species::Seaking => 390,
+        Species::Staryu => 345,
+        Species::Starmie => 800,
+        Species::MrMime => 545,
+        Species::Scyther => 560,
+        Species::Jynx => 406,
+        Species::Electabuzz => 300,
+        Species::Magmar => 445,
+        Species::Pinsir(MegaEvolution::Normal) => 550,
+        Species::Pinsir(MegaEvolution::Mega) => 590,
+        Species::Tauros => 884,
+        Species::Magikarp => 100,
+        Species::Gyarados(MegaEvolution::Normal) => 2350,
+        Species::Gyarados(MegaEvolution::Mega) => 3050,
+        Species::Lapras => 2200,
+        Species::Ditto => 40,
+        Species::Eevee => 65,
+        Species::Vaporeon => 290,
+        Species::Jolteon => 245,
+        Species::Flareon => 250,
+        Species::Porygon => 365,
+        Species::Omanyte => 75,
+        Species::Omastar => 350,
+        Species::Kabuto => 115,
+        Species::Kabutops => 405,
+        Species::Aerodactyl(MegaEvolution::Normal) => 590,
+        Species::Aerodactyl(MegaEvolution::Mega) => 790,
+        Species::Snorlax => 4600,
+        Species::Articuno => 554,
+        Species::Zapdos => 526,
+        Species::Moltres => 600,
+        Species::Dratini => 33,
+        Species::Dragonair => 165,
+        Species::Dragonite => 2100,
+        Species::Mewtwo(XYMegaEvolution::Normal) => 1220,
+        Species::Mewtwo(XYMegaEvolution::MegaX) => 1270,
+        Species::Mewtwo(XYMegaEvolution::MegaY) => 330,
+        Species::Mew => 40,
+        // gen 2 starts here
+        Species::Chikorita => 64,
+        Species::Bayleef => 158,
+        Species::Meganium => 1005,
+        Species::Cyndaquil => 79,
+        Species::Quilava => 190,
+        Species::Typhlosion => 795,
+        Species::Totodile => 95,
+        Species::Croconaw => 250,
+        Species::Feraligatr => 888,
+        Species::Sentret => 60,
+        Species::Furret => 325,
+        Species::Hoothoot => 212,
+        Species::Noctowl => 408,
+        Species::Ledyba => 108,
+        Species::Ledian => 356,
+        Species::Spinarak => 85,
+        Species::Ariados => 335,
+        Species::Crobat => 750,
+        Species::Chinchou => 120,
+        Species::Lanturn => 225,
+        Species::Pichu => 20,
+        Species::Cleffa => 30,
+        Species::Igglybuff => 10,
+        Species::Togepi => 15,
+        Species::Togetic => 32,
+        Species::Natu => 20,
+        Species::Xatu => 150,
+        Species::Mareep => 78,
+        Species::Flaaffy => 133,
+        Species::Ampharos => 615,
+        Species::Bellossom => 58,
+        Species::Marill => 85,
+        Species::Azumarill => 285,
+        Species::Sudowoodo => 380,
+        Species::Politoed => 339,
+        Species::Hoppip => 5,
+        Species::Skiploom => 10,
+        Species::Jumpluff => 30,
+        Species::Aipom => 115,
+        Species::Sunkern => 18,
+        Species::Sunflora => 85,
+        Species::Yanma => 380,
+        Species::Wooper => 85,
+        Species::Quagsire => 750,
+        Species::Espeon => 265,
+        Species::Umbreon => 270,
+        Species::Murkrow => 21,
+        Species::Slowking => 795,
+        Species::Misdreavus => 10,
+        Species::Unown => 50,
+        Species::Wobbuffet => 285,
+        Species::Girafarig => 415,
+        Species::Pineco => 72,
+        Species::Forretress => 1258,
+        Species::Dunsparce => 140,
+        Species::Gligar => 648,
+        Species::Steelix(MegaEvolution::Normal) => 4000,
+        Species::Steelix(MegaEvolution::Mega) => 7400,
+        Species::Snubbull => 78,
+        Species::Granbull => 487,
+        Species::Qwilfish => 39,
+        Species::Scizor(MegaEvolution::Normal) => 1180,
+        Species::Scizor(MegaEvolution::Mega) => 1250,
+        Species::Shuckle => 205,
+        Species::Heracross(MegaEvolution::Normal) => 540,
+        Species::Heracross(MegaEvolution::Mega) => 625,
+        Species::Sneasel => 280,
+        Species::Teddiursa => 88,
+        Species::Ursaring => 1258,
+        Species::Slugma => 350,
+        Species::Magcargo => 550,
+        Species::Swinub => 65,
+        Species::Piloswine => 558,
+        Species::Corsola => 50,
+        Species::Remoraid => 120,
+        Species::Octillery => 285,
+        Species::Delibird => 160,
+        Species::Mantine => 2200,
+        Species::Skarmory => 505,
+        Species::Houndour => 108,
+        Species::Houndoom(MegaEvolution::Normal) => 350,
+        Species::Houndoom(MegaEvolution::Mega) => 495,
+        Species::Kingdra => 1520,
+        Species::Phanpy => 335,
+        Species::Donphan => 1200,
+        Species::Porygon2 => 325,
+        Species::Stantler => 712,
+        Species::Smeargle => 580,
+        Species::Tyrogue => 210,
+        Species::Hitmontop => 480,
+        Species::Smoochum => 60,
+        Species::Elekid => 235,
+        Species::Magby => 214,
+        Species::Miltank => 755,
+        Species::Blissey => 468,
+        Species::Raikou => 1780,
+        Species::Entei => 1980,
+        Species::Suicune => 1870,
+        Species::Larvitar => 720,
+        Species::Pupitar => 1520,
+        Species::Tyranitar(MegaEvolution::Normal) => 2020,
+        Species::Tyranitar(MegaEvolution::Mega) => 2550,
+        Species::Lugia => 2160,
+        Species::HoOh => 1990,
+        Species::Celebi => 50,
+        // gen 3 starts here
+        Species::Treecko => 50,
+        Species::Grovyle => 216,
+        Species::Sceptile(MegaEvolution::Normal) => 522,
+        Species::Sceptile(MegaEvolution::Mega) => 552,
+        Species::Torchic => 25,
+        Species::Combusken => 195,
+        Species::Blaziken(_) => 520,
+        Species::Mudkip => 76,
+        Species::Marshtomp => 280,
+        Species::Swampert(MegaEvolution::Normal) => 819,
+        Species::Swampert(MegaEvolution::Mega) => 1020,
+        Species::Poochyena => 136,
+        Species::Mightyena => 370,
+        Species::Zigzagoon => 175,
+        Species::Linoone => 325,
+        Species::Wurmple => 36,
+        Species::Silcoon => 100,
+        Species::Beautifly => 284,
+        Species::Cascoon => 115,
+        Species::Dustox => 316,
+        Species::Lotad => 26,
+        Species::Lombre => 325,
+        Species::Ludicolo => 550,
+        Species::Seedot => 40,
+        Species::Nuzleaf => 280,
+        Species::Shiftry => 596,
+        Species::Taillow => 23,
+        Species::Swellow => 198,
+        Species::Wingull => 95,
+        Species::Pelipper => 280,
+        Species::Ralts => 66,
+        Species::Kirlia => 202,
+        Species::Gardevoir(_) => 484,
+        Species::Surskit => 17,
+        Species::Masquerain => 36,
+        Species::Shroomish => 45,
+        Species::Breloom => 392,
+        Species::Slakoth => 240,
+        Species::Vigoroth => 465,
+        Species::Slaking => 1305,
+        Species::Nincada => 55,
+        Species::Ninjask => 120,
+        Species::Shedinja => 12,
+        Species::Whismur => 163,
+        Species::Loudred => 405,
+        Species::Exploud => 840,
+        Species::Makuhita => 864,
+        Species::Hariyama => 2538,
+        Species::Azurill => 20,
+        Species::Nosepass => 970,
+        Species::Skitty => 110,
+        Species::Delcatty => 326,
+        Species::Sableye(MegaEvolution::Normal) => 110,
+        Species::Sableye(MegaEvolution::Mega) => 1610,
+        Species::Mawile(MegaEvolution::Normal) => 115,
+        Species::Mawile(MegaEvolution::Mega) => 235,
+        Species::Aron => 600,
+        Species::Lairon => 1200,
+        Species::Aggron(MegaEvolution::Normal) => 3600,
+        Species::Aggron(MegaEvolution::Mega) => 3950,
+        Species::Meditite => 112,
+        Species::Medicham(_) => 315,
+        Species::Electrike => 152,
+        Species::Manectric(MegaEvolution::Normal) => 402,
+        Species::Manectric(MegaEvolution::Mega) => 440,
+        Species::Plusle => 42,
+        Species::Minun => 42,
+        Species::Volbeat => 177,
+        Species::Illumise => 177,
+        Species::Roselia => 20,
+        Species::Gulpin => 103,
+        Species::Swalot => 800,
+        Species::Carvanha => 208,
+        Species::Sharpedo(MegaEvolution::Normal) => 888,
+        Species::Sharpedo(MegaEvolution::Mega) => 1303,
+        Species::Wailmer => 1300,
+        Species::Wailord => 3980,
+        Species::Numel => 240,
+        Species::Camerupt(MegaEvolution::Normal) => 2200,
+        Species::Camerupt(MegaEvolution::Mega) => 3205,
+        Species::Torkoal => 804,
+        Species::Spoink => 306,
+        Species::Grumpig => 715,
+        Species::Spinda => 50,
+        Species::Trapinch => 150,
+        Species::Vibrava => 153,
+        Species::Flygon => 820,
+        Species::Cacnea => 513,
+        Species::Cacturne => 774,
+        Species::Swablu => 12,
+        Species::Altaria(_) => 206,
+        Species::Zangoose => 403,
+        Species::Seviper => 525,
+        Species::Lunatone => 1680,
+        Species::Solrock => 1540,
+        Species::Barboach => 19,
+        Species::Whiscash => 236,
+        Species::Corphish => 115,
+        Species::Crawdaunt => 328,
+        Species::Baltoy => 215,
+        Species::Claydol => 1080,
+        Species::Lileep => 238,
+        Species::Cradily => 604,
+        Species::Anorith => 125,
+        Species::Armaldo => 682,
+        Species::Feebas => 74,
+        Species::Milotic => 1620,
+        Species::Castform(_) => 8,
+        Species::Kecleon => 220,
+        Species::Shuppet => 23,
+        Species::Banette => 125,
+        Species::Duskull => 150,
+        Species::Dusclops => 306,
+        Species::Tropius => 1000,
+        Species::Chimecho => 10,
+        Species::Absol => 470,
+        Species::Wynaut => 140,
+        Species::Snorunt => 168,
+        Species::Glalie => 2565,
+        Species::Spheal => 395,
+        Species::Sealeo => 876,
+        Species::Walrein => 1506,
+        Species::Clamperl => 525,
+        Species::Huntail => 270,
+        Species::Gorebyss => 226,
+        Species::Relicanth => 234,
+        Species::Luvdisc => 87,
+        Species::Bagon => 421,
+        Species::Shelgon => 1105,
+        Species::Salamence(MegaEvolution::Normal) => 1026,
+        Species::Salamence(MegaEvolution::Mega) => 1126,
+        Species::Beldum => 952,
+        Species::Metang => 2025,
+        Species::Metagross(MegaEvolution::Normal) => 5500,
+        Species::Metagross(MegaEvolution::Mega) => 9429,
+        Species::Regirock => 2300,
+        Species::Regice => 1750,
+        Species::Registeel => 2050,
+        Species::Latias => 400,
+        Species::Latios => 600,
+        Species::Kyogre(PrimalReversion::Normal) => 3520,
+        Species::Kyogre(PrimalReversion::Primal) => 4300,
+        Species::Groudon(PrimalReversion::Normal) => 9500,
+        Species::Groudon(PrimalReversion::Primal) => 9997,
+        Species::Rayquaza(MegaEvolution::Normal) => 2065,
+        Species::Rayquaza(MegaEvolution::Mega) => 3920,
+        Species::Jirachi => 11,
+        Species::Deoxys(_) => 608,
+        // gen 4 starts here
+        Species::Turtwig => 102,
+        Species::Grotle => 970,
+        Species::Torterra => 3100,
+        Species::Chimchar => 62,
+        Species::Monferno => 220,
+        Species::Infernape => 550,
+        Species::Piplup => 52,
+        Species::Prinplup => 230,
+        Species::Empoleon => 845,
+        Species::Starly => 20,
+        Species::Staravia => 155,
+        Species::Staraptor => 249,
+        Species::Bidoof => 200,
+        Species::Bibarel => 315,
+        Species::Kricketot => 22,
+        Species::Kricketune => 255,
+        Species::Shinx => 95,
+        Species::Luxio => 305,
+        Species::Luxray => 420,
+        Species::Budew => 12,
+        Species::Roserade => 145,
+        Species::Cranidos => 315,
+        Species::Rampardos => 1025,
+        Species::Shieldon => 570,
+        Species::Bastiodon => 1495,
+        Species::Burmy => 34,
+        Species::Wormadam(_) => 65,
+        Species::Mothim => 233,
+        Species::Combee => 55,
+        Species::Vespiquen => 385,
+        Species::Pachirisu => 39,
+        Species::Buizel => 295,
+        Species::Floatzel => 335,
+        Species::Cherubi => 33,
+        Species::Cherrim => 93,
+        Species::Shellos => 63,
+        Species::Gastrodon => 299,
+        Species::Ambipom => 203,
+        Species::Drifloon => 12,
+        Species::Drifblim => 150,
+        Species::Buneary => 55,
+        Species::Lopunny(MegaEvolution::Normal) => 333,
+        Species::Lopunny(MegaEvolution::Mega) => 283,
+        Species::Mismagius => 44,
+        Species::Honchkrow => 273,
+        Species::Glameow => 39,
+        Species::Purugly => 438,
+        Species::Chingling => 6,
+        Species::Stunky => 192,
+        Species::Skuntank => 380,
+        Species::Bronzor => 605,
+        Species::Bronzong => 1870,
+        Species::Bonsly => 150,
+        Species::MimeJr => 130,
+        Species::Happiny => 244,
+        Species::Chatot => 19,
+        Species::Spiritomb => 1080,
+        Species::Gible => 205,
+        Species::Gabite => 560,
+        Species::Garchomp(_) => 950,
+        Species::Munchlax => 1050,
+        Species::Riolu => 202,
+        Species::Lucario(MegaEvolution::Normal) => 540,
+        Species::Lucario(MegaEvolution::Mega) => 575,
+        Species::Hippopotas => 495,
+        Species::Hippowdon => 3000,
+        Species::Skorupi => 120,
+        Species::Drapion => 615,
+        Species::Croagunk => 230,
+        Species::Toxicroak => 444,
+        Species::Carnivine => 270,
+        Species::Finneon => 70,
+        Species::Lumineon => 240,
+        Species::Mantyke => 650,
+        Species::Snover => 505,
+        Species::Abomasnow(MegaEvolution::Normal) => 1355,
+        Species::Abomasnow(MegaEvolution::Mega) => 1850,
+        Species::Weavile => 340,
+        Species::Magnezone => 1800,
+        Species::Lickilicky => 1400,
+        Species::Rhyperior => 2828,
+        Species::Tangrowth => 1286,
+        Species::Electivire => 1386,
+        Species::Magmortar => 680,
+        Species::Togekiss => 380,
+        Species::Yanmega => 515,
+        Species::Leafeon => 255,
+        Species::Glaceon => 259,
+        Species::Gliscor => 425,
+        Species::Mamoswine => 2910,
+        Species::PorygonZ => 340,
+        Species::Gallade(MegaEvolution::Normal) => 520,
+        Species::Gallade(MegaEvolution::Mega) => 564,
+        Species::Probopass => 3400,
+        Species::Dusknoir => 1066,
+        Species::Froslass => 266,
+        Species::Rotom(_) => 3,
+        Species::Uxie => 3,
+        Species::Mesprit => 3,
+        Species::Azelf => 3,
+        Species::Dialga => 6830,
+        Species::Palkia => 3360,
+        Species::Heatran => 4300,
+        Species::Regigigas => 4200,
+        Species::Giratina(GiratinaForme::Altered) => 7500,
+        Species::Giratina(GiratinaForme::Origin) => 6500,
+        Species::Cresselia => 856,
+        Species::Phione => 31,
+        Species::Manaphy => 14,
+        Species::Darkrai => 505,
+        Species::Shaymin(ShayminForme::Land) => 21,
+        Species::Shaymin(ShayminForme::Sky) => 52,
+        Species::Arceus(_) => 3200,
+        // gen 5 starts here
+        Species::Victini => 40,
+        Species::Snivy => 81,
+        Species::Servine => 160,
+        Species::Serperior => 630,
+        Species::Tepig => 99,
+        Species::Pignite => 555,
+        Species::Emboar => 1500,
+        Species::Oshawott => 59,
+        Species::Dewott => 245,
+        Species::Samurott => 946,
+        Species::Patrat => 116,
+        Species::Watchog => 270,
+        Species::Lillipup => 41,
+        Species::Herdier => 147,
+        Species::Stoutland => 610,
+        Species::Purrloin => 101,
+        Species::Liepard => 375,
+        Species::Pansage => 105,
+        Species::Simisage => 305,
+        Species::Pansear => 110,
+        Species::Simisear => 280,
+        Species::Panpour => 135,
+        Species::Simipour => 290,
+        Species::Munna => 233,
+        Species::Musharna => 605,
+        Species::Pidove => 21,
+        Species::Tranquill => 150,
+        Species::Unfezant => 290,
+        Species::Blitzle => 298,
+        Species::Zebstrika => 795,
+        Species::Roggenrola => 180,
+        Species::Boldore => 1020,
+        Species::Gigalith => 2600,
+        Species::Woobat => 21,
+        Species::Swoobat => 105,
+        Species::Drilbur => 85,
+        Species::Excadrill => 404,
+        Species::Audino => 310,
+        Species::Timburr => 125,
+        Species::Gurdurr => 400,
+        Species::Conkeldurr => 870,
+        Species::Tympole => 45,
+        Species::Palpitoad => 170,
+        Species::Seismitoad => 620,
+        Species::Throh => 555,
+        Species::Sawk => 510,
+        Species::Sewaddle => 25,
+        Species::Swadloon => 73,
+        Species::Leavanny => 205,
+        Species::Venipede => 53,
+        Species::Whirlipede => 585,
+        Species::Scolipede => 2005,
+        Species::Cottonee => 6,
+        Species::Whimsicott => 66,
+        Species::Petilil => 66,
+        Species::Lilligant => 163,
+        Species::Basculin => 180,
+        Species::Sandile => 152,
+        Species::Krokorok => 334,
+        Species::Krookodile => 963,
+        Species::Darumaka => 375,
+        Species::Darmanitan(_) => 929,
+        Species::Maractus => 280,
+        Species::Dwebble => 145,
+        Species::Crustle => 2000,
+        Species::Scraggy => 118,
+        Species::Scrafty => 300,
+        Species::Sigilyph => 140,
+        Species::Yamask => 15,
+        Species::Cofagrigus => 765,
+        Species::Tirtouga => 165,
+        Species::Carracosta => 810,
+        Species::Archen => 95,
+        Species::Archeops => 320,
+        Species::Trubbish => 310,
+        Species::Garbodor => 1073,
+        Species::Zorua => 125,
+        Species::Zoroark => 811,
+        Species::Minccino => 58,
+        Species::Cinccino => 75,
+        Species::Gothita => 58,
+        Species::Gothorita => 180,
+        Species::Gothitelle => 440,
+        Species::Solosis => 10,
+        Species::Duosion => 80,
+        Species::Reuniclus => 201,
+        Species::Ducklett => 55,
+        Species::Swanna => 242,
+        Species::Vanillite => 57,
+        Species::Vanillish => 410,
+        Species::Vanilluxe => 575,
+        Species::Deerling => 195,
+        Species::Sawsbuck => 925,
+        Species::Emolga => 50,
+        Species::Karrablast => 59,
+        Species::Escavalier => 330,
+        Species::Foongus => 10,
+        Species::Amoonguss => 105,
+        Species::Frillish => 330,
+        Species::Jellicent => 1350,
+        Species::Alomomola => 316,
+        Species::Joltik => 6,
+        Species::Galvantula => 143,
+        Species::Ferroseed => 188,
+        Species::Ferrothorn => 1100,
+        Species::Klink => 210,
+        Species::Klang => 510,
+        Species::Klinklang => 810,
+        Species::Tynamo => 3,
+        Species::Eelektrik => 220,
+        Species::Eelektross => 805,
+        Species::Elgyem => 90,
+        Species::Beheeyem => 345,
+        Species::Litwick => 31,
+        Species::Lampent => 130,
+        Species::Chandelure => 343,
+        Species::Axew => 180,
+        Species::Fraxure => 360,
+        Species::Haxorus => 1055,
+        Species::Cubchoo => 85,
+        Species::Beartic => 2600,
+        Species::Cryogonal => 1480,
+        Species::Shelmet => 77,
+        Species::Accelgor => 253,
+        Species::Stunfisk => 110,
+        Species::Mienfoo => 200,
+        Species::Mienshao => 355,
+        Species::Druddigon => 1390,
+        Species::Golett => 920,
+        Species::Golurk => 3300,
+        Species::Pawniard => 102,
+        Species::Bisharp => 700,
+        Species::Bouffalant => 946,
+        Species::Rufflet => 105,
+        Species::Braviary => 410,
+        Species::Vullaby => 90,
+        Species::Mandibuzz => 395,
+        Species::Heatmor => 580,
+        Species::Durant => 330,
+        Species::Deino => 173,
+        Species::Zweilous => 500,
+        Species::Hydreigon => 1600,
+        Species::Larvesta => 288,
+        Species::Volcarona => 460,
+        Species::Cobalion => 2500,
+        Species::Terrakion => 2600,
+        Species::Virizion => 2000,
+        Species::Tornadus(_) => 630,
+        Species::Thundurus(_) => 610,
+        Species::Reshiram => 3300,
+        Species::Zekrom => 3450,
+        Species::Landorus(_) => 680,
+        Species::Kyurem(_) => 3250,
+        Species::Keldeo => 485,
+        Species::Meloetta(_) => 65,
+        Species::Genesect => 825,
+        // gen 6 starts here
+        Species::Chespin => 90,
+        Species::Quilladin => 290,
+        Species::Chesnaught => 900,
+        Species::Fennekin => 94,
+        Species::Braixen => 145,
+        Species::Delphox => 390,
+        Species::Froakie => 70,
+        Species::Frogadier => 109,
+        Species::Greninja(_) => 400,
+        Species::Bunnelby => 50,
+        Species::Diggersby => 424,
+        Species::Fletchling => 17,
+        Species::Fletchinder => 160,
+        Species::Talonflame => 245,
+        Species::Scatterbug => 25,
+        Species::Spewpa => 84,
+        Species::Vivillon => 170,
+        Species::Litleo => 135,
+        Species::Pyroar => 815,
+        Species::Flabebe => 1,
+        Species::Floette => 9,
+        Species::Florges => 100,
+        Species::Skiddo => 310,
+        Species::Gogoat => 910,
+        Species::Pancham => 80,
+        Species::Pangoro => 1360,
+        Species::Furfrou => 280,
+        Species::Espurr => 35,
+        Species::Meowstic => 85,
+        Species::Honedge => 20,
+        Species::Doublade => 45,
+        Species::Aegislash(_) => 530,
+        Species::Spritzee => 5,
+        Species::Aromatisse => 155,
+        Species::Swirlix => 35,
+        Species::Slurpuff => 50,
+        Species::Inkay => 35,
+        Species::Malamar => 470,
+        Species::Binacle => 310,
+        Species::Barbaracle => 960,
+        Species::Skrelp => 73,
+        Species::Dragalge => 815,
+        Species::Clauncher => 83,
+        Species::Clawitzer => 353,
+        Species::Helioptile => 60,
+        Species::Heliolisk => 210,
+        Species::Tyrunt => 260,
+        Species::Tyrantrum => 2700,
+        Species::Amaura => 252,
+        Species::Aurorus => 2250,
+        Species::Sylveon => 235,
+        Species::Hawlucha => 215,
+        Species::Dedenne => 22,
+        Species::Carbink => 57,
+        Species::Goomy => 28,
+        Species::Sliggoo => 175,
+        Species::Goodra => 1505,
+        Species::Klefki => 30,
+        Species::Phantump => 70,
+        Species::Trevenant => 710,
+        Species::Pumpkaboo(GourgeistForme::Small) => 35,
+        Species::Pumpkaboo(GourgeistForme::Average) => 50,
+        Species::Pumpkaboo(GourgeistForme::Large) => 75,
+        Species::Pumpkaboo(GourgeistForme::Super) => 150,
+        Species::Gourgeist(GourgeistForme::Small) => 95,
+        Species::Gourgeist(GourgeistForme::Average) => 125,
+        Species::Gourgeist(GourgeistForme::Large) => 140,
+        Species::Gourgeist(GourgeistForme::Super) => 390,
+        Species::Bergmite => 995,
+        Species::Avalugg => 5050,
+        Species::Noibat => 80,
+        Species::Noivern => 850,
+        Species::Xerneas => 2150,
+        Species::Yveltal => 2030,
+        Species::Zygarde(ZygardeForme::TenPercent) => 335,
+        Species::Zygarde(ZygardeForme::FiftyPercent) => 3050,
+        Species::Zygarde(ZygardeForme::Complete) => 6100,
+        Species::Diancie(MegaEvolution::Normal) => 88,
+        Species::Diancie(MegaEvolution::Mega) => 278,
+        Species::Hoopa(HoopaForme::Confined) => 90,
+        Species::Hoopa(HoopaForme::Unbound) => 4900,
+        Species::Volcanion => 1950,
+        // gen 7 starts here
+        Species::Rowlet => 15,
+        Species::Dartrix => 160,
+        Species::Decidueye => 366,
+        Species::Litten => 43,
+        Species::Torracat => 250,
+        Species::Incineroar => 830,
+        Species::Popplio => 75,
+        Species::Brionne => 175,
+        Species::Primarina => 440,
+        Species::Pikipek => 12,
+        Species::Trumbeak => 148,
+        Species::Toucannon => 260,
+        Species::Yungoos => 60,
+        Species::Gumshoos => 142,
+        Species::Grubbin => 44,
+        Species::Charjabug => 105,
+        Species::Vikavolt => 450,
+        Species::Crabrawler => 70,
+        Species::Crabominable => 1800,
+        Species::Oricorio(_) => 34,
+        Species::Cutiefly => 2,
+        Species::Ribombee => 5,
+        Species::Rockruff => 92,
+        Species::Lycanroc(_) => 250,
+        Species::Wishiwashi(WishiwashiForme::School) => 786,
+        Species::Wishiwashi(WishiwashiForme::Solo) => 3,
+        Species::Mareanie => 80,
+        Species::Toxapex => 145,
+        Species::Mudbray => 1100,
+        Species::Mudsdale => 9200,
+        Species::Dewpider => 40,
+        Species::Araquanid => 820,
+        Species::Fomantis => 15,
+        Species::Lurantis => 185,
+        Species::Morelull => 15,
+        Species::Shiinotic => 115,
+        Species::Salandit => 48,
+        Species::Salazzle => 222,
+        Species::Stufful => 68,
+        Species::Bewear => 1350,
+        Species::Bounsweet => 32,
+        Species::Steenee => 82,
+        Species::Tsareena => 214,
+        Species::Comfey => 3,
+        Species::Oranguru => 760,
+        Species::Passimian => 828,
+        Species::Wimpod => 120,
+        Species::Golisopod => 1080,
+        Species::Sandygast => 700,
+        Species::Palossand => 2500,
+        Species::Pyukumuku => 12,
+        Species::TypeNull(_) => 1205,
+        Species::Silvally(_) => 1005,
+        Species::Minior(MiniorForme::Meteor) => 400,
+        Species::Minior(MiniorForme::Core) => 3,
+        Species::Komala => 199,
+        Species::Turtonator => 2120,
+        Species::Togedemaru => 33,
+        Species::Mimikyu(_) => 7,
+        Species::Bruxish => 190,
+        Species::Drampa => 1850,
+        Species::Dhelmise => 2100,
+        Species::JangmoO => 297,
+        Species::HakamoO => 470,
+        Species::KommoO => 782,
+        Species::TapuKoko => 205,
+        Species::TapuLele => 186,
+        Species::TapuBulu => 455,
+        Species::TapuFini => 212,
+        Species::Cosmog => 1,
+        Species::Cosmoem => 9999,
+        Species::Solgaleo => 2300,
+        Species::Lunala => 1200,
+        Species::Nihilego => 555,
+        Species::Buzzwole => 3336,
+        Species::Pheromosa => 250,
+        Species::Xurkitree => 1000,
+        Species::Celesteela => 9999,
+        Species::Kartana => 1,
+        Species::Guzzlord => 8880,
+        Species::Necrozma(NecrozmaForme::Normal) => 2300,
+        Species::Necrozma(NecrozmaForme::DuskMane) => 4600,
+        Species::Necrozma(NecrozmaForme::DuskWings) => 3500,
+        Species::Necrozma(NecrozmaForme::Ultra) => 2300,
+        Species::Magearna => 805,
+        Species::Marshadow => 222,
+        Species::Poipole => 18,
+        Species::Naganadel => 1500,
+        Species::Stakataka => 8200,
+        Species::Blacephalon => 130,
+        Species::Zeraora => 445,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn test_weights() {
+        assert_eq!(weight_hg(Species::Snorlax), 4600);
+        assert_eq!(weight_hg(Species::Flabebe), 1);
+        assert_eq!(weight_hg(Species::Metagross(MegaEvolution::Normal)), 5500);
+        assert_eq!(weight_hg(Species::Metagross(MegaEvolution::Mega)), 9429);
+        assert_eq!(weight_hg(Species::Exeggutor(AlolaForme::Alola)), 4156);
+        assert_eq!(weight_hg(Species::Arceus(ArceusForme::Fire)), weight_hg(Species::Arceus(ArceusForme::Water)));
+    }
+}